@@ -50,7 +50,7 @@ use iced::{
     widget::{canvas, column, container, opaque, row, stack, text, toggler},
 };
 use iced_nodegraph::{
-    Counts, GraphInfo, PinInfo, PinRef, PinStatus, PinStyle, default_pin_style, edge, node,
+    Counts, GraphInfo, PinInfo, PinRef, PinStatus, PinStyle, default_pin_style, node,
 };
 use nodes::NodeType;
 
@@ -239,6 +239,7 @@ impl Application {
                 .on_move(|delta, indices| ApplicationMessage::NodesMoved { delta, indices })
                 .on_select(ApplicationMessage::SelectionChanged)
                 .selection(&self.selected_nodes)
+                .edge_bundling(0.6)
                 .on_pan(|pos, zoom| ApplicationMessage::CameraReport { pos, zoom });
         // The `on_info` frame stream exists only while the stats panel is
         // shown: live per-frame diagnostics force continuous redraws, so with
@@ -255,10 +256,8 @@ impl Application {
             );
         }
 
-        // Add all edges
-        for (from, to) in &self.edges {
-            ng.push_edge(edge!(*from, *to));
-        }
+        // Add all edges in one batch
+        ng.push_edges(self.edges.iter().copied());
 
         // Top-right overlay: the toggle chip, plus the stats panel while shown.
         // `opaque` ensures the overlay claims wheel/click events for its own
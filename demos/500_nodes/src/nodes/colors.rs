@@ -8,7 +8,7 @@
 use iced::Color;
 
 // === Pin Data Type Markers ===
-// These marker types are used with the pin! macro for TypeId-based matching
+// These marker types are the pins' `info`, for TypeId-based matching
 
 /// Scalar float data type marker
 pub struct Float;
@@ -64,8 +64,3 @@ pub const PIN_Y: Color = Color::from_rgb(0.3, 0.9, 0.3); // Green
 
 /// Z component / Blue channel
 pub const PIN_Z: Color = Color::from_rgb(0.3, 0.3, 0.9); // Blue
-
-// === Spacing Constants ===
-
-/// Spacing between pin rows
-pub const SPACING_PIN: f32 = 6.0;
@@ -1,100 +1,32 @@
-use iced::{
-    Length, Theme,
-    widget::{column, container, text},
-};
-use iced_nodegraph::pin;
+use iced::Theme;
 
-use super::colors::{self, SPACING_PIN};
-
-fn title_bar<'a, Message>(
-    title: &'a str,
-    theme: &'a Theme,
-) -> iced::widget::Container<'a, Message, Theme, iced::Renderer>
-where
-    Message: 'a,
-{
-    let palette = theme.extended_palette();
-    container(text(title).size(12).width(Length::Fill))
-        .width(Length::Fill)
-        .padding([2, 8])
-        .style(move |_: &Theme| container::Style {
-            background: None,
-            text_color: Some(palette.background.base.text),
-            ..container::Style::default()
-        })
-}
+use super::colors::{Float, Vec2, Vec3};
+use super::{pin, shader_node};
 
 pub fn time_input_node<'a, Message>(theme: &'a Theme) -> iced::Element<'a, Message>
 where
     Message: Clone + 'a,
 {
-    let pins = column![pin!(
-        Right,
-        0usize,
-        text("t"),
-        Output,
-        ::std::any::TypeId::of::<colors::Float>()
-    ),]
-    .spacing(SPACING_PIN);
-
-    column![title_bar("Time", theme), container(pins).padding([4, 0])]
-        .width(100.0)
-        .into()
+    shader_node("Time", 100.0, theme, &[], &[pin::<Float>("t")])
 }
 
 pub fn uv_input_node<'a, Message>(theme: &'a Theme) -> iced::Element<'a, Message>
 where
     Message: Clone + 'a,
 {
-    let pins = column![pin!(
-        Right,
-        0usize,
-        text("uv"),
-        Output,
-        ::std::any::TypeId::of::<colors::Vec2>()
-    ),]
-    .spacing(SPACING_PIN);
-
-    column![title_bar("UV", theme), container(pins).padding([4, 0])]
-        .width(100.0)
-        .into()
+    shader_node("UV", 100.0, theme, &[], &[pin::<Vec2>("uv")])
 }
 
 pub fn normal_input_node<'a, Message>(theme: &'a Theme) -> iced::Element<'a, Message>
 where
     Message: Clone + 'a,
 {
-    let pins = column![pin!(
-        Right,
-        0usize,
-        text("N"),
-        Output,
-        ::std::any::TypeId::of::<colors::Vec3>()
-    ),]
-    .spacing(SPACING_PIN);
-
-    column![title_bar("Normal", theme), container(pins).padding([4, 0])]
-        .width(100.0)
-        .into()
+    shader_node("Normal", 100.0, theme, &[], &[pin::<Vec3>("N")])
 }
 
 pub fn position_input_node<'a, Message>(theme: &'a Theme) -> iced::Element<'a, Message>
 where
     Message: Clone + 'a,
 {
-    let pins = column![pin!(
-        Right,
-        0usize,
-        text("P"),
-        Output,
-        ::std::any::TypeId::of::<colors::Vec3>()
-    ),]
-    .spacing(SPACING_PIN);
-
-    column![
-        title_bar("Position", theme),
-        container(pins).padding([4, 0])
-    ]
-    .width(100.0)
-    .into()
+    shader_node("Position", 100.0, theme, &[], &[pin::<Vec3>("P")])
 }
@@ -1,247 +1,69 @@
-use iced::{
-    Length, Theme,
-    alignment::Horizontal,
-    widget::{column, container, row, text},
-};
-use iced_nodegraph::pin;
+use iced::Theme;
 
-use super::colors::{self, SPACING_PIN};
-
-fn title_bar<'a, Message>(
-    title: &'a str,
-    theme: &'a Theme,
-) -> iced::widget::Container<'a, Message, Theme, iced::Renderer>
-where
-    Message: 'a,
-{
-    let palette = theme.extended_palette();
-    container(text(title).size(12).width(Length::Fill))
-        .width(Length::Fill)
-        .padding([2, 8])
-        .style(move |_: &Theme| container::Style {
-            background: None,
-            text_color: Some(palette.background.base.text),
-            ..container::Style::default()
-        })
-}
+use super::colors::Float;
+use super::{pin, shader_node};
 
 pub fn add_node<'a, Message>(theme: &'a Theme) -> iced::Element<'a, Message>
 where
     Message: Clone + 'a,
 {
-    let pins = column![
-        row![
-            container(pin!(
-                Left,
-                0usize,
-                text("A"),
-                Input,
-                ::std::any::TypeId::of::<colors::Float>()
-            ))
-            .width(Length::FillPortion(1))
-            .align_x(Horizontal::Left),
-            container(pin!(
-                Right,
-                1usize,
-                text(""),
-                Output,
-                ::std::any::TypeId::of::<colors::Float>()
-            ))
-            .width(Length::FillPortion(1))
-            .align_x(Horizontal::Right),
-        ]
-        .width(Length::Fill),
-        container(pin!(
-            Left,
-            2usize,
-            text("B"),
-            Input,
-            ::std::any::TypeId::of::<colors::Float>()
-        ))
-        .width(Length::Fill)
-        .align_x(Horizontal::Left),
-    ]
-    .spacing(SPACING_PIN);
-
-    column![title_bar("Add", theme), container(pins).padding([4, 0])]
-        .width(130.0)
-        .into()
+    shader_node(
+        "Add",
+        130.0,
+        theme,
+        &[pin::<Float>("A"), pin::<Float>("B")],
+        &[pin::<Float>("")],
+    )
 }
 
 pub fn multiply_node<'a, Message>(theme: &'a Theme) -> iced::Element<'a, Message>
 where
     Message: Clone + 'a,
 {
-    let pins = column![
-        row![
-            container(pin!(
-                Left,
-                0usize,
-                text("A"),
-                Input,
-                ::std::any::TypeId::of::<colors::Float>()
-            ))
-            .width(Length::FillPortion(1))
-            .align_x(Horizontal::Left),
-            container(pin!(
-                Right,
-                1usize,
-                text(""),
-                Output,
-                ::std::any::TypeId::of::<colors::Float>()
-            ))
-            .width(Length::FillPortion(1))
-            .align_x(Horizontal::Right),
-        ]
-        .width(Length::Fill),
-        container(pin!(
-            Left,
-            2usize,
-            text("B"),
-            Input,
-            ::std::any::TypeId::of::<colors::Float>()
-        ))
-        .width(Length::Fill)
-        .align_x(Horizontal::Left),
-    ]
-    .spacing(SPACING_PIN);
-
-    column![
-        title_bar("Multiply", theme),
-        container(pins).padding([4, 0])
-    ]
-    .width(130.0)
-    .into()
+    shader_node(
+        "Multiply",
+        130.0,
+        theme,
+        &[pin::<Float>("A"), pin::<Float>("B")],
+        &[pin::<Float>("")],
+    )
 }
 
 pub fn divide_node<'a, Message>(theme: &'a Theme) -> iced::Element<'a, Message>
 where
     Message: Clone + 'a,
 {
-    let pins = column![
-        row![
-            container(pin!(
-                Left,
-                0usize,
-                text("A"),
-                Input,
-                ::std::any::TypeId::of::<colors::Float>()
-            ))
-            .width(Length::FillPortion(1))
-            .align_x(Horizontal::Left),
-            container(pin!(
-                Right,
-                1usize,
-                text(""),
-                Output,
-                ::std::any::TypeId::of::<colors::Float>()
-            ))
-            .width(Length::FillPortion(1))
-            .align_x(Horizontal::Right),
-        ]
-        .width(Length::Fill),
-        container(pin!(
-            Left,
-            2usize,
-            text("B"),
-            Input,
-            ::std::any::TypeId::of::<colors::Float>()
-        ))
-        .width(Length::Fill)
-        .align_x(Horizontal::Left),
-    ]
-    .spacing(SPACING_PIN);
-
-    column![title_bar("Divide", theme), container(pins).padding([4, 0])]
-        .width(130.0)
-        .into()
+    shader_node(
+        "Divide",
+        130.0,
+        theme,
+        &[pin::<Float>("A"), pin::<Float>("B")],
+        &[pin::<Float>("")],
+    )
 }
 
 pub fn subtract_node<'a, Message>(theme: &'a Theme) -> iced::Element<'a, Message>
 where
     Message: Clone + 'a,
 {
-    let pins = column![
-        row![
-            container(pin!(
-                Left,
-                0usize,
-                text("A"),
-                Input,
-                ::std::any::TypeId::of::<colors::Float>()
-            ))
-            .width(Length::FillPortion(1))
-            .align_x(Horizontal::Left),
-            container(pin!(
-                Right,
-                1usize,
-                text(""),
-                Output,
-                ::std::any::TypeId::of::<colors::Float>()
-            ))
-            .width(Length::FillPortion(1))
-            .align_x(Horizontal::Right),
-        ]
-        .width(Length::Fill),
-        container(pin!(
-            Left,
-            2usize,
-            text("B"),
-            Input,
-            ::std::any::TypeId::of::<colors::Float>()
-        ))
-        .width(Length::Fill)
-        .align_x(Horizontal::Left),
-    ]
-    .spacing(SPACING_PIN);
-
-    column![
-        title_bar("Subtract", theme),
-        container(pins).padding([4, 0])
-    ]
-    .width(130.0)
-    .into()
+    shader_node(
+        "Subtract",
+        130.0,
+        theme,
+        &[pin::<Float>("A"), pin::<Float>("B")],
+        &[pin::<Float>("")],
+    )
 }
 
 pub fn power_node<'a, Message>(theme: &'a Theme) -> iced::Element<'a, Message>
 where
     Message: Clone + 'a,
 {
-    let pins = column![
-        row![
-            container(pin!(
-                Left,
-                0usize,
-                text("val"),
-                Input,
-                ::std::any::TypeId::of::<colors::Float>()
-            ))
-            .width(Length::FillPortion(1))
-            .align_x(Horizontal::Left),
-            container(pin!(
-                Right,
-                1usize,
-                text(""),
-                Output,
-                ::std::any::TypeId::of::<colors::Float>()
-            ))
-            .width(Length::FillPortion(1))
-            .align_x(Horizontal::Right),
-        ]
-        .width(Length::Fill),
-        container(pin!(
-            Left,
-            2usize,
-            text("exp"),
-            Input,
-            ::std::any::TypeId::of::<colors::Float>()
-        ))
-        .width(Length::Fill)
-        .align_x(Horizontal::Left),
-    ]
-    .spacing(SPACING_PIN);
-
-    column![title_bar("Power", theme), container(pins).padding([4, 0])]
-        .width(130.0)
-        .into()
+    shader_node(
+        "Power",
+        130.0,
+        theme,
+        &[pin::<Float>("val"), pin::<Float>("exp")],
+        &[pin::<Float>("")],
+    )
 }
@@ -6,7 +6,50 @@ mod output;
 mod texture;
 mod vector;
 
-use iced::Theme;
+use std::any::TypeId;
+
+use demo_common::{PinSpec, pin_rows};
+use iced::{
+    Length, Theme,
+    widget::{column, container, text},
+};
+
+/// A pin labelled `label` whose `info` is the data-type marker `T`, which
+/// `pin_style` colors by.
+fn pin<T: 'static>(label: &str) -> PinSpec<TypeId> {
+    PinSpec::new(label, TypeId::of::<T>())
+}
+
+/// A node titled `title`, `width` wide, pairing `inputs` with `outputs` in
+/// rows. Pin ids put inputs first (`0..n`), then outputs (`n..`), which the
+/// `input_pin` / `output_pin` tables below follow.
+fn shader_node<'a, Message>(
+    title: &'a str,
+    width: f32,
+    theme: &'a Theme,
+    inputs: &[PinSpec<TypeId>],
+    outputs: &[PinSpec<TypeId>],
+) -> iced::Element<'a, Message>
+where
+    Message: Clone + 'a,
+{
+    let palette = theme.extended_palette();
+    let title_bar = container(text(title).size(12).width(Length::Fill))
+        .width(Length::Fill)
+        .padding([2, 8])
+        .style(move |_: &Theme| container::Style {
+            background: None,
+            text_color: Some(palette.background.base.text),
+            ..container::Style::default()
+        });
+
+    column![
+        title_bar,
+        container(pin_rows(inputs, outputs)).padding([4, 0])
+    ]
+    .width(width)
+    .into()
+}
 
 /// All available node types in the shader graph.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -56,23 +99,27 @@ impl NodeType {
             // Input nodes: output at pin 0
             Self::TimeInput | Self::UVInput | Self::NormalInput | Self::PositionInput => Some(0),
 
-            // Most processing nodes: output at pin 1
+            // Single-input nodes: output after the input, at pin 1
             Self::PerlinNoise
             | Self::VoronoiNoise
             | Self::SimplexNoise
-            | Self::Add
+            | Self::Normalize
+            | Self::Sampler2D
+            | Self::Gradient
+            | Self::VectorSplit => Some(1),
+
+            // Dual-input nodes: output after both inputs, at pin 2
+            Self::Add
             | Self::Multiply
             | Self::Divide
             | Self::Subtract
             | Self::Power
-            | Self::VectorCombine
-            | Self::Normalize
-            | Self::DotProduct
-            | Self::CrossProduct
-            | Self::Sampler2D
             | Self::ColorMix
-            | Self::Gradient
-            | Self::VectorSplit => Some(1),
+            | Self::DotProduct
+            | Self::CrossProduct => Some(2),
+
+            // VectorCombine: output after x, y, z
+            Self::VectorCombine => Some(3),
 
             // Output nodes: no output
             Self::BaseColor | Self::Roughness | Self::Metallic | Self::Emission | Self::Normal => {
@@ -102,7 +149,7 @@ impl NodeType {
                 }
             }
 
-            // Dual-input nodes: slot 0 -> pin 0, slot 1 -> pin 2
+            // Dual-input nodes: slot n -> pin n
             Self::Add
             | Self::Multiply
             | Self::Divide
@@ -110,19 +157,10 @@ impl NodeType {
             | Self::Power
             | Self::ColorMix
             | Self::DotProduct
-            | Self::CrossProduct => match slot {
-                0 => Some(0),
-                1 => Some(2),
-                _ => None,
-            },
-
-            // VectorCombine: x=0, y=2, z=3
-            Self::VectorCombine => match slot {
-                0 => Some(0),
-                1 => Some(2),
-                2 => Some(3),
-                _ => None,
-            },
+            | Self::CrossProduct => (slot < 2).then_some(slot),
+
+            // VectorCombine: x=0, y=1, z=2
+            Self::VectorCombine => (slot < 3).then_some(slot),
 
             // Output nodes: single input at pin 0
             Self::BaseColor | Self::Roughness | Self::Metallic | Self::Emission | Self::Normal => {
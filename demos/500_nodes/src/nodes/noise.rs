@@ -1,119 +1,43 @@
-use iced::{
-    Length, Theme,
-    alignment::Horizontal,
-    widget::{column, container, row, text},
-};
-use iced_nodegraph::pin;
+use iced::Theme;
 
-use super::colors::{self};
-
-fn title_bar<'a, Message>(
-    title: &'a str,
-    theme: &'a Theme,
-) -> iced::widget::Container<'a, Message, Theme, iced::Renderer>
-where
-    Message: 'a,
-{
-    let palette = theme.extended_palette();
-    container(text(title).size(12).width(Length::Fill))
-        .width(Length::Fill)
-        .padding([2, 8])
-        .style(move |_: &Theme| container::Style {
-            background: None,
-            text_color: Some(palette.background.base.text),
-            ..container::Style::default()
-        })
-}
+use super::colors::{Float, Vec2};
+use super::{pin, shader_node};
 
 pub fn perlin_noise_node<'a, Message>(theme: &'a Theme) -> iced::Element<'a, Message>
 where
     Message: Clone + 'a,
 {
-    let pins = row![
-        container(pin!(
-            Left,
-            0usize,
-            text("uv"),
-            Input,
-            ::std::any::TypeId::of::<colors::Vec2>()
-        ))
-        .width(Length::FillPortion(1))
-        .align_x(Horizontal::Left),
-        container(pin!(
-            Right,
-            1usize,
-            text("out"),
-            Output,
-            ::std::any::TypeId::of::<colors::Float>()
-        ))
-        .width(Length::FillPortion(1))
-        .align_x(Horizontal::Right),
-    ]
-    .width(Length::Fill);
-
-    column![title_bar("Perlin", theme), container(pins).padding([4, 0])]
-        .width(130.0)
-        .into()
+    shader_node(
+        "Perlin",
+        130.0,
+        theme,
+        &[pin::<Vec2>("uv")],
+        &[pin::<Float>("out")],
+    )
 }
 
 pub fn voronoi_noise_node<'a, Message>(theme: &'a Theme) -> iced::Element<'a, Message>
 where
     Message: Clone + 'a,
 {
-    let pins = row![
-        container(pin!(
-            Left,
-            0usize,
-            text("uv"),
-            Input,
-            ::std::any::TypeId::of::<colors::Vec2>()
-        ))
-        .width(Length::FillPortion(1))
-        .align_x(Horizontal::Left),
-        container(pin!(
-            Right,
-            1usize,
-            text("out"),
-            Output,
-            ::std::any::TypeId::of::<colors::Float>()
-        ))
-        .width(Length::FillPortion(1))
-        .align_x(Horizontal::Right),
-    ]
-    .width(Length::Fill);
-
-    column![title_bar("Voronoi", theme), container(pins).padding([4, 0])]
-        .width(130.0)
-        .into()
+    shader_node(
+        "Voronoi",
+        130.0,
+        theme,
+        &[pin::<Vec2>("uv")],
+        &[pin::<Float>("out")],
+    )
 }
 
 pub fn simplex_noise_node<'a, Message>(theme: &'a Theme) -> iced::Element<'a, Message>
 where
     Message: Clone + 'a,
 {
-    let pins = row![
-        container(pin!(
-            Left,
-            0usize,
-            text("uv"),
-            Input,
-            ::std::any::TypeId::of::<colors::Vec2>()
-        ))
-        .width(Length::FillPortion(1))
-        .align_x(Horizontal::Left),
-        container(pin!(
-            Right,
-            1usize,
-            text("out"),
-            Output,
-            ::std::any::TypeId::of::<colors::Float>()
-        ))
-        .width(Length::FillPortion(1))
-        .align_x(Horizontal::Right),
-    ]
-    .width(Length::Fill);
-
-    column![title_bar("Simplex", theme), container(pins).padding([4, 0])]
-        .width(130.0)
-        .into()
+    shader_node(
+        "Simplex",
+        130.0,
+        theme,
+        &[pin::<Vec2>("uv")],
+        &[pin::<Float>("out")],
+    )
 }
@@ -1,127 +1,39 @@
-use iced::{
-    Length, Theme,
-    widget::{column, container, text},
-};
-use iced_nodegraph::pin;
+use iced::Theme;
 
-use super::colors::{self, SPACING_PIN};
-
-fn title_bar<'a, Message>(
-    title: &'a str,
-    theme: &'a Theme,
-) -> iced::widget::Container<'a, Message, Theme, iced::Renderer>
-where
-    Message: 'a,
-{
-    let palette = theme.extended_palette();
-    container(text(title).size(12).width(Length::Fill))
-        .width(Length::Fill)
-        .padding([2, 8])
-        .style(move |_: &Theme| container::Style {
-            background: None,
-            text_color: Some(palette.background.base.text),
-            ..container::Style::default()
-        })
-}
+use super::colors::{Float, Vec3, Vec4};
+use super::{pin, shader_node};
 
 pub fn base_color_node<'a, Message>(theme: &'a Theme) -> iced::Element<'a, Message>
 where
     Message: Clone + 'a,
 {
-    let pins = column![pin!(
-        Left,
-        0usize,
-        text("rgba"),
-        Input,
-        ::std::any::TypeId::of::<colors::Vec4>()
-    ),]
-    .spacing(SPACING_PIN);
-
-    column![
-        title_bar("Base Color", theme),
-        container(pins).padding([4, 0])
-    ]
-    .width(140.0)
-    .into()
+    shader_node("Base Color", 140.0, theme, &[pin::<Vec4>("rgba")], &[])
 }
 
 pub fn roughness_node<'a, Message>(theme: &'a Theme) -> iced::Element<'a, Message>
 where
     Message: Clone + 'a,
 {
-    let pins = column![pin!(
-        Left,
-        0usize,
-        text("in"),
-        Input,
-        ::std::any::TypeId::of::<colors::Float>()
-    ),]
-    .spacing(SPACING_PIN);
-
-    column![
-        title_bar("Roughness", theme),
-        container(pins).padding([4, 0])
-    ]
-    .width(140.0)
-    .into()
+    shader_node("Roughness", 140.0, theme, &[pin::<Float>("in")], &[])
 }
 
 pub fn metallic_node<'a, Message>(theme: &'a Theme) -> iced::Element<'a, Message>
 where
     Message: Clone + 'a,
 {
-    let pins = column![pin!(
-        Left,
-        0usize,
-        text("in"),
-        Input,
-        ::std::any::TypeId::of::<colors::Float>()
-    ),]
-    .spacing(SPACING_PIN);
-
-    column![
-        title_bar("Metallic", theme),
-        container(pins).padding([4, 0])
-    ]
-    .width(140.0)
-    .into()
+    shader_node("Metallic", 140.0, theme, &[pin::<Float>("in")], &[])
 }
 
 pub fn emission_node<'a, Message>(theme: &'a Theme) -> iced::Element<'a, Message>
 where
     Message: Clone + 'a,
 {
-    let pins = column![pin!(
-        Left,
-        0usize,
-        text("rgba"),
-        Input,
-        ::std::any::TypeId::of::<colors::Vec4>()
-    ),]
-    .spacing(SPACING_PIN);
-
-    column![
-        title_bar("Emission", theme),
-        container(pins).padding([4, 0])
-    ]
-    .width(140.0)
-    .into()
+    shader_node("Emission", 140.0, theme, &[pin::<Vec4>("rgba")], &[])
 }
 
 pub fn normal_output_node<'a, Message>(theme: &'a Theme) -> iced::Element<'a, Message>
 where
     Message: Clone + 'a,
 {
-    let pins = column![pin!(
-        Left,
-        0usize,
-        text("N"),
-        Input,
-        ::std::any::TypeId::of::<colors::Vec3>()
-    ),]
-    .spacing(SPACING_PIN);
-
-    column![title_bar("Normal", theme), container(pins).padding([4, 0])]
-        .width(140.0)
-        .into()
+    shader_node("Normal", 140.0, theme, &[pin::<Vec3>("N")], &[])
 }
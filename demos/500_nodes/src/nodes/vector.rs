@@ -1,250 +1,69 @@
-use iced::{
-    Length, Theme,
-    alignment::Horizontal,
-    widget::{column, container, row, text},
-};
-use iced_nodegraph::pin;
+use iced::Theme;
 
-use super::colors::{self, SPACING_PIN};
-
-fn title_bar<'a, Message>(
-    title: &'a str,
-    theme: &'a Theme,
-) -> iced::widget::Container<'a, Message, Theme, iced::Renderer>
-where
-    Message: 'a,
-{
-    let palette = theme.extended_palette();
-    container(text(title).size(12).width(Length::Fill))
-        .width(Length::Fill)
-        .padding([2, 8])
-        .style(move |_: &Theme| container::Style {
-            background: None,
-            text_color: Some(palette.background.base.text),
-            ..container::Style::default()
-        })
-}
+use super::colors::{Float, Vec3};
+use super::{pin, shader_node};
 
 pub fn vector_split_node<'a, Message>(theme: &'a Theme) -> iced::Element<'a, Message>
 where
     Message: Clone + 'a,
 {
-    let pins = column![
-        row![
-            container(pin!(
-                Left,
-                0usize,
-                text("vec"),
-                Input,
-                ::std::any::TypeId::of::<colors::Vec3>()
-            ))
-            .width(Length::FillPortion(1))
-            .align_x(Horizontal::Left),
-            container(pin!(
-                Right,
-                1usize,
-                text("x"),
-                Output,
-                ::std::any::TypeId::of::<colors::Float>()
-            ))
-            .width(Length::FillPortion(1))
-            .align_x(Horizontal::Right),
-        ]
-        .width(Length::Fill),
-        container(pin!(
-            Right,
-            2usize,
-            text("y"),
-            Output,
-            ::std::any::TypeId::of::<colors::Float>()
-        ))
-        .width(Length::Fill)
-        .align_x(Horizontal::Right),
-        container(pin!(
-            Right,
-            3usize,
-            text("z"),
-            Output,
-            ::std::any::TypeId::of::<colors::Float>()
-        ))
-        .width(Length::Fill)
-        .align_x(Horizontal::Right),
-    ]
-    .spacing(SPACING_PIN);
-
-    column![title_bar("Split", theme), container(pins).padding([4, 0])]
-        .width(130.0)
-        .into()
+    shader_node(
+        "Split",
+        130.0,
+        theme,
+        &[pin::<Vec3>("vec")],
+        &[pin::<Float>("x"), pin::<Float>("y"), pin::<Float>("z")],
+    )
 }
 
 pub fn vector_combine_node<'a, Message>(theme: &'a Theme) -> iced::Element<'a, Message>
 where
     Message: Clone + 'a,
 {
-    let pins = column![
-        row![
-            container(pin!(
-                Left,
-                0usize,
-                text("x"),
-                Input,
-                ::std::any::TypeId::of::<colors::Float>()
-            ))
-            .width(Length::FillPortion(1))
-            .align_x(Horizontal::Left),
-            container(pin!(
-                Right,
-                1usize,
-                text("vec"),
-                Output,
-                ::std::any::TypeId::of::<colors::Vec3>()
-            ))
-            .width(Length::FillPortion(1))
-            .align_x(Horizontal::Right),
-        ]
-        .width(Length::Fill),
-        container(pin!(
-            Left,
-            2usize,
-            text("y"),
-            Input,
-            ::std::any::TypeId::of::<colors::Float>()
-        ))
-        .width(Length::Fill)
-        .align_x(Horizontal::Left),
-        container(pin!(
-            Left,
-            3usize,
-            text("z"),
-            Input,
-            ::std::any::TypeId::of::<colors::Float>()
-        ))
-        .width(Length::Fill)
-        .align_x(Horizontal::Left),
-    ]
-    .spacing(SPACING_PIN);
-
-    column![title_bar("Combine", theme), container(pins).padding([4, 0])]
-        .width(130.0)
-        .into()
+    shader_node(
+        "Combine",
+        130.0,
+        theme,
+        &[pin::<Float>("x"), pin::<Float>("y"), pin::<Float>("z")],
+        &[pin::<Vec3>("vec")],
+    )
 }
 
 pub fn normalize_node<'a, Message>(theme: &'a Theme) -> iced::Element<'a, Message>
 where
     Message: Clone + 'a,
 {
-    let pins = row![
-        container(pin!(
-            Left,
-            0usize,
-            text("in"),
-            Input,
-            ::std::any::TypeId::of::<colors::Vec3>()
-        ))
-        .width(Length::FillPortion(1))
-        .align_x(Horizontal::Left),
-        container(pin!(
-            Right,
-            1usize,
-            text("out"),
-            Output,
-            ::std::any::TypeId::of::<colors::Vec3>()
-        ))
-        .width(Length::FillPortion(1))
-        .align_x(Horizontal::Right),
-    ]
-    .width(Length::Fill);
-
-    column![
-        title_bar("Normalize", theme),
-        container(pins).padding([4, 0])
-    ]
-    .width(130.0)
-    .into()
+    shader_node(
+        "Normalize",
+        130.0,
+        theme,
+        &[pin::<Vec3>("in")],
+        &[pin::<Vec3>("out")],
+    )
 }
 
 pub fn dot_product_node<'a, Message>(theme: &'a Theme) -> iced::Element<'a, Message>
 where
     Message: Clone + 'a,
 {
-    let pins = column![
-        row![
-            container(pin!(
-                Left,
-                0usize,
-                text("A"),
-                Input,
-                ::std::any::TypeId::of::<colors::Vec3>()
-            ))
-            .width(Length::FillPortion(1))
-            .align_x(Horizontal::Left),
-            container(pin!(
-                Right,
-                1usize,
-                text("out"),
-                Output,
-                ::std::any::TypeId::of::<colors::Float>()
-            ))
-            .width(Length::FillPortion(1))
-            .align_x(Horizontal::Right),
-        ]
-        .width(Length::Fill),
-        container(pin!(
-            Left,
-            2usize,
-            text("B"),
-            Input,
-            ::std::any::TypeId::of::<colors::Vec3>()
-        ))
-        .width(Length::Fill)
-        .align_x(Horizontal::Left),
-    ]
-    .spacing(SPACING_PIN);
-
-    column![title_bar("Dot", theme), container(pins).padding([4, 0])]
-        .width(130.0)
-        .into()
+    shader_node(
+        "Dot",
+        130.0,
+        theme,
+        &[pin::<Vec3>("A"), pin::<Vec3>("B")],
+        &[pin::<Float>("out")],
+    )
 }
 
 pub fn cross_product_node<'a, Message>(theme: &'a Theme) -> iced::Element<'a, Message>
 where
     Message: Clone + 'a,
 {
-    let pins = column![
-        row![
-            container(pin!(
-                Left,
-                0usize,
-                text("A"),
-                Input,
-                ::std::any::TypeId::of::<colors::Vec3>()
-            ))
-            .width(Length::FillPortion(1))
-            .align_x(Horizontal::Left),
-            container(pin!(
-                Right,
-                1usize,
-                text("out"),
-                Output,
-                ::std::any::TypeId::of::<colors::Vec3>()
-            ))
-            .width(Length::FillPortion(1))
-            .align_x(Horizontal::Right),
-        ]
-        .width(Length::Fill),
-        container(pin!(
-            Left,
-            2usize,
-            text("B"),
-            Input,
-            ::std::any::TypeId::of::<colors::Vec3>()
-        ))
-        .width(Length::Fill)
-        .align_x(Horizontal::Left),
-    ]
-    .spacing(SPACING_PIN);
-
-    column![title_bar("Cross", theme), container(pins).padding([4, 0])]
-        .width(130.0)
-        .into()
+    shader_node(
+        "Cross",
+        130.0,
+        theme,
+        &[pin::<Vec3>("A"), pin::<Vec3>("B")],
+        &[pin::<Vec3>("out")],
+    )
 }
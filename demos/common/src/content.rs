@@ -2,7 +2,12 @@
 //!
 //! [`NodeContentStyle`] derives title/body colors from the active iced theme so
 //! demo nodes look consistent across every built-in theme, and [`simple_node`]
-//! stacks a themed title bar over a body. These are demo conveniences, not part
+//! stacks a themed title bar over a body ([`simple_node_with_pins`] fills the
+//! body with labelled input/output pins, laid out by [`pin_rows`] or, one
+//! per line, [`pin_list`]). [`node_label`] and
+//! [`node_separator`] resolve their colors from the theme they are drawn
+//! with, so section labels and divider lines stay legible on light and dark
+//! themes alike. These are demo conveniences, not part
 //! of the `iced_nodegraph` widget: the widget renders the node fill/border/pins;
 //! the app decides what goes inside. The title bar is built with the library's
 //! [`node_header`](iced_nodegraph::node_header) so its rounded top corners match
@@ -10,9 +15,10 @@
 
 use iced::{
     Color, Element, Length, Padding, Theme,
-    widget::{column, container, text},
+    alignment::Horizontal,
+    widget::{Column, Container, Text, column, container, row, text},
 };
-use iced_nodegraph::node_header;
+use iced_nodegraph::{PinDirection, PinSide, node_header, node_pin};
 
 /// Default node corner radius. Kept in sync with the widget's `default_node_style`
 /// so a `simple_node` lines up with the rendered fill (asserted in tests).
@@ -86,6 +92,31 @@ impl NodeContentStyle {
         }
     }
 
+    /// Creates a neutral node style from the theme's background palette.
+    ///
+    /// The fallback for interiors without a role color; [`node_label`] and
+    /// [`node_separator`] use it to pick their colors.
+    pub fn from_theme(theme: &Theme) -> Self {
+        Self::from_accent(theme.extended_palette().background.strong.color, theme)
+    }
+
+    /// Muted text color for secondary labels: the body text at reduced alpha,
+    /// so it keeps the theme's contrast direction.
+    pub fn label_text(&self) -> Color {
+        Color {
+            a: self.body_text.a * 0.65,
+            ..self.body_text
+        }
+    }
+
+    /// Color for thin divider lines: a faint wash of the body text color.
+    pub fn separator(&self) -> Color {
+        Color {
+            a: self.body_text.a * 0.15,
+            ..self.body_text
+        }
+    }
+
     /// Creates an input node style derived from theme's primary color.
     pub fn input(theme: &Theme) -> Self {
         Self::from_accent(theme.extended_palette().primary.base.color, theme)
@@ -211,6 +242,318 @@ where
     .into()
 }
 
+/// One pin for [`simple_node_with_pins`]: its label, an optional label
+/// color, and the `info` payload the node's `pin_style` and the graph's
+/// `can_connect` see.
+///
+/// `color` tints the label only. The pin dot carries no style of its own,
+/// so key the node's `pin_style` on `info` (a type marker, or the color
+/// itself) to color it to match.
+#[derive(Debug, Clone)]
+pub struct PinSpec<UI> {
+    /// Text shown next to the pin.
+    pub label: String,
+    /// Label color; `None` keeps the body text color.
+    pub color: Option<Color>,
+    /// User payload attached with `NodePin::info`.
+    pub info: UI,
+}
+
+impl<UI> PinSpec<UI> {
+    /// A pin labelled `label` carrying `info`, in the body text color.
+    pub fn new(label: impl Into<String>, info: UI) -> Self {
+        Self {
+            label: label.into(),
+            color: None,
+            info,
+        }
+    }
+
+    /// Tints the label with `color`.
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+/// A laid-out pin: its id, direction and spec.
+type PlacedPin<'s, UI> = (usize, PinDirection, &'s PinSpec<UI>);
+
+/// Every pin of `inputs` and `outputs` with its "inputs first" id and its
+/// direction, in id order.
+fn placed_pins<'s, UI>(
+    inputs: &'s [PinSpec<UI>],
+    outputs: &'s [PinSpec<UI>],
+) -> impl Iterator<Item = PlacedPin<'s, UI>> {
+    let count = inputs.len();
+    let inputs = inputs.iter().enumerate().map(move |(i, spec)| {
+        (
+            pin_id(PinDirection::Input, i, count),
+            PinDirection::Input,
+            spec,
+        )
+    });
+    let outputs = outputs.iter().enumerate().map(move |(i, spec)| {
+        (
+            pin_id(PinDirection::Output, i, count),
+            PinDirection::Output,
+            spec,
+        )
+    });
+    inputs.chain(outputs)
+}
+
+/// Pairs input `i` with output `i`, one row per pair; the shorter side
+/// leaves its slot empty.
+fn paired_rows<'s, UI>(
+    inputs: &'s [PinSpec<UI>],
+    outputs: &'s [PinSpec<UI>],
+) -> Vec<[Option<PlacedPin<'s, UI>>; 2]> {
+    let count = inputs.len();
+    (0..count.max(outputs.len()))
+        .map(|i| {
+            [
+                inputs.get(i).map(|spec| {
+                    (
+                        pin_id(PinDirection::Input, i, count),
+                        PinDirection::Input,
+                        spec,
+                    )
+                }),
+                outputs.get(i).map(|spec| {
+                    (
+                        pin_id(PinDirection::Output, i, count),
+                        PinDirection::Output,
+                        spec,
+                    )
+                }),
+            ]
+        })
+        .collect()
+}
+
+/// The `node_pin` for one placed pin, on the left edge for inputs and the
+/// right edge for outputs, wrapping `label`.
+fn spec_pin<'a, Message, UI>(
+    (id, direction, spec): PlacedPin<'_, UI>,
+    label: impl Into<Element<'a, Message, Theme, iced::Renderer>>,
+) -> Element<'a, Message, Theme, iced::Renderer>
+where
+    Message: Clone + 'a,
+    UI: Clone + 'static,
+{
+    let side = match direction {
+        PinDirection::Output => PinSide::Right,
+        _ => PinSide::Left,
+    };
+    node_pin(side, id, label)
+        .direction(direction)
+        .info(spec.info.clone())
+        .into()
+}
+
+/// The label text of `spec`.
+fn spec_label<'a, UI>(spec: &PinSpec<UI>) -> Text<'a, Theme, iced::Renderer> {
+    text(spec.label.clone()).size(11).color_maybe(spec.color)
+}
+
+/// Lays out `inputs` and `outputs` in rows, input `i` on the left paired
+/// with output `i` on the right.
+///
+/// Pin ids are sequential: inputs take `0..inputs.len()`, outputs follow
+/// from `inputs.len()`. This is the body of [`simple_node_with_pins`].
+pub fn pin_rows<'a, Message, UI>(
+    inputs: &[PinSpec<UI>],
+    outputs: &[PinSpec<UI>],
+) -> Element<'a, Message, Theme, iced::Renderer>
+where
+    Message: Clone + 'a,
+    UI: Clone + 'static,
+{
+    let slot = |placed: Option<PlacedPin<'_, UI>>, align| match placed {
+        Some(placed) => container(spec_pin(placed, spec_label(placed.2)))
+            .width(Length::FillPortion(1))
+            .align_x(align),
+        None => container(text("")).width(Length::FillPortion(1)),
+    };
+    let rows = paired_rows(inputs, outputs)
+        .into_iter()
+        .map(|[input, output]| {
+            row![
+                slot(input, Horizontal::Left),
+                slot(output, Horizontal::Right)
+            ]
+            .spacing(8)
+            .into()
+        });
+    Column::with_children(rows).spacing(2).into()
+}
+
+/// Lists `inputs` and then `outputs`, one pin per line, each label padded
+/// away from its pin.
+///
+/// Pin ids follow the same "inputs first" numbering as [`pin_rows`]. Use it
+/// for nodes whose pins read as a list rather than paired columns.
+pub fn pin_list<'a, Message, UI>(
+    inputs: &[PinSpec<UI>],
+    outputs: &[PinSpec<UI>],
+) -> Element<'a, Message, Theme, iced::Renderer>
+where
+    Message: Clone + 'a,
+    UI: Clone + 'static,
+{
+    let pins = placed_pins(inputs, outputs)
+        .map(|placed| spec_pin(placed, container(spec_label(placed.2)).padding([0, 8])));
+    Column::with_children(pins).spacing(2).into()
+}
+
+/// Creates a [`simple_node`] whose body lists `inputs` on the left edge and
+/// `outputs` on the right.
+///
+/// Row `i` pairs input `i` with output `i`, so a node with two inputs and
+/// one output is two rows tall. Pin ids are sequential: inputs take
+/// `0..inputs.len()`, outputs follow from `inputs.len()`. Shorthand for a
+/// [`NodeBuilder`] without body widgets.
+pub fn simple_node_with_pins<'a, Message, UI>(
+    title: impl Into<String>,
+    style: NodeContentStyle,
+    inputs: &[PinSpec<UI>],
+    outputs: &[PinSpec<UI>],
+) -> Element<'a, Message, Theme, iced::Renderer>
+where
+    Message: Clone + 'a,
+    UI: Clone + 'static,
+{
+    NodeBuilder {
+        inputs: inputs.to_vec(),
+        outputs: outputs.to_vec(),
+        ..NodeBuilder::new(title, style)
+    }
+    .build()
+}
+
+/// Pin id of the `index`-th pin of `direction` on a node with `input_count`
+/// inputs: inputs come first, outputs follow.
+fn pin_id(direction: PinDirection, index: usize, input_count: usize) -> usize {
+    match direction {
+        PinDirection::Output => input_count + index,
+        _ => index,
+    }
+}
+
+/// Accumulates a node's title, body widgets and typed pins, then builds the
+/// [`simple_node`] element.
+///
+/// Pins may be declared in any order; ids always follow the "inputs first"
+/// convention (inputs `0..n` in declaration order, then outputs `n..`), so a
+/// `PinRef` built from [`input_id`](Self::input_id) or
+/// [`output_id`](Self::output_id) names the pin the widget lays out. Body
+/// widgets stack above the pin rows.
+///
+/// # Example
+/// ```ignore
+/// let node = NodeBuilder::new("Mix", NodeContentStyle::process(theme))
+///     .input("a", TypeId::of::<Float>())
+///     .input("b", TypeId::of::<Float>())
+///     .output("out", TypeId::of::<Float>())
+///     .body(slider(0.0..=1.0, factor, Message::Factor))
+///     .build();
+/// ```
+pub struct NodeBuilder<'a, Message, UI> {
+    title: String,
+    style: NodeContentStyle,
+    body: Vec<Element<'a, Message, Theme, iced::Renderer>>,
+    inputs: Vec<PinSpec<UI>>,
+    outputs: Vec<PinSpec<UI>>,
+}
+
+impl<'a, Message, UI> NodeBuilder<'a, Message, UI>
+where
+    Message: Clone + 'a,
+    UI: Clone + 'static,
+{
+    /// A node titled `title` with no body widgets or pins yet.
+    pub fn new(title: impl Into<String>, style: NodeContentStyle) -> Self {
+        Self {
+            title: title.into(),
+            style,
+            body: Vec::new(),
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+        }
+    }
+
+    /// Appends a body widget, drawn above the pins.
+    pub fn body(mut self, content: impl Into<Element<'a, Message, Theme, iced::Renderer>>) -> Self {
+        self.body.push(content.into());
+        self
+    }
+
+    /// Appends an input pin on the left edge.
+    pub fn input(mut self, label: impl Into<String>, info: UI) -> Self {
+        self.inputs.push(PinSpec::new(label, info));
+        self
+    }
+
+    /// Appends an output pin on the right edge.
+    pub fn output(mut self, label: impl Into<String>, info: UI) -> Self {
+        self.outputs.push(PinSpec::new(label, info));
+        self
+    }
+
+    /// Pin id of the `index`-th declared input, if there is one.
+    pub fn input_id(&self, index: usize) -> Option<usize> {
+        (index < self.inputs.len()).then(|| pin_id(PinDirection::Input, index, self.inputs.len()))
+    }
+
+    /// Pin id of the `index`-th declared output, if there is one.
+    pub fn output_id(&self, index: usize) -> Option<usize> {
+        (index < self.outputs.len()).then(|| pin_id(PinDirection::Output, index, self.inputs.len()))
+    }
+
+    /// Every pin with its id and direction, in id order.
+    pub fn pins(&self) -> impl Iterator<Item = (usize, PinDirection, &PinSpec<UI>)> {
+        placed_pins(&self.inputs, &self.outputs)
+    }
+
+    /// Builds the node: title bar, body widgets, then the [`pin_rows`].
+    pub fn build(self) -> Element<'a, Message, Theme, iced::Renderer> {
+        let pins = pin_rows(&self.inputs, &self.outputs);
+        let content = Column::with_children(self.body)
+            .push(pins)
+            .spacing(4)
+            .width(Length::Fill);
+        simple_node(self.title, self.style, content)
+    }
+}
+
+/// Small muted label for section titles and captions inside a node.
+///
+/// The color comes from [`NodeContentStyle::label_text`] of the theme the
+/// label is drawn with, so it needs no palette lookup at build time.
+pub fn node_label<'a>(label: impl Into<String>) -> Text<'a, Theme, iced::Renderer> {
+    text(label.into())
+        .size(9)
+        .style(|theme: &Theme| text::Style {
+            color: Some(NodeContentStyle::from_theme(theme).label_text()),
+        })
+}
+
+/// One-pixel horizontal divider filling the available width, colored with
+/// [`NodeContentStyle::separator`] of the theme it is drawn with.
+pub fn node_separator<'a, Message>() -> Container<'a, Message, Theme, iced::Renderer>
+where
+    Message: 'a,
+{
+    container(text(""))
+        .width(Length::Fill)
+        .height(1)
+        .style(|theme: &Theme| container::Style {
+            background: Some(NodeContentStyle::from_theme(theme).separator().into()),
+            ..Default::default()
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -229,4 +572,76 @@ mod tests {
             "NodeContentStyle default radius must equal the rendered fill radius"
         );
     }
+
+    /// Labels and separators follow the theme: light text on dark themes,
+    /// dark text on light ones.
+    #[test]
+    fn label_and_separator_follow_theme_contrast() {
+        let dark = NodeContentStyle::from_theme(&Theme::Dark);
+        let light = NodeContentStyle::from_theme(&Theme::Light);
+        let lum = |c: Color| c.r + c.g + c.b;
+        assert!(lum(dark.label_text()) > lum(light.label_text()));
+        assert!(lum(dark.separator()) > lum(light.separator()));
+        assert!(dark.separator().a < dark.label_text().a);
+    }
+
+    /// Pin ids follow "inputs first" whatever order the pins were declared in.
+    #[test]
+    fn builder_numbers_inputs_before_outputs() {
+        let builder: NodeBuilder<'_, (), char> =
+            NodeBuilder::new("Mix", NodeContentStyle::from_theme(&Theme::Dark))
+                .output("out", 'o')
+                .input("a", 'a')
+                .output("alpha", 'l')
+                .input("b", 'b');
+
+        let pins: Vec<_> = builder
+            .pins()
+            .map(|(id, direction, spec)| (id, direction, spec.info))
+            .collect();
+        assert_eq!(
+            pins,
+            vec![
+                (0, PinDirection::Input, 'a'),
+                (1, PinDirection::Input, 'b'),
+                (2, PinDirection::Output, 'o'),
+                (3, PinDirection::Output, 'l'),
+            ]
+        );
+        assert_eq!(builder.input_id(1), Some(1));
+        assert_eq!(builder.output_id(0), Some(2));
+        assert_eq!(builder.output_id(2), None);
+    }
+
+    /// Input `i` shares row `i` with output `i`; ids put inputs first, and a
+    /// pin's color travels with its spec.
+    #[test]
+    fn pin_rows_pair_inputs_with_outputs() {
+        let red = Color::from_rgb(1.0, 0.0, 0.0);
+        let inputs = [PinSpec::new("a", 'a').color(red), PinSpec::new("b", 'b')];
+        let outputs = [PinSpec::new("out", 'o')];
+
+        let rows: Vec<_> = paired_rows(&inputs, &outputs)
+            .into_iter()
+            .map(|row| row.map(|pin| pin.map(|(id, direction, spec)| (id, direction, spec.info))))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                [
+                    Some((0, PinDirection::Input, 'a')),
+                    Some((2, PinDirection::Output, 'o')),
+                ],
+                [Some((1, PinDirection::Input, 'b')), None],
+            ]
+        );
+        assert_eq!(inputs[0].color, Some(red));
+        assert_eq!(inputs[1].color, None);
+
+        // One row per pair for `pin_rows`, one line per pin for `pin_list`.
+        let rows = pin_rows::<(), _>(&inputs, &outputs);
+        assert_eq!(rows.as_widget().children().len(), 2);
+        let list = pin_list::<(), _>(&inputs, &outputs);
+        assert_eq!(list.as_widget().children().len(), 3);
+    }
 }
@@ -5,5 +5,8 @@
 mod content;
 mod screenshot;
 
-pub use content::{NodeContentStyle, simple_node};
+pub use content::{
+    NodeBuilder, NodeContentStyle, PinSpec, node_label, node_separator, pin_list, pin_rows,
+    simple_node, simple_node_with_pins,
+};
 pub use screenshot::{ScreenshotHelper, ScreenshotMessage};
@@ -47,10 +47,10 @@ use iced::{
     window,
 };
 use iced_nodegraph::{
-    ColorQuad, EdgeStatus, EdgeStyle, PinRef, default_edge_style, default_node_style,
-    default_pin_style, edge as ng_edge, node as ng_node,
+    BoxSelectStyle, Camera2D, ColorQuad, EdgeStatus, EdgeStyle, PinRef, default_edge_style,
+    default_node_style, default_pin_style, edge as ng_edge, node as ng_node,
 };
-use iced_nodegraph::{EdgeCurve, PinShape, TilingKind};
+use iced_nodegraph::{EdgeCurve, Pattern, PinShape, TilingKind};
 use iced_palette::{
     Command, Shortcut, command, command_palette, find_matching_shortcut, focus_input,
     get_filtered_command_index, get_filtered_count, is_toggle_shortcut, navigate_down, navigate_up,
@@ -71,6 +71,10 @@ use persistence::EdgeData;
 use std::collections::{HashMap, HashSet};
 use style_overlay::{EdgeOverlay, GraphOverlay, NodeOverlay, PinOverlay};
 
+/// Pitch of the theme's default canvas grid (`GraphStyle::from_theme`);
+/// spawned nodes snap to it unless a GraphConfig overrides the spacing.
+const DEFAULT_GRID_SPACING: f32 = 40.0;
+
 /// Edge data for in-memory representation (WASM version).
 #[cfg(target_arch = "wasm32")]
 #[derive(Debug, Clone)]
@@ -565,7 +569,8 @@ impl Application {
         // No-op on WASM
     }
 
-    /// Calculate spawn position at screen center, converted to world coordinates.
+    /// Calculate spawn position at screen center, converted to world coordinates
+    /// and snapped to the canvas grid.
     fn spawn_position(&self) -> Point {
         // Screen center
         let screen_center_x = self.viewport_size.width / 2.0;
@@ -575,8 +580,14 @@ impl Application {
         let world_x = screen_center_x / self.camera_zoom - self.camera_position.x;
         let world_y = screen_center_y / self.camera_zoom - self.camera_position.y;
 
-        // Offset for node size (approximate center, ~100x80 typical node)
-        Point::new(world_x - 50.0, world_y - 40.0)
+        // Offset for node size (approximate center, ~100x80 typical node), then
+        // land on the grid currently drawn (a GraphConfig may change its pitch).
+        let spacing = self
+            .computed_style
+            .graph
+            .tiling_spacing
+            .unwrap_or(DEFAULT_GRID_SPACING);
+        Camera2D::world_to_grid(Point::new(world_x - 50.0, world_y - 40.0), spacing)
     }
 
     /// Export current graph state to a file for Claude to read and update demos.
@@ -1826,6 +1837,9 @@ impl Application {
             .on_delete(ApplicationMessage::DeleteNodes)
             .on_pan(|position, zoom| ApplicationMessage::CameraChanged { position, zoom })
             .view(self.camera_position, self.camera_zoom)
+            // The command palette is a modal overlay: the graph underneath
+            // keeps its state but ignores input while it is open.
+            .input_enabled(!self.command_palette_open)
             // A connection is valid only between opposite directions (output ->
             // input) carrying the same data type (the pin's TypeId marker). Color
             // and ColorQuad share the `ColorData` marker, so a color pin accepts
@@ -1833,11 +1847,10 @@ impl Application {
             .can_connect(|from, to| from.direction() != to.direction() && from.info() == to.info())
             // Selection highlight and pending-cut feedback are handled internally
             // by the widget; per-node/edge styling flows through push_*_styled.
-            .box_select_style(|_theme| {
-                (
-                    iced::Color::from_rgba(0.3, 0.6, 1.0, 0.15), // fill
-                    iced::Color::from_rgb(0.3, 0.6, 1.0),        // border
-                )
+            .box_select_style(|_theme| BoxSelectStyle {
+                fill: iced::Color::from_rgba(0.3, 0.6, 1.0, 0.15),
+                border: iced::Color::from_rgb(0.3, 0.6, 1.0),
+                pattern: Pattern::dashed(1.5, 6.0, 4.0),
             })
             .cutting_tool_style(|_theme| iced::Color::from_rgb(1.0, 0.3, 0.3))
             .dragging_edge_style(move |theme, source| {
@@ -239,6 +239,7 @@ where
     let curve_label = match result.curve {
         Some(EdgeCurve::BezierCubic) => "bezier",
         Some(EdgeCurve::Line) => "line",
+        Some(EdgeCurve::Catmull) => "catmull",
         None => "--",
     };
     let pattern_label = match inputs.get_pattern_type() {
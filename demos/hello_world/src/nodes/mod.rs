@@ -36,7 +36,7 @@ pub use int_slider::{IntSliderConfig, int_slider_node};
 pub use math::math_node;
 pub use theme_node::{theme_extended_node, theme_node};
 
-use demo_common::NodeContentStyle;
+use demo_common::{NodeContentStyle, node_label, node_separator};
 use iced::{
     Color, Element, Length, Padding, Theme,
     alignment::Horizontal,
@@ -534,82 +534,37 @@ pub fn section_header_with_pins<'a, Message: Clone + 'a>(
     let indicator = if expanded { "-" } else { "+" };
     let label_text = format!("{} {}", title, indicator);
 
-    // Separator line style
-    let separator_style = |_: &_| container::Style {
-        background: Some(iced::Background::Color(Color::from_rgba(
-            1.0, 1.0, 1.0, 0.1,
-        ))),
-        ..Default::default()
-    };
+    let label = || node_label(label_text.clone());
 
     // Build the row content based on whether we have collapsed pins
-    let row_content: iced::Element<'a, Message> = if !expanded {
-        if let Some(pins) = collapsed_pins {
-            // Collapsed with pins: [pins] ── Label + ──
-            row![
-                pins,
-                container(text(""))
-                    .width(Length::Fill)
-                    .height(1)
-                    .style(separator_style),
-                text(label_text).size(9).color(colors::TEXT_MUTED),
-                container(text(""))
-                    .width(Length::Fill)
-                    .height(1)
-                    .style(separator_style),
-            ]
-            .spacing(6)
-            .align_y(iced::Alignment::Center)
-            .into()
-        } else {
-            // Collapsed without pins: ──── Label + ────
-            row![
-                container(text(""))
-                    .width(Length::Fill)
-                    .height(1)
-                    .style(separator_style),
-                text(label_text).size(9).color(colors::TEXT_MUTED),
-                container(text(""))
-                    .width(Length::Fill)
-                    .height(1)
-                    .style(separator_style),
-            ]
-            .spacing(6)
-            .align_y(iced::Alignment::Center)
-            .into()
-        }
-    } else {
-        // Expanded: ──── Label - ────
-        row![
-            container(text(""))
-                .width(Length::Fill)
-                .height(1)
-                .style(separator_style),
-            text(label_text).size(9).color(colors::TEXT_MUTED),
-            container(text(""))
-                .width(Length::Fill)
-                .height(1)
-                .style(separator_style),
-        ]
-        .spacing(6)
-        .align_y(iced::Alignment::Center)
-        .into()
-    };
+    let row_content: iced::Element<'a, Message> = match collapsed_pins {
+        // Collapsed with pins: [pins] ── Label + ──
+        Some(pins) if !expanded => row![pins, node_separator(), label(), node_separator()],
+        // Otherwise: ──── Label +/- ────
+        _ => row![node_separator(), label(), node_separator()],
+    }
+    .spacing(6)
+    .align_y(iced::Alignment::Center)
+    .into();
 
     button(row_content)
         .width(Length::Fill)
         .on_press(on_toggle)
         .padding([4, 0])
-        .style(|_theme: &Theme, status| {
+        .style(|theme: &Theme, status| {
+            let content = NodeContentStyle::from_theme(theme);
             let background = match status {
-                button::Status::Hovered | button::Status::Pressed => Some(iced::Background::Color(
-                    Color::from_rgba(1.0, 1.0, 1.0, 0.03),
-                )),
+                button::Status::Hovered | button::Status::Pressed => {
+                    Some(iced::Background::Color(Color {
+                        a: 0.03,
+                        ..content.body_text
+                    }))
+                }
                 _ => None,
             };
             button::Style {
                 background,
-                text_color: colors::TEXT_MUTED,
+                text_color: content.label_text(),
                 border: iced::Border::default(),
                 shadow: iced::Shadow::default(),
                 snap: false,
@@ -731,6 +731,7 @@ fn edge_curve_to_string(curve: EdgeCurve) -> String {
     match curve {
         EdgeCurve::BezierCubic => "BezierCubic",
         EdgeCurve::Line => "Line",
+        EdgeCurve::Catmull => "Catmull",
     }
     .to_string()
 }
@@ -738,6 +739,7 @@ fn edge_curve_to_string(curve: EdgeCurve) -> String {
 fn string_to_edge_curve(s: &str) -> EdgeCurve {
     match s {
         "Line" => EdgeCurve::Line,
+        "Catmull" => EdgeCurve::Catmull,
         _ => EdgeCurve::BezierCubic,
     }
 }
@@ -46,14 +46,15 @@ pub fn wasm_init() {
 }
 
 use compiler::ShaderCompiler;
+use demo_common::{PinSpec, pin_list};
 use iced::{
     Element, Event, Length, Point, Subscription, Task, Theme, Vector, event, keyboard,
     widget::{column, container, opaque, stack, text},
     window,
 };
 use iced_nodegraph::{
-    PinDirection, PinInfo, PinRef, PinSide, PinStatus, PinStyle, default_pin_style,
-    edge as ng_edge, node as ng_node, node_pin,
+    Camera2D, PinInfo, PinRef, PinStatus, PinStyle, default_pin_style, edge as ng_edge,
+    node as ng_node,
 };
 use iced_palette::{
     Command, command, command_palette, focus_input, get_filtered_command_index, get_filtered_count,
@@ -61,8 +62,13 @@ use iced_palette::{
 };
 use shader_graph::ShaderGraph;
 use shader_graph::nodes::ShaderNodeType;
+use std::any::TypeId;
 use std::collections::HashSet;
 
+/// Pitch of the theme's canvas grid (`GraphStyle::from_theme`); spawned nodes
+/// snap to it.
+const GRID_SPACING: f32 = 40.0;
+
 pub fn main() -> iced::Result {
     #[cfg(target_arch = "wasm32")]
     let window_settings = iced::window::Settings {
@@ -100,6 +106,8 @@ enum Message {
         to: PinRef<usize, usize>,
     },
     SelectionChanged(Vec<usize>),
+    /// Nodes or edges were added or removed; the shader is out of date.
+    GraphChanged,
     NodesMoved {
         delta: Vector,
         indices: Vec<usize>,
@@ -211,8 +219,8 @@ impl Application {
         let world_x = screen_center_x / self.camera_zoom - self.camera_position.x;
         let world_y = screen_center_y / self.camera_zoom - self.camera_position.y;
 
-        // Offset for node size (approximate center)
-        Point::new(world_x - 60.0, world_y - 40.0)
+        // Offset for node size (approximate center), on the theme grid
+        Camera2D::world_to_grid(Point::new(world_x - 60.0, world_y - 40.0), GRID_SPACING)
     }
 
     fn update(&mut self, message: Message) -> iced::Task<Message> {
@@ -256,7 +264,6 @@ impl Application {
                         to_socket,
                     });
                 }
-                self.recompile();
             }
             Message::EdgeDisconnected { from, to } => {
                 self.visual_edges.retain(|(f, t)| !(f == &from && t == &to));
@@ -278,9 +285,11 @@ impl Application {
                             && c.to_socket == to_socket)
                     });
                 }
-                self.recompile();
                 return Task::none();
             }
+            Message::GraphChanged => {
+                self.recompile();
+            }
             Message::SelectionChanged(indices) => {
                 self.graph_selection = indices.into_iter().collect();
             }
@@ -378,11 +387,12 @@ impl Application {
 
     fn view(&self) -> Element<'_, Message> {
         // Build node graph
-        let mut graph: ::iced_nodegraph::NodeGraph<usize, usize, ::std::any::TypeId, _, _, _> =
+        let mut graph: ::iced_nodegraph::NodeGraph<usize, usize, TypeId, _, _, _> =
             ::iced_nodegraph::NodeGraph::default()
                 .on_connect(|from, to| Message::EdgeConnected { from, to })
                 .on_move(|delta, indices| Message::NodesMoved { delta, indices })
                 .on_disconnect(|from, to| Message::EdgeDisconnected { from, to })
+                .on_graph_changed(|_| Message::GraphChanged)
                 .on_select(Message::SelectionChanged)
                 .on_pan(|position, zoom| Message::CameraChanged { position, zoom })
                 .selection(&self.graph_selection);
@@ -527,12 +537,7 @@ fn create_node_widget<'a>(
     node_type: &shader_graph::nodes::ShaderNodeType,
     theme: &'a Theme,
 ) -> iced::Element<'a, Message> {
-    use iced_nodegraph::node_pin;
-
     let name = node_type.name();
-    let inputs = node_type.inputs();
-    let outputs = node_type.outputs();
-
     let palette = theme.extended_palette();
 
     // Title bar - matching hello_world pattern exactly
@@ -545,51 +550,17 @@ fn create_node_widget<'a>(
             ..container::Style::default()
         });
 
-    // Build pin list - must match hello_world's column![] macro structure
     // Pin IDs use sequential indices: inputs first (0..n), then outputs (n..n+m)
-    let pin_section = if inputs.is_empty() && outputs.is_empty() {
-        // No pins - minimal output indicator
-        container(
-            column![
-                node_pin(
-                    PinSide::Right,
-                    0usize,
-                    container(text("out").size(11)).padding([0, 8])
-                )
-                .direction(PinDirection::Output)
-            ]
-            .spacing(2),
-        )
-        .padding([6, 0])
-    } else {
-        // Build pins dynamically but wrap in container same way
-        let mut pin_elements: Vec<iced::Element<'a, Message>> = Vec::new();
-        let num_inputs = inputs.len();
-
-        for (i, input) in inputs.into_iter().enumerate() {
-            let label = input.name.clone();
-            pin_elements.push(create_typed_pin(
-                PinSide::Left,
-                i, // Pin ID = input index
-                label,
-                PinDirection::Input,
-                &input.socket_type,
-            ));
-        }
-
-        for (i, output) in outputs.into_iter().enumerate() {
-            let label = output.name.clone();
-            pin_elements.push(create_typed_pin(
-                PinSide::Right,
-                num_inputs + i, // Pin ID = num_inputs + output index
-                label,
-                PinDirection::Output,
-                &output.socket_type,
-            ));
-        }
-
-        container(iced::widget::Column::with_children(pin_elements).spacing(2)).padding([6, 0])
+    let spec = |socket: shader_graph::sockets::Socket| {
+        PinSpec::new(socket.name, socket_info(&socket.socket_type))
     };
+    let inputs: Vec<_> = node_type.inputs().into_iter().map(spec).collect();
+    let mut outputs: Vec<_> = node_type.outputs().into_iter().map(spec).collect();
+    if inputs.is_empty() && outputs.is_empty() {
+        // No pins - minimal output indicator
+        outputs.push(PinSpec::new("out", TypeId::of::<()>()));
+    }
+    let pin_section = container(pin_list(&inputs, &outputs)).padding([6, 0]);
 
     column![title_bar, pin_section].width(160.0).into()
 }
@@ -597,11 +568,10 @@ fn create_node_widget<'a>(
 /// Colors a node's pins by their socket data-type marker.
 fn pin_style(
     theme: &Theme,
-    pin: &PinInfo<'_, usize, ::std::any::TypeId>,
-    _other: Option<&PinInfo<'_, usize, ::std::any::TypeId>>,
+    pin: &PinInfo<'_, usize, TypeId>,
+    _other: Option<&PinInfo<'_, usize, TypeId>>,
     status: PinStatus,
 ) -> PinStyle {
-    use std::any::TypeId;
     let ty = *pin.info();
     let color = if ty == TypeId::of::<colors::Float>() {
         colors::SOCKET_FLOAT
@@ -622,43 +592,17 @@ fn pin_style(
     }
 }
 
-/// Creates a typed pin element based on the socket type.
-/// Uses marker types for TypeId-based connection matching.
-fn create_typed_pin<'a, Message: Clone + 'a>(
-    side: PinSide,
-    pin_id: usize,
-    label: String,
-    direction: PinDirection,
-    socket_type: &shader_graph::sockets::SocketType,
-) -> Element<'a, Message> {
+/// The pin info marker for a socket type, matched by `pin_style` and the
+/// graph's TypeId-based connection check.
+fn socket_info(socket_type: &shader_graph::sockets::SocketType) -> TypeId {
     use shader_graph::sockets::SocketType;
 
-    let content = container(text(label).size(11)).padding([0, 8]);
-
     match socket_type {
-        SocketType::Float => node_pin(side, pin_id, content)
-            .direction(direction)
-            .info(::std::any::TypeId::of::<colors::Float>())
-            .into(),
-        SocketType::Vec2 => node_pin(side, pin_id, content)
-            .direction(direction)
-            .info(::std::any::TypeId::of::<colors::Vec2>())
-            .into(),
-        SocketType::Vec3 => node_pin(side, pin_id, content)
-            .direction(direction)
-            .info(::std::any::TypeId::of::<colors::Vec3>())
-            .into(),
-        SocketType::Vec4 => node_pin(side, pin_id, content)
-            .direction(direction)
-            .info(::std::any::TypeId::of::<colors::Vec4>())
-            .into(),
-        SocketType::Bool => node_pin(side, pin_id, content)
-            .direction(direction)
-            .info(::std::any::TypeId::of::<colors::Bool>())
-            .into(),
-        SocketType::Int => node_pin(side, pin_id, content)
-            .direction(direction)
-            .info(::std::any::TypeId::of::<colors::Int>())
-            .into(),
+        SocketType::Float => TypeId::of::<colors::Float>(),
+        SocketType::Vec2 => TypeId::of::<colors::Vec2>(),
+        SocketType::Vec3 => TypeId::of::<colors::Vec3>(),
+        SocketType::Vec4 => TypeId::of::<colors::Vec4>(),
+        SocketType::Bool => TypeId::of::<colors::Bool>(),
+        SocketType::Int => TypeId::of::<colors::Int>(),
     }
 }
@@ -5,9 +5,9 @@
 //!
 //! We assert on the `viewport` argument that reaches a leaf child widget. To
 //! invoke `Widget::draw` / `Widget::update` we need *something* that satisfies
-//! NodeGraph's renderer bounds (`core::Renderer + iced_wgpu::primitive::Renderer`),
-//! but we do not need a real renderer: the bug is observable in a single
-//! argument value, not in pixel output.
+//! NodeGraph's renderer bounds (`core::Renderer`, `iced_wgpu::primitive::Renderer`
+//! and `text::Renderer`), but we do not need a real renderer: the bug is
+//! observable in a single argument value, not in pixel output.
 
 use std::cell::{Cell, RefCell};
 use std::rc::Rc;
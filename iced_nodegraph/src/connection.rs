@@ -85,6 +85,8 @@ mod tests {
         assert!(direction_ok(out, inp));
         assert!(direction_ok(inp, out));
         assert!(direction_ok(out, both));
+        assert!(direction_ok(both, inp)); // both acts as the output
+        assert!(direction_ok(both, both)); // undirected pair
         assert!(!direction_ok(out, out)); // output -> output
         assert!(!direction_ok(inp, inp)); // input -> input
     }
@@ -12,7 +12,7 @@
 //! (matching iced_graphics' `current * transformation` composition) and capture
 //! the bounds handed to `draw_primitive` (the SDF clip rect, in absolute pixels).
 
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::rc::Rc;
 
 use iced::advanced::renderer::Renderer as _;
@@ -193,7 +193,25 @@ fn draw_at_origin(
         .height(Length::Fixed(400.0))
         .view(camera_pos, camera_zoom);
     graph.push_node(node(0_usize, node_world, Element::from(ContentProbe)));
+    draw_graph(graph, widget_origin)
+}
+
+/// Lays out `graph` at `widget_origin`, syncs its `view()`, draws it, and
+/// returns what the recorder captured.
+fn draw_graph(
+    graph: NodeGraph<'static, usize, usize, (), (), Theme, Rec>,
+    widget_origin: Vector,
+) -> Recorded {
+    draw_graph_with_cursor(graph, widget_origin, mouse::Cursor::Unavailable)
+}
 
+/// [`draw_graph`] with the syncing update's cursor at `cursor`, for
+/// hover-driven visuals.
+fn draw_graph_with_cursor(
+    mut graph: NodeGraph<'static, usize, usize, (), (), Theme, Rec>,
+    widget_origin: Vector,
+    cursor: mouse::Cursor,
+) -> Recorded {
     let mut tree = Tree::new(&graph as &dyn Widget<(), Theme, Rec>);
     let out = Rc::new(RefCell::new(Recorded::default()));
     let mut renderer = Rec::new(out.clone());
@@ -218,7 +236,7 @@ fn draw_at_origin(
             position: Point::new(-1.0, -1.0),
         }),
         layout,
-        mouse::Cursor::Unavailable,
+        cursor,
         &renderer,
         &mut clipboard,
         &mut shell,
@@ -366,193 +384,1293 @@ fn click_hits_node_at_nonzero_origin_zoom2() {
     );
 }
 
-// Antialias padding the fill clip adds around the node bbox.
-const FILL_PAD: f32 = 6.0;
-
 #[test]
-fn content_and_fill_correct_at_origin_zoom1() {
-    // Graph at (0, 100); node world (30, 40); default camera. Both content and
-    // fill must land at origin + world = (30, 140).
-    let origin = Vector::new(0.0, 100.0);
-    let world = Point::new(30.0, 40.0);
-    let rec = draw_at_origin(origin, world, Point::ORIGIN, 1.0);
-    let expected = expected_screen(origin, world, Point::ORIGIN, 1.0);
+fn pin_world_position_is_independent_of_origin_and_zoom() {
+    // Node 0 at world (30,40), 40x20 body, output pin on its right side: the
+    // pin anchors at world (70,50) wherever the widget sits and however it
+    // is zoomed.
+    let mut graph: NodeGraph<'static, usize, usize, (), (), Theme, Rec> = NodeGraph::default()
+        .width(Length::Fixed(400.0))
+        .height(Length::Fixed(400.0))
+        .view(Point::new(15.0, -5.0), 2.0);
+    graph.push_node(node(
+        0_usize,
+        Point::new(30.0, 40.0),
+        crate::pin!(Right, 0_usize, Element::from(ContentProbe), Output),
+    ));
 
-    let content = node_content_quad(&rec);
-    let fill = node_fill_primitive(&rec);
+    let mut tree = Tree::new(&graph as &dyn Widget<(), Theme, Rec>);
+    let renderer = Rec::new(Rc::new(RefCell::new(Recorded::default())));
+    let layout_node = graph.layout(
+        &mut tree,
+        &renderer,
+        &layout::Limits::new(Size::ZERO, Size::new(1024.0, 768.0)),
+    );
+    let layout = Layout::with_offset(Vector::new(0.0, 100.0), &layout_node);
 
-    assert!(
-        (content.x - expected.x).abs() < 1.0 && (content.y - expected.y).abs() < 1.0,
-        "content {content:?} should sit at {expected:?}",
+    assert_eq!(
+        graph.pin_world_position(&tree, layout, &crate::PinRef::new(0, 0)),
+        Some(Point::new(70.0, 50.0))
     );
-    assert!(
-        (fill.x - expected.x).abs() < FILL_PAD && (fill.y - expected.y).abs() < FILL_PAD,
-        "fill {fill:?} should sit at {expected:?}",
+    assert_eq!(
+        graph.pin_world_position(&tree, layout, &crate::PinRef::new(0, 1)),
+        None,
+        "unknown pin"
     );
-}
-
-#[test]
-fn content_correct_at_origin_zoom2() {
-    // The crux: at zoom != 1 with a non-zero widget origin, content must land at
-    // origin + (world + pos) * zoom, NOT zoom * (origin + world + pos).
-    let origin = Vector::new(0.0, 100.0);
-    let world = Point::new(30.0, 40.0);
-    let zoom = 2.0;
-    let rec = draw_at_origin(origin, world, Point::ORIGIN, zoom);
-    let expected = expected_screen(origin, world, Point::ORIGIN, zoom);
-
-    let content = node_content_quad(&rec);
-    assert!(
-        (content.x - expected.x).abs() < 1.0 && (content.y - expected.y).abs() < 1.0,
-        "content {content:?} should sit at {expected:?} at zoom {zoom} \
-         with widget origin {origin:?}",
+    assert_eq!(
+        graph.pin_world_position(&tree, layout, &crate::PinRef::new(7, 0)),
+        None,
+        "unknown node"
     );
 }
 
 #[test]
-fn fill_correct_at_origin_zoom2() {
-    let origin = Vector::new(0.0, 100.0);
-    let world = Point::new(30.0, 40.0);
-    let zoom = 2.0;
-    let rec = draw_at_origin(origin, world, Point::ORIGIN, zoom);
-    let expected = expected_screen(origin, world, Point::ORIGIN, zoom);
-
-    let fill = node_fill_primitive(&rec);
-    assert!(
-        (fill.x - expected.x).abs() < FILL_PAD && (fill.y - expected.y).abs() < FILL_PAD,
-        "fill {fill:?} should sit at {expected:?} at zoom {zoom} \
-         with widget origin {origin:?}",
+fn rotated_node_turns_its_pins_and_hit_area() {
+    // The 40x20 body at world (30,40) turned a quarter clockwise about its
+    // centre (50,50) stands 20x40: the right-side pin swings down to (50,70).
+    let mut graph: NodeGraph<'static, usize, usize, (), (), Theme, Rec> = NodeGraph::default()
+        .width(Length::Fixed(400.0))
+        .height(Length::Fixed(400.0))
+        .view(Point::ORIGIN, 1.0);
+    graph.push_node(
+        node(
+            0_usize,
+            Point::new(30.0, 40.0),
+            crate::pin!(Right, 0_usize, Element::from(ContentProbe), Output),
+        )
+        .rotation(std::f32::consts::FRAC_PI_2),
     );
-}
 
-#[test]
-fn content_and_fill_coincide_at_origin_zoom2() {
-    // Regardless of correctness vs. world, the two layers must not drift apart.
-    let origin = Vector::new(0.0, 100.0);
-    let world = Point::new(30.0, 40.0);
-    let rec = draw_at_origin(origin, world, Point::ORIGIN, 2.0);
+    let mut tree = Tree::new(&graph as &dyn Widget<(), Theme, Rec>);
+    let renderer = Rec::new(Rc::new(RefCell::new(Recorded::default())));
+    let layout_node = graph.layout(
+        &mut tree,
+        &renderer,
+        &layout::Limits::new(Size::ZERO, Size::new(1024.0, 768.0)),
+    );
+    let layout = Layout::new(&layout_node);
+    let mut msgs: Vec<()> = Vec::new();
+    let mut shell = iced_wgpu::core::Shell::new(&mut msgs);
+    graph.update(
+        &mut tree,
+        &iced::Event::Mouse(mouse::Event::CursorMoved {
+            position: Point::new(-1.0, -1.0),
+        }),
+        layout,
+        mouse::Cursor::Unavailable,
+        &renderer,
+        &mut clipboard::Null,
+        &mut shell,
+        &Rectangle::new(Point::ORIGIN, Size::new(1024.0, 768.0)),
+    );
 
-    let content = node_content_quad(&rec);
-    let fill = node_fill_primitive(&rec);
-    let dx = (content.x - fill.x).abs();
-    let dy = (content.y - fill.y).abs();
-    assert!(
-        dx < FILL_PAD && dy < FILL_PAD,
-        "content {content:?} and fill {fill:?} diverge (dx={dx}, dy={dy})",
+    let at = graph
+        .pin_world_position(&tree, layout, &crate::PinRef::new(0, 0))
+        .expect("pin resolves");
+    assert!(at.distance(Point::new(50.0, 70.0)) < 1e-3, "{at:?}");
+    assert_eq!(
+        graph.pin_at_screen(&tree, layout, Point::new(50.0, 70.0)),
+        Some(crate::PinRef::new(0, 0))
+    );
+    // Below the unrotated body but inside the turned one, and the reverse.
+    assert_eq!(
+        graph.node_at_screen(&tree, layout, Point::new(50.0, 64.0)),
+        Some(0)
+    );
+    assert_eq!(
+        graph.node_at_screen(&tree, layout, Point::new(66.0, 50.0)),
+        None
     );
 }
 
-/// Drags a box-select from screen `p1` to `p2` over empty graph space (the only
-/// node is far away) and returns the SDF primitives recorded by a final draw.
-fn box_select_primitives(
-    widget_origin: Vector,
-    camera_zoom: f32,
-    p1: Point,
-    p2: Point,
-) -> Vec<Rectangle> {
+#[test]
+fn flipped_node_mirrors_its_pins() {
+    // Mirrored, the 40x20 body at world (30,40) shows its right-side output
+    // on the left border at (30,50); the old place no longer hits it.
     let mut graph: NodeGraph<'static, usize, usize, (), (), Theme, Rec> = NodeGraph::default()
         .width(Length::Fixed(400.0))
         .height(Length::Fixed(400.0))
-        .view(Point::ORIGIN, camera_zoom)
-        .on_select(|_ids| {});
-    // Node far from the drag so the press starts a box select, not a node click.
-    graph.push_node(node(
-        0_usize,
-        Point::new(900.0, 900.0),
-        Element::from(ContentProbe),
-    ));
+        .view(Point::ORIGIN, 1.0);
+    graph.push_node(
+        node(
+            0_usize,
+            Point::new(30.0, 40.0),
+            crate::pin!(Right, 0_usize, Element::from(ContentProbe), Output),
+        )
+        .flip_horizontal(true),
+    );
 
     let mut tree = Tree::new(&graph as &dyn Widget<(), Theme, Rec>);
-    let out = Rc::new(RefCell::new(Recorded::default()));
-    let mut renderer = Rec::new(out.clone());
+    let renderer = Rec::new(Rc::new(RefCell::new(Recorded::default())));
     let layout_node = graph.layout(
         &mut tree,
         &renderer,
         &layout::Limits::new(Size::ZERO, Size::new(1024.0, 768.0)),
     );
-    let layout = Layout::with_offset(widget_origin, &layout_node);
-    let viewport = Rectangle::new(Point::ORIGIN, Size::new(1024.0, 768.0));
+    let layout = Layout::new(&layout_node);
     let mut msgs: Vec<()> = Vec::new();
     let mut shell = iced_wgpu::core::Shell::new(&mut msgs);
-    let mut clipboard = clipboard::Null;
-
-    let send = |graph: &mut NodeGraph<'static, usize, usize, (), (), Theme, Rec>,
-                tree: &mut Tree,
-                shell: &mut iced_wgpu::core::Shell<'_, ()>,
-                clipboard: &mut clipboard::Null,
-                renderer: &Rec,
-                event: iced::Event,
-                at: Point| {
-        graph.update(
-            tree,
-            &event,
-            layout,
-            mouse::Cursor::Available(at),
-            renderer,
-            clipboard,
-            shell,
-            &viewport,
-        );
-    };
-
-    // Move to p1, press (starts box select at p1), drag to p2.
-    send(
-        &mut graph,
+    graph.update(
         &mut tree,
-        &mut shell,
-        &mut clipboard,
+        &iced::Event::Mouse(mouse::Event::CursorMoved {
+            position: Point::new(-1.0, -1.0),
+        }),
+        layout,
+        mouse::Cursor::Unavailable,
         &renderer,
-        iced::Event::Mouse(mouse::Event::CursorMoved { position: p1 }),
-        p1,
+        &mut clipboard::Null,
+        &mut shell,
+        &Rectangle::new(Point::ORIGIN, Size::new(1024.0, 768.0)),
     );
-    send(
-        &mut graph,
+
+    assert_eq!(
+        graph.pin_world_position(&tree, layout, &crate::PinRef::new(0, 0)),
+        Some(Point::new(30.0, 50.0))
+    );
+    assert_eq!(
+        graph.pin_at_screen(&tree, layout, Point::new(30.0, 50.0)),
+        Some(crate::PinRef::new(0, 0))
+    );
+    assert_eq!(
+        graph.pin_at_screen(&tree, layout, Point::new(70.0, 50.0)),
+        None
+    );
+}
+
+#[test]
+fn shaped_node_attaches_pins_and_hits_on_its_outline() {
+    // The 40x20 body at world (30,40) drawn as a circle is 20 wide around
+    // its centre (50,50): the right-side pin moves in from (70,50) to
+    // (60,50), and the box corners no longer hit the node.
+    let mut graph: NodeGraph<'static, usize, usize, (), (), Theme, Rec> = NodeGraph::default()
+        .width(Length::Fixed(400.0))
+        .height(Length::Fixed(400.0))
+        .view(Point::ORIGIN, 1.0);
+    graph.push_node(
+        node(
+            0_usize,
+            Point::new(30.0, 40.0),
+            crate::pin!(Right, 0_usize, Element::from(ContentProbe), Output),
+        )
+        .shape(crate::NodeShape::Circle),
+    );
+
+    let mut tree = Tree::new(&graph as &dyn Widget<(), Theme, Rec>);
+    let renderer = Rec::new(Rc::new(RefCell::new(Recorded::default())));
+    let layout_node = graph.layout(
         &mut tree,
-        &mut shell,
-        &mut clipboard,
         &renderer,
-        iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
-        p1,
+        &layout::Limits::new(Size::ZERO, Size::new(1024.0, 768.0)),
     );
-    send(
-        &mut graph,
+    let layout = Layout::new(&layout_node);
+    let mut msgs: Vec<()> = Vec::new();
+    let mut shell = iced_wgpu::core::Shell::new(&mut msgs);
+    graph.update(
         &mut tree,
-        &mut shell,
-        &mut clipboard,
+        &iced::Event::Mouse(mouse::Event::CursorMoved {
+            position: Point::new(-1.0, -1.0),
+        }),
+        layout,
+        mouse::Cursor::Unavailable,
         &renderer,
-        iced::Event::Mouse(mouse::Event::CursorMoved { position: p2 }),
-        p2,
+        &mut clipboard::Null,
+        &mut shell,
+        &Rectangle::new(Point::ORIGIN, Size::new(1024.0, 768.0)),
     );
 
-    graph.draw(
-        &tree,
-        &mut renderer,
-        &Theme::Dark,
-        &renderer::Style {
-            text_color: Color::WHITE,
-        },
-        layout,
-        mouse::Cursor::Available(p2),
-        &viewport,
+    assert_eq!(
+        graph.pin_world_position(&tree, layout, &crate::PinRef::new(0, 0)),
+        Some(Point::new(60.0, 50.0))
+    );
+    assert_eq!(
+        graph.pin_at_screen(&tree, layout, Point::new(60.0, 50.0)),
+        Some(crate::PinRef::new(0, 0))
+    );
+    assert_eq!(
+        graph.node_at_screen(&tree, layout, Point::new(50.0, 50.0)),
+        Some(0)
+    );
+    assert_eq!(
+        graph.node_at_screen(&tree, layout, Point::new(33.0, 42.0)),
+        None
     );
 
-    out.borrow().primitives.clone()
+    // A hexagon keeps the side points and slides off-centre pins in along
+    // the slanted edges; top pins keep their place on the flat top.
+    let bounds = Rectangle::new(Point::new(0.0, 0.0), Size::new(100.0, 40.0));
+    let hexagon = crate::NodeShape::Hexagon;
+    let inset = 20.0 / 3f32.sqrt();
+    let mid = hexagon.attach(Point::new(100.0, 20.0), crate::PinSide::Right, bounds);
+    assert!(mid.distance(Point::new(100.0, 20.0)) < 1e-4, "{mid:?}");
+    let low = hexagon.attach(Point::new(0.0, 30.0), crate::PinSide::Left, bounds);
+    assert!(
+        low.distance(Point::new(inset * 0.5, 30.0)) < 1e-4,
+        "{low:?}"
+    );
+    let top = hexagon.attach(Point::new(50.0, 0.0), crate::PinSide::Top, bounds);
+    assert!(top.distance(Point::new(50.0, 0.0)) < 1e-4, "{top:?}");
+    assert!(!hexagon.contains(bounds, Point::new(1.0, 1.0)));
+    let content = hexagon.content_bounds(bounds);
+    assert!((content.x - inset).abs() < 1e-4, "{content:?}");
+    assert!(
+        (content.width - (100.0 - 2.0 * inset)).abs() < 1e-4,
+        "{content:?}"
+    );
+    assert_eq!((content.y, content.height), (0.0, 40.0));
 }
 
 #[test]
-fn box_select_renders_where_dragged_at_nonzero_origin() {
-    // The selection box must render at the screen rectangle the user dragged,
-    // regardless of widget origin or zoom. Box corners map back to the cursor
-    // screen positions, so the select clip should span p1..p2 (plus AA padding).
-    let origin = Vector::new(0.0, 100.0);
-    let p1 = Point::new(40.0, 160.0);
-    let p2 = Point::new(120.0, 240.0);
-    let prims = box_select_primitives(origin, 2.0, p1, p2);
+fn collapsed_node_keeps_its_title_and_stacks_its_pins() {
+    // A 10px title over a 40x20 pin row: collapsed, the body at world
+    // (30,40) is 10px tall and the right-side pin sits on the title row.
+    let mut graph: NodeGraph<'static, usize, usize, (), (), Theme, Rec> = NodeGraph::default()
+        .width(Length::Fixed(400.0))
+        .height(Length::Fixed(400.0))
+        .view(Point::ORIGIN, 1.0);
+    graph.push_node(
+        node(
+            0_usize,
+            Point::new(30.0, 40.0),
+            iced::widget::column![
+                iced::widget::Space::new().width(40.0).height(10.0),
+                crate::pin!(Right, 0_usize, Element::from(ContentProbe), Output),
+            ],
+        )
+        .collapsed(true),
+    );
 
-    let expect = Rectangle::new(p1, Size::new(p2.x - p1.x, p2.y - p1.y));
-    // The far node's layers sit elsewhere; find the primitive near the drag rect.
-    let found = prims.iter().any(|r| {
-        (r.x - expect.x).abs() < 8.0
+    let mut tree = Tree::new(&graph as &dyn Widget<(), Theme, Rec>);
+    let renderer = Rec::new(Rc::new(RefCell::new(Recorded::default())));
+    let layout_node = graph.layout(
+        &mut tree,
+        &renderer,
+        &layout::Limits::new(Size::ZERO, Size::new(1024.0, 768.0)),
+    );
+    let layout = Layout::new(&layout_node);
+
+    let body = layout.children().next().expect("node layout").bounds();
+    assert_eq!(
+        body,
+        Rectangle::new(Point::new(30.0, 40.0), Size::new(40.0, 10.0))
+    );
+    assert_eq!(
+        graph.pin_world_position(&tree, layout, &crate::PinRef::new(0, 0)),
+        Some(Point::new(70.0, 45.0))
+    );
+    // The hidden pin row no longer belongs to the node.
+    assert_eq!(
+        graph.node_at_screen(&tree, layout, Point::new(50.0, 60.0)),
+        None
+    );
+}
+
+/// Lays out a one-node graph whose content is `first` wide, sends an event,
+/// rebuilds it `second` wide on the same tree (as a host view would after an
+/// update), sends another, and returns what `on_node_resized` reported.
+fn resizes_between(first: f32, second: f32) -> Vec<(usize, Size)> {
+    let build = |width: f32| {
+        let mut graph: NodeGraph<'static, usize, usize, (), (usize, Size), Theme, Rec> =
+            NodeGraph::default()
+                .width(Length::Fixed(400.0))
+                .height(Length::Fixed(400.0))
+                .on_node_resized(|id, size| (id, size));
+        graph.push_node(node(
+            0_usize,
+            Point::new(30.0, 40.0),
+            iced::widget::Space::new().width(width).height(20.0),
+        ));
+        graph
+    };
+    let renderer = Rec::new(Rc::new(RefCell::new(Recorded::default())));
+    let limits = layout::Limits::new(Size::ZERO, Size::new(1024.0, 768.0));
+    let mut msgs = Vec::new();
+    let mut graph = build(first);
+    let mut tree = Tree::new(&graph as &dyn Widget<(usize, Size), Theme, Rec>);
+    for width in [first, second] {
+        if width != first {
+            graph = build(width);
+            Widget::<(usize, Size), Theme, Rec>::diff(&graph, &mut tree);
+        }
+        let layout_node = graph.layout(&mut tree, &renderer, &limits);
+        let mut shell = iced_wgpu::core::Shell::new(&mut msgs);
+        graph.update(
+            &mut tree,
+            &iced::Event::Mouse(mouse::Event::CursorMoved {
+                position: Point::new(-1.0, -1.0),
+            }),
+            Layout::new(&layout_node),
+            mouse::Cursor::Unavailable,
+            &renderer,
+            &mut clipboard::Null,
+            &mut shell,
+            &Rectangle::new(Point::ORIGIN, Size::new(1024.0, 768.0)),
+        );
+    }
+    msgs
+}
+
+#[test]
+fn on_node_resized_reports_changed_sizes_only() {
+    assert_eq!(resizes_between(40.0, 40.0), vec![]);
+    assert_eq!(
+        resizes_between(40.0, 90.0),
+        vec![(0, Size::new(90.0, 20.0))]
+    );
+}
+
+#[test]
+fn edge_hover_reports_the_edge_under_the_cursor_on_change() {
+    type Hover = Option<(crate::PinRef<usize, usize>, crate::PinRef<usize, usize>)>;
+    let mut graph: NodeGraph<'static, usize, usize, (), Hover, Theme, Rec> = NodeGraph::default()
+        .width(Length::Fixed(400.0))
+        .height(Length::Fixed(400.0))
+        .on_edge_hover(|edge| edge);
+    graph.push_node(node(
+        0_usize,
+        Point::new(0.0, 0.0),
+        crate::pin!(Right, 0_usize, Element::from(ContentProbe), Output),
+    ));
+    graph.push_node(node(
+        1_usize,
+        Point::new(200.0, 0.0),
+        crate::pin!(Left, 0_usize, Element::from(ContentProbe), Input),
+    ));
+    let wire = (crate::PinRef::new(0, 0), crate::PinRef::new(1, 0));
+    graph.push_edge(crate::edge!(wire.0, wire.1));
+
+    let renderer = Rec::new(Rc::new(RefCell::new(Recorded::default())));
+    let mut tree = Tree::new(&graph as &dyn Widget<Hover, Theme, Rec>);
+    let layout_node = graph.layout(
+        &mut tree,
+        &renderer,
+        &layout::Limits::new(Size::ZERO, Size::new(1024.0, 768.0)),
+    );
+    let layout = Layout::new(&layout_node);
+    let mut msgs = Vec::new();
+    // Off the graph, onto the edge's middle, onto its start pin (the pin
+    // wins), away, away again.
+    for position in [
+        Point::new(-1.0, -1.0),
+        Point::new(120.0, 10.0),
+        Point::new(40.0, 10.0),
+        Point::new(120.0, 100.0),
+        Point::new(121.0, 100.0),
+    ] {
+        let mut shell = iced_wgpu::core::Shell::new(&mut msgs);
+        graph.update(
+            &mut tree,
+            &iced::Event::Mouse(mouse::Event::CursorMoved { position }),
+            layout,
+            mouse::Cursor::Available(position),
+            &renderer,
+            &mut clipboard::Null,
+            &mut shell,
+            &Rectangle::new(Point::ORIGIN, Size::new(1024.0, 768.0)),
+        );
+    }
+    assert_eq!(msgs, vec![Some(wire), None]);
+}
+
+/// The edge hovered at `position` after one draw of two wires running
+/// between a left and a right pair of nodes, bundled with `strength`.
+fn bundled_hover(strength: f32, position: Point) -> Vec<Option<usize>> {
+    let mut graph: NodeGraph<'static, usize, usize, (), Option<usize>, Theme, Rec> =
+        NodeGraph::default()
+            .width(Length::Fixed(800.0))
+            .height(Length::Fixed(400.0))
+            .edge_bundling(strength)
+            .on_edge_hover(|edge| edge.map(|(from, _)| from.node_id));
+    for (id, x, y) in [(0, 0.0, 0.0), (1, 0.0, 100.0)] {
+        graph.push_node(node(
+            id,
+            Point::new(x, y),
+            crate::pin!(Right, 0_usize, Element::from(ContentProbe), Output),
+        ));
+    }
+    for (id, x, y) in [(2, 600.0, 0.0), (3, 600.0, 100.0)] {
+        graph.push_node(node(
+            id,
+            Point::new(x, y),
+            crate::pin!(Left, 0_usize, Element::from(ContentProbe), Input),
+        ));
+    }
+    graph.push_edge(crate::edge!(
+        crate::PinRef::new(0, 0),
+        crate::PinRef::new(2, 0)
+    ));
+    graph.push_edge(crate::edge!(
+        crate::PinRef::new(1, 0),
+        crate::PinRef::new(3, 0)
+    ));
+
+    let mut renderer = Rec::new(Rc::new(RefCell::new(Recorded::default())));
+    let mut tree = Tree::new(&graph as &dyn Widget<Option<usize>, Theme, Rec>);
+    let layout_node = graph.layout(
+        &mut tree,
+        &renderer,
+        &layout::Limits::new(Size::ZERO, Size::new(1024.0, 768.0)),
+    );
+    let layout = Layout::new(&layout_node);
+    let viewport = Rectangle::new(Point::ORIGIN, Size::new(1024.0, 768.0));
+    graph.draw(
+        &tree,
+        &mut renderer,
+        &Theme::Dark,
+        &renderer::Style {
+            text_color: Color::WHITE,
+        },
+        layout,
+        mouse::Cursor::Unavailable,
+        &viewport,
+    );
+    let mut msgs = Vec::new();
+    let mut shell = iced_wgpu::core::Shell::new(&mut msgs);
+    graph.update(
+        &mut tree,
+        &iced::Event::Mouse(mouse::Event::CursorMoved { position }),
+        layout,
+        mouse::Cursor::Available(position),
+        &renderer,
+        &mut clipboard::Null,
+        &mut shell,
+        &viewport,
+    );
+    msgs
+}
+
+#[test]
+fn edge_bundling_pulls_cluster_wires_onto_a_shared_path() {
+    // The shared path runs between the clusters' mean ends, halfway between
+    // the two wires (y 10 and 110); a quarter along it only a bundled wire
+    // passes.
+    let between = Point::new(170.0, 60.0);
+    assert_eq!(bundled_hover(0.0, between), vec![]);
+    let hovered = bundled_hover(1.0, between);
+    assert!(
+        matches!(hovered.as_slice(), [Some(0 | 1)]),
+        "a bundled wire runs through the shared path: {hovered:?}"
+    );
+    // Unbundled, the wires stay where they were.
+    assert_eq!(bundled_hover(0.0, Point::new(320.0, 10.0)), vec![Some(0)]);
+}
+
+/// Hovers `position` over one bezier edge from (40, 10) to (400, 410),
+/// drawn simplified when `threshold` is under the edge count.
+fn simplified_hover(threshold: Option<usize>, position: Point) -> Vec<Option<usize>> {
+    let mut graph: NodeGraph<'static, usize, usize, (), Option<usize>, Theme, Rec> =
+        NodeGraph::default()
+            .width(Length::Fixed(800.0))
+            .height(Length::Fixed(600.0))
+            .on_edge_hover(|edge| edge.map(|(from, _)| from.node_id));
+    if let Some(threshold) = threshold {
+        graph = graph.edge_simplify_threshold(threshold);
+    }
+    graph.push_node(node(
+        0_usize,
+        Point::ORIGIN,
+        crate::pin!(Right, 0_usize, Element::from(ContentProbe), Output),
+    ));
+    graph.push_node(node(
+        1_usize,
+        Point::new(400.0, 400.0),
+        crate::pin!(Left, 0_usize, Element::from(ContentProbe), Input),
+    ));
+    graph.push_edge(crate::edge!(
+        crate::PinRef::new(0, 0),
+        crate::PinRef::new(1, 0)
+    ));
+
+    let mut renderer = Rec::new(Rc::new(RefCell::new(Recorded::default())));
+    let mut tree = Tree::new(&graph as &dyn Widget<Option<usize>, Theme, Rec>);
+    let layout_node = graph.layout(
+        &mut tree,
+        &renderer,
+        &layout::Limits::new(Size::ZERO, Size::new(1024.0, 768.0)),
+    );
+    let layout = Layout::new(&layout_node);
+    let viewport = Rectangle::new(Point::ORIGIN, Size::new(1024.0, 768.0));
+    graph.draw(
+        &tree,
+        &mut renderer,
+        &Theme::Dark,
+        &renderer::Style {
+            text_color: Color::WHITE,
+        },
+        layout,
+        mouse::Cursor::Unavailable,
+        &viewport,
+    );
+    let mut msgs = Vec::new();
+    let mut shell = iced_wgpu::core::Shell::new(&mut msgs);
+    graph.update(
+        &mut tree,
+        &iced::Event::Mouse(mouse::Event::CursorMoved { position }),
+        layout,
+        mouse::Cursor::Available(position),
+        &renderer,
+        &mut clipboard::Null,
+        &mut shell,
+        &viewport,
+    );
+    msgs
+}
+
+#[test]
+fn edge_simplify_threshold_straightens_edges_past_the_count() {
+    // A quarter along the bezier, and a quarter along the straight line.
+    let (on_curve, on_line) = (Point::new(118.8, 72.5), Point::new(130.0, 110.0));
+    for threshold in [None, Some(1)] {
+        assert_eq!(simplified_hover(threshold, on_curve), vec![Some(0)]);
+        assert_eq!(simplified_hover(threshold, on_line), vec![]);
+    }
+    // Over the threshold the edge is the straight line.
+    assert_eq!(simplified_hover(Some(0), on_line), vec![Some(0)]);
+    assert_eq!(simplified_hover(Some(0), on_curve), vec![]);
+}
+
+#[test]
+fn edges_off_screen_are_culled_and_counted() {
+    let mut graph: NodeGraph<'static, usize, usize, (), crate::Counts, Theme, Rec> =
+        NodeGraph::default()
+            .width(Length::Fixed(400.0))
+            .height(Length::Fixed(400.0))
+            .on_info(|info| info.edges);
+    for (id, x, y) in [
+        (0, 0.0, 0.0),
+        (1, 200.0, 100.0),
+        (2, 2000.0, 2000.0),
+        (3, 2300.0, 2100.0),
+    ] {
+        graph.push_node(node(
+            id,
+            Point::new(x, y),
+            iced::widget::column![
+                crate::pin!(Left, 0_usize, Element::from(ContentProbe), Input),
+                crate::pin!(Right, 1_usize, Element::from(ContentProbe), Output),
+            ],
+        ));
+    }
+    // On screen; far off screen; leaving the screen toward the far nodes.
+    for (from, to) in [(0, 1), (2, 3), (1, 2)] {
+        graph.push_edge(crate::edge!(
+            crate::PinRef::new(from, 1),
+            crate::PinRef::new(to, 0)
+        ));
+    }
+
+    let mut renderer = Rec::new(Rc::new(RefCell::new(Recorded::default())));
+    let mut tree = Tree::new(&graph as &dyn Widget<crate::Counts, Theme, Rec>);
+    let layout_node = graph.layout(
+        &mut tree,
+        &renderer,
+        &layout::Limits::new(Size::ZERO, Size::new(1024.0, 768.0)),
+    );
+    let layout = Layout::new(&layout_node);
+    let viewport = Rectangle::new(Point::ORIGIN, Size::new(1024.0, 768.0));
+    graph.draw(
+        &tree,
+        &mut renderer,
+        &Theme::Dark,
+        &renderer::Style {
+            text_color: Color::WHITE,
+        },
+        layout,
+        mouse::Cursor::Unavailable,
+        &viewport,
+    );
+    let mut msgs = Vec::new();
+    let mut shell = iced_wgpu::core::Shell::new(&mut msgs);
+    graph.update(
+        &mut tree,
+        &iced::Event::Window(iced::window::Event::RedrawRequested(
+            web_time::Instant::now(),
+        )),
+        layout,
+        mouse::Cursor::Unavailable,
+        &renderer,
+        &mut clipboard::Null,
+        &mut shell,
+        &viewport,
+    );
+    assert_eq!(
+        msgs,
+        vec![crate::Counts {
+            total: 3,
+            in_view: 2,
+            culled: 1,
+        }]
+    );
+}
+
+/// Drags the node built from `id(1)` while the host rebuilds with the two
+/// nodes pushed in the opposite order, and returns the ids the last
+/// `on_move` reported.
+fn drag_across_reorder<N: crate::ids::NodeId + 'static>(id: fn(usize) -> N) -> Option<Vec<N>> {
+    let build = |order: [usize; 2]| {
+        let mut graph: NodeGraph<'static, N, usize, (), Vec<N>, Theme, Rec> = NodeGraph::default()
+            .width(Length::Fixed(400.0))
+            .height(Length::Fixed(400.0))
+            .on_move(|_, ids| ids);
+        for n in order {
+            let x = if n == 0 { 10.0 } else { 120.0 };
+            graph.push_node(node(
+                id(n),
+                Point::new(x, 10.0),
+                Element::from(ContentProbe),
+            ));
+        }
+        graph
+    };
+    let renderer = Rec::new(Rc::new(RefCell::new(Recorded::default())));
+    let limits = layout::Limits::new(Size::ZERO, Size::new(1024.0, 768.0));
+    let viewport = Rectangle::new(Point::ORIGIN, Size::new(1024.0, 768.0));
+    let mut msgs = Vec::new();
+    let mut graph = build([0, 1]);
+    let mut tree = Tree::new(&graph as &dyn Widget<Vec<N>, Theme, Rec>);
+    let events = [
+        mouse::Event::ButtonPressed(mouse::Button::Left),
+        mouse::Event::CursorMoved {
+            position: Point::new(160.0, 40.0),
+        },
+        mouse::Event::ButtonReleased(mouse::Button::Left),
+    ];
+    let cursors = [
+        Point::new(140.0, 20.0),
+        Point::new(160.0, 40.0),
+        Point::new(160.0, 40.0),
+    ];
+    for (step, (event, cursor)) in events.into_iter().zip(cursors).enumerate() {
+        if step == 1 {
+            // The host pushes the same nodes in the opposite order mid-drag.
+            graph = build([1, 0]);
+            Widget::<Vec<N>, Theme, Rec>::diff(&graph, &mut tree);
+        }
+        let layout_node = graph.layout(&mut tree, &renderer, &limits);
+        let mut shell = iced_wgpu::core::Shell::new(&mut msgs);
+        graph.update(
+            &mut tree,
+            &iced::Event::Mouse(event),
+            Layout::new(&layout_node),
+            mouse::Cursor::Available(cursor),
+            &renderer,
+            &mut clipboard::Null,
+            &mut shell,
+            &viewport,
+        );
+    }
+    msgs.pop()
+}
+
+#[test]
+fn node_drag_follows_its_node_when_a_rebuild_reorders_nodes() {
+    assert_eq!(
+        drag_across_reorder(|n| n),
+        Some(vec![1]),
+        "the drag moved to another node"
+    );
+}
+
+/// Node ids are matched by equality, so ids whose hashes collide still
+/// keep their own state.
+#[test]
+fn node_drag_follows_its_node_when_node_id_hashes_collide() {
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    struct Colliding(usize);
+    impl std::hash::Hash for Colliding {
+        fn hash<H: std::hash::Hasher>(&self, _: &mut H) {}
+    }
+    impl crate::ids::NodeId for Colliding {}
+
+    assert_eq!(
+        drag_across_reorder(Colliding),
+        Some(vec![Colliding(1)]),
+        "the drag moved to another node"
+    );
+}
+
+type Topology = crate::topology::Topology<usize, usize>;
+
+/// Runs one update per frame, rebuilding the graph from `frames` (nodes at
+/// `(id, x)` and edges from node to node) in between, and returns what
+/// `on_graph_changed` published.
+fn topology_changes(frames: &[(&[(usize, f32)], &[(usize, usize)])]) -> Vec<Topology> {
+    let build = |(nodes, edges): (&[(usize, f32)], &[(usize, usize)])| {
+        let mut graph: NodeGraph<'static, usize, usize, (), Topology, Theme, Rec> =
+            NodeGraph::default()
+                .width(Length::Fixed(400.0))
+                .height(Length::Fixed(400.0))
+                .on_graph_changed(Topology::clone);
+        for &(id, x) in nodes {
+            graph.push_node(node(id, Point::new(x, 10.0), Element::from(ContentProbe)));
+        }
+        for &(from, to) in edges {
+            graph.push_edge(crate::edge!(
+                crate::PinRef::new(from, 0),
+                crate::PinRef::new(to, 0)
+            ));
+        }
+        graph
+    };
+    let renderer = Rec::new(Rc::new(RefCell::new(Recorded::default())));
+    let limits = layout::Limits::new(Size::ZERO, Size::new(1024.0, 768.0));
+    let mut msgs = Vec::new();
+    let mut graph = build(frames[0]);
+    let mut tree = Tree::new(&graph as &dyn Widget<Topology, Theme, Rec>);
+    for (step, &frame) in frames.iter().enumerate() {
+        if step > 0 {
+            graph = build(frame);
+            Widget::<Topology, Theme, Rec>::diff(&graph, &mut tree);
+        }
+        let layout_node = graph.layout(&mut tree, &renderer, &limits);
+        let mut shell = iced_wgpu::core::Shell::new(&mut msgs);
+        graph.update(
+            &mut tree,
+            &iced::Event::Mouse(mouse::Event::CursorMoved {
+                position: Point::new(-1.0, -1.0),
+            }),
+            Layout::new(&layout_node),
+            mouse::Cursor::Unavailable,
+            &renderer,
+            &mut clipboard::Null,
+            &mut shell,
+            &Rectangle::new(Point::ORIGIN, Size::new(1024.0, 768.0)),
+        );
+    }
+    msgs
+}
+
+#[test]
+fn on_graph_changed_reports_structural_changes_only() {
+    let nodes: &[(usize, f32)] = &[(0, 10.0), (1, 120.0)];
+    let moved: &[(usize, f32)] = &[(0, 50.0), (1, 160.0)];
+    // Start-up and moves are not structural changes.
+    assert_eq!(topology_changes(&[(nodes, &[]), (moved, &[])]), vec![]);
+
+    let changes = topology_changes(&[(nodes, &[]), (nodes, &[(0, 1)]), (&nodes[..1], &[])]);
+    assert_eq!(changes.len(), 2, "{changes:?}");
+    assert_eq!(changes[0].nodes(), [0, 1]);
+    assert_eq!(changes[0].out_edges(&0).count(), 1);
+    assert_eq!(changes[0].neighbors(&1).collect::<Vec<_>>(), [&0]);
+    assert_eq!(changes[1], Topology::from_count(1, []));
+}
+
+/// Lays out a node at world (30,40) holding a pin group: a 40x20 header over
+/// two 40x20 left-side input rows. Returns each pin's world anchor and the
+/// pin hit at that anchor after one update.
+fn pin_group_anchors(collapsed: bool) -> Vec<(Point, Option<crate::PinRef<usize, usize>>)> {
+    let mut graph: NodeGraph<'static, usize, usize, (), (), Theme, Rec> = NodeGraph::default()
+        .width(Length::Fixed(400.0))
+        .height(Length::Fixed(400.0))
+        .view(Point::ORIGIN, 1.0)
+        .on_connect(|_, _| ());
+    graph.push_node(node(
+        0_usize,
+        Point::new(30.0, 40.0),
+        crate::pin_group(
+            ContentProbe,
+            [0_usize, 1].map(|id| crate::pin!(Left, id, Element::from(ContentProbe), Input).into()),
+        )
+        .collapsed(collapsed),
+    ));
+
+    let mut tree = Tree::new(&graph as &dyn Widget<(), Theme, Rec>);
+    let renderer = Rec::new(Rc::new(RefCell::new(Recorded::default())));
+    let layout_node = graph.layout(
+        &mut tree,
+        &renderer,
+        &layout::Limits::new(Size::ZERO, Size::new(1024.0, 768.0)),
+    );
+    let layout = Layout::new(&layout_node);
+    let mut msgs: Vec<()> = Vec::new();
+    let mut shell = iced_wgpu::core::Shell::new(&mut msgs);
+    graph.update(
+        &mut tree,
+        &iced::Event::Mouse(mouse::Event::CursorMoved {
+            position: Point::new(-1.0, -1.0),
+        }),
+        layout,
+        mouse::Cursor::Unavailable,
+        &renderer,
+        &mut clipboard::Null,
+        &mut shell,
+        &Rectangle::new(Point::ORIGIN, Size::new(1024.0, 768.0)),
+    );
+
+    [0, 1]
+        .map(|id| {
+            let at = graph
+                .pin_world_position(&tree, layout, &crate::PinRef::new(0, id))
+                .expect("pin resolves");
+            (at, graph.pin_at_screen(&tree, layout, at))
+        })
+        .to_vec()
+}
+
+#[test]
+fn collapsed_pin_group_moves_its_pins_onto_the_header() {
+    assert_eq!(
+        pin_group_anchors(false),
+        vec![
+            (Point::new(30.0, 70.0), Some(crate::PinRef::new(0, 0))),
+            (Point::new(30.0, 90.0), Some(crate::PinRef::new(0, 1))),
+        ]
+    );
+    // Collapsed: both pins anchor on the header row and take no drags.
+    assert_eq!(
+        pin_group_anchors(true),
+        vec![
+            (Point::new(30.0, 50.0), None),
+            (Point::new(30.0, 50.0), None)
+        ]
+    );
+}
+
+/// Rightmost screen x reached by the node's SDF layers for a 40x20 node at
+/// world (30,40) with an output pin on its right border, drawn at the origin
+/// and zoom 1 under `visibility`. A drawn pin reaches past the border stroke.
+fn pin_layer_reach(visibility: crate::PinVisibility, cursor: mouse::Cursor) -> f32 {
+    let mut graph: NodeGraph<'static, usize, usize, (), (), Theme, Rec> = NodeGraph::default()
+        .width(Length::Fixed(400.0))
+        .height(Length::Fixed(400.0))
+        .pin_visibility(visibility);
+    graph.push_node(node(
+        0_usize,
+        Point::new(30.0, 40.0),
+        crate::pin!(Right, 0_usize, Element::from(ContentProbe), Output),
+    ));
+    let rec = draw_graph_with_cursor(graph, Vector::ZERO, cursor);
+    rec.primitives
+        .iter()
+        .filter(|p| p.width <= 120.0 && p.height <= 120.0)
+        .map(|p| p.x + p.width)
+        .fold(f32::MIN, f32::max)
+}
+
+#[test]
+fn pin_visibility_hides_unhovered_and_unconnected_pins() {
+    use crate::PinVisibility;
+    let away = mouse::Cursor::Available(Point::new(300.0, 300.0));
+    let over = mouse::Cursor::Available(Point::new(50.0, 50.0));
+    let shown = pin_layer_reach(PinVisibility::Always, away);
+    assert!(shown > 70.0 + 2.0, "pin indicator drawn past the border");
+    assert!(pin_layer_reach(PinVisibility::OnHover, away) < shown);
+    assert!(pin_layer_reach(PinVisibility::Connected, away) < shown);
+    assert_eq!(pin_layer_reach(PinVisibility::OnHover, over), shown);
+}
+
+#[test]
+fn public_hit_tests_map_screen_points_at_nonzero_origin_zoom2() {
+    // One node at world (30,40) whose 40x20 body is an output pin on its right
+    // side: the pin anchors at world (70,50). Graph at (0,100), zoom 2, so the
+    // body center is screen (100,200) and the pin (140,200).
+    let mut graph: NodeGraph<'static, usize, usize, (), (), Theme, Rec> = NodeGraph::default()
+        .width(Length::Fixed(400.0))
+        .height(Length::Fixed(400.0))
+        .view(Point::ORIGIN, 2.0);
+    graph.push_node(node(
+        0_usize,
+        Point::new(30.0, 40.0),
+        crate::pin!(Right, 0_usize, Element::from(ContentProbe), Output),
+    ));
+
+    let mut tree = Tree::new(&graph as &dyn Widget<(), Theme, Rec>);
+    let renderer = Rec::new(Rc::new(RefCell::new(Recorded::default())));
+    let layout_node = graph.layout(
+        &mut tree,
+        &renderer,
+        &layout::Limits::new(Size::ZERO, Size::new(1024.0, 768.0)),
+    );
+    let layout = Layout::with_offset(Vector::new(0.0, 100.0), &layout_node);
+
+    // One update syncs the camera and lets the pin record its place.
+    let mut msgs: Vec<()> = Vec::new();
+    let mut shell = iced_wgpu::core::Shell::new(&mut msgs);
+    graph.update(
+        &mut tree,
+        &iced::Event::Mouse(mouse::Event::CursorMoved {
+            position: Point::new(-1.0, -1.0),
+        }),
+        layout,
+        mouse::Cursor::Unavailable,
+        &renderer,
+        &mut clipboard::Null,
+        &mut shell,
+        &Rectangle::new(Point::ORIGIN, Size::new(1024.0, 768.0)),
+    );
+
+    let pin = Some(crate::PinRef::new(0, 0));
+    assert_eq!(
+        graph.pin_at_screen(&tree, layout, Point::new(140.0, 200.0)),
+        pin
+    );
+    // Reach is constant on screen: 6px off still hits at zoom 2.
+    assert_eq!(
+        graph.pin_at_screen(&tree, layout, Point::new(146.0, 200.0)),
+        pin
+    );
+    // The body center is the node, not its pin.
+    assert_eq!(
+        graph.pin_at_screen(&tree, layout, Point::new(100.0, 200.0)),
+        None
+    );
+    assert_eq!(
+        graph.node_at_screen(&tree, layout, Point::new(100.0, 200.0)),
+        Some(0)
+    );
+    // Empty canvas, and a point above the widget.
+    assert_eq!(
+        graph.node_at_screen(&tree, layout, Point::new(300.0, 300.0)),
+        None
+    );
+    assert_eq!(
+        graph.node_at_screen(&tree, layout, Point::new(100.0, 90.0)),
+        None
+    );
+}
+
+/// Snapshots a graph holding one probe node at `world`, as laid out for a
+/// 400x400 widget under a (`camera_pos`, `camera_zoom`) camera.
+fn snapshot_probe(world: Point, camera_pos: Point, camera_zoom: f32) -> crate::LayoutSnapshot {
+    let mut graph: NodeGraph<'static, usize, usize, (), (), Theme, Rec> = NodeGraph::default()
+        .width(Length::Fixed(400.0))
+        .height(Length::Fixed(400.0));
+    graph.push_node(node(0_usize, world, Element::from(ContentProbe)));
+    let renderer = Rec::new(Rc::new(RefCell::new(Recorded::default())));
+    graph.layout_snapshot(&renderer, Size::new(400.0, 400.0), camera_pos, camera_zoom)
+}
+
+#[test]
+fn layout_snapshot_matches_rendered_content() {
+    let world = Point::new(30.0, 40.0);
+    for (pos, zoom) in [(Point::ORIGIN, 1.0), (Point::new(10.0, -5.0), 2.0)] {
+        let snapshot = snapshot_probe(world, pos, zoom);
+        let drawn = node_content_quad(&draw_at_origin(Vector::ZERO, world, pos, zoom));
+        assert_eq!(snapshot.node(&0), Some(drawn), "camera ({pos:?}, {zoom})");
+    }
+}
+
+#[test]
+fn layout_snapshot_projects_edge_pin_anchors() {
+    // Output pin on the right of a 40x20 body at world (30,40) anchors at
+    // (70,50); input pin on the left of one at (200,40) at (200,50).
+    let mut graph: NodeGraph<'static, usize, usize, (), (), Theme, Rec> = NodeGraph::default()
+        .width(Length::Fixed(400.0))
+        .height(Length::Fixed(400.0));
+    graph.push_node(node(
+        0_usize,
+        Point::new(30.0, 40.0),
+        crate::pin!(Right, 0_usize, Element::from(ContentProbe), Output),
+    ));
+    graph.push_node(node(
+        1_usize,
+        Point::new(200.0, 40.0),
+        crate::pin!(Left, 0_usize, Element::from(ContentProbe), Input),
+    ));
+    graph.push_edge(crate::edge!(
+        crate::PinRef::new(0, 0),
+        crate::PinRef::new(1, 0)
+    ));
+
+    let renderer = Rec::new(Rc::new(RefCell::new(Recorded::default())));
+    let snapshot = graph.layout_snapshot(
+        &renderer,
+        Size::new(400.0, 400.0),
+        Point::new(10.0, 0.0),
+        2.0,
+    );
+
+    // screen = (world + camera_position) * zoom
+    assert_eq!(
+        snapshot.node(&0),
+        Some(Rectangle::new(
+            Point::new(80.0, 80.0),
+            Size::new(80.0, 40.0)
+        )),
+    );
+    assert_eq!(
+        snapshot.edges,
+        vec![crate::EdgeSnapshot {
+            from: crate::PinRef::new(0, 0),
+            to: crate::PinRef::new(1, 0),
+            start: Point::new(160.0, 100.0),
+            end: Point::new(420.0, 100.0),
+        }],
+    );
+}
+
+#[test]
+fn auto_pin_side_leaves_from_the_border_facing_the_other_node() {
+    // The output's node sits right of the input's: with auto sides the edge
+    // leaves the output node's left border and enters the input's right one.
+    let snapshot_edge = |auto: bool| {
+        let mut graph: NodeGraph<'static, usize, usize, (), (), Theme, Rec> =
+            NodeGraph::default().auto_pin_side(auto);
+        graph.push_node(node(
+            0_usize,
+            Point::new(200.0, 40.0),
+            crate::pin!(Right, 0_usize, Element::from(ContentProbe), Output),
+        ));
+        graph.push_node(node(
+            1_usize,
+            Point::new(30.0, 40.0),
+            crate::pin!(Left, 0_usize, Element::from(ContentProbe), Input),
+        ));
+        graph.push_edge(crate::edge!(
+            crate::PinRef::new(0, 0),
+            crate::PinRef::new(1, 0)
+        ));
+        let renderer = Rec::new(Rc::new(RefCell::new(Recorded::default())));
+        let edge = graph
+            .layout_snapshot(&renderer, Size::new(400.0, 400.0), Point::ORIGIN, 1.0)
+            .edges
+            .remove(0);
+        (edge.start, edge.end)
+    };
+    assert_eq!(
+        snapshot_edge(false),
+        (Point::new(240.0, 50.0), Point::new(30.0, 50.0))
+    );
+    assert_eq!(
+        snapshot_edge(true),
+        (Point::new(200.0, 50.0), Point::new(70.0, 50.0))
+    );
+}
+
+// Antialias padding the fill clip adds around the node bbox.
+const FILL_PAD: f32 = 6.0;
+
+#[test]
+fn content_and_fill_correct_at_origin_zoom1() {
+    // Graph at (0, 100); node world (30, 40); default camera. Both content and
+    // fill must land at origin + world = (30, 140).
+    let origin = Vector::new(0.0, 100.0);
+    let world = Point::new(30.0, 40.0);
+    let rec = draw_at_origin(origin, world, Point::ORIGIN, 1.0);
+    let expected = expected_screen(origin, world, Point::ORIGIN, 1.0);
+
+    let content = node_content_quad(&rec);
+    let fill = node_fill_primitive(&rec);
+
+    assert!(
+        (content.x - expected.x).abs() < 1.0 && (content.y - expected.y).abs() < 1.0,
+        "content {content:?} should sit at {expected:?}",
+    );
+    assert!(
+        (fill.x - expected.x).abs() < FILL_PAD && (fill.y - expected.y).abs() < FILL_PAD,
+        "fill {fill:?} should sit at {expected:?}",
+    );
+}
+
+/// Draws `graph` at the window origin straight after building its tree, with
+/// no update in between: what the very first frame shows.
+fn draw_first_frame(mut graph: NodeGraph<'static, usize, usize, (), (), Theme, Rec>) -> Recorded {
+    let mut tree = Tree::new(&graph as &dyn Widget<(), Theme, Rec>);
+    let layout_node = graph.layout(
+        &mut tree,
+        &Rec::new(Rc::new(RefCell::new(Recorded::default()))),
+        &layout::Limits::new(Size::ZERO, Size::new(1024.0, 768.0)),
+    );
+    let out = Rc::new(RefCell::new(Recorded::default()));
+    let mut renderer = Rec::new(out.clone());
+    graph.draw(
+        &tree,
+        &mut renderer,
+        &Theme::Dark,
+        &renderer::Style {
+            text_color: Color::WHITE,
+        },
+        Layout::new(&layout_node),
+        mouse::Cursor::Unavailable,
+        &Rectangle::new(Point::ORIGIN, Size::new(1024.0, 768.0)),
+    );
+    out.borrow().clone()
+}
+
+#[test]
+fn initial_camera_applies_to_the_first_frame() {
+    let pos = Point::new(-20.0, 30.0);
+    let world = Point::new(50.0, 60.0);
+    let build = |configure: fn(
+        NodeGraph<'static, usize, usize, (), (), Theme, Rec>,
+    ) -> NodeGraph<'static, usize, usize, (), (), Theme, Rec>| {
+        let mut graph = configure(
+            NodeGraph::default()
+                .width(Length::Fixed(400.0))
+                .height(Length::Fixed(400.0)),
+        );
+        graph.push_node(node(0_usize, world, Element::from(ContentProbe)));
+        node_content_quad(&draw_first_frame(graph)).position()
+    };
+    let expected = expected_screen(Vector::ZERO, world, pos, 2.0);
+    assert_eq!(
+        build(|g| g.initial_camera(Point::new(-20.0, 30.0), 2.0)),
+        expected
+    );
+    assert_eq!(build(|g| g.view(Point::new(-20.0, 30.0), 2.0)), expected);
+}
+
+#[test]
+fn content_correct_at_origin_zoom2() {
+    // The crux: at zoom != 1 with a non-zero widget origin, content must land at
+    // origin + (world + pos) * zoom, NOT zoom * (origin + world + pos).
+    let origin = Vector::new(0.0, 100.0);
+    let world = Point::new(30.0, 40.0);
+    let zoom = 2.0;
+    let rec = draw_at_origin(origin, world, Point::ORIGIN, zoom);
+    let expected = expected_screen(origin, world, Point::ORIGIN, zoom);
+
+    let content = node_content_quad(&rec);
+    assert!(
+        (content.x - expected.x).abs() < 1.0 && (content.y - expected.y).abs() < 1.0,
+        "content {content:?} should sit at {expected:?} at zoom {zoom} \
+         with widget origin {origin:?}",
+    );
+}
+
+#[test]
+fn fill_correct_at_origin_zoom2() {
+    let origin = Vector::new(0.0, 100.0);
+    let world = Point::new(30.0, 40.0);
+    let zoom = 2.0;
+    let rec = draw_at_origin(origin, world, Point::ORIGIN, zoom);
+    let expected = expected_screen(origin, world, Point::ORIGIN, zoom);
+
+    let fill = node_fill_primitive(&rec);
+    assert!(
+        (fill.x - expected.x).abs() < FILL_PAD && (fill.y - expected.y).abs() < FILL_PAD,
+        "fill {fill:?} should sit at {expected:?} at zoom {zoom} \
+         with widget origin {origin:?}",
+    );
+}
+
+#[test]
+fn content_and_fill_coincide_at_origin_zoom2() {
+    // Regardless of correctness vs. world, the two layers must not drift apart.
+    let origin = Vector::new(0.0, 100.0);
+    let world = Point::new(30.0, 40.0);
+    let rec = draw_at_origin(origin, world, Point::ORIGIN, 2.0);
+
+    let content = node_content_quad(&rec);
+    let fill = node_fill_primitive(&rec);
+    let dx = (content.x - fill.x).abs();
+    let dy = (content.y - fill.y).abs();
+    assert!(
+        dx < FILL_PAD && dy < FILL_PAD,
+        "content {content:?} and fill {fill:?} diverge (dx={dx}, dy={dy})",
+    );
+}
+
+/// Drags a box-select from screen `p1` to `p2` over empty graph space (the only
+/// node is far away) and returns the SDF primitives recorded by a final draw.
+fn box_select_primitives(
+    widget_origin: Vector,
+    camera_zoom: f32,
+    p1: Point,
+    p2: Point,
+) -> Vec<Rectangle> {
+    let mut graph: NodeGraph<'static, usize, usize, (), (), Theme, Rec> = NodeGraph::default()
+        .width(Length::Fixed(400.0))
+        .height(Length::Fixed(400.0))
+        .view(Point::ORIGIN, camera_zoom)
+        .on_select(|_ids| {});
+    // Node far from the drag so the press starts a box select, not a node click.
+    graph.push_node(node(
+        0_usize,
+        Point::new(900.0, 900.0),
+        Element::from(ContentProbe),
+    ));
+
+    let mut tree = Tree::new(&graph as &dyn Widget<(), Theme, Rec>);
+    let out = Rc::new(RefCell::new(Recorded::default()));
+    let mut renderer = Rec::new(out.clone());
+    let layout_node = graph.layout(
+        &mut tree,
+        &renderer,
+        &layout::Limits::new(Size::ZERO, Size::new(1024.0, 768.0)),
+    );
+    let layout = Layout::with_offset(widget_origin, &layout_node);
+    let viewport = Rectangle::new(Point::ORIGIN, Size::new(1024.0, 768.0));
+    let mut msgs: Vec<()> = Vec::new();
+    let mut shell = iced_wgpu::core::Shell::new(&mut msgs);
+    let mut clipboard = clipboard::Null;
+
+    let send = |graph: &mut NodeGraph<'static, usize, usize, (), (), Theme, Rec>,
+                tree: &mut Tree,
+                shell: &mut iced_wgpu::core::Shell<'_, ()>,
+                clipboard: &mut clipboard::Null,
+                renderer: &Rec,
+                event: iced::Event,
+                at: Point| {
+        graph.update(
+            tree,
+            &event,
+            layout,
+            mouse::Cursor::Available(at),
+            renderer,
+            clipboard,
+            shell,
+            &viewport,
+        );
+    };
+
+    // Move to p1, press (starts box select at p1), drag to p2.
+    send(
+        &mut graph,
+        &mut tree,
+        &mut shell,
+        &mut clipboard,
+        &renderer,
+        iced::Event::Mouse(mouse::Event::CursorMoved { position: p1 }),
+        p1,
+    );
+    send(
+        &mut graph,
+        &mut tree,
+        &mut shell,
+        &mut clipboard,
+        &renderer,
+        iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+        p1,
+    );
+    send(
+        &mut graph,
+        &mut tree,
+        &mut shell,
+        &mut clipboard,
+        &renderer,
+        iced::Event::Mouse(mouse::Event::CursorMoved { position: p2 }),
+        p2,
+    );
+
+    graph.draw(
+        &tree,
+        &mut renderer,
+        &Theme::Dark,
+        &renderer::Style {
+            text_color: Color::WHITE,
+        },
+        layout,
+        mouse::Cursor::Available(p2),
+        &viewport,
+    );
+
+    out.borrow().primitives.clone()
+}
+
+#[test]
+fn box_select_renders_where_dragged_at_nonzero_origin() {
+    // The selection box must render at the screen rectangle the user dragged,
+    // regardless of widget origin or zoom. Box corners map back to the cursor
+    // screen positions, so the select clip should span p1..p2 (plus AA padding).
+    let origin = Vector::new(0.0, 100.0);
+    let p1 = Point::new(40.0, 160.0);
+    let p2 = Point::new(120.0, 240.0);
+    let prims = box_select_primitives(origin, 2.0, p1, p2);
+
+    let expect = Rectangle::new(p1, Size::new(p2.x - p1.x, p2.y - p1.y));
+    // The far node's layers sit elsewhere; find the primitive near the drag rect.
+    let found = prims.iter().any(|r| {
+        (r.x - expect.x).abs() < 8.0
             && (r.y - expect.y).abs() < 8.0
             && (r.width - expect.width).abs() < 12.0
             && (r.height - expect.height).abs() < 12.0
@@ -563,6 +1681,179 @@ fn box_select_renders_where_dragged_at_nonzero_origin() {
     );
 }
 
+#[test]
+fn box_select_previews_the_nodes_it_would_select() {
+    // Mid-drag, before any release, the node inside the box already draws
+    // selected and the one outside does not; the release then selects the same.
+    let statuses = Rc::new(RefCell::new(Vec::new()));
+    let recorded = statuses.clone();
+    let mut graph: NodeGraph<'static, usize, usize, (), Vec<usize>, Theme, Rec> =
+        NodeGraph::default()
+            .width(Length::Fixed(400.0))
+            .height(Length::Fixed(400.0))
+            .node_style(move |theme, status| {
+                recorded.borrow_mut().push(status);
+                crate::style::default_node_style(theme, status)
+            })
+            .on_select(|ids| ids);
+    graph.push_node(node(
+        0_usize,
+        Point::new(40.0, 40.0),
+        Element::from(ContentProbe),
+    ));
+    graph.push_node(node(
+        1_usize,
+        Point::new(300.0, 300.0),
+        Element::from(ContentProbe),
+    ));
+
+    let renderer = Rec::new(Rc::new(RefCell::new(Recorded::default())));
+    let mut tree = Tree::new(&graph as &dyn Widget<Vec<usize>, Theme, Rec>);
+    let layout_node = graph.layout(
+        &mut tree,
+        &renderer,
+        &layout::Limits::new(Size::ZERO, Size::new(1024.0, 768.0)),
+    );
+    let layout = Layout::new(&layout_node);
+    let viewport = Rectangle::new(Point::ORIGIN, Size::new(1024.0, 768.0));
+    let mut msgs = Vec::new();
+    let mut send = |graph: &mut NodeGraph<'static, usize, usize, (), Vec<usize>, Theme, Rec>,
+                    tree: &mut Tree,
+                    event: mouse::Event,
+                    at: Point| {
+        let mut shell = iced_wgpu::core::Shell::new(&mut msgs);
+        graph.update(
+            tree,
+            &iced::Event::Mouse(event),
+            layout,
+            mouse::Cursor::Available(at),
+            &renderer,
+            &mut clipboard::Null,
+            &mut shell,
+            &viewport,
+        );
+    };
+    let (from, to) = (Point::new(10.0, 10.0), Point::new(120.0, 120.0));
+    send(
+        &mut graph,
+        &mut tree,
+        mouse::Event::CursorMoved { position: from },
+        from,
+    );
+    send(
+        &mut graph,
+        &mut tree,
+        mouse::Event::ButtonPressed(mouse::Button::Left),
+        from,
+    );
+    send(
+        &mut graph,
+        &mut tree,
+        mouse::Event::CursorMoved { position: to },
+        to,
+    );
+
+    statuses.borrow_mut().clear();
+    let mut draw_renderer = Rec::new(Rc::new(RefCell::new(Recorded::default())));
+    graph.draw(
+        &tree,
+        &mut draw_renderer,
+        &Theme::Dark,
+        &renderer::Style {
+            text_color: Color::WHITE,
+        },
+        layout,
+        mouse::Cursor::Available(to),
+        &viewport,
+    );
+    assert_eq!(
+        *statuses.borrow(),
+        [crate::NodeStatus::Selected, crate::NodeStatus::Idle]
+    );
+
+    send(
+        &mut graph,
+        &mut tree,
+        mouse::Event::ButtonReleased(mouse::Button::Left),
+        to,
+    );
+    assert_eq!(msgs.last(), Some(&vec![0]));
+}
+
+/// Box-selects from screen `from` to `to` under `rule` over one node at world
+/// (40, 40) and returns the ids the release reports.
+fn box_select_ids(rule: crate::SelectRule, from: Point, to: Point) -> Vec<usize> {
+    let mut graph: NodeGraph<'static, usize, usize, (), Vec<usize>, Theme, Rec> =
+        NodeGraph::default()
+            .width(Length::Fixed(400.0))
+            .height(Length::Fixed(400.0))
+            .box_select_rule(rule)
+            .on_select(|ids| ids);
+    graph.push_node(node(
+        0_usize,
+        Point::new(40.0, 40.0),
+        Element::from(ContentProbe),
+    ));
+    let renderer = Rec::new(Rc::new(RefCell::new(Recorded::default())));
+    let mut tree = Tree::new(&graph as &dyn Widget<Vec<usize>, Theme, Rec>);
+    let layout_node = graph.layout(
+        &mut tree,
+        &renderer,
+        &layout::Limits::new(Size::ZERO, Size::new(1024.0, 768.0)),
+    );
+    let mut msgs = Vec::new();
+    for (event, at) in [
+        (mouse::Event::CursorMoved { position: from }, from),
+        (mouse::Event::ButtonPressed(mouse::Button::Left), from),
+        (mouse::Event::CursorMoved { position: to }, to),
+        (mouse::Event::ButtonReleased(mouse::Button::Left), to),
+    ] {
+        let mut shell = iced_wgpu::core::Shell::new(&mut msgs);
+        graph.update(
+            &mut tree,
+            &iced::Event::Mouse(event),
+            Layout::new(&layout_node),
+            mouse::Cursor::Available(at),
+            &renderer,
+            &mut clipboard::Null,
+            &mut shell,
+            &Rectangle::new(Point::ORIGIN, Size::new(1024.0, 768.0)),
+        );
+    }
+    msgs.pop().expect("release reports the selection")
+}
+
+#[test]
+fn box_select_rule_picks_touched_or_enclosed_nodes() {
+    use crate::SelectRule;
+    // The 40x20 node spans (40, 40)..(80, 60): `partial` clips its corner,
+    // `whole` encloses it.
+    let partial = (Point::new(10.0, 10.0), Point::new(60.0, 50.0));
+    let whole = (Point::new(10.0, 10.0), Point::new(120.0, 90.0));
+    assert_eq!(
+        box_select_ids(SelectRule::Intersect, partial.0, partial.1),
+        [0]
+    );
+    assert_eq!(
+        box_select_ids(SelectRule::Contain, partial.0, partial.1),
+        Vec::<usize>::new()
+    );
+    assert_eq!(box_select_ids(SelectRule::Contain, whole.0, whole.1), [0]);
+    // Left to right contains, right to left intersects.
+    assert_eq!(
+        box_select_ids(SelectRule::ByDirection, partial.0, partial.1),
+        Vec::<usize>::new()
+    );
+    assert_eq!(
+        box_select_ids(SelectRule::ByDirection, partial.1, partial.0),
+        [0]
+    );
+    assert_eq!(
+        box_select_ids(SelectRule::ByDirection, whole.0, whole.1),
+        [0]
+    );
+}
+
 // ---------------------------------------------------------------------------
 // SDF culling: a node whose screen bounds fall entirely outside the graph must
 // not emit its fill/border/pin primitives (clipped_shape_bounds returns None).
@@ -790,6 +2081,102 @@ fn recipe_hash_is_stable_across_120_frames() {
     }
 }
 
+/// Draws a one-node graph whose border flows, then feeds it a redraw and
+/// reports whether the widget asked for another frame.
+fn requests_redraw_after_flowing_frame(
+    configure: impl FnOnce(
+        NodeGraph<'static, usize, usize, (), (), Theme, Rec>,
+    ) -> NodeGraph<'static, usize, usize, (), (), Theme, Rec>,
+) -> bool {
+    let mut graph = configure(
+        NodeGraph::default()
+            .width(Length::Fixed(400.0))
+            .height(Length::Fixed(400.0))
+            .reduced_motion(false),
+    );
+    graph.push_node(
+        node(0_usize, Point::new(40.0, 40.0), Element::from(ContentProbe)).style(
+            |theme, status| crate::NodeStyle {
+                border_pattern: crate::Pattern::dashed(2.0, 6.0, 4.0).flow(40.0),
+                ..crate::default_node_style(theme, status)
+            },
+        ),
+    );
+
+    let mut tree = Tree::new(&graph as &dyn Widget<(), Theme, Rec>);
+    let mut renderer = Rec::new(Rc::new(RefCell::new(Recorded::default())));
+    let layout_node = graph.layout(
+        &mut tree,
+        &renderer,
+        &layout::Limits::new(Size::ZERO, Size::new(1024.0, 768.0)),
+    );
+    let layout = Layout::new(&layout_node);
+    let viewport = Rectangle::new(Point::ORIGIN, Size::new(1024.0, 768.0));
+    graph.draw(
+        &tree,
+        &mut renderer,
+        &Theme::Dark,
+        &renderer::Style {
+            text_color: Color::WHITE,
+        },
+        layout,
+        mouse::Cursor::Unavailable,
+        &viewport,
+    );
+
+    let mut msgs: Vec<()> = Vec::new();
+    let mut shell = iced_wgpu::core::Shell::new(&mut msgs);
+    graph.update(
+        &mut tree,
+        &iced::Event::Window(iced::window::Event::RedrawRequested(
+            web_time::Instant::now(),
+        )),
+        layout,
+        mouse::Cursor::Unavailable,
+        &renderer,
+        &mut clipboard::Null,
+        &mut shell,
+        &viewport,
+    );
+    shell.redraw_request() == iced::window::RedrawRequest::NextFrame
+}
+
+#[test]
+fn animations_off_stops_continuous_redraws() {
+    assert!(requests_redraw_after_flowing_frame(|g| g));
+    assert!(!requests_redraw_after_flowing_frame(
+        |g| g.animations_enabled(false)
+    ));
+}
+
+#[test]
+fn reduced_motion_stops_continuous_redraws() {
+    assert!(!requests_redraw_after_flowing_frame(
+        |g| g.reduced_motion(true)
+    ));
+}
+
+#[test]
+fn reduced_motion_hard_clamps_elastic_zoom() {
+    // A wheel burst far past ZOOM_MAX: elastic zoom overshoots and defers the
+    // commit to the bounce, reduced motion clamps and commits immediately.
+    let cursor = mouse::Cursor::Available(Point::new(200.0, 200.0));
+    let wheel = iced::Event::Mouse(mouse::Event::WheelScrolled {
+        delta: mouse::ScrollDelta::Pixels { x: 0.0, y: 1000.0 },
+    });
+    let zooms = |reduced_motion: bool| {
+        let graph: NodeGraph<'static, usize, usize, (), f32, Theme, Rec> = NodeGraph::default()
+            .width(Length::Fixed(400.0))
+            .height(Length::Fixed(400.0))
+            .zoom_elastic(true)
+            .reduced_motion(reduced_motion)
+            .on_pan(|_position, zoom| zoom);
+        run_events(graph, &[(wheel.clone(), cursor)])
+    };
+    assert!(zooms(false).is_empty());
+    assert_eq!(zooms(true), vec![crate::Camera2D::ZOOM_MAX]);
+}
+
 // ---------------------------------------------------------------------------
 // Host-integration sandwich order. Hosted iced content interleaves BETWEEN a
 // node's SDF layers: per node, in z-order, the stack is [SDF fill,
@@ -921,10 +2308,29 @@ fn key_press(c: char, code: keyboard::key::Code, modifiers: keyboard::Modifiers)
     })
 }
 
+/// A left press on empty canvas: gives the graph keyboard focus, which the
+/// selection shortcuts require.
+fn focus_press() -> (iced::Event, mouse::Cursor) {
+    (
+        iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+        mouse::Cursor::Available(Point::new(300.0, 300.0)),
+    )
+}
+
 /// Builds a two-node graph, feeds it `events` (each with its cursor), and
 /// returns every message the widget published.
 fn run_events<Msg: 'static>(
+    graph: NodeGraph<'static, usize, usize, (), Msg, Theme, Rec>,
+    events: &[(iced::Event, mouse::Cursor)],
+) -> Vec<Msg> {
+    run_events_at(graph, Vector::ZERO, events)
+}
+
+/// [`run_events`] with the graph laid out at `widget_origin` in the window,
+/// as below a toolbar.
+fn run_events_at<Msg: 'static>(
     mut graph: NodeGraph<'static, usize, usize, (), Msg, Theme, Rec>,
+    widget_origin: Vector,
     events: &[(iced::Event, mouse::Cursor)],
 ) -> Vec<Msg> {
     graph.push_node(node(
@@ -938,54 +2344,243 @@ fn run_events<Msg: 'static>(
         Element::from(ContentProbe),
     ));
 
-    let mut tree = Tree::new(&graph as &dyn Widget<Msg, Theme, Rec>);
+    let mut tree = Tree::new(&graph as &dyn Widget<Msg, Theme, Rec>);
+    let renderer = Rec::new(Rc::new(RefCell::new(Recorded::default())));
+    let layout_node = graph.layout(
+        &mut tree,
+        &renderer,
+        &layout::Limits::new(Size::ZERO, Size::new(1024.0, 768.0)),
+    );
+    let layout = Layout::with_offset(widget_origin, &layout_node);
+    let viewport = Rectangle::new(Point::ORIGIN, Size::new(1024.0, 768.0));
+
+    let mut msgs: Vec<Msg> = Vec::new();
+    let mut clipboard = clipboard::Null;
+    for (event, cursor) in events {
+        let mut shell = iced_wgpu::core::Shell::new(&mut msgs);
+        graph.update(
+            &mut tree,
+            event,
+            layout,
+            *cursor,
+            &renderer,
+            &mut clipboard,
+            &mut shell,
+            &viewport,
+        );
+    }
+    msgs
+}
+
+#[test]
+fn default_keymap_select_all_publishes_selection() {
+    let graph: NodeGraph<'static, usize, usize, (), Vec<usize>, Theme, Rec> = NodeGraph::default()
+        .width(Length::Fixed(400.0))
+        .height(Length::Fixed(400.0))
+        .on_select(|ids| ids);
+
+    let msgs = run_events(
+        graph,
+        &[
+            focus_press(),
+            (
+                key_press('a', keyboard::key::Code::KeyA, keyboard::Modifiers::COMMAND),
+                mouse::Cursor::Unavailable,
+            ),
+        ],
+    );
+    let mut selected = msgs
+        .into_iter()
+        .next()
+        .expect("select-all published no selection");
+    selected.sort_unstable();
+    assert_eq!(selected, vec![0, 1]);
+}
+
+/// Node content that takes every key press, like a focused text input.
+struct KeyEater;
+
+impl<Message> Widget<Message, Theme, Rec> for KeyEater {
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fixed(40.0), Length::Fixed(20.0))
+    }
+    fn layout(&mut self, _: &mut Tree, _: &Rec, limits: &layout::Limits) -> layout::Node {
+        layout::Node::new(limits.resolve(Length::Fixed(40.0), Length::Fixed(20.0), Size::ZERO))
+    }
+    fn draw(
+        &self,
+        _: &Tree,
+        _: &mut Rec,
+        _: &Theme,
+        _: &renderer::Style,
+        _: Layout<'_>,
+        _: mouse::Cursor,
+        _: &Rectangle,
+    ) {
+    }
+    fn update(
+        &mut self,
+        _: &mut Tree,
+        event: &iced::Event,
+        _: Layout<'_>,
+        _: mouse::Cursor,
+        _: &Rec,
+        _: &mut dyn iced::advanced::Clipboard,
+        shell: &mut iced_wgpu::core::Shell<'_, Message>,
+        _: &Rectangle,
+    ) {
+        if let iced::Event::Keyboard(keyboard::Event::KeyPressed { .. }) = event {
+            shell.capture_event();
+        }
+    }
+}
+
+#[test]
+fn selection_shortcuts_yield_to_node_content_and_need_focus() {
+    let ctrl = |c, code| {
+        (
+            key_press(c, code, keyboard::Modifiers::COMMAND),
+            mouse::Cursor::Unavailable,
+        )
+    };
+    let graph = || -> NodeGraph<'static, usize, usize, (), Vec<usize>, Theme, Rec> {
+        NodeGraph::default()
+            .width(Length::Fixed(400.0))
+            .height(Length::Fixed(400.0))
+            .on_select(|ids| ids)
+    };
+    let select_all = ctrl('a', keyboard::key::Code::KeyA);
+    let invert = ctrl('i', keyboard::key::Code::KeyI);
+
+    // Without keyboard focus the graph leaves the keys alone.
+    let msgs = run_events(graph(), &[select_all.clone(), invert.clone()]);
+    assert!(msgs.is_empty(), "unfocused graph selected: {msgs:?}");
+
+    // A child that consumes the key (a text input) keeps it.
+    let mut eating = graph();
+    eating.push_node(node(
+        7_usize,
+        Point::new(10.0, 100.0),
+        Element::new(KeyEater),
+    ));
+    let msgs = run_events(eating, &[focus_press(), select_all.clone(), invert.clone()]);
+    assert!(
+        msgs.is_empty(),
+        "shortcut stolen from node content: {msgs:?}"
+    );
+
+    // Focused and unclaimed, both shortcuts act.
+    let msgs = run_events(graph(), &[focus_press(), select_all, invert]);
+    assert_eq!(msgs.len(), 2, "{msgs:?}");
+    assert!(msgs[1].is_empty(), "invert of everything is nothing");
+}
+
+#[test]
+fn host_selection_never_echoes_through_on_select() {
+    // Each host `view` rebuilds the graph with the selection it holds; the
+    // widget adopts every new set silently, so a host that answers
+    // `on_select` by storing the ids cannot loop. Only the user's own
+    // Escape afterwards reports, clearing the adopted selection.
+    let build = |selected: &[usize]| {
+        let mut graph: NodeGraph<'static, usize, usize, (), Vec<usize>, Theme, Rec> =
+            NodeGraph::default()
+                .width(Length::Fixed(400.0))
+                .height(Length::Fixed(400.0))
+                .on_select(|ids| ids);
+        graph.push_node(node(
+            0_usize,
+            Point::new(10.0, 10.0),
+            Element::from(ContentProbe),
+        ));
+        graph.push_node(node(
+            1_usize,
+            Point::new(120.0, 10.0),
+            Element::from(ContentProbe),
+        ));
+        graph.selection(selected)
+    };
+    let moved = iced::Event::Mouse(mouse::Event::CursorMoved {
+        position: Point::new(300.0, 300.0),
+    });
+    let escape = iced::Event::Keyboard(keyboard::Event::KeyPressed {
+        key: keyboard::Key::Named(keyboard::key::Named::Escape),
+        modified_key: keyboard::Key::Named(keyboard::key::Named::Escape),
+        physical_key: keyboard::key::Physical::Code(keyboard::key::Code::Escape),
+        location: keyboard::Location::Standard,
+        modifiers: keyboard::Modifiers::empty(),
+        text: None,
+        repeat: false,
+    });
+    let at = mouse::Cursor::Available(Point::new(300.0, 300.0));
     let renderer = Rec::new(Rc::new(RefCell::new(Recorded::default())));
-    let layout_node = graph.layout(
-        &mut tree,
-        &renderer,
-        &layout::Limits::new(Size::ZERO, Size::new(1024.0, 768.0)),
-    );
-    let layout = Layout::new(&layout_node);
     let viewport = Rectangle::new(Point::ORIGIN, Size::new(1024.0, 768.0));
 
-    let mut msgs: Vec<Msg> = Vec::new();
-    let mut clipboard = clipboard::Null;
-    for (event, cursor) in events {
+    let mut tree = Tree::new(&build(&[]) as &dyn Widget<Vec<usize>, Theme, Rec>);
+    let mut msgs: Vec<Vec<usize>> = Vec::new();
+    for (selected, event) in [
+        (&[0][..], &moved),
+        (&[1], &moved),
+        (&[0, 1], &moved),
+        (&[], &moved),
+        (&[1], &moved),
+        (&[1], &escape),
+    ] {
+        let mut graph = build(selected);
+        graph.diff(&mut tree);
+        let layout_node = graph.layout(
+            &mut tree,
+            &renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(1024.0, 768.0)),
+        );
         let mut shell = iced_wgpu::core::Shell::new(&mut msgs);
         graph.update(
             &mut tree,
             event,
-            layout,
-            *cursor,
+            Layout::new(&layout_node),
+            at,
             &renderer,
-            &mut clipboard,
+            &mut clipboard::Null,
             &mut shell,
             &viewport,
         );
     }
-    msgs
+    assert_eq!(
+        msgs,
+        vec![Vec::<usize>::new()],
+        "only the user's Escape reports"
+    );
 }
 
 #[test]
-fn default_keymap_select_all_publishes_selection() {
-    let graph: NodeGraph<'static, usize, usize, (), Vec<usize>, Theme, Rec> = NodeGraph::default()
-        .width(Length::Fixed(400.0))
-        .height(Length::Fixed(400.0))
-        .on_select(|ids| ids);
-
-    let msgs = run_events(
-        graph,
-        &[(
-            key_press('a', keyboard::key::Code::KeyA, keyboard::Modifiers::COMMAND),
-            mouse::Cursor::Unavailable,
-        )],
-    );
-    let mut selected = msgs
-        .into_iter()
-        .next()
-        .expect("select-all published no selection");
-    selected.sort_unstable();
-    assert_eq!(selected, vec![0, 1]);
+fn disabled_input_ignores_keys_and_clicks() {
+    let events = |enabled: bool| {
+        let graph: NodeGraph<'static, usize, usize, (), Vec<usize>, Theme, Rec> =
+            NodeGraph::default()
+                .width(Length::Fixed(400.0))
+                .height(Length::Fixed(400.0))
+                .input_enabled(enabled)
+                .on_select(|ids| ids);
+        let on_node = mouse::Cursor::Available(Point::new(20.0, 20.0));
+        run_events(
+            graph,
+            &[
+                (
+                    key_press('a', keyboard::key::Code::KeyA, keyboard::Modifiers::COMMAND),
+                    mouse::Cursor::Unavailable,
+                ),
+                (
+                    iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+                    on_node,
+                ),
+                (
+                    iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+                    on_node,
+                ),
+            ],
+        )
+    };
+    assert!(!events(true).is_empty());
+    assert_eq!(events(false), Vec::<Vec<usize>>::new());
 }
 
 #[test]
@@ -1003,6 +2598,7 @@ fn rebound_select_all_moves_to_the_new_combo() {
     let msgs = run_events(
         graph,
         &[
+            focus_press(),
             // The default combo must be inert once rebound.
             (
                 key_press('a', keyboard::key::Code::KeyA, keyboard::Modifiers::COMMAND),
@@ -1020,6 +2616,121 @@ fn rebound_select_all_moves_to_the_new_combo() {
     assert_eq!(selected, vec![0, 1]);
 }
 
+#[test]
+fn group_shortcut_reports_the_selection_and_its_bounds() {
+    let graph: NodeGraph<'static, usize, usize, (), crate::NodeGraphEvent, Theme, Rec> =
+        NodeGraph::default()
+            .width(Length::Fixed(400.0))
+            .height(Length::Fixed(400.0))
+            .on_group_create(|node_ids, bounds| crate::NodeGraphEvent::GroupCreate {
+                node_ids,
+                bounds,
+            })
+            .on_ungroup(|node_ids| crate::NodeGraphEvent::Ungroup { node_ids });
+
+    let press = |modifiers| {
+        (
+            key_press('g', keyboard::key::Code::KeyG, modifiers),
+            mouse::Cursor::Unavailable,
+        )
+    };
+    let msgs = run_events(
+        graph,
+        &[
+            focus_press(),
+            // Nothing selected yet: the shortcut stays inert.
+            press(keyboard::Modifiers::COMMAND),
+            (
+                key_press('a', keyboard::key::Code::KeyA, keyboard::Modifiers::COMMAND),
+                mouse::Cursor::Unavailable,
+            ),
+            press(keyboard::Modifiers::COMMAND),
+            press(keyboard::Modifiers::COMMAND | keyboard::Modifiers::SHIFT),
+        ],
+    );
+    let [
+        crate::NodeGraphEvent::GroupCreate {
+            node_ids: mut grouped,
+            bounds,
+        },
+        crate::NodeGraphEvent::Ungroup {
+            node_ids: mut ungrouped,
+        },
+    ] = <[_; 2]>::try_from(msgs).expect("one group and one ungroup")
+    else {
+        panic!("group then ungroup expected");
+    };
+    grouped.sort_unstable();
+    ungrouped.sort_unstable();
+    assert_eq!(grouped, vec![0, 1]);
+    assert_eq!(ungrouped, vec![0, 1]);
+    // Two 40x20 bodies at world (10,10) and (120,10).
+    assert_eq!(
+        bounds,
+        Rectangle::new(Point::new(10.0, 10.0), Size::new(150.0, 20.0))
+    );
+}
+
+#[test]
+fn group_shortcuts_yield_to_node_content_and_need_focus() {
+    let graph = |content: Element<'static, crate::NodeGraphEvent, Theme, Rec>| {
+        let mut graph: NodeGraph<'static, usize, usize, (), crate::NodeGraphEvent, Theme, Rec> =
+            NodeGraph::default()
+                .width(Length::Fixed(400.0))
+                .height(Length::Fixed(400.0))
+                .on_group_create(|node_ids, bounds| crate::NodeGraphEvent::GroupCreate {
+                    node_ids,
+                    bounds,
+                })
+                .on_ungroup(|node_ids| crate::NodeGraphEvent::Ungroup { node_ids });
+        graph.push_node(node(7_usize, Point::new(10.0, 100.0), content));
+        graph.selection(&[7])
+    };
+    let group = (
+        key_press('g', keyboard::key::Code::KeyG, keyboard::Modifiers::COMMAND),
+        mouse::Cursor::Unavailable,
+    );
+    let ungroup = (
+        key_press(
+            'g',
+            keyboard::key::Code::KeyG,
+            keyboard::Modifiers::COMMAND | keyboard::Modifiers::SHIFT,
+        ),
+        mouse::Cursor::Unavailable,
+    );
+    // The selection is adopted on the first event. A press on empty canvas
+    // would clear it, so focus comes from pressing the selected node.
+    let adopt = (
+        iced::Event::Mouse(mouse::Event::CursorLeft),
+        mouse::Cursor::Unavailable,
+    );
+    let focus = (
+        iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+        mouse::Cursor::Available(Point::new(20.0, 110.0)),
+    );
+
+    let msgs = run_events(
+        graph(Element::from(ContentProbe)),
+        &[adopt.clone(), group.clone(), ungroup.clone()],
+    );
+    assert!(msgs.is_empty(), "unfocused graph grouped: {msgs:?}");
+
+    let msgs = run_events(
+        graph(Element::new(KeyEater)),
+        &[adopt.clone(), focus.clone(), group.clone(), ungroup.clone()],
+    );
+    assert!(
+        msgs.is_empty(),
+        "shortcut stolen from node content: {msgs:?}"
+    );
+
+    let msgs = run_events(
+        graph(Element::from(ContentProbe)),
+        &[adopt, focus, group, ungroup],
+    );
+    assert_eq!(msgs.len(), 2, "one group and one ungroup: {msgs:?}");
+}
+
 #[test]
 fn keymap_none_disables_all_shortcuts() {
     let graph: NodeGraph<'static, usize, usize, (), Vec<usize>, Theme, Rec> = NodeGraph::default()
@@ -1030,14 +2741,62 @@ fn keymap_none_disables_all_shortcuts() {
 
     let msgs = run_events(
         graph,
-        &[(
-            key_press('a', keyboard::key::Code::KeyA, keyboard::Modifiers::COMMAND),
-            mouse::Cursor::Unavailable,
-        )],
+        &[
+            focus_press(),
+            (
+                key_press('a', keyboard::key::Code::KeyA, keyboard::Modifiers::COMMAND),
+                mouse::Cursor::Unavailable,
+            ),
+        ],
     );
     assert!(msgs.is_empty(), "disabled keymap still published: {msgs:?}");
 }
 
+#[test]
+fn performance_warning_fires_once_over_the_threshold() {
+    let event = (
+        iced::Event::Mouse(mouse::Event::CursorLeft),
+        mouse::Cursor::Unavailable,
+    );
+    let graph = |threshold| -> NodeGraph<'static, usize, usize, (), usize, Theme, Rec> {
+        NodeGraph::default()
+            .width(Length::Fixed(400.0))
+            .height(Length::Fixed(400.0))
+            .performance_hint(crate::PerformanceHint::new(threshold))
+            .on_performance_warning(|count| count)
+    };
+
+    // Two nodes over a threshold of one: a single warning with the count.
+    let msgs = run_events(graph(1), &[event.clone(), event.clone()]);
+    assert_eq!(msgs, vec![2]);
+
+    // At the threshold the graph is not over it.
+    let msgs = run_events(graph(2), &[event.clone(), event]);
+    assert!(msgs.is_empty(), "warned at the threshold: {msgs:?}");
+}
+
+#[test]
+fn first_layout_reports_the_node_bounds_once() {
+    let event = (
+        iced::Event::Mouse(mouse::Event::CursorLeft),
+        mouse::Cursor::Unavailable,
+    );
+    let graph: NodeGraph<'static, usize, usize, (), Rectangle, Theme, Rec> = NodeGraph::default()
+        .width(Length::Fixed(400.0))
+        .height(Length::Fixed(400.0))
+        .on_first_layout(|bounds| bounds);
+
+    // The 40x20 nodes at (10, 10) and (120, 10), in world space.
+    let msgs = run_events(graph, &[event.clone(), event]);
+    assert_eq!(
+        msgs,
+        vec![Rectangle::new(
+            Point::new(10.0, 10.0),
+            Size::new(150.0, 20.0)
+        )]
+    );
+}
+
 #[test]
 fn rebound_pan_button_commits_a_pan() {
     let over = mouse::Cursor::Available(Point::new(200.0, 200.0));
@@ -1071,18 +2830,377 @@ fn rebound_pan_button_commits_a_pan() {
         pan_button: mouse::Button::Middle,
         ..crate::Keymap::default()
     };
-    let rebound_graph: NodeGraph<'static, usize, usize, (), (Point, f32), Theme, Rec> =
+    let rebound_graph: NodeGraph<'static, usize, usize, (), (Point, f32), Theme, Rec> =
+        NodeGraph::default()
+            .width(Length::Fixed(400.0))
+            .height(Length::Fixed(400.0))
+            .keymap(keymap)
+            .on_pan(|position, zoom| (position, zoom));
+    let msgs = run_events(rebound_graph, &events(mouse::Button::Middle));
+    assert_eq!(
+        msgs.len(),
+        1,
+        "rebound pan button must commit exactly one pan: {msgs:?}"
+    );
+}
+
+#[test]
+fn background_click_modes_retarget_a_left_drag_on_empty_canvas() {
+    let (from, to) = (Point::new(200.0, 200.0), Point::new(230.0, 210.0));
+    let drag = [
+        (
+            iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            mouse::Cursor::Available(from),
+        ),
+        (
+            iced::Event::Mouse(mouse::Event::CursorMoved { position: to }),
+            mouse::Cursor::Available(to),
+        ),
+        (
+            iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+            mouse::Cursor::Available(to),
+        ),
+    ];
+
+    // Pan: the left drag moves the camera and commits it on release.
+    let graph: NodeGraph<'static, usize, usize, (), (Point, f32), Theme, Rec> =
+        NodeGraph::default()
+            .width(Length::Fixed(400.0))
+            .height(Length::Fixed(400.0))
+            .view(Point::ORIGIN, 1.0)
+            .background_click(crate::BackgroundClick::Pan)
+            .on_pan(|position, zoom| (position, zoom));
+    let msgs = run_events(graph, &drag);
+    assert_eq!(msgs, vec![(Point::new(30.0, 10.0), 1.0)]);
+
+    // Custom: only the press position is reported; no box select follows.
+    let custom = || -> NodeGraph<'static, usize, usize, (), Point, Theme, Rec> {
+        NodeGraph::default()
+            .width(Length::Fixed(400.0))
+            .height(Length::Fixed(400.0))
+            .view(Point::ORIGIN, 1.0)
+            .background_click(crate::BackgroundClick::Custom)
+            .on_background_click(|at| at)
+            .on_select(|_| Point::ORIGIN)
+            .on_drag_start(|_| Point::ORIGIN)
+    };
+    let msgs = run_events(custom(), &drag);
+    assert_eq!(msgs, vec![from]);
+
+    // Laid out below a toolbar, the press is still reported in world space.
+    let origin = Vector::new(0.0, 100.0);
+    let press = (
+        iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+        mouse::Cursor::Available(from + origin),
+    );
+    let msgs = run_events_at(custom(), origin, &[press]);
+    assert_eq!(msgs, vec![from]);
+}
+
+#[test]
+fn drag_reports_world_start_below_a_toolbar() {
+    // Laid out 100px down, a press at screen (300, 400) is world (300, 300).
+    let origin = Vector::new(0.0, 100.0);
+    let (from, to) = (Point::new(300.0, 400.0), Point::new(330.0, 420.0));
+    let world = Point::new(300.0, 300.0);
+    let events = [
+        (
+            iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            mouse::Cursor::Available(from),
+        ),
+        (
+            iced::Event::Mouse(mouse::Event::CursorMoved { position: to }),
+            mouse::Cursor::Available(to),
+        ),
+    ];
+    let graph: NodeGraph<'static, usize, usize, (), _, Theme, Rec> = NodeGraph::default()
+        .width(Length::Fixed(400.0))
+        .height(Length::Fixed(400.0))
+        .view(Point::ORIGIN, 1.0)
+        .on_drag_start(|info| (info, None))
+        .on_drag(|info, progress| (info, Some(progress)));
+    let msgs = run_events_at(graph, origin, &events);
+
+    let start = crate::DragInfo::BoxSelect {
+        start_x: world.x,
+        start_y: world.y,
+    };
+    assert_eq!(msgs.len(), 2, "{msgs:?}");
+    assert_eq!(msgs[0], (start.clone(), None));
+    let (info, progress) = msgs[1].clone();
+    let progress = progress.expect("no drag progress");
+    assert_eq!(info, start);
+    assert_eq!(progress.start, world);
+    assert_eq!(progress.delta, Vector::new(30.0, 20.0));
+}
+
+#[test]
+fn wheel_pans_horizontally_and_vertically_in_pan_mode_or_with_shift() {
+    let at = mouse::Cursor::Available(Point::new(200.0, 200.0));
+    let wheel = |delta| {
+        (
+            iced::Event::Mouse(mouse::Event::WheelScrolled { delta }),
+            at,
+        )
+    };
+    let shift = |modifiers| {
+        (
+            iced::Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)),
+            at,
+        )
+    };
+    let graph = |mode| -> NodeGraph<'static, usize, usize, (), (Point, f32), Theme, Rec> {
+        NodeGraph::default()
+            .width(Length::Fixed(400.0))
+            .height(Length::Fixed(400.0))
+            .view(Point::ORIGIN, 1.0)
+            .scroll_mode(mode)
+            .on_pan(|position, zoom| (position, zoom))
+    };
+
+    let msgs = run_events(
+        graph(crate::ScrollMode::Zoom),
+        &[
+            wheel(mouse::ScrollDelta::Pixels { x: -30.0, y: 0.0 }),
+            shift(keyboard::Modifiers::SHIFT),
+            wheel(mouse::ScrollDelta::Lines { x: 0.0, y: -1.0 }),
+        ],
+    );
+    assert_eq!(
+        msgs,
+        [
+            (Point::new(-30.0, 0.0), 1.0),
+            (Point::new(-30.0, -40.0), 1.0),
+        ],
+        "horizontal scroll pans; Shift turns the wheel into a vertical pan"
+    );
+
+    let msgs = run_events(
+        graph(crate::ScrollMode::Pan),
+        &[
+            wheel(mouse::ScrollDelta::Lines { x: 0.0, y: -1.0 }),
+            shift(keyboard::Modifiers::SHIFT),
+            wheel(mouse::ScrollDelta::Lines { x: 0.0, y: 1.0 }),
+        ],
+    );
+    assert_eq!(msgs[0], (Point::new(0.0, -40.0), 1.0), "pan mode pans");
+    assert!(
+        msgs[1].1 > 1.0,
+        "Shift swaps pan mode back to zoom: {msgs:?}"
+    );
+}
+
+#[test]
+fn zoom_change_fires_for_zoom_but_not_for_pan() {
+    let at = mouse::Cursor::Available(Point::new(200.0, 200.0));
+    let moved = mouse::Cursor::Available(Point::new(240.0, 220.0));
+    let graph: NodeGraph<'static, usize, usize, (), f32, Theme, Rec> = NodeGraph::default()
+        .width(Length::Fixed(400.0))
+        .height(Length::Fixed(400.0))
+        .view(Point::ORIGIN, 1.0)
+        .on_zoom_change(|zoom| zoom);
+    let msgs = run_events(
+        graph,
+        &[
+            (
+                iced::Event::Mouse(mouse::Event::WheelScrolled {
+                    delta: mouse::ScrollDelta::Lines { x: 0.0, y: 1.0 },
+                }),
+                at,
+            ),
+            // A right-button pan moves the camera without zooming.
+            (
+                iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)),
+                at,
+            ),
+            (
+                iced::Event::Mouse(mouse::Event::CursorMoved {
+                    position: Point::new(240.0, 220.0),
+                }),
+                moved,
+            ),
+            (
+                iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Right)),
+                moved,
+            ),
+        ],
+    );
+    assert_eq!(msgs.len(), 1, "one zoom, no pan reports: {msgs:?}");
+    assert!(msgs[0] > 1.0, "scrolling up zooms in: {msgs:?}");
+}
+
+#[test]
+fn viewport_change_reports_world_bounds_once_per_change() {
+    let at = mouse::Cursor::Available(Point::new(200.0, 200.0));
+    let moved = iced::Event::Mouse(mouse::Event::CursorMoved {
+        position: Point::new(200.0, 200.0),
+    });
+    let graph: NodeGraph<'static, usize, usize, (), Rectangle, Theme, Rec> = NodeGraph::default()
+        .width(Length::Fixed(400.0))
+        .height(Length::Fixed(400.0))
+        .view(Point::new(-100.0, 0.0), 2.0)
+        .on_viewport_change(|bounds| bounds);
+    let msgs = run_events(
+        graph,
+        &[
+            (moved.clone(), at),
+            // Nothing moved: no second report.
+            (moved, at),
+            (
+                iced::Event::Mouse(mouse::Event::WheelScrolled {
+                    delta: mouse::ScrollDelta::Lines { x: 0.0, y: 1.0 },
+                }),
+                at,
+            ),
+        ],
+    );
+    assert_eq!(msgs.len(), 2, "first event and the zoom: {msgs:?}");
+    let first = msgs[0];
+    assert!(
+        (first.x - 100.0).abs() < 1e-3 && first.y.abs() < 1e-3,
+        "{first:?}"
+    );
+    assert!((first.width - 200.0).abs() < 1e-3 && (first.height - 200.0).abs() < 1e-3);
+    // Zooming in keeps the cursor's world point and shrinks the view.
+    let zoomed = msgs[1];
+    assert!(zoomed.width < first.width, "{zoomed:?}");
+    assert!(first.contains(zoomed.center()), "{zoomed:?}");
+}
+
+#[test]
+fn visible_nodes_reports_screen_rects_of_nodes_in_view() {
+    let at = mouse::Cursor::Available(Point::new(200.0, 200.0));
+    let moved = iced::Event::Mouse(mouse::Event::CursorMoved {
+        position: Point::new(200.0, 200.0),
+    });
+    let graph: NodeGraph<'static, usize, usize, (), Vec<(usize, Rectangle)>, Theme, Rec> =
+        NodeGraph::default()
+            .width(Length::Fixed(400.0))
+            .height(Length::Fixed(400.0))
+            .view(Point::new(-100.0, 0.0), 2.0)
+            .on_visible_nodes(|nodes| nodes);
+    let msgs = run_events(
+        graph,
+        &[
+            (moved.clone(), at),
+            // Nothing moved: no second report.
+            (moved, at),
+            (
+                iced::Event::Mouse(mouse::Event::WheelScrolled {
+                    delta: mouse::ScrollDelta::Lines { x: 0.0, y: -1.0 },
+                }),
+                at,
+            ),
+        ],
+    );
+    assert_eq!(msgs.len(), 2, "first event and the zoom report: {msgs:?}");
+    // screen = (world + position) * zoom; node 0 sits left of the widget.
+    assert_eq!(
+        msgs[0],
+        vec![(
+            1,
+            Rectangle::new(Point::new(40.0, 20.0), Size::new(80.0, 40.0))
+        )]
+    );
+    assert!(
+        msgs[1].iter().any(|&(id, _)| id == 1) && msgs[1] != msgs[0],
+        "zooming out moves the rect: {msgs:?}"
+    );
+}
+
+#[test]
+fn keyboard_focus_outlives_a_cleared_selection() {
+    let at = mouse::Cursor::Available(Point::new(300.0, 300.0));
+    let named = |key: keyboard::key::Named| {
+        iced::Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(key),
+            modified_key: keyboard::Key::Named(key),
+            physical_key: keyboard::key::Physical::Unidentified(
+                keyboard::key::NativeCode::Unidentified,
+            ),
+            location: keyboard::Location::Standard,
+            modifiers: keyboard::Modifiers::empty(),
+            text: None,
+            repeat: false,
+        })
+    };
+    let press = (
+        iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+        at,
+    );
+    let release = (
+        iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        at,
+    );
+    let tab = (named(keyboard::key::Named::Tab), at);
+    let escape = (named(keyboard::key::Named::Escape), at);
+    let graph: NodeGraph<'static, usize, usize, (), Vec<usize>, Theme, Rec> = NodeGraph::default()
+        .width(Length::Fixed(400.0))
+        .height(Length::Fixed(400.0))
+        .on_select(|ids| ids);
+    let msgs = run_events(
+        graph,
+        &[
+            press.clone(),
+            release.clone(),
+            tab.clone(),
+            escape,
+            // Tab steps on from the focused node, not from the start.
+            tab.clone(),
+            // A press drops the focus: Tab starts over.
+            press,
+            release,
+            tab,
+        ],
+    );
+    let selections: Vec<&Vec<usize>> = msgs.iter().filter(|ids| !ids.is_empty()).collect();
+    assert_eq!(selections, [&vec![0], &vec![1], &vec![0]], "{msgs:?}");
+    assert!(
+        msgs.contains(&vec![]),
+        "escape cleared the selection: {msgs:?}"
+    );
+}
+
+#[test]
+fn double_click_on_empty_canvas_reports_its_world_position() {
+    let click = |at: Point| {
+        let cursor = mouse::Cursor::Available(at);
+        [
+            (
+                iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+                cursor,
+            ),
+            (
+                iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+                cursor,
+            ),
+        ]
+    };
+    let graph = || -> NodeGraph<'static, usize, usize, (), Point, Theme, Rec> {
         NodeGraph::default()
             .width(Length::Fixed(400.0))
             .height(Length::Fixed(400.0))
-            .keymap(keymap)
-            .on_pan(|position, zoom| (position, zoom));
-    let msgs = run_events(rebound_graph, &events(mouse::Button::Middle));
-    assert_eq!(
-        msgs.len(),
-        1,
-        "rebound pan button must commit exactly one pan: {msgs:?}"
+            .view(Point::ORIGIN, 1.0)
+            .on_canvas_double_click(|at| at)
+    };
+
+    let empty = Point::new(200.0, 200.0);
+    let msgs = run_events(graph(), &[click(empty), click(empty)].concat());
+    assert_eq!(msgs, vec![empty]);
+
+    // A double-click on node 0's body is not a canvas double-click.
+    let body = Point::new(20.0, 20.0);
+    let msgs = run_events(graph(), &[click(body), click(body)].concat());
+    assert!(msgs.is_empty(), "{msgs:?}");
+
+    // Laid out below a toolbar, the reported point is still world space.
+    let below = Point::new(200.0, 300.0);
+    let msgs = run_events_at(
+        graph(),
+        Vector::new(0.0, 100.0),
+        &[click(below), click(below)].concat(),
     );
+    assert_eq!(msgs, vec![empty]);
 }
 
 // ---------------------------------------------------------------------------
@@ -1199,6 +3317,143 @@ fn two_finger_pinch_zooms_the_camera() {
     );
 }
 
+#[test]
+fn touch_drag_on_a_node_moves_it_instead_of_panning() {
+    let graph: NodeGraph<'static, usize, usize, (), (Vector, Vec<usize>), Theme, Rec> =
+        NodeGraph::default()
+            .width(Length::Fixed(400.0))
+            .height(Length::Fixed(400.0))
+            .on_move(|delta, ids| (delta, ids));
+
+    let msgs = run_events(
+        graph,
+        &[
+            finger_press(1, Point::new(30.0, 20.0)),
+            finger_move(1, Point::new(55.0, 35.0)),
+            finger_lift(1, Point::new(55.0, 35.0)),
+        ],
+    );
+    assert_eq!(
+        msgs,
+        vec![(Vector::new(25.0, 15.0), vec![0])],
+        "a finger drag starting on a node must move that node",
+    );
+}
+
+#[test]
+fn axis_lock_modifier_keeps_a_drag_on_its_dominant_axis() {
+    let drag = |modifiers: keyboard::Modifiers| {
+        let graph: NodeGraph<'static, usize, usize, (), (Vector, Vec<usize>), Theme, Rec> =
+            NodeGraph::default()
+                .width(Length::Fixed(400.0))
+                .height(Length::Fixed(400.0))
+                .on_move(|delta, ids| (delta, ids));
+        let start = mouse::Cursor::Available(Point::new(30.0, 20.0));
+        let end = mouse::Cursor::Available(Point::new(80.0, 35.0));
+        run_events(
+            graph,
+            &[
+                (
+                    iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+                    start,
+                ),
+                // Held after the press, so it does not extend the selection.
+                (
+                    iced::Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)),
+                    start,
+                ),
+                (
+                    iced::Event::Mouse(mouse::Event::CursorMoved {
+                        position: Point::new(80.0, 35.0),
+                    }),
+                    end,
+                ),
+                (
+                    iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+                    end,
+                ),
+            ],
+        )
+    };
+    assert_eq!(
+        drag(keyboard::Modifiers::SHIFT),
+        vec![(Vector::new(50.0, 0.0), vec![0])]
+    );
+    assert_eq!(
+        drag(keyboard::Modifiers::empty()),
+        vec![(Vector::new(50.0, 15.0), vec![0])]
+    );
+}
+
+#[test]
+fn prevent_overlap_pushes_a_dropped_node_clear_of_others() {
+    // Node 0 (40x20 at 10,10) dropped 95,2 lands over node 1 (120..160 x
+    // 10..30); the shortest way out is 18 down.
+    let drop = |prevent: bool| {
+        let graph: NodeGraph<'static, usize, usize, (), (Vector, Vec<usize>), Theme, Rec> =
+            NodeGraph::default()
+                .width(Length::Fixed(400.0))
+                .height(Length::Fixed(400.0))
+                .prevent_overlap(prevent)
+                .on_move(|delta, ids| (delta, ids));
+        let start = mouse::Cursor::Available(Point::new(30.0, 20.0));
+        let end = mouse::Cursor::Available(Point::new(125.0, 22.0));
+        run_events(
+            graph,
+            &[
+                (
+                    iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+                    start,
+                ),
+                (
+                    iced::Event::Mouse(mouse::Event::CursorMoved {
+                        position: Point::new(125.0, 22.0),
+                    }),
+                    end,
+                ),
+                (
+                    iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+                    end,
+                ),
+            ],
+        )
+    };
+
+    assert_eq!(drop(false), vec![(Vector::new(95.0, 2.0), vec![0])]);
+    assert_eq!(
+        drop(true),
+        vec![(Vector::new(95.0, 20.0), vec![0])],
+        "the drop must be pushed out along the minimum translation",
+    );
+}
+
+#[test]
+fn pinch_keeps_the_world_point_between_the_fingers_anchored() {
+    let graph: NodeGraph<'static, usize, usize, (), (Point, f32), Theme, Rec> =
+        NodeGraph::default()
+            .width(Length::Fixed(400.0))
+            .height(Length::Fixed(400.0))
+            .on_pan(|position, zoom| (position, zoom));
+
+    let msgs = run_events(
+        graph,
+        &[
+            finger_press(1, Point::new(100.0, 200.0)),
+            finger_press(2, Point::new(300.0, 200.0)),
+            // Midpoint 200,200 -> 250,200 while zooming 1.0 -> 1.5.
+            finger_move(2, Point::new(400.0, 200.0)),
+        ],
+    );
+    let (position, zoom) = *msgs.last().expect("pinch published no camera commit");
+    // World 200,200 (under the old midpoint) must land under the new one:
+    // screen = (world + position) * zoom.
+    let screen = Point::new((200.0 + position.x) * zoom, (200.0 + position.y) * zoom);
+    assert!(
+        screen.distance(Point::new(250.0, 200.0)) < 1e-2,
+        "pinch drifted the anchored world point to {screen:?}",
+    );
+}
+
 #[test]
 fn second_finger_cancels_a_touch_node_drag() {
     let graph: NodeGraph<'static, usize, usize, (), &'static str, Theme, Rec> =
@@ -1223,3 +3478,468 @@ fn second_finger_cancels_a_touch_node_drag() {
         "second finger must cancel the drag via on_drag_end",
     );
 }
+
+/// Moves the cursor to `screen` (optionally pressing the left button there)
+/// over a single-node graph at `widget_origin` zoomed 2x, and returns the
+/// cursor the widget asks for.
+fn hover_interaction(widget_origin: Vector, screen: Point, press: bool) -> mouse::Interaction {
+    let mut graph: NodeGraph<'static, usize, usize, (), (), Theme, Rec> = NodeGraph::default()
+        .width(Length::Fixed(400.0))
+        .height(Length::Fixed(400.0))
+        .view(Point::ORIGIN, 2.0)
+        .on_move(|_, _| ());
+    graph.push_node(node(
+        0_usize,
+        Point::new(30.0, 40.0),
+        Element::from(ContentProbe),
+    ));
+
+    let mut tree = Tree::new(&graph as &dyn Widget<(), Theme, Rec>);
+    let renderer = Rec::new(Rc::new(RefCell::new(Recorded::default())));
+    let layout_node = graph.layout(
+        &mut tree,
+        &renderer,
+        &layout::Limits::new(Size::ZERO, Size::new(1024.0, 768.0)),
+    );
+    let layout = Layout::with_offset(widget_origin, &layout_node);
+    let viewport = Rectangle::new(Point::ORIGIN, Size::new(1024.0, 768.0));
+
+    let mut msgs: Vec<()> = Vec::new();
+    let mut shell = iced_wgpu::core::Shell::new(&mut msgs);
+    let mut clipboard = clipboard::Null;
+    let cursor = mouse::Cursor::Available(screen);
+    let mut events = vec![iced::Event::Mouse(mouse::Event::CursorMoved {
+        position: screen,
+    })];
+    if press {
+        events.push(iced::Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Left,
+        )));
+    }
+    for event in events {
+        graph.update(
+            &mut tree,
+            &event,
+            layout,
+            cursor,
+            &renderer,
+            &mut clipboard,
+            &mut shell,
+            &viewport,
+        );
+    }
+
+    graph.mouse_interaction(&tree, layout, cursor, &viewport, &renderer)
+}
+
+#[test]
+fn hover_cursor_tracks_node_at_nonzero_origin_zoom2() {
+    // Node body spans world (30,40)..(70,60); at zoom 2 below a 100px toolbar
+    // its screen center is (0,100) + (50,50) * 2 = (100, 200).
+    let origin = Vector::new(0.0, 100.0);
+    assert_eq!(
+        hover_interaction(origin, Point::new(100.0, 200.0), false),
+        mouse::Interaction::Grab,
+    );
+    assert_eq!(
+        hover_interaction(origin, Point::new(300.0, 400.0), false),
+        mouse::Interaction::default(),
+        "empty canvas keeps the default cursor",
+    );
+    assert_eq!(
+        hover_interaction(origin, Point::new(100.0, 200.0), true),
+        mouse::Interaction::Grabbing,
+        "pressing the node starts a drag",
+    );
+}
+
+/// Draws the single-node graph with a badge on node 0, turned by `rotation`,
+/// and returns the badge's SDF clip (the last primitive: badges are the final
+/// layer of their node).
+fn badge_primitive(
+    node_world: Point,
+    rotation: f32,
+    camera_pos: Point,
+    camera_zoom: f32,
+    badge: crate::Badge,
+) -> Rectangle {
+    let origin = Vector::new(20.0, 40.0);
+    let mut graph: NodeGraph<'static, usize, usize, (), (), Theme, Rec> = NodeGraph::default()
+        .width(Length::Fixed(400.0))
+        .height(Length::Fixed(400.0))
+        .view(camera_pos, camera_zoom);
+    graph.push_node(node(0_usize, node_world, Element::from(ContentProbe)).rotation(rotation));
+    let plain = draw_graph(graph, origin).primitives.len();
+
+    let mut graph: NodeGraph<'static, usize, usize, (), (), Theme, Rec> = NodeGraph::default()
+        .width(Length::Fixed(400.0))
+        .height(Length::Fixed(400.0))
+        .view(camera_pos, camera_zoom);
+    graph.push_node(node(0_usize, node_world, Element::from(ContentProbe)).rotation(rotation));
+    let graph = graph.node_badges(&std::collections::HashMap::from([(0_usize, badge)]));
+    let rec = draw_graph(graph, origin);
+    assert_eq!(rec.primitives.len(), plain + 1, "badge adds one SDF layer");
+    *rec.primitives.last().unwrap()
+}
+
+#[test]
+fn node_badge_keeps_screen_size_across_zoom() {
+    let dot = crate::Badge::new(Color::from_rgb(0.9, 0.2, 0.2));
+    let at_1 = badge_primitive(Point::new(30.0, 40.0), 0.0, Point::ORIGIN, 1.0, dot);
+    let at_2 = badge_primitive(Point::new(30.0, 40.0), 0.0, Point::ORIGIN, 2.0, dot);
+    assert!(
+        (at_1.width - at_2.width).abs() < 0.01 && (at_1.height - at_2.height).abs() < 0.01,
+        "badge is screen-sized: {at_1:?} at zoom 1 vs {at_2:?} at zoom 2",
+    );
+
+    // Top-right corner of the node: screen (20,40) + (30+40, 40) = (90, 80).
+    assert!(at_1.x + at_1.width <= 90.0 + 0.01 && at_1.x + at_1.width > 80.0);
+    assert!(at_1.y >= 80.0 - 0.01 && at_1.y < 90.0);
+}
+
+#[test]
+fn node_badge_clips_to_node_and_graph() {
+    // The node spans world x 370..410 in a 400 wide graph: its right edge, and
+    // the badge corner, hang past the graph edge at screen x = 20 + 400.
+    let badge = crate::Badge::new(Color::from_rgb(0.9, 0.2, 0.2))
+        .corner(crate::BadgeCorner::BottomRight)
+        .count(4);
+    let clip = badge_primitive(Point::new(370.0, 100.0), 0.0, Point::ORIGIN, 1.0, badge);
+    let node = Rectangle::new(Point::new(390.0, 140.0), Size::new(40.0, 20.0));
+    let graph = Rectangle::new(Point::new(20.0, 40.0), Size::new(400.0, 400.0));
+    let bound = node.intersection(&graph).unwrap();
+    assert!(
+        clip.x >= bound.x - 0.01
+            && clip.y >= bound.y - 0.01
+            && clip.x + clip.width <= bound.x + bound.width + 0.01
+            && clip.y + clip.height <= bound.y + bound.height + 0.01,
+        "badge clip {clip:?} must stay inside node-within-graph {bound:?}",
+    );
+}
+
+#[test]
+fn node_badge_turns_with_a_rotated_node() {
+    // The 40x20 body at world (30, 40) turned a quarter clockwise stands
+    // 20 wide and 40 tall about its centre (50, 50): screen x 60..80,
+    // y 70..110. Its own top-right corner now points down and right.
+    let dot = crate::Badge::new(Color::from_rgb(0.9, 0.2, 0.2));
+    let quarter = std::f32::consts::FRAC_PI_2;
+    let clip = badge_primitive(Point::new(30.0, 40.0), quarter, Point::ORIGIN, 1.0, dot);
+    let turned = Rectangle::new(Point::new(60.0, 70.0), Size::new(20.0, 40.0));
+    assert!(
+        clip.x >= turned.x - 0.01
+            && clip.y >= turned.y - 0.01
+            && clip.x + clip.width <= turned.x + turned.width + 0.01
+            && clip.y + clip.height <= turned.y + turned.height + 0.01,
+        "badge clip {clip:?} must stay inside the turned body {turned:?}",
+    );
+    assert!(
+        clip.x + clip.width > 70.0 && clip.y + clip.height > 100.0,
+        "badge {clip:?} is not at the turned top-right corner",
+    );
+}
+
+/// Primitives drawn for a source fanning out to `fan_out` targets.
+fn fan_out_primitives(fan_out: usize, counts: bool) -> usize {
+    let mut graph: NodeGraph<'static, usize, usize, (), (), Theme, Rec> = NodeGraph::default()
+        .width(Length::Fixed(400.0))
+        .height(Length::Fixed(400.0))
+        .show_pin_connection_counts(counts);
+    graph.push_node(node(
+        0_usize,
+        Point::ORIGIN,
+        crate::pin!(Right, 0_usize, Element::from(ContentProbe), Output),
+    ));
+    for target in 1..=fan_out {
+        graph.push_node(node(
+            target,
+            Point::new(200.0, target as f32 * 60.0),
+            crate::pin!(Left, 0_usize, Element::from(ContentProbe), Input),
+        ));
+        graph.push_edge(crate::edge!(
+            crate::PinRef::new(0, 0),
+            crate::PinRef::new(target, 0)
+        ));
+    }
+    draw_graph(graph, Vector::new(0.0, 0.0)).primitives.len()
+}
+
+#[test]
+fn pin_connection_counts_label_only_busy_pins() {
+    // One edge per pin: nothing to count.
+    assert_eq!(fan_out_primitives(1, true), fan_out_primitives(1, false));
+    // The fanning-out source pin gets one label; each target pin has one edge.
+    assert_eq!(
+        fan_out_primitives(3, true),
+        fan_out_primitives(3, false) + 1
+    );
+}
+
+#[test]
+fn preview_matches_default_takes_the_graph_edge_curve() {
+    use crate::{EdgeCurve, EdgeStyle, PinDirection, PinSide};
+
+    let source = crate::node_pin::NodePinState {
+        pin_id: 0_usize,
+        side: PinSide::Right,
+        direction: PinDirection::Output,
+        position: Point::ORIGIN,
+        interactions_disabled: false,
+        user_info: (),
+    };
+    let red = Color::from_rgb(1.0, 0.0, 0.0);
+    let graph = |matches: bool| -> NodeGraph<'static, usize, usize, (), (), Theme, Rec> {
+        NodeGraph::default()
+            .edge_style(|theme, status, _, _| EdgeStyle {
+                curve: EdgeCurve::Line,
+                stub_length: 12.0,
+                ..crate::style::default_edge_style(theme, status)
+            })
+            .dragging_edge_style(move |theme, _| EdgeStyle {
+                curve: EdgeCurve::Catmull,
+                stroke_color: crate::ColorQuad::solid(red),
+                ..crate::style::default_edge_style(theme, crate::EdgeStatus::Idle)
+            })
+            .preview_matches_default(matches)
+    };
+
+    let own = graph(false).drag_edge_style(&Theme::Dark, &source);
+    assert_eq!(own.curve, EdgeCurve::Catmull);
+
+    let matched = graph(true).drag_edge_style(&Theme::Dark, &source);
+    assert_eq!(matched.curve, EdgeCurve::Line);
+    assert_eq!(matched.stub_length, 12.0);
+    // Only the shape follows the graph; the drag's own look stays.
+    assert_eq!(matched.stroke_color, crate::ColorQuad::solid(red));
+}
+
+#[test]
+fn push_edge_accepts_borrowed_pin_info() {
+    // `push_edge` does no pin lookup, so it needs no `UI: 'static`: here
+    // `UI` borrows a local for a caller-chosen lifetime.
+    fn edges_with<'b>(_info: &'b str) -> usize {
+        let mut graph: NodeGraph<'b, usize, usize, &'b str, (), Theme, Rec> = NodeGraph::default();
+        graph.push_node(node(0_usize, Point::ORIGIN, Element::from(ContentProbe)));
+        graph.push_node(node(
+            1_usize,
+            Point::new(200.0, 0.0),
+            Element::from(ContentProbe),
+        ));
+        graph.push_edge(crate::edge!(
+            crate::PinRef::new(0, 0),
+            crate::PinRef::new(1, 0)
+        ));
+        graph.topology().edges().len()
+    }
+    assert_eq!(edges_with(&String::from("float")), 1);
+}
+
+#[test]
+fn edge_pushed_before_its_node_is_kept_and_reported_by_try_push_edge() {
+    let mut graph: NodeGraph<'static, usize, usize, (), (), Theme, Rec> = NodeGraph::default();
+    graph.push_node(node(0_usize, Point::ORIGIN, Element::from(ContentProbe)));
+    let early = || crate::edge!(crate::PinRef::new(0, 0), crate::PinRef::new(1, 0));
+
+    // `push_edge` neither panics nor drops it; the edge waits for its node.
+    graph.push_edge(early());
+    assert_eq!(graph.topology().edges().len(), 1);
+    assert_eq!(
+        graph.try_push_edge(early()),
+        Err(crate::EdgeError::UnknownNode(1))
+    );
+    assert_eq!(graph.topology().edges().len(), 1);
+}
+
+#[test]
+fn layout_check_resolves_edges_in_any_push_order() {
+    let mut graph: NodeGraph<'static, usize, usize, (), (), Theme, Rec> = NodeGraph::default();
+    // The edge comes first; once its node is pushed it resolves.
+    graph.push_edge(crate::edge!(
+        crate::PinRef::new(0, 0),
+        crate::PinRef::new(1, 5)
+    ));
+    graph.push_node(node(
+        0_usize,
+        Point::ORIGIN,
+        crate::pin!(Right, 0_usize, Element::from(ContentProbe), Output),
+    ));
+    graph.push_node(node(
+        1_usize,
+        Point::new(200.0, 0.0),
+        Element::from(ContentProbe),
+    ));
+    let tree = Tree::new(&graph as &dyn Widget<(), Theme, Rec>);
+    assert_eq!(graph.unresolved_edge(&tree.children), None);
+
+    graph.push_edge(crate::edge!(
+        crate::PinRef::new(0, 1),
+        crate::PinRef::new(2, 0)
+    ));
+    let tree = Tree::new(&graph as &dyn Widget<(), Theme, Rec>);
+    assert_eq!(
+        graph.unresolved_edge(&tree.children),
+        Some(crate::EdgeError::UnknownPin(crate::PinRef::new(0, 1)))
+    );
+}
+
+#[test]
+fn try_push_edge_rejects_undeclared_pins_and_keeps_valid_edges() {
+    let mut graph: NodeGraph<'static, usize, usize, (), (), Theme, Rec> = NodeGraph::default();
+    graph.push_node(node(
+        0_usize,
+        Point::ORIGIN,
+        crate::pin!(Right, 0_usize, Element::from(ContentProbe), Output),
+    ));
+    graph.push_node(node(
+        1_usize,
+        Point::new(200.0, 0.0),
+        Element::from(ContentProbe),
+    ));
+
+    assert_eq!(
+        graph.try_push_edge(crate::edge!(
+            crate::PinRef::new(0, 1),
+            crate::PinRef::new(1, 0)
+        )),
+        Err(crate::EdgeError::UnknownPin(crate::PinRef::new(0, 1)))
+    );
+    // Node 1 declares no pins, so any pin id on it is accepted.
+    assert_eq!(
+        graph.try_push_edge(crate::edge!(
+            crate::PinRef::new(0, 0),
+            crate::PinRef::new(1, 5)
+        )),
+        Ok(())
+    );
+    assert_eq!(graph.connected_component(&0), vec![0, 1]);
+}
+
+#[test]
+fn graph_styles_apply_only_where_an_element_has_none() {
+    // How often each style closure was asked, by source.
+    let graph_nodes = Rc::new(Cell::new(0));
+    let own_nodes = Rc::new(Cell::new(0));
+    let graph_edges = Rc::new(Cell::new(0));
+    let (g, o, e) = (graph_nodes.clone(), own_nodes.clone(), graph_edges.clone());
+    let mut graph: NodeGraph<'static, usize, usize, (), (), Theme, Rec> = NodeGraph::default()
+        .node_style(move |theme, status| {
+            g.set(g.get() + 1);
+            crate::default_node_style(theme, status)
+        })
+        .edge_style(move |theme, status, _, _| {
+            e.set(e.get() + 1);
+            crate::default_edge_style(theme, status)
+        });
+    graph.push_node(node(
+        0_usize,
+        Point::new(0.0, 0.0),
+        crate::pin!(Right, 0_usize, Element::from(ContentProbe), Both),
+    ));
+    graph.push_node(
+        node(
+            1_usize,
+            Point::new(80.0, 0.0),
+            crate::pin!(Left, 0_usize, Element::from(ContentProbe), Both),
+        )
+        .style(move |theme, status| {
+            o.set(o.get() + 1);
+            crate::default_node_style(theme, status)
+        }),
+    );
+    graph.push_edge(crate::edge!(
+        crate::PinRef::new(0, 0),
+        crate::PinRef::new(1, 0)
+    ));
+
+    draw_graph(graph, Vector::ZERO);
+    assert_eq!(
+        graph_nodes.get(),
+        1,
+        "only the unstyled node uses the graph style"
+    );
+    assert_eq!(own_nodes.get(), 1, "a node's own style wins");
+    assert!(
+        graph_edges.get() > 0,
+        "the unstyled edge uses the graph style"
+    );
+}
+
+#[test]
+fn style_overrides_receive_each_elements_resolved_style_by_id() {
+    let seen_nodes = Rc::new(RefCell::new(Vec::new()));
+    let seen_pins = Rc::new(RefCell::new(Vec::new()));
+    let seen_edges = Rc::new(RefCell::new(Vec::new()));
+    let (n, p, e) = (seen_nodes.clone(), seen_pins.clone(), seen_edges.clone());
+    let mut graph: NodeGraph<'static, usize, usize, (), (), Theme, Rec> = NodeGraph::default()
+        .node_style_override(move |id, base| {
+            n.borrow_mut().push((*id, base.corner_radius));
+            base
+        })
+        .pin_style_override(move |pin, base| {
+            p.borrow_mut().push((pin.node_id, pin.pin_id));
+            base
+        })
+        .edge_style_override(move |from, to, base| {
+            e.borrow_mut().push((from.node_id, to.node_id));
+            base
+        });
+    graph.push_node(node(
+        0_usize,
+        Point::new(0.0, 0.0),
+        crate::pin!(Right, 0_usize, Element::from(ContentProbe), Both),
+    ));
+    graph.push_node(
+        node(
+            1_usize,
+            Point::new(80.0, 0.0),
+            crate::pin!(Left, 0_usize, Element::from(ContentProbe), Both),
+        )
+        .style(|theme, status| crate::NodeStyle {
+            corner_radius: 9.0,
+            ..crate::default_node_style(theme, status)
+        }),
+    );
+    graph.push_edge(crate::edge!(
+        crate::PinRef::new(0, 0),
+        crate::PinRef::new(1, 0)
+    ));
+
+    draw_graph(graph, Vector::ZERO);
+    let nodes = seen_nodes.borrow();
+    assert!(
+        nodes.contains(&(1, 9.0)),
+        "the override layers over the node's own style"
+    );
+    assert!(nodes.iter().any(|&(id, _)| id == 0));
+    let pins = seen_pins.borrow();
+    assert!(pins.contains(&(0, 0)) && pins.contains(&(1, 0)));
+    assert!(
+        seen_edges.borrow().contains(&(0, 1)),
+        "edges are keyed as pushed"
+    );
+}
+
+#[test]
+fn push_edges_styled_adds_every_pair_with_the_shared_style() {
+    let mut graph: NodeGraph<'static, usize, usize, (), (), Theme, Rec> = NodeGraph::default();
+    for id in 0..4 {
+        graph.push_node(node(
+            id,
+            Point::new(id as f32 * 60.0, 0.0),
+            crate::pin!(Right, 0_usize, Element::from(ContentProbe), Both),
+        ));
+    }
+    let calls = Rc::new(Cell::new(0));
+    let counter = calls.clone();
+    graph.push_edges_styled(
+        (0..3).map(|id| (crate::PinRef::new(id, 0), crate::PinRef::new(id + 1, 0))),
+        move |theme, status, _, _| {
+            counter.set(counter.get() + 1);
+            crate::default_edge_style(theme, status)
+        },
+    );
+    assert_eq!(graph.connected_component(&0), vec![0, 1, 2, 3]);
+
+    draw_graph(graph, Vector::ZERO);
+    assert!(calls.get() > 0, "edges resolve through the shared style");
+}
@@ -0,0 +1,356 @@
+//! JSON and RON import/export of a graph's structure (requires the `serde`
+//! feature).
+//!
+//! A [`GraphDocument`] captures everything the widget knows about a graph
+//! except node content, which stays host-defined: node ids and positions with
+//! an opaque per-node payload `D`, edges as [`PinRef`] pairs, the camera, and
+//! optionally the graph-wide [`GraphStyle`]. Build one with
+//! [`NodeGraph::to_document`](crate::NodeGraph::to_document) (or by hand),
+//! write it with [`to_json`](GraphDocument::to_json), and rebuild the graph
+//! from [`from_json`](GraphDocument::from_json) by pushing each node's content
+//! for its payload. [`to_ron`](GraphDocument::to_ron) and
+//! [`from_ron`](GraphDocument::from_ron) do the same in RON, which reads
+//! better for hand-edited project files and style configs.
+//!
+//! ```rust,ignore
+//! let doc = graph.to_document(|id| app.kind_of(id));
+//! std::fs::write("graph.json", doc.to_json()?)?;
+//!
+//! let doc: GraphDocument<usize, usize, NodeKind> = GraphDocument::from_json(&text)?;
+//! for node in &doc.nodes {
+//!     graph.push_node(node!(node.id, node.position, view_for(&node.data)));
+//! }
+//! ```
+
+use std::fmt;
+
+use iced::Point;
+use serde::{Deserialize, Serialize};
+
+use crate::PinRef;
+use crate::style::GraphStyle;
+
+/// Format version written by [`GraphDocument::to_json`] and
+/// [`GraphDocument::to_ron`]. Documents with a newer version are rejected by
+/// [`GraphDocument::from_json`] and [`GraphDocument::from_ron`]; older ones
+/// are read as-is.
+pub const DOCUMENT_VERSION: u32 = 1;
+
+/// Serializable snapshot of a graph's structure; see the [module docs](self).
+///
+/// `N` and `P` are the graph's node and pin id types and `D` the per-node
+/// payload the host needs to rebuild each node's content.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GraphDocument<N = usize, P = usize, D = ()> {
+    /// Format version; [`DOCUMENT_VERSION`] for documents built here.
+    pub version: u32,
+    /// Nodes in push order.
+    pub nodes: Vec<DocumentNode<N, D>>,
+    /// Edges in push order.
+    pub edges: Vec<DocumentEdge<N, P>>,
+    /// Camera the graph was viewed with.
+    pub camera: DocumentCamera,
+    /// Graph-wide style, if the host stores one.
+    #[serde(default)]
+    pub style: Option<GraphStyle>,
+}
+
+/// One node of a [`GraphDocument`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DocumentNode<N, D> {
+    /// The node's user id.
+    pub id: N,
+    /// World position of the node's top-left corner.
+    #[serde(with = "point")]
+    pub position: Point,
+    /// Host payload describing the node's content.
+    pub data: D,
+}
+
+/// One edge of a [`GraphDocument`]: the pins it connects.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DocumentEdge<N, P> {
+    /// The edge's `from` pin.
+    pub from: PinRef<N, P>,
+    /// The edge's `to` pin.
+    pub to: PinRef<N, P>,
+}
+
+/// Camera of a [`GraphDocument`], as passed to
+/// [`NodeGraph::view`](crate::NodeGraph::view).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DocumentCamera {
+    /// Camera position in world space.
+    #[serde(with = "point")]
+    pub position: Point,
+    /// Zoom factor.
+    pub zoom: f32,
+}
+
+impl Default for DocumentCamera {
+    fn default() -> Self {
+        Self {
+            position: Point::ORIGIN,
+            zoom: 1.0,
+        }
+    }
+}
+
+impl<N, P, D> Default for GraphDocument<N, P, D> {
+    fn default() -> Self {
+        Self {
+            version: DOCUMENT_VERSION,
+            nodes: Vec::new(),
+            edges: Vec::new(),
+            camera: DocumentCamera::default(),
+            style: None,
+        }
+    }
+}
+
+impl<N, P, D> GraphDocument<N, P, D>
+where
+    N: Serialize + for<'de> Deserialize<'de>,
+    P: Serialize + for<'de> Deserialize<'de>,
+    D: Serialize + for<'de> Deserialize<'de>,
+{
+    /// Writes the document as pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String, DocumentError> {
+        serde_json::to_string_pretty(self).map_err(DocumentError::Json)
+    }
+
+    /// Reads a document from JSON, rejecting versions newer than
+    /// [`DOCUMENT_VERSION`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::document::{DocumentNode, GraphDocument};
+    /// use iced_nodegraph::iced::Point;
+    ///
+    /// let mut doc: GraphDocument<usize, usize, String> = GraphDocument::default();
+    /// doc.nodes.push(DocumentNode {
+    ///     id: 0,
+    ///     position: Point::new(10.0, 20.0),
+    ///     data: "add".to_string(),
+    /// });
+    /// let json = doc.to_json().unwrap();
+    /// assert_eq!(GraphDocument::from_json(&json).unwrap(), doc);
+    /// ```
+    pub fn from_json(json: &str) -> Result<Self, DocumentError> {
+        #[derive(Deserialize)]
+        struct Header {
+            version: u32,
+        }
+        let header: Header = serde_json::from_str(json).map_err(DocumentError::Json)?;
+        if header.version > DOCUMENT_VERSION {
+            return Err(DocumentError::UnsupportedVersion(header.version));
+        }
+        serde_json::from_str(json).map_err(DocumentError::Json)
+    }
+
+    /// Writes the document as pretty-printed RON.
+    pub fn to_ron(&self) -> Result<String, DocumentError> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(DocumentError::RonWrite)
+    }
+
+    /// Reads a document from RON, rejecting versions newer than
+    /// [`DOCUMENT_VERSION`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::document::{DocumentNode, GraphDocument};
+    /// use iced_nodegraph::iced::Point;
+    ///
+    /// let mut doc: GraphDocument<usize, usize, String> = GraphDocument::default();
+    /// doc.nodes.push(DocumentNode {
+    ///     id: 0,
+    ///     position: Point::new(10.0, 20.0),
+    ///     data: "add".to_string(),
+    /// });
+    /// let ron = doc.to_ron().unwrap();
+    /// assert_eq!(GraphDocument::from_ron(&ron).unwrap(), doc);
+    /// ```
+    pub fn from_ron(ron: &str) -> Result<Self, DocumentError> {
+        #[derive(Deserialize)]
+        struct Header {
+            version: u32,
+        }
+        let header: Header = ron::from_str(ron).map_err(DocumentError::RonRead)?;
+        if header.version > DOCUMENT_VERSION {
+            return Err(DocumentError::UnsupportedVersion(header.version));
+        }
+        ron::from_str(ron).map_err(DocumentError::RonRead)
+    }
+}
+
+/// Why a [`GraphDocument`] could not be read or written.
+#[derive(Debug)]
+pub enum DocumentError {
+    /// Malformed JSON, or JSON that does not match the document shape.
+    Json(serde_json::Error),
+    /// Malformed RON, or RON that does not match the document shape, with
+    /// where in the text it went wrong.
+    RonRead(ron::error::SpannedError),
+    /// The document could not be written as RON.
+    RonWrite(ron::Error),
+    /// The document was written by a newer format version.
+    UnsupportedVersion(u32),
+}
+
+impl fmt::Display for DocumentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Json(error) => write!(f, "invalid graph document: {error}"),
+            Self::RonRead(error) => write!(f, "invalid graph document: {error}"),
+            Self::RonWrite(error) => write!(f, "graph document not written: {error}"),
+            Self::UnsupportedVersion(version) => write!(
+                f,
+                "graph document version {version} is newer than the supported {DOCUMENT_VERSION}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DocumentError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Json(error) => Some(error),
+            Self::RonRead(error) => Some(error),
+            Self::RonWrite(error) => Some(error),
+            Self::UnsupportedVersion(_) => None,
+        }
+    }
+}
+
+/// `iced::Point` as an `[x, y]` pair (an `(x, y)` tuple in RON).
+mod point {
+    use iced::Point;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(point: &Point, serializer: S) -> Result<S::Ok, S::Error> {
+        [point.x, point.y].serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Point, D::Error> {
+        let [x, y] = <[f32; 2]>::deserialize(deserializer)?;
+        Ok(Point::new(x, y))
+    }
+}
+
+/// `iced::Color` as an `[r, g, b, a]` array, for the style types.
+pub(crate) mod color {
+    use iced::Color;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+        [color.r, color.g, color.b, color.a].serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        let [r, g, b, a] = <[f32; 4]>::deserialize(deserializer)?;
+        Ok(Color { r, g, b, a })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::{ShadowStyle, TilingBackground};
+    use iced::Color;
+
+    fn sample() -> GraphDocument<usize, usize, String> {
+        GraphDocument {
+            version: DOCUMENT_VERSION,
+            nodes: vec![
+                DocumentNode {
+                    id: 0,
+                    position: Point::new(10.0, 20.0),
+                    data: "source".into(),
+                },
+                DocumentNode {
+                    id: 1,
+                    position: Point::new(200.0, -40.5),
+                    data: "sink".into(),
+                },
+            ],
+            edges: vec![DocumentEdge {
+                from: PinRef::new(0, 1),
+                to: PinRef::new(1, 0),
+            }],
+            camera: DocumentCamera {
+                position: Point::new(-5.0, 7.5),
+                zoom: 1.5,
+            },
+            style: Some(
+                GraphStyle::dark()
+                    .tiling(TilingBackground::dots(30.0, 1.5, Color::WHITE))
+                    .selection_style(
+                        crate::SelectionStyle::default().shadow(ShadowStyle::lifted()),
+                    ),
+            ),
+        }
+    }
+
+    #[test]
+    fn round_trips_structure_camera_and_style() {
+        let doc = sample();
+        let json = doc.to_json().unwrap();
+        assert_eq!(GraphDocument::from_json(&json).unwrap(), doc);
+    }
+
+    #[test]
+    fn rejects_newer_versions_and_malformed_json() {
+        let mut doc = sample();
+        doc.version = DOCUMENT_VERSION + 1;
+        let json = doc.to_json().unwrap();
+        assert!(matches!(
+            GraphDocument::<usize, usize, String>::from_json(&json),
+            Err(DocumentError::UnsupportedVersion(v)) if v == DOCUMENT_VERSION + 1
+        ));
+        assert!(matches!(
+            GraphDocument::<usize, usize, String>::from_json("{\"nodes\": []}"),
+            Err(DocumentError::Json(_))
+        ));
+    }
+
+    #[test]
+    fn round_trips_through_ron_and_rejects_newer_versions() {
+        let doc = sample();
+        let ron = doc.to_ron().unwrap();
+        assert_eq!(GraphDocument::from_ron(&ron).unwrap(), doc);
+
+        let mut newer = sample();
+        newer.version = DOCUMENT_VERSION + 1;
+        assert!(matches!(
+            GraphDocument::<usize, usize, String>::from_ron(&newer.to_ron().unwrap()),
+            Err(DocumentError::UnsupportedVersion(v)) if v == DOCUMENT_VERSION + 1
+        ));
+        assert!(matches!(
+            GraphDocument::<usize, usize, String>::from_ron("(nodes: [)"),
+            Err(DocumentError::RonRead(_))
+        ));
+    }
+
+    #[test]
+    fn hand_written_ron_reads_without_a_style() {
+        let ron = r#"(
+            version: 1,
+            nodes: [(id: 0, position: (10.0, 20.0), data: ())],
+            edges: [],
+            camera: (position: (0.0, 0.0), zoom: 1.0),
+        )"#;
+        let doc = GraphDocument::<usize, usize, ()>::from_ron(ron).unwrap();
+        assert_eq!(doc.nodes[0].position, Point::new(10.0, 20.0));
+        assert_eq!(doc.style, None);
+    }
+
+    #[test]
+    fn style_is_optional_in_the_json() {
+        let json = r#"{"version":1,"nodes":[],"edges":[],"camera":{"position":[0,0],"zoom":1}}"#;
+        let doc = GraphDocument::<usize, usize, ()>::from_json(json).unwrap();
+        assert_eq!(doc, GraphDocument::default());
+    }
+}
@@ -0,0 +1,273 @@
+//! Tidying passes over an existing arrangement.
+//!
+//! Where [`Topology::layered_positions`](crate::topology::Topology::layered_positions)
+//! lays a graph out from scratch, these passes start from the positions the
+//! user already has and only reorder within them, so a hand-placed graph
+//! keeps its overall shape.
+//!
+//! ```rust
+//! use iced::Point;
+//! use iced_nodegraph::PinRef;
+//! use iced_nodegraph::layout::reduce_crossings;
+//!
+//! // Two columns wired across each other: 0 -> 3 and 1 -> 2.
+//! let positions = [
+//!     Point::new(0.0, 0.0),
+//!     Point::new(0.0, 100.0),
+//!     Point::new(300.0, 0.0),
+//!     Point::new(300.0, 100.0),
+//! ];
+//! let edges = [
+//!     (PinRef::new(0, 0), PinRef::new(3, 0)),
+//!     (PinRef::new(1, 0), PinRef::new(2, 0)),
+//! ];
+//! let tidy = reduce_crossings(&positions, &edges, 4);
+//! assert_eq!(tidy[2], Point::new(300.0, 100.0)); // 2 and 3 swapped rows
+//! assert_eq!(tidy[3], Point::new(300.0, 0.0));
+//! ```
+
+use iced::Point;
+
+use crate::PinRef;
+
+/// Width in world-space pixels of the x band that groups nodes into one
+/// implicit column: a column starts at its leftmost node and takes every
+/// node less than this far right of it.
+pub const COLUMN_BAND: f32 = 100.0;
+
+/// Positions with nodes reordered within their columns to reduce edge
+/// crossings, by node index like `positions`.
+///
+/// Columns are implicit: nodes are grouped into x bands [`COLUMN_BAND`] wide.
+/// Each of the `iterations` rounds sweeps the columns left to right, then
+/// right to left, sorting each column by the mean y of its neighbors in the
+/// columns already swept (the barycenter heuristic). Nodes only trade y
+/// positions within their column and keep their x, so the arrangement keeps
+/// its rough shape. The result with the fewest crossings of straight lines
+/// between node positions is returned, never more than the input has, and
+/// the same input always gives the same output. Edges naming a node outside
+/// `positions` are ignored.
+pub fn reduce_crossings<P>(
+    positions: &[Point],
+    edges: &[(PinRef<usize, P>, PinRef<usize, P>)],
+    iterations: usize,
+) -> Vec<Point> {
+    let count = positions.len();
+    let links: Vec<(usize, usize)> = edges
+        .iter()
+        .map(|(from, to)| (from.node_id, to.node_id))
+        .filter(|&(from, to)| from < count && to < count && from != to)
+        .collect();
+    let mut neighbors = vec![Vec::new(); count];
+    for &(from, to) in &links {
+        neighbors[from].push(to);
+        neighbors[to].push(from);
+    }
+
+    let columns = columns(positions);
+    let mut column_of = vec![0; count];
+    for (column, nodes) in columns.iter().enumerate() {
+        for &node in nodes {
+            column_of[node] = column;
+        }
+    }
+    // Each column's y slots, top to bottom; reordering deals them out anew.
+    let slots: Vec<Vec<f32>> = columns
+        .iter()
+        .map(|nodes| nodes.iter().map(|&node| positions[node].y).collect())
+        .collect();
+
+    let mut current = positions.to_vec();
+    let mut best = current.clone();
+    let mut fewest = crossings(&current, &links);
+    let mut order = columns;
+    for _ in 0..iterations {
+        if fewest == 0 {
+            break;
+        }
+        for forward in [true, false] {
+            let sweep: Vec<usize> = if forward {
+                (0..order.len()).collect()
+            } else {
+                (0..order.len()).rev().collect()
+            };
+            for column in sweep {
+                let swept = |other: usize| {
+                    if forward {
+                        column_of[other] < column
+                    } else {
+                        column_of[other] > column
+                    }
+                };
+                let key = |node: usize| {
+                    let ys: Vec<f32> = neighbors[node]
+                        .iter()
+                        .filter(|&&other| swept(other))
+                        .map(|&other| current[other].y)
+                        .collect();
+                    if ys.is_empty() {
+                        current[node].y
+                    } else {
+                        ys.iter().sum::<f32>() / ys.len() as f32
+                    }
+                };
+                let mut keyed: Vec<(f32, usize, usize)> = order[column]
+                    .iter()
+                    .enumerate()
+                    .map(|(rank, &node)| (key(node), rank, node))
+                    .collect();
+                keyed.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+                order[column] = keyed.into_iter().map(|(_, _, node)| node).collect();
+                for (&node, &y) in order[column].iter().zip(&slots[column]) {
+                    current[node].y = y;
+                }
+            }
+            let now = crossings(&current, &links);
+            if now < fewest {
+                fewest = now;
+                best.clone_from(&current);
+            }
+        }
+    }
+    best
+}
+
+/// Node indices grouped into x bands [`COLUMN_BAND`] wide, left to right,
+/// each column sorted top to bottom (ties by index).
+fn columns(positions: &[Point]) -> Vec<Vec<usize>> {
+    let mut by_x: Vec<usize> = (0..positions.len()).collect();
+    by_x.sort_by(|&a, &b| positions[a].x.total_cmp(&positions[b].x).then(a.cmp(&b)));
+    let mut columns: Vec<Vec<usize>> = Vec::new();
+    let mut start = f32::NEG_INFINITY;
+    for node in by_x {
+        let x = positions[node].x;
+        match columns.last_mut() {
+            Some(column) if x - start < COLUMN_BAND => column.push(node),
+            _ => {
+                start = x;
+                columns.push(vec![node]);
+            }
+        }
+    }
+    for column in &mut columns {
+        column.sort_by(|&a, &b| positions[a].y.total_cmp(&positions[b].y).then(a.cmp(&b)));
+    }
+    columns
+}
+
+/// Pairs of edges whose straight lines between node positions cross. Edges
+/// sharing a node never count.
+fn crossings(positions: &[Point], links: &[(usize, usize)]) -> usize {
+    let mut total = 0;
+    for (i, &(a, b)) in links.iter().enumerate() {
+        for &(c, d) in &links[i + 1..] {
+            if a == c || a == d || b == c || b == d {
+                continue;
+            }
+            if segments_cross(positions[a], positions[b], positions[c], positions[d]) {
+                total += 1;
+            }
+        }
+    }
+    total
+}
+
+/// Whether segments `p1-p2` and `p3-p4` cross at a point inside both.
+fn segments_cross(p1: Point, p2: Point, p3: Point, p4: Point) -> bool {
+    let side = |a: Point, b: Point, c: Point| (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x);
+    let (d1, d2) = (side(p3, p4, p1), side(p3, p4, p2));
+    let (d3, d4) = (side(p1, p2, p3), side(p1, p2, p4));
+    d1 * d2 < 0.0 && d3 * d4 < 0.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wire(from: usize, to: usize) -> (PinRef<usize, usize>, PinRef<usize, usize>) {
+        (PinRef::new(from, 0), PinRef::new(to, 0))
+    }
+
+    fn crossings_of(
+        positions: &[Point],
+        edges: &[(PinRef<usize, usize>, PinRef<usize, usize>)],
+    ) -> usize {
+        let links: Vec<_> = edges.iter().map(|(f, t)| (f.node_id, t.node_id)).collect();
+        crossings(positions, &links)
+    }
+
+    /// Three columns of three, wired so every layer pair is reversed.
+    fn tangled() -> (
+        Vec<Point>,
+        Vec<(PinRef<usize, usize>, PinRef<usize, usize>)>,
+    ) {
+        let positions = (0..9)
+            .map(|i| {
+                Point::new(
+                    (i / 3) as f32 * 250.0 + (i % 2) as f32 * 20.0,
+                    (i % 3) as f32 * 80.0,
+                )
+            })
+            .collect();
+        let edges = vec![
+            wire(0, 5),
+            wire(1, 4),
+            wire(2, 3),
+            wire(3, 8),
+            wire(4, 7),
+            wire(5, 6),
+            wire(0, 4),
+        ];
+        (positions, edges)
+    }
+
+    #[test]
+    fn untangles_a_crossing_heavy_graph() {
+        let (positions, edges) = tangled();
+        let before = crossings_of(&positions, &edges);
+        let tidy = reduce_crossings(&positions, &edges, 4);
+        let after = crossings_of(&tidy, &edges);
+        assert!(before > 0);
+        assert!(after < before, "{after} crossings left of {before}");
+        // Only y moves, and only among the column's own y values.
+        for (old, new) in positions.iter().zip(&tidy) {
+            assert_eq!(old.x, new.x);
+            assert!(
+                positions
+                    .iter()
+                    .any(|p| (p.x - old.x).abs() < COLUMN_BAND && p.y == new.y)
+            );
+        }
+    }
+
+    #[test]
+    fn is_deterministic_and_never_worse() {
+        let (positions, edges) = tangled();
+        assert_eq!(
+            reduce_crossings(&positions, &edges, 3),
+            reduce_crossings(&positions, &edges, 3)
+        );
+        assert_eq!(reduce_crossings(&positions, &edges, 0), positions);
+
+        // Already crossing-free: left as is.
+        let clean = [
+            Point::new(0.0, 0.0),
+            Point::new(0.0, 50.0),
+            Point::new(300.0, 0.0),
+            Point::new(300.0, 50.0),
+        ];
+        let edges = [wire(0, 2), wire(1, 3), wire(7, 0)];
+        assert_eq!(reduce_crossings(&clean, &edges, 5), clean);
+    }
+
+    #[test]
+    fn nearby_x_positions_share_a_column() {
+        let positions = [
+            Point::new(0.0, 0.0),
+            Point::new(60.0, 10.0),
+            Point::new(120.0, 0.0),
+            Point::new(400.0, 0.0),
+        ];
+        assert_eq!(columns(&positions), [vec![0, 1], vec![2], vec![3]]);
+    }
+}
@@ -24,6 +24,7 @@
 //! - **Spatial Index** - A GPU tile index culls geometry per pixel, scaling to large graphs
 //! - **Pin Feedback** - Valid drop targets pulse while dragging an edge
 //! - **Theme Support** - Integrates with Iced's theming system
+//! - **JSON Documents** - Save and load graph structure, camera and style with the `serde` feature ([`document`])
 //!
 //! ## Quick Start
 //!
@@ -92,10 +93,14 @@
 //! - Edges: [`EdgeStyle::error`] (red animated marching-ants with a border ring),
 //!   [`EdgeStyle::disabled`] (gray dashed), [`EdgeStyle::highlighted`] (yellow with
 //!   a soft ring), [`EdgeStyle::data_flow`] (blue), [`EdgeStyle::debug`] (dotted
-//!   cyan straight line).
+//!   cyan straight line). Theme-aware twins such as [`EdgeStyle::error_themed`]
+//!   (palette danger), [`EdgeStyle::success_themed`] and
+//!   [`EdgeStyle::warning_themed`] take their colors from the theme instead, so
+//!   they stay readable on light themes too.
 //!
 //! ```ignore
 //! edge!(from, to).style(|_theme, _status, _from, _to| EdgeStyle::error());
+//! edge!(from, to).style(|theme, _status, _from, _to| EdgeStyle::error_themed(theme));
 //! ```
 //!
 //! ### Stroke patterns
@@ -253,26 +258,42 @@
 //! per-frame metrics (element counts total/in-view/culled and CPU op timings),
 //! register a callback with [`NodeGraph::on_info`]; it delivers a [`GraphInfo`]
 //! each redraw.
+//!
+//! For very large graphs, [`NodeGraph::performance_hint`] sets a node-count
+//! threshold above which zoomed-out frames drop shadows and pins, and
+//! [`NodeGraph::on_performance_warning`] tells the host once when it is crossed.
 
 pub use connection::{default_can_connect, direction_ok, input_not_occupied, not_same_node};
 pub use content::{EdgeRadii, node_footer, node_header};
 pub use ids::{EdgeId, NodeId, PinId};
 pub use node_graph::{
-    Counts, DragInfo, Edge, GraphInfo, Node, NodeGraph, OpTiming, PinRef,
+    BackgroundClick, ConnectMode, Connection, ConnectionState, Counts, DragInfo, DragProgress,
+    Edge, EdgeError, EdgeSnapshot, GraphInfo, LayoutSnapshot, Node, NodeGraph, NodeGraphEvent,
+    NodeShape, OpTiming, PerformanceHint, PinRef, PinVisibility, ScreenAnchor, ScrollMode,
+    SelectRule,
     camera::Camera2D,
     edge,
-    input::{ComboKey, KeyAction, KeyCombo, Keymap},
-    node,
+    input::{ComboKey, CursorConfig, KeyAction, KeyCombo, Keymap},
+    node, prefers_reduced_motion,
     widget::node_graph,
 };
-pub use node_pin::{NodePin, PinDirection, PinEnd, PinInfo, PinSide, node_pin};
+pub use node_pin::{
+    NodePin, PinDirection, PinEnd, PinGroup, PinInfo, PinSide, node_pin, pin_group,
+};
 pub use style::{
+    BackgroundSpace,
+    // Per-node status indicators
+    Badge,
+    BadgeCorner,
+    BoxSelectStyle,
     // Unified color type for style fields
     ColorQuad,
+    Decoration,
     EdgeCurve,
     // Status enums for widget-side styling
     EdgeStatus,
     EdgeStyle,
+    FocusRingStyle,
     GraphStyle,
     NodeStatus,
     // Node/edge/pin style types (concrete; override via struct-update over defaults)
@@ -281,9 +302,11 @@ pub use style::{
     PinStatus,
     PinStyle,
     SelectionStyle,
+    ShadowStyle,
     // Tiling background (grid/dots/...) for GraphStyle
     TilingBackground,
     TilingKind,
+    WidthSpace,
     // Built-in status-driven default styles
     default_edge_style,
     default_node_style,
@@ -292,15 +315,21 @@ pub use style::{
 
 // Re-export iced_nodegraph_sdf pattern types for downstream crates
 pub use iced_nodegraph_sdf::Pattern;
+pub use iced_nodegraph_sdf::Pulse;
 pub use iced_nodegraph_sdf::pattern::PatternType as SdfPatternType;
+pub use topology::{GraphMetrics, Topology, graph_to_dot};
 
 pub mod connection;
 pub mod content;
+#[cfg(feature = "serde")]
+pub mod document;
 pub mod ids;
+pub mod layout;
 mod node_graph;
 mod node_pin;
 pub mod prelude;
 pub mod style;
+pub mod topology;
 
 #[cfg(test)]
 mod clipping_tests;
@@ -161,7 +161,7 @@ use super::euclid::{
     WorldPoint, WorldRect, WorldSize, WorldVector,
 };
 use euclid::{Scale, Transform2D};
-use iced::Rectangle;
+use iced::{Point, Rectangle, Size};
 use iced_wgpu::core::{mouse, renderer};
 
 #[derive(Debug, Clone, Copy)]
@@ -185,10 +185,15 @@ impl Default for Camera2D {
 impl Camera2D {
     /// Zoom clamp bounds: keep the world<->screen transforms invertible and
     /// well-conditioned (`world_to_screen` inverts the scale). Enforced by
-    /// every zoom entry point (`zoom_at`, `with_zoom_and_position`).
+    /// every zoom entry point (`zoom_at`, `with_zoom_and_position`) except
+    /// `zoom_at_elastic`, which may exceed them by up to `ZOOM_OVERSHOOT`.
     pub const ZOOM_MIN: f32 = 0.1;
     pub const ZOOM_MAX: f32 = 10.0;
 
+    /// Maximum elastic overshoot past a zoom limit, as a fraction of that
+    /// limit (see [`zoom_at_elastic`](Self::zoom_at_elastic)).
+    pub const ZOOM_OVERSHOOT: f32 = 0.15;
+
     /// Clamp a zoom factor into [`ZOOM_MIN`](Self::ZOOM_MIN)..=[`ZOOM_MAX`](Self::ZOOM_MAX);
     /// non-finite input (corrupt persistence, division fallout) becomes 1.0.
     fn clamp_zoom(zoom: f32) -> f32 {
@@ -259,12 +264,75 @@ impl Camera2D {
     /// World-to-screen transform: `screen = (world + position) * zoom + viewport_origin`.
     pub fn world_to_screen(&self) -> Transform2D<f32, World, Screen> {
         // Converts world coordinates to screen coordinates.
-        // The transform is always invertible since zoom is clamped to [0.1, 10.0].
+        // The transform is always invertible since zoom is clamped to [0.1, 10.0]
+        // (elastic overshoot only stretches that range by a bounded fraction).
         self.screen_to_world()
             .inverse()
             .expect("Camera transform must be invertible (zoom cannot be 0)")
     }
 
+    /// Maps a world-space rectangle to screen space through
+    /// [`world_to_screen`](Self::world_to_screen): the origin is transformed
+    /// like a point and the size scales by the zoom.
+    pub fn world_rect_to_screen(&self, rect: Rectangle) -> Rectangle {
+        let rect: WorldRect = rect.into_euclid();
+        let screen: ScreenRect = self
+            .world_to_screen()
+            .outer_transformed_box(&rect.to_box2d())
+            .to_rect();
+        screen.into_iced()
+    }
+
+    /// Maps a screen-space rectangle to world space; the inverse of
+    /// [`world_rect_to_screen`](Self::world_rect_to_screen).
+    pub fn screen_rect_to_world(&self, rect: Rectangle) -> Rectangle {
+        let rect: ScreenRect = rect.into_euclid();
+        let world: WorldRect = self
+            .screen_to_world()
+            .outer_transformed_box(&rect.to_box2d())
+            .to_rect();
+        world.into_iced()
+    }
+
+    /// The world-space rectangle visible in a widget of `viewport` size sitting
+    /// at the [`viewport_origin`](Self::viewport_origin).
+    ///
+    /// Useful for culling, drawing the view frame on a minimap, or checking
+    /// whether a node is on screen.
+    pub fn visible_world_bounds(&self, viewport: Size) -> Rectangle {
+        self.screen_rect_to_world(Rectangle::new(
+            Point::new(self.viewport_origin.x, self.viewport_origin.y),
+            viewport,
+        ))
+    }
+
+    /// Rounds a world-space point to the nearest intersection of a square
+    /// grid with `spacing` world units, anchored at the world origin like the
+    /// [`Grid`](crate::TilingKind::Grid) and [`Dots`](crate::TilingKind::Dots)
+    /// tilings.
+    ///
+    /// Use it to land spawned or pasted nodes on the same lattice the canvas
+    /// draws. A non-positive or non-finite `spacing` returns `point` as is.
+    pub fn world_to_grid(point: Point, spacing: f32) -> Point {
+        if !(spacing.is_finite() && spacing > 0.0) {
+            return point;
+        }
+        Point::new(
+            (point.x / spacing).round() * spacing,
+            (point.y / spacing).round() * spacing,
+        )
+    }
+
+    /// Maps a screen-space point to world space, then snaps it with
+    /// [`world_to_grid`](Self::world_to_grid).
+    ///
+    /// Typical for spawn positions: pass the viewport center (or the cursor)
+    /// to place a new node on the grid intersection closest to it.
+    pub fn screen_to_grid(&self, screen: Point, spacing: f32) -> Point {
+        let world = self.screen_to_world().transform_point(screen.into_euclid());
+        Self::world_to_grid(world.into_iced(), spacing)
+    }
+
     pub fn move_by(&self, offset: WorldVector) -> Self {
         // Moves the camera by a given offset in world space.
         Self {
@@ -275,9 +343,71 @@ impl Camera2D {
     }
 
     pub fn zoom_at(&self, cursor_screen: ScreenPoint, offset: f32) -> Self {
-        // Adjusts the zoom level, keeping the screen cursor position stable.
-        // This means the world point under the cursor stays at the same screen location.
-        //
+        self.zoom_to(cursor_screen, Self::clamp_zoom(self.zoom.get() + offset))
+    }
+
+    /// Elastic variant of [`zoom_at`](Self::zoom_at): past
+    /// [`ZOOM_MIN`](Self::ZOOM_MIN)/[`ZOOM_MAX`](Self::ZOOM_MAX) the zoom keeps
+    /// moving with growing resistance instead of stopping dead, overshooting by
+    /// at most [`ZOOM_OVERSHOOT`](Self::ZOOM_OVERSHOOT) of the limit. Pair with
+    /// [`relax_zoom`](Self::relax_zoom) to bounce back.
+    pub fn zoom_at_elastic(&self, cursor_screen: ScreenPoint, offset: f32) -> Self {
+        self.zoom_to(cursor_screen, Self::elastic_zoom(self.zoom.get(), offset))
+    }
+
+    /// Whether the zoom currently sits outside the clamp range (only reachable
+    /// through [`zoom_at_elastic`](Self::zoom_at_elastic)).
+    pub fn is_overshooting(&self) -> bool {
+        let zoom = self.zoom.get();
+        !(Self::ZOOM_MIN..=Self::ZOOM_MAX).contains(&zoom)
+    }
+
+    /// Moves an overshooting zoom back toward the nearest limit, keeping the
+    /// world point under `anchor_screen` fixed. `t` is the fraction of the
+    /// remaining overshoot to remove (0 keeps it, 1 lands on the limit).
+    pub fn relax_zoom(&self, anchor_screen: ScreenPoint, t: f32) -> Self {
+        let zoom = self.zoom.get();
+        let limit = Self::clamp_zoom(zoom);
+        let relaxed = zoom + (limit - zoom) * t.clamp(0.0, 1.0);
+        self.zoom_to(anchor_screen, relaxed)
+    }
+
+    /// Rubber-band mapping behind [`zoom_at_elastic`](Self::zoom_at_elastic).
+    ///
+    /// The displayed overshoot `f` relates to the raw (undamped) excess `x`
+    /// through `f = d * x / (x + d)`, with `d` the headroom past the limit, so
+    /// it grows with the excess but never reaches `d`. The current overshoot
+    /// is unwound to its raw excess first, which keeps successive small steps
+    /// equivalent to one large step.
+    fn elastic_zoom(old: f32, offset: f32) -> f32 {
+        if !old.is_finite() || !offset.is_finite() {
+            return Self::clamp_zoom(old);
+        }
+        let band = |x: f32, d: f32| d * x / (x + d);
+        let unband = |f: f32, d: f32| d * f / (d - f).max(f32::EPSILON);
+        let max_room = Self::ZOOM_MAX * Self::ZOOM_OVERSHOOT;
+        let min_room = Self::ZOOM_MIN * Self::ZOOM_OVERSHOOT;
+
+        let raw = if old > Self::ZOOM_MAX {
+            Self::ZOOM_MAX + unband(old - Self::ZOOM_MAX, max_room)
+        } else if old < Self::ZOOM_MIN {
+            Self::ZOOM_MIN - unband(Self::ZOOM_MIN - old, min_room)
+        } else {
+            old
+        } + offset;
+
+        if raw > Self::ZOOM_MAX {
+            Self::ZOOM_MAX + band(raw - Self::ZOOM_MAX, max_room)
+        } else if raw < Self::ZOOM_MIN {
+            Self::ZOOM_MIN - band(Self::ZOOM_MIN - raw, min_room)
+        } else {
+            raw
+        }
+    }
+
+    /// Sets the zoom to `new_zoom`, keeping the world point under
+    /// `cursor_screen` at the same screen location.
+    fn zoom_to(&self, cursor_screen: ScreenPoint, new_zoom: f32) -> Self {
         // Rendering formula: screen = (world + position) * zoom
         // For a fixed screen point, we need:
         //   screen = (world + pos1) * zoom1 = (world + pos2) * zoom2
@@ -286,7 +416,6 @@ impl Camera2D {
         //   pos2 = pos1 + screen * (1/zoom1 - 1/zoom2)
 
         let old_zoom = self.zoom.get();
-        let new_zoom = Self::clamp_zoom(old_zoom + offset);
 
         // Cursor must be relative to the widget origin; screen = origin + (world + pos) * zoom.
         let local_x = cursor_screen.x - self.viewport_origin.x;
@@ -423,6 +552,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn grid_snap_rounds_to_nearest_intersection() {
+        let snapped = Camera2D::world_to_grid(Point::new(29.0, -31.0), 20.0);
+        assert_eq!(snapped, Point::new(20.0, -40.0));
+        // Degenerate spacing leaves the point alone.
+        for spacing in [0.0, -5.0, f32::NAN] {
+            let p = Point::new(3.3, 4.4);
+            assert_eq!(Camera2D::world_to_grid(p, spacing), p);
+        }
+
+        // Screen points go through the camera first: at zoom 2 and position
+        // (10, 0), screen (100, 50) is world (40, 25), which snaps to (40, 20).
+        let cam = Camera2D::with_zoom_and_position(2.0, WorldPoint::new(10.0, 0.0));
+        assert_eq!(
+            cam.screen_to_grid(Point::new(100.0, 50.0), 20.0),
+            Point::new(40.0, 20.0)
+        );
+    }
+
     #[test]
     fn test_identity_transform() {
         // At default zoom (1.0) and position (0,0), screen = world
@@ -837,6 +985,57 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_zoom_at_elastic_overshoot_is_bounded() {
+        let cursor = ScreenPoint::new(300.0, 200.0);
+        let ceiling = Camera2D::ZOOM_MAX * (1.0 + Camera2D::ZOOM_OVERSHOOT);
+        let mut camera = Camera2D::with_zoom_and_position(Camera2D::ZOOM_MAX, WorldPoint::zero());
+        let mut previous = camera.zoom();
+        for _ in 0..50 {
+            camera = camera.zoom_at_elastic(cursor, 5.0);
+            assert!(camera.zoom() > previous, "overshoot must keep growing");
+            assert!(
+                camera.zoom() < ceiling,
+                "overshoot must stay under the band"
+            );
+            previous = camera.zoom();
+        }
+        assert!(camera.is_overshooting());
+
+        let mut floor = Camera2D::with_zoom_and_position(Camera2D::ZOOM_MIN, WorldPoint::zero());
+        floor = floor.zoom_at_elastic(cursor, -100.0);
+        assert!(floor.zoom() < Camera2D::ZOOM_MIN);
+        assert!(floor.zoom() > Camera2D::ZOOM_MIN * (1.0 - Camera2D::ZOOM_OVERSHOOT));
+    }
+
+    #[test]
+    fn test_zoom_at_elastic_matches_zoom_at_inside_limits() {
+        let cursor = ScreenPoint::new(120.0, 80.0);
+        let camera = Camera2D::with_zoom_and_position(1.5, WorldPoint::new(4.0, -9.0));
+        let hard = camera.zoom_at(cursor, 0.7);
+        let elastic = camera.zoom_at_elastic(cursor, 0.7);
+        assert!(approx_eq(hard.zoom(), elastic.zoom()));
+        assert!(point_approx_eq(hard.position(), elastic.position()));
+        assert!(!elastic.is_overshooting());
+    }
+
+    #[test]
+    fn test_relax_zoom_returns_to_limit_keeping_anchor() {
+        let anchor = ScreenPoint::new(426.0, 222.0);
+        let camera = Camera2D::with_zoom_and_position(Camera2D::ZOOM_MAX, WorldPoint::zero())
+            .zoom_at_elastic(anchor, 20.0);
+        let world_before = camera.screen_to_world().transform_point(anchor);
+
+        let halfway = camera.relax_zoom(anchor, 0.5);
+        assert!(halfway.zoom() < camera.zoom() && halfway.is_overshooting());
+
+        let settled = camera.relax_zoom(anchor, 1.0);
+        assert_eq!(settled.zoom(), Camera2D::ZOOM_MAX);
+        assert!(!settled.is_overshooting());
+        let world_after = settled.screen_to_world().transform_point(anchor);
+        assert!(point_approx_eq(world_before, world_after));
+    }
+
     #[test]
     fn test_real_world_scenario_from_bug_report() {
         // This is the exact scenario from the bug report that was failing
@@ -940,6 +1139,66 @@ mod tests {
         assert!(approx_eq(layout.width, 400.0), "w: got {}", layout.width);
         assert!(approx_eq(layout.height, 300.0), "h: got {}", layout.height);
     }
+
+    #[test]
+    fn test_rect_round_trip_across_zoom_and_pan() {
+        let rect = Rectangle::new(Point::new(-30.0, 45.0), Size::new(120.0, 80.0));
+        for zoom in [0.25, 1.0, 3.5] {
+            for position in [WorldPoint::origin(), WorldPoint::new(50.0, -75.0)] {
+                for origin in [ScreenVector::zero(), ScreenVector::new(40.0, 100.0)] {
+                    let camera = Camera2D::with_zoom_and_position(zoom, position)
+                        .with_viewport_origin(origin);
+                    let screen = camera.world_rect_to_screen(rect);
+
+                    // screen = (world + position) * zoom + origin; sizes scale by zoom.
+                    assert!(approx_eq(screen.x, (rect.x + position.x) * zoom + origin.x));
+                    assert!(approx_eq(screen.y, (rect.y + position.y) * zoom + origin.y));
+                    assert!(approx_eq(screen.width, rect.width * zoom));
+                    assert!(approx_eq(screen.height, rect.height * zoom));
+
+                    let back = camera.screen_rect_to_world(screen);
+                    assert!(approx_eq(back.x, rect.x), "x: got {}", back.x);
+                    assert!(approx_eq(back.y, rect.y), "y: got {}", back.y);
+                    assert!(approx_eq(back.width, rect.width), "w: got {}", back.width);
+                    assert!(
+                        approx_eq(back.height, rect.height),
+                        "h: got {}",
+                        back.height
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_visible_world_bounds() {
+        let viewport = Size::new(800.0, 600.0);
+
+        let identity = Camera2D::new().visible_world_bounds(viewport);
+        assert_eq!(identity, Rectangle::new(Point::ORIGIN, viewport));
+
+        // Zoomed in 2x and panned by (10, 20): the view spans half the world
+        // size, starting at -position. The viewport origin does not move it.
+        let camera = Camera2D::with_zoom_and_position(2.0, WorldPoint::new(10.0, 20.0))
+            .with_viewport_origin(ScreenVector::new(40.0, 100.0));
+        let bounds = camera.visible_world_bounds(viewport);
+        assert!(approx_eq(bounds.x, -10.0), "x: got {}", bounds.x);
+        assert!(approx_eq(bounds.y, -20.0), "y: got {}", bounds.y);
+        assert!(approx_eq(bounds.width, 400.0), "w: got {}", bounds.width);
+        assert!(approx_eq(bounds.height, 300.0), "h: got {}", bounds.height);
+
+        // Zoomed out: the same viewport covers more world.
+        let far = Camera2D::with_zoom_and_position(0.5, WorldPoint::new(-100.0, 0.0));
+        let wide = far.visible_world_bounds(viewport);
+        assert!(approx_eq(wide.x, 100.0), "x: got {}", wide.x);
+        assert!(approx_eq(wide.width, 1600.0), "w: got {}", wide.width);
+        assert!(approx_eq(wide.height, 1200.0), "h: got {}", wide.height);
+
+        // Everything in the bounds lands inside the widget on screen.
+        let on_screen = camera.world_rect_to_screen(bounds);
+        assert!(approx_eq(on_screen.width, 800.0) && approx_eq(on_screen.height, 600.0));
+        assert!(approx_eq(on_screen.width, 800.0) && approx_eq(on_screen.height, 600.0));
+    }
 }
 
 /// Property tests generalizing the example-based invariants above over a
@@ -1,8 +1,11 @@
-//! Host-configurable, platform-aware keymap for graph-level actions.
+//! Host-configurable, platform-aware keymap for graph-level actions, plus the
+//! pointer cursors shown for each interaction state.
 //!
 //! This module is pure data plus a resolver: it decides which [`KeyAction`] a
 //! key press maps to, given the current [`Keymap`]. It performs no widget
 //! wiring; the widget owns the event loop and calls [`Keymap::key_action`].
+//! [`CursorConfig`] is likewise plain data the widget reads in
+//! `mouse_interaction`.
 
 use iced::keyboard::key::{Named, Physical};
 use iced::keyboard::{Key, Modifiers};
@@ -15,10 +18,28 @@ pub enum KeyAction {
     SelectAll,
     /// Clear the current selection.
     ClearSelection,
+    /// Select exactly the nodes that are not selected.
+    InvertSelection,
     /// Duplicate the selected nodes.
     CloneSelection,
     /// Remove the selected nodes (and their incident edges).
     DeleteSelection,
+    /// Select the next node in reading order (top to bottom, left to right).
+    SelectNext,
+    /// Select the previous node in reading order.
+    SelectPrevious,
+    /// Select the nearest node to the left of the current one.
+    SelectLeft,
+    /// Select the nearest node to the right of the current one.
+    SelectRight,
+    /// Select the nearest node above the current one.
+    SelectUp,
+    /// Select the nearest node below the current one.
+    SelectDown,
+    /// Wrap the selected nodes in a group.
+    GroupSelection,
+    /// Dissolve the groups around the selected nodes.
+    UngroupSelection,
 }
 
 /// The logical key half of a [`KeyCombo`].
@@ -124,11 +145,32 @@ pub struct Keymap {
     pub select_all: Option<KeyCombo>,
     /// Clears the current selection. `None` disables the shortcut.
     pub clear_selection: Option<KeyCombo>,
+    /// Inverts the current selection. `None` disables the shortcut.
+    pub invert_selection: Option<KeyCombo>,
     /// Duplicates the selected nodes. `None` disables the shortcut.
     pub clone_selection: Option<KeyCombo>,
     /// Removes the selected nodes. Any combo in this list triggers the
     /// action; an empty list disables the shortcut.
     pub delete_selection: Vec<KeyCombo>,
+    /// Selects the next node in reading order. `None` disables the shortcut.
+    pub select_next: Option<KeyCombo>,
+    /// Selects the previous node in reading order. `None` disables the
+    /// shortcut.
+    pub select_previous: Option<KeyCombo>,
+    /// Selects the nearest node to the left. `None` disables the shortcut.
+    pub select_left: Option<KeyCombo>,
+    /// Selects the nearest node to the right. `None` disables the shortcut.
+    pub select_right: Option<KeyCombo>,
+    /// Selects the nearest node above. `None` disables the shortcut.
+    pub select_up: Option<KeyCombo>,
+    /// Selects the nearest node below. `None` disables the shortcut.
+    pub select_down: Option<KeyCombo>,
+    /// Asks the host to group the selected nodes. `None` disables the
+    /// shortcut.
+    pub group_selection: Option<KeyCombo>,
+    /// Asks the host to ungroup the selected nodes. `None` disables the
+    /// shortcut.
+    pub ungroup_selection: Option<KeyCombo>,
     /// The pointer button that pans the graph.
     pub pan_button: mouse::Button,
     /// The modifier state that starts an edge-cutting drag.
@@ -146,6 +188,24 @@ pub struct Keymap {
     /// [`Keymap::edge_cut_modifiers`] when both could apply to the same
     /// chord.
     pub multi_select_modifiers: Modifiers,
+    /// The modifier state that turns a double-click on a node into selecting
+    /// its whole connected component.
+    ///
+    /// Tested with [`Modifiers::contains`]; a double-click without it stays
+    /// two plain clicks, so click-then-drag is never intercepted.
+    pub component_select_modifiers: Modifiers,
+    /// The modifier state that, held during a node or group drag, locks the
+    /// move to the axis it has travelled furthest along.
+    ///
+    /// Tested with [`Modifiers::contains`] while dragging, so it can be
+    /// pressed or released mid-drag. Empty modifiers disable the lock.
+    pub axis_lock_modifiers: Modifiers,
+    /// The modifier state that, held during a vertical wheel scroll, swaps
+    /// it between zooming and panning; see
+    /// [`NodeGraph::scroll_mode`](crate::NodeGraph::scroll_mode).
+    ///
+    /// Tested with [`Modifiers::contains`]. Empty modifiers disable the swap.
+    pub scroll_pan_modifiers: Modifiers,
 }
 
 impl Default for Keymap {
@@ -174,11 +234,26 @@ impl Default for Keymap {
         Self {
             select_all: Some(KeyCombo::command('a')),
             clear_selection: Some(KeyCombo::bare(ComboKey::Named(Named::Escape))),
+            invert_selection: Some(KeyCombo::command('i')),
             clone_selection,
             delete_selection,
+            select_next: Some(KeyCombo::bare(ComboKey::Named(Named::Tab))),
+            select_previous: Some(KeyCombo::new(ComboKey::Named(Named::Tab), Modifiers::SHIFT)),
+            select_left: Some(KeyCombo::bare(ComboKey::Named(Named::ArrowLeft))),
+            select_right: Some(KeyCombo::bare(ComboKey::Named(Named::ArrowRight))),
+            select_up: Some(KeyCombo::bare(ComboKey::Named(Named::ArrowUp))),
+            select_down: Some(KeyCombo::bare(ComboKey::Named(Named::ArrowDown))),
+            group_selection: Some(KeyCombo::command('g')),
+            ungroup_selection: Some(KeyCombo::new(
+                ComboKey::Char('g'),
+                Modifiers::COMMAND | Modifiers::SHIFT,
+            )),
             pan_button: mouse::Button::Right,
             edge_cut_modifiers: Modifiers::COMMAND,
             multi_select_modifiers: Modifiers::SHIFT,
+            component_select_modifiers: Modifiers::COMMAND,
+            axis_lock_modifiers: Modifiers::SHIFT,
+            scroll_pan_modifiers: Modifiers::SHIFT,
         }
     }
 }
@@ -193,8 +268,17 @@ impl Keymap {
         Self {
             select_all: None,
             clear_selection: None,
+            invert_selection: None,
             clone_selection: None,
             delete_selection: Vec::new(),
+            select_next: None,
+            select_previous: None,
+            select_left: None,
+            select_right: None,
+            select_up: None,
+            select_down: None,
+            group_selection: None,
+            ungroup_selection: None,
             ..Self::default()
         }
     }
@@ -202,8 +286,9 @@ impl Keymap {
     /// Resolves a key press to the [`KeyAction`] it triggers, if any.
     ///
     /// Checks bindings in field order (`select_all`, `clear_selection`,
-    /// `clone_selection`, then `delete_selection`) and returns the first
-    /// match.
+    /// `invert_selection`, `clone_selection`, `delete_selection`, the
+    /// navigation bindings, then `group_selection` and `ungroup_selection`)
+    /// and returns the first match.
     pub fn key_action(
         &self,
         key: &Key,
@@ -219,6 +304,9 @@ impl Keymap {
         if hit(self.clear_selection) {
             return Some(KeyAction::ClearSelection);
         }
+        if hit(self.invert_selection) {
+            return Some(KeyAction::InvertSelection);
+        }
         if hit(self.clone_selection) {
             return Some(KeyAction::CloneSelection);
         }
@@ -229,8 +317,63 @@ impl Keymap {
         {
             return Some(KeyAction::DeleteSelection);
         }
+        let bindings = [
+            (self.select_next, KeyAction::SelectNext),
+            (self.select_previous, KeyAction::SelectPrevious),
+            (self.select_left, KeyAction::SelectLeft),
+            (self.select_right, KeyAction::SelectRight),
+            (self.select_up, KeyAction::SelectUp),
+            (self.select_down, KeyAction::SelectDown),
+            (self.group_selection, KeyAction::GroupSelection),
+            (self.ungroup_selection, KeyAction::UngroupSelection),
+        ];
+        bindings
+            .into_iter()
+            .find(|(combo, _)| hit(*combo))
+            .map(|(_, action)| action)
+    }
+}
+
+/// The mouse cursor shown for each interaction state of the graph.
+///
+/// Hover states apply when no drag is active: content widgets inside a node
+/// (buttons, text inputs) report their own cursor first, then pins and node
+/// bodies, then the empty canvas. Drag states apply for the whole drag, even
+/// when the cursor leaves the widget. Hover cursors for pins and bodies only
+/// show when the interaction is wired (`on_connect` / `on_move`), so the
+/// cursor never promises a drag that would not happen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CursorConfig {
+    /// Over empty canvas.
+    pub canvas: mouse::Interaction,
+    /// Over a draggable node body or an edge handle.
+    pub node: mouse::Interaction,
+    /// While dragging one or more nodes or an edge handle.
+    pub node_dragging: mouse::Interaction,
+    /// Over a pin an edge can be dragged from.
+    pub pin: mouse::Interaction,
+    /// While dragging an edge (new connection or reroute).
+    pub edge_dragging: mouse::Interaction,
+    /// While panning the camera.
+    pub panning: mouse::Interaction,
+    /// While dragging a selection box.
+    pub box_select: mouse::Interaction,
+    /// While dragging the edge-cutting trail.
+    pub edge_cutting: mouse::Interaction,
+}
 
-        None
+impl Default for CursorConfig {
+    fn default() -> Self {
+        Self {
+            canvas: mouse::Interaction::default(),
+            node: mouse::Interaction::Grab,
+            node_dragging: mouse::Interaction::Grabbing,
+            pin: mouse::Interaction::Crosshair,
+            edge_dragging: mouse::Interaction::Crosshair,
+            panning: mouse::Interaction::Move,
+            box_select: mouse::Interaction::Crosshair,
+            edge_cutting: mouse::Interaction::Crosshair,
+        }
     }
 }
 
@@ -263,6 +406,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn group_and_ungroup_differ_by_shift() {
+        let keymap = Keymap::default();
+        let key = Key::Character("g".into());
+        let physical = Physical::Code(Code::KeyG);
+
+        assert_eq!(
+            keymap.key_action(&key, physical, Modifiers::COMMAND),
+            Some(KeyAction::GroupSelection)
+        );
+        assert_eq!(
+            keymap.key_action(&key, physical, Modifiers::COMMAND | Modifiers::SHIFT),
+            Some(KeyAction::UngroupSelection)
+        );
+    }
+
     #[test]
     fn exact_modifier_match_rejects_extra_modifiers() {
         let keymap = Keymap::default();
@@ -352,6 +511,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn shift_tab_selects_previous_and_tab_next() {
+        let keymap = Keymap::default();
+        let tab = Key::Named(Named::Tab);
+        let physical = Physical::Code(Code::Tab);
+
+        assert_eq!(
+            keymap.key_action(&tab, physical, Modifiers::empty()),
+            Some(KeyAction::SelectNext)
+        );
+        assert_eq!(
+            keymap.key_action(&tab, physical, Modifiers::SHIFT),
+            Some(KeyAction::SelectPrevious)
+        );
+        assert_eq!(
+            Keymap::none().key_action(&tab, physical, Modifiers::empty()),
+            None
+        );
+    }
+
     #[test]
     fn disabling_one_binding_leaves_the_others_working() {
         let keymap = Keymap {
@@ -371,4 +550,14 @@ mod tests {
             Some(KeyAction::ClearSelection)
         );
     }
+
+    #[test]
+    fn cursor_config_default_grabs_nodes_and_crosshairs_pins() {
+        let cursors = CursorConfig::default();
+        assert_eq!(cursors.canvas, mouse::Interaction::default());
+        assert_eq!(cursors.node, mouse::Interaction::Grab);
+        assert_eq!(cursors.node_dragging, mouse::Interaction::Grabbing);
+        assert_eq!(cursors.pin, mouse::Interaction::Crosshair);
+        assert_eq!(cursors.panning, mouse::Interaction::Move);
+    }
 }
@@ -22,6 +22,19 @@
 //! - [`PinRef`] - Type-safe reference to a pin (generic over ID types)
 //! - [`Camera2D`](camera::Camera2D) - Zoom and pan state management
 //!
+//! ## Node Identity
+//!
+//! Every node carries the host's own id (`N`), which is its key: edges,
+//! callbacks and [`NodeGraph::selection`] all name nodes by id, so there is
+//! no separate keyed push. The graph is rebuilt from scratch in each
+//! `view()`, and the widget state that outlives a rebuild (the hosted
+//! content's own widget state, selection, z-order, hover, an in-progress
+//! drag) follows each node by its id. Reordering or filtering nodes between
+//! frames is safe: a node keeps its state wherever it is pushed, a removed
+//! node's drag is cancelled, and a new node animates in. Push order is still
+//! the draw order among nodes of equal z. [`NodeGraph::clear`] empties a
+//! configured graph for reuse.
+//!
 //! ## Event Handling
 //!
 //! Interaction is reported through individual callbacks: `on_connect()`,
@@ -38,15 +51,22 @@
 //! - [`NodeGraph::graph_style`] / [`NodeGraph::dragging_edge_style`] - graph chrome
 
 use std::collections::{HashMap, HashSet};
-use std::fmt::Debug;
-use std::hash::Hash;
+use std::fmt::{self, Debug};
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
 use std::time::Duration;
 
-use iced::{Length, Point, Size, Vector};
+use iced::{Length, Point, Rectangle, Size, Vector};
 
 use crate::ids::{EdgeId, NodeId, PinId};
-use crate::node_pin::{PinEnd, PinInfo};
-use crate::style::{EdgeStatus, EdgeStyle, GraphStyle, NodeStatus, NodeStyle, PinStatus, PinStyle};
+use crate::node_pin::{PinDirection, PinEnd, PinInfo, PinSide};
+use crate::style::{
+    Badge, BoxSelectStyle, Decoration, EdgeCurve, EdgeStatus, EdgeStyle, GraphStyle, NodeStatus,
+    NodeStyle, PinStatus, PinStyle,
+};
+use crate::topology::Topology;
+use iced_nodegraph_sdf::Shape;
+use iced_wgpu::core::widget::Tree;
 
 /// Per-node style callback: theme + status -> resolved style. Used by [`Node`].
 pub(crate) type NodeStyleFn<'a, Theme> = Box<dyn Fn(&Theme, NodeStatus) -> NodeStyle + 'a>;
@@ -66,6 +86,17 @@ pub(crate) type PinStyleFn<'a, P, UI, Theme> = Box<
 /// freshly dragged edge has no status. Used by [`NodeGraph::dragging_edge_style`].
 pub(crate) type DragEdgeStyleFn<'a, P, UI, Theme> =
     Box<dyn Fn(&Theme, PinInfo<'_, P, UI>) -> EdgeStyle + 'a>;
+/// Per-node style override: node id + the node's resolved style -> the style
+/// drawn. Used by [`NodeGraph::node_style_override`].
+pub(crate) type NodeStyleOverrideFn<'a, N> = Box<dyn Fn(&N, NodeStyle) -> NodeStyle + 'a>;
+/// Per-pin style override: the pin's reference + its resolved style -> the
+/// style drawn. Used by [`NodeGraph::pin_style_override`].
+pub(crate) type PinStyleOverrideFn<'a, N, P> =
+    Box<dyn Fn(&PinRef<N, P>, PinStyle) -> PinStyle + 'a>;
+/// Per-edge style override: both endpoints as pushed + the edge's resolved
+/// style -> the style drawn. Used by [`NodeGraph::edge_style_override`].
+pub(crate) type EdgeStyleOverrideFn<'a, N, P> =
+    Box<dyn Fn(&PinRef<N, P>, &PinRef<N, P>, EdgeStyle) -> EdgeStyle + 'a>;
 
 /// A node to push onto the graph: id, position, content element, an optional
 /// per-node style closure, and an optional closure styling all of its pins.
@@ -78,6 +109,7 @@ pub struct Node<'a, N, P, UI, Message, Theme, Renderer> {
     element: iced::Element<'a, Message, Theme, Renderer>,
     style_fn: Option<NodeStyleFn<'a, Theme>>,
     pin_style_fn: Option<PinStyleFn<'a, P, UI, Theme>>,
+    transform: NodeTransform,
 }
 
 /// Creates a [`Node`] with default (theme) styling.
@@ -92,6 +124,7 @@ pub fn node<'a, N, P, UI, Message, Theme, Renderer>(
         element: element.into(),
         style_fn: None,
         pin_style_fn: None,
+        transform: NodeTransform::default(),
     }
 }
 
@@ -127,6 +160,67 @@ impl<'a, N, P, UI, Message, Theme, Renderer> Node<'a, N, P, UI, Message, Theme,
         self.pin_style_fn = Some(Box::new(f));
         self
     }
+
+    /// Rotates the node body clockwise by `radians` about its centre.
+    ///
+    /// The SDF body, its border and shadow, the pins, the edge anchors and
+    /// the status badge all turn with it, and clicks hit the rotated outline. Two things stay
+    /// upright: the hosted content, since iced can translate and scale a
+    /// widget but not rotate it, and the edge tangents, which still leave
+    /// each pin along its declared side's unrotated direction (a `Right` pin
+    /// sends its edge off to the right whatever the angle). Suits shapes
+    /// whose content reads fine level, such as a flowchart decision diamond
+    /// (`FRAC_PI_4` on a square node).
+    /// ```ignore
+    /// node(0, pos, label("yes?")).rotation(std::f32::consts::FRAC_PI_4)
+    /// ```
+    pub fn rotation(mut self, radians: f32) -> Self {
+        self.transform.rotation = radians;
+        self
+    }
+
+    /// Mirrors the node's pins left to right: `Left` pins render on the
+    /// right border and `Right` pins on the left, with their edges leaving
+    /// outward from the new side. Pin ids and indices are unchanged, and
+    /// hit tests follow the mirrored places. Top and bottom pins mirror
+    /// along their border. Lays out feedback loops without crossing edges.
+    /// The hosted content is not mirrored.
+    /// ```ignore
+    /// node(DELAY, pos, delay_view()).flip_horizontal(true)
+    /// ```
+    pub fn flip_horizontal(mut self, flipped: bool) -> Self {
+        self.transform.flip_horizontal = flipped;
+        self
+    }
+
+    /// Collapses the node to its title bar: the body is laid out only as
+    /// tall as the content's first row (the title), everything below is
+    /// clipped away, and side pins stack at the middle of that row with
+    /// their edges attached there. Pin ids and indices are unchanged, but
+    /// the stacked pins take no new drags or drops until the node expands
+    /// again. Unlike dropping rows from the content, the host keeps one view
+    /// and toggles a flag.
+    /// ```ignore
+    /// node(id, pos, mixer_view()).collapsed(state.is_collapsed(id))
+    /// ```
+    pub fn collapsed(mut self, collapsed: bool) -> Self {
+        self.transform.collapsed = collapsed;
+        self
+    }
+
+    /// Sets the outline of the node body; see [`NodeShape`].
+    ///
+    /// The fill, border, shadow and clicks follow the outline, and the
+    /// hosted content is clipped to the largest upright rectangle inside it.
+    /// Pins move onto the outline along their side's axis and their edges
+    /// attach there. Suits reroute dots and compact operator nodes.
+    /// ```ignore
+    /// node(DOT, pos, space()).shape(NodeShape::Circle)
+    /// ```
+    pub fn shape(mut self, shape: NodeShape) -> Self {
+        self.transform.shape = shape;
+        self
+    }
 }
 
 /// An edge to push onto the graph: a user id, endpoint pin references, and an
@@ -186,6 +280,14 @@ impl<'a, N, P, E, UI, Theme> Edge<'a, N, P, E, UI, Theme> {
     }
 }
 
+/// A bare `(from, to)` pair is an unstyled edge without an id, as `edge!`
+/// builds it; lets [`NodeGraph::push_edges`] take plain pin pairs.
+impl<'a, N, P, UI, Theme> From<(PinRef<N, P>, PinRef<N, P>)> for Edge<'a, N, P, (), UI, Theme> {
+    fn from((from, to): (PinRef<N, P>, PinRef<N, P>)) -> Self {
+        edge(from, to, ())
+    }
+}
+
 pub mod camera;
 pub(crate) mod euclid;
 pub(crate) mod input;
@@ -202,6 +304,10 @@ pub(crate) struct RenderContext {
     /// graph is not at the window origin (e.g. below a toolbar).
     pub viewport_origin: euclid::ScreenVector,
     pub time: f32,
+    /// Antialiasing width for every SDF layer, in physical pixels.
+    pub aa_width: f32,
+    /// Whether every SDF layer snaps to the pixel grid.
+    pub pixel_snap: bool,
 }
 
 /// Counts for one element kind in a frame: how many exist, how many are in view,
@@ -250,24 +356,481 @@ pub struct GraphInfo {
     pub sdf_tiles: u32,
 }
 
+/// Where a graph's nodes and edges land on screen for a given viewport and
+/// camera, computed on the CPU by [`NodeGraph::layout_snapshot`].
+///
+/// Coordinates are screen pixels relative to the widget's top-left, projected
+/// exactly as the renderer does: `(world + camera_position) * zoom`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayoutSnapshot<N = usize, P = usize> {
+    /// Screen rectangle of each node's body, in push order.
+    pub nodes: Vec<(N, iced::Rectangle)>,
+    /// Screen endpoints of each edge whose pins resolve, in push order.
+    pub edges: Vec<EdgeSnapshot<N, P>>,
+}
+
+impl<N: PartialEq, P> LayoutSnapshot<N, P> {
+    /// Screen rectangle of the node `id`, if it was pushed.
+    pub fn node(&self, id: &N) -> Option<iced::Rectangle> {
+        self.nodes
+            .iter()
+            .find(|(node_id, _)| node_id == id)
+            .map(|(_, bounds)| *bounds)
+    }
+}
+
+/// One edge of a [`LayoutSnapshot`]: its pins as pushed and where each pin's
+/// anchor lands on screen (on the facing border under
+/// [`auto_pin_side`](NodeGraph::auto_pin_side)).
+#[derive(Debug, Clone, PartialEq)]
+pub struct EdgeSnapshot<N = usize, P = usize> {
+    /// The edge's `from` pin.
+    pub from: PinRef<N, P>,
+    /// The edge's `to` pin.
+    pub to: PinRef<N, P>,
+    /// Screen anchor of `from`.
+    pub start: Point,
+    /// Screen anchor of `to`.
+    pub end: Point,
+}
+
 /// Identifies what an in-progress drag is moving. Delivered to the
 /// [`on_drag_start`](NodeGraph::on_drag_start) callback so the app can observe a
-/// drag live (e.g. to broadcast it), alongside the commit-on-drop callbacks.
+/// drag live (e.g. to broadcast it), alongside the commit-on-drop callbacks, and
+/// with every cursor move to [`on_drag`](NodeGraph::on_drag).
 ///
 /// Ids are the user's own node/pin id types (`N`/`P`), matching the rest of the
 /// callback API (e.g. [`PinRef`]); both default to `usize`.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum DragInfo<N = usize, P = usize> {
     /// Dragging a single node.
     Node { node_id: N },
-    /// Dragging a group of selected nodes.
+    /// Dragging a group of selected nodes (the whole selection moves).
     Group { node_ids: Vec<N> },
-    /// Dragging an edge from a pin (the source node and pin).
+    /// Dragging an edge from a pin (the source node and pin). Also reported
+    /// for reroutes: grabbing one end of a connected edge drags from the end
+    /// that stays put, so `from_*` names the kept end.
     Edge { from_node: N, from_pin: P },
     /// Box selection drag, anchored at this world-space corner.
     BoxSelect { start_x: f32, start_y: f32 },
 }
 
+/// Progress of an in-progress drag, delivered with its [`DragInfo`] to
+/// [`on_drag`](NodeGraph::on_drag).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DragProgress {
+    /// World-space cursor position where the drag began.
+    pub start: Point,
+    /// World-space cursor travel since `start`.
+    pub delta: Vector,
+    /// Keyboard modifiers held at this point of the drag.
+    pub modifiers: iced::keyboard::Modifiers,
+}
+
+/// Whether the user is wiring an edge, reported to
+/// [`on_connection_state_change`](NodeGraph::on_connection_state_change)
+/// whenever it changes, e.g. to disable other UI while a connection is made.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConnectionState<N = usize, P = usize> {
+    /// No edge is being dragged.
+    Idle,
+    /// A new edge is being dragged (or armed by a click) from this pin.
+    Connecting { from: PinRef<N, P> },
+    /// An existing edge was pulled off `detached` and is being dragged from
+    /// its kept end `from`.
+    Rerouting {
+        from: PinRef<N, P>,
+        detached: PinRef<N, P>,
+    },
+}
+
+/// How pin presses start and finish an edge; see
+/// [`connect_mode`](NodeGraph::connect_mode).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConnectMode {
+    /// Press a pin, drag, release on the target.
+    #[default]
+    Drag,
+    /// Click a pin, move to the target, click again. Escape or a click away
+    /// from a valid target cancels.
+    ClickClick,
+    /// Dragging works as in `Drag`; a press released without moving arms
+    /// the edge as in `ClickClick`.
+    Both,
+}
+
+/// What a left-button drag on empty canvas does; see
+/// [`background_click`](NodeGraph::background_click).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BackgroundClick {
+    /// Drag a selection box; the multi-select modifier keeps the current
+    /// selection.
+    #[default]
+    BoxSelect,
+    /// Pan the camera, as the keymap's pan button does.
+    Pan,
+    /// Leave the selection and camera alone and report the press through
+    /// [`on_background_click`](NodeGraph::on_background_click).
+    Custom,
+}
+
+/// What the vertical mouse wheel does; see
+/// [`scroll_mode`](NodeGraph::scroll_mode).
+///
+/// Horizontal scroll always pans. Holding the keymap's
+/// [`scroll_pan_modifiers`](crate::Keymap::scroll_pan_modifiers) swaps the
+/// vertical wheel to the other mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScrollMode {
+    /// Zoom about the cursor.
+    #[default]
+    Zoom,
+    /// Pan the camera vertically, as trackpads and spreadsheets do.
+    Pan,
+}
+
+/// Which pin indicators are drawn; see
+/// [`pin_visibility`](NodeGraph::pin_visibility).
+///
+/// Hidden pins only skip drawing: they still accept presses and drops, and
+/// edges keep attaching to them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PinVisibility {
+    /// Every pin is drawn.
+    #[default]
+    Always,
+    /// Pins are drawn on the node under the cursor and on selected nodes.
+    OnHover,
+    /// Only pins with at least one edge are drawn.
+    Connected,
+}
+
+/// Which nodes a box select picks up; see
+/// [`box_select_rule`](NodeGraph::box_select_rule).
+///
+/// Both rules test the box against each node's bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SelectRule {
+    /// Every node the box touches.
+    #[default]
+    Intersect,
+    /// Only nodes lying entirely inside the box.
+    Contain,
+    /// Chosen by drag direction, as in CAD tools: dragged left to right the
+    /// box acts as [`Contain`](Self::Contain), right to left as
+    /// [`Intersect`](Self::Intersect).
+    ByDirection,
+}
+
+/// Where a screen-fixed node sits within the graph widget; see
+/// [`push_node_screen_fixed`](NodeGraph::push_node_screen_fixed).
+///
+/// Each variant names the widget corner the node is aligned to and carries
+/// the margin (in screen pixels) between that corner and the node.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScreenAnchor {
+    /// Node's top-left corner at this margin from the widget's top-left.
+    TopLeft(Vector),
+    /// Node's top-right corner at this margin from the widget's top-right.
+    TopRight(Vector),
+    /// Node's bottom-left corner at this margin from the widget's bottom-left.
+    BottomLeft(Vector),
+    /// Node's bottom-right corner at this margin from the widget's
+    /// bottom-right.
+    BottomRight(Vector),
+}
+
+/// Outline of a node body; see [`Node::shape`].
+///
+/// Every shape fills the node's laid-out box, so the content still sizes
+/// the node. Pins slide along their side's axis onto the outline: a `Left`
+/// or `Right` pin keeps its height and moves in to where the outline crosses
+/// it, a `Top` or `Bottom` pin keeps its horizontal place. Edges still leave
+/// in the pin side's direction.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NodeShape {
+    /// Sharp-cornered rectangle; ignores
+    /// [`NodeStyle::corner_radius`](crate::NodeStyle::corner_radius).
+    Rect,
+    /// Rectangle with [`NodeStyle::corner_radius`](crate::NodeStyle::corner_radius)
+    /// corners. Pins sit on the straight borders, as they always have.
+    #[default]
+    RoundedRect,
+    /// Circle as wide as the shorter side of the box, centred in it. Side
+    /// pins further from the middle than the radius land on its top or
+    /// bottom.
+    Circle,
+    /// Flat-topped hexagon filling the box, pointed at the middle of the
+    /// left and right sides. Side pins move in along the slanted edges.
+    Hexagon,
+}
+
+/// Node-count threshold above which the graph trades detail for speed; see
+/// [`performance_hint`](NodeGraph::performance_hint).
+///
+/// Above `node_threshold` nodes, the graph draws in low detail whenever the
+/// zoom is below `lod_zoom`: node and edge shadows are skipped, and pins are
+/// neither drawn nor cut out of the node bodies. Edge strokes, node bodies,
+/// borders and content stay. Off-screen culling is always on and does not
+/// depend on the hint.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PerformanceHint {
+    /// Node count the graph may hold before degrading. Default 1000.
+    pub node_threshold: usize,
+    /// Zoom below which a degraded graph draws in low detail. Default 0.5;
+    /// `f32::INFINITY` keeps it in low detail at every zoom.
+    pub lod_zoom: f32,
+}
+
+impl PerformanceHint {
+    /// A hint degrading above `node_threshold` nodes, with the default
+    /// `lod_zoom`.
+    pub fn new(node_threshold: usize) -> Self {
+        Self {
+            node_threshold,
+            ..Self::default()
+        }
+    }
+}
+
+impl Default for PerformanceHint {
+    fn default() -> Self {
+        Self {
+            node_threshold: 1000,
+            lod_zoom: 0.5,
+        }
+    }
+}
+
+/// Whether the platform asks for reduced motion.
+///
+/// On `wasm32` this reads the browser's `prefers-reduced-motion` media query.
+/// Native platforms expose no such setting to the widget, so it returns
+/// `false` there; hosts that know the preference pass it to
+/// [`reduced_motion`](NodeGraph::reduced_motion) themselves.
+pub fn prefers_reduced_motion() -> bool {
+    #[cfg(target_arch = "wasm32")]
+    {
+        web_sys::window()
+            .and_then(|window| {
+                window
+                    .match_media("(prefers-reduced-motion: reduce)")
+                    .ok()
+                    .flatten()
+            })
+            .is_some_and(|query| query.matches())
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        false
+    }
+}
+
+/// Waypoints and curve of an edge pushed via
+/// [`push_edge_via`](NodeGraph::push_edge_via).
+#[derive(Debug, Clone)]
+pub(crate) struct EdgeRoute {
+    /// World-space points between the pins, in `from -> to` order.
+    pub waypoints: Vec<Point>,
+    /// Curve through the waypoints; overrides the style's curve.
+    pub curve: EdgeCurve,
+}
+
+/// How a node's body and pins are turned about its centre, whether it is
+/// folded to its title and the outline it draws; set by
+/// [`Node::rotation`], [`Node::flip_horizontal`], [`Node::collapsed`] and
+/// [`Node::shape`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct NodeTransform {
+    /// Clockwise body rotation in radians.
+    pub rotation: f32,
+    /// Pins mirrored left to right (applied before the rotation).
+    pub flip_horizontal: bool,
+    /// Laid out at title height with side pins stacked on the title row.
+    pub collapsed: bool,
+    /// Outline of the body within its laid-out box.
+    pub shape: NodeShape,
+}
+
+impl NodeShape {
+    /// Distance from the centre of a `size` box to the outline, measured
+    /// horizontally at `dy` below the centre (above for negative `dy`).
+    pub(crate) fn half_width_at(self, size: Size, dy: f32) -> f32 {
+        let (w, h) = (size.width * 0.5, size.height * 0.5);
+        match self {
+            NodeShape::Rect | NodeShape::RoundedRect => w,
+            NodeShape::Circle => {
+                let r = w.min(h);
+                (r * r - dy * dy).max(0.0).sqrt()
+            }
+            NodeShape::Hexagon => {
+                let inset = Shape::hexagon_inset([size.width, size.height]);
+                let t = if h > 0.0 {
+                    (dy.abs() / h).min(1.0)
+                } else {
+                    1.0
+                };
+                w - inset * t
+            }
+        }
+    }
+
+    /// Distance from the centre of a `size` box to the outline, measured
+    /// vertically at `dx` right of the centre (left for negative `dx`).
+    pub(crate) fn half_height_at(self, size: Size, dx: f32) -> f32 {
+        let (w, h) = (size.width * 0.5, size.height * 0.5);
+        match self {
+            NodeShape::Rect | NodeShape::RoundedRect => h,
+            NodeShape::Circle => {
+                let r = w.min(h);
+                (r * r - dx * dx).max(0.0).sqrt()
+            }
+            NodeShape::Hexagon => {
+                let inset = Shape::hexagon_inset([size.width, size.height]);
+                let flat = w - inset;
+                if dx.abs() <= flat || inset <= 0.0 {
+                    h
+                } else {
+                    (h * (w - dx.abs()) / inset).max(0.0)
+                }
+            }
+        }
+    }
+
+    /// Whether `point` lies inside the outline of a body laid out at
+    /// `bounds`.
+    pub(crate) fn contains(self, bounds: Rectangle, point: Point) -> bool {
+        let center = bounds.center();
+        let (dx, dy) = (point.x - center.x, point.y - center.y);
+        dy.abs() <= self.half_height_at(bounds.size(), 0.0)
+            && dx.abs() <= self.half_width_at(bounds.size(), dy)
+    }
+
+    /// Where a pin laid out at `point` on `side` of a body at `bounds`
+    /// meets the outline: side pins slide horizontally, top and bottom pins
+    /// vertically. A pin past the outline's extent stops at its tip. Pins of
+    /// rectangular bodies stay where they are laid out.
+    pub(crate) fn attach(self, point: Point, side: PinSide, bounds: Rectangle) -> Point {
+        if matches!(self, NodeShape::Rect | NodeShape::RoundedRect) {
+            return point;
+        }
+        let center = bounds.center();
+        let size = bounds.size();
+        match side {
+            PinSide::Left | PinSide::Right | PinSide::Row => {
+                let reach = self.half_height_at(size, 0.0);
+                let y = point.y.clamp(center.y - reach, center.y + reach);
+                let half = self.half_width_at(size, y - center.y);
+                let x = if point.x < center.x {
+                    center.x - half
+                } else {
+                    center.x + half
+                };
+                Point::new(x, y)
+            }
+            PinSide::Top | PinSide::Bottom => {
+                let reach = self.half_width_at(size, 0.0);
+                let x = point.x.clamp(center.x - reach, center.x + reach);
+                let half = self.half_height_at(size, x - center.x);
+                let y = if point.y < center.y {
+                    center.y - half
+                } else {
+                    center.y + half
+                };
+                Point::new(x, y)
+            }
+        }
+    }
+
+    /// The largest upright rectangle inside the outline of a body at
+    /// `bounds`, which node content is clipped to.
+    pub(crate) fn content_bounds(self, bounds: Rectangle) -> Rectangle {
+        let center = bounds.center();
+        let size = bounds.size();
+        let (w, h) = match self {
+            NodeShape::Rect | NodeShape::RoundedRect => return bounds,
+            // The inscribed square.
+            NodeShape::Circle => {
+                let side = size.width.min(size.height) * std::f32::consts::FRAC_1_SQRT_2;
+                (side, side)
+            }
+            // Between the slanted edges at full height.
+            NodeShape::Hexagon => (
+                size.width - 2.0 * Shape::hexagon_inset([size.width, size.height]),
+                size.height,
+            ),
+        };
+        Rectangle::new(
+            Point::new(center.x - w * 0.5, center.y - h * 0.5),
+            Size::new(w, h),
+        )
+    }
+}
+
+impl NodeTransform {
+    /// The side a pin declared on `side` renders on, which sets the
+    /// direction its edges leave in.
+    pub(crate) fn side(&self, side: PinSide) -> PinSide {
+        match (self.flip_horizontal, side) {
+            (true, PinSide::Left) => PinSide::Right,
+            (true, PinSide::Right | PinSide::Row) => PinSide::Left,
+            (_, side) => side,
+        }
+    }
+}
+
+impl ScreenAnchor {
+    /// Top-left of a node of `node` screen size, relative to a widget of
+    /// `bounds` size.
+    pub(crate) fn resolve(&self, bounds: Size, node: Size) -> Point {
+        let far = |extent: f32, size: f32, margin: f32| extent - size - margin;
+        match *self {
+            Self::TopLeft(m) => Point::new(m.x, m.y),
+            Self::TopRight(m) => Point::new(far(bounds.width, node.width, m.x), m.y),
+            Self::BottomLeft(m) => Point::new(m.x, far(bounds.height, node.height, m.y)),
+            Self::BottomRight(m) => Point::new(
+                far(bounds.width, node.width, m.x),
+                far(bounds.height, node.height, m.y),
+            ),
+        }
+    }
+}
+
+/// A discrete interaction reported through the unified
+/// [`on_event`](NodeGraph::on_event) callback.
+///
+/// Each variant carries exactly what the matching granular callback receives:
+/// `Connect`/`Disconnect` mirror [`on_connect`](NodeGraph::on_connect) /
+/// [`on_disconnect`](NodeGraph::on_disconnect) (output-first), `Move` mirrors
+/// [`on_move`](NodeGraph::on_move), and so on. Continuous streams (pan, drag
+/// hooks, diagnostics) keep their dedicated callbacks.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NodeGraphEvent<N = usize, P = usize> {
+    /// An edge snapped between two pins (`from` = output, `to` = input).
+    Connect {
+        from: PinRef<N, P>,
+        to: PinRef<N, P>,
+    },
+    /// An edge was cut or unplugged (`from` = output, `to` = input).
+    Disconnect {
+        from: PinRef<N, P>,
+        to: PinRef<N, P>,
+    },
+    /// Nodes were dropped after a drag; apply `delta` (world units) to each.
+    Move { delta: Vector, node_ids: Vec<N> },
+    /// The selection changed to exactly these nodes.
+    Select { node_ids: Vec<N> },
+    /// The user asked to clone these nodes.
+    Clone { node_ids: Vec<N> },
+    /// The user asked to delete these nodes.
+    Delete { node_ids: Vec<N> },
+    /// The user asked to group these nodes; `bounds` is their world-space
+    /// bounding box.
+    GroupCreate { node_ids: Vec<N>, bounds: Rectangle },
+    /// The user asked to ungroup these nodes.
+    Ungroup { node_ids: Vec<N> },
+}
+
 /// Type-safe reference to a pin: a `node_id` paired with a `pin_id`, generic over
 /// your id types.
 ///
@@ -276,7 +839,15 @@ pub enum DragInfo<N = usize, P = usize> {
 /// whether two pins may actually connect is decided elsewhere (e.g. via
 /// [`can_connect`](NodeGraph::can_connect)). Build it with a struct literal or
 /// [`PinRef::new`], and match or destructure it freely.
+///
+/// `pin_id` is the id given to the pin widget, not a position: pin ids only
+/// need to be unique within their node. The widget's own positional
+/// numbering (declaration order) is internal; query a node's pins with
+/// [`NodeGraph::pin_count`] and [`NodeGraph::pin_direction`].
+/// [`NodeGraph::try_push_edge`] checks that both ends name a declared pin
+/// and reports a mismatch as an [`EdgeError`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PinRef<N, P> {
     /// The node's user id.
     pub node_id: N,
@@ -291,6 +862,54 @@ impl<N: Clone, P: Clone> PinRef<N, P> {
     }
 }
 
+/// A connection as reported to [`on_connect_with`](NodeGraph::on_connect_with):
+/// both ends with their direction and pin payload, normalized output-first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Connection<N = usize, P = usize, UI = ()> {
+    /// The output end (the edge's start).
+    pub from: PinRef<N, P>,
+    /// Direction of the `from` pin: `Output`, or `Both`.
+    pub from_direction: PinDirection,
+    /// The `from` pin's payload (see [`NodePin::info`](crate::NodePin::info)).
+    pub from_info: UI,
+    /// The input end (the edge's end).
+    pub to: PinRef<N, P>,
+    /// Direction of the `to` pin: `Input`, or `Both`.
+    pub to_direction: PinDirection,
+    /// The `to` pin's payload.
+    pub to_info: UI,
+}
+
+/// Why [`NodeGraph::try_push_edge`] rejected an edge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EdgeError<N, P> {
+    /// An end names a node that was not pushed (yet), e.g. one deleted since
+    /// the edge list was built.
+    UnknownNode(N),
+    /// An end names a pin its node does not declare.
+    UnknownPin(PinRef<N, P>),
+}
+
+impl<N: Debug, P: Debug> fmt::Display for EdgeError<N, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownNode(node_id) => {
+                write!(
+                    f,
+                    "edge references node {node_id:?}, which was never pushed"
+                )
+            }
+            Self::UnknownPin(pin) => write!(
+                f,
+                "edge references pin {:?} on node {:?}, which declares no such pin",
+                pin.pin_id, pin.node_id
+            ),
+        }
+    }
+}
+
+impl<N: Debug, P: Debug> std::error::Error for EdgeError<N, P> {}
+
 /// Node graph widget with generic ID types.
 ///
 /// # Type Parameters
@@ -318,9 +937,9 @@ pub struct NodeGraph<
     E: EdgeId,
 {
     pub(super) size: Size<Length>,
-    /// Nodes with position, element, and config overrides.
-    /// Config fields set to Some() override theme defaults.
-    /// None fields use `default_node_style()` values at render time.
+    /// Nodes with position, element, and their own node and pin style
+    /// closures. A `None` closure falls back to the graph-wide `node_style` /
+    /// `pin_style`, then to the theme default, at render time.
     pub(super) nodes: Vec<(
         N,
         Point,
@@ -333,8 +952,8 @@ pub struct NodeGraph<
     node_lookup: HashMap<N, usize>,
     /// Edges with user-defined pin references and config overrides.
     /// Pin IDs are resolved to local indices at render time.
-    /// Config fields set to Some() override theme defaults.
-    /// None fields use `default_edge_style()` values at render time.
+    /// A `None` style closure falls back to the graph-wide `edge_style`, then
+    /// to `default_edge_style()`, at render time.
     pub(super) edges: Vec<(
         E,
         PinRef<N, P>,
@@ -342,12 +961,29 @@ pub struct NodeGraph<
         Option<EdgeStyleFn<'a, P, UI, Theme>>,
     )>,
     graph_style: Option<Box<dyn Fn(&Theme) -> GraphStyle + 'a>>,
-    on_connect: Option<Box<dyn Fn(PinRef<N, P>, PinRef<N, P>) -> Message + 'a>>,
+    /// Whether the canvas color comes from the theme when no `graph_style`
+    /// is set.
+    pub(super) background_follows_theme: bool,
+    /// Graph-wide style for nodes, pins and edges pushed without their own.
+    pub(super) node_style: Option<NodeStyleFn<'a, Theme>>,
+    pub(super) pin_style: Option<PinStyleFn<'a, P, UI, Theme>>,
+    pub(super) edge_style: Option<EdgeStyleFn<'a, P, UI, Theme>>,
+    /// Per-id adjustments applied over each element's resolved style.
+    pub(super) node_style_override: Option<NodeStyleOverrideFn<'a, N>>,
+    pub(super) pin_style_override: Option<PinStyleOverrideFn<'a, N, P>>,
+    pub(super) edge_style_override: Option<EdgeStyleOverrideFn<'a, N, P>>,
+    on_connect: Option<Box<dyn Fn(Connection<N, P, UI>) -> Message + 'a>>,
     on_disconnect: Option<Box<dyn Fn(PinRef<N, P>, PinRef<N, P>) -> Message + 'a>>,
     on_move: Option<Box<dyn Fn(Vector, Vec<N>) -> Message + 'a>>,
     on_select: Option<Box<dyn Fn(Vec<N>) -> Message + 'a>>,
     on_clone: Option<Box<dyn Fn(Vec<N>) -> Message + 'a>>,
     on_delete: Option<Box<dyn Fn(Vec<N>) -> Message + 'a>>,
+    on_group_create: Option<Box<dyn Fn(Vec<N>, Rectangle) -> Message + 'a>>,
+    on_ungroup: Option<Box<dyn Fn(Vec<N>) -> Message + 'a>>,
+    on_background_click: Option<Box<dyn Fn(Point) -> Message + 'a>>,
+    on_canvas_double_click: Option<Box<dyn Fn(Point) -> Message + 'a>>,
+    on_edge_split: Option<Box<dyn Fn((PinRef<N, P>, PinRef<N, P>), N) -> Message + 'a>>,
+    on_edge_reshape: Option<Box<dyn Fn((PinRef<N, P>, PinRef<N, P>), Vec<Point>) -> Message + 'a>>,
     /// External selection using internal indices.
     /// Populated by `selection()` method which converts user IDs to indices.
     external_selection: Option<HashSet<usize>>,
@@ -358,26 +994,66 @@ pub struct NodeGraph<
     on_drag_start: Option<Box<dyn Fn(DragInfo<N, P>) -> Message + 'a>>,
     on_drag_update: Option<Box<dyn Fn(Point) -> Message + 'a>>,
     on_drag_end: Option<Box<dyn Fn() -> Message + 'a>>,
+    on_drag: Option<Box<dyn Fn(DragInfo<N, P>, DragProgress) -> Message + 'a>>,
+    on_connection_state_change: Option<Box<dyn Fn(ConnectionState<N, P>) -> Message + 'a>>,
     /// Commit callback for pan/zoom: fires with the new camera (position, zoom)
     /// when the user finishes a pan drag or zooms. The host stores it and feeds
     /// it back via `view()`, mirroring `on_move` / `selection`.
     on_pan: Option<Box<dyn Fn(Point, f32) -> Message + 'a>>,
+    on_zoom_change: Option<Box<dyn Fn(f32) -> Message + 'a>>,
+    on_viewport_change: Option<Box<dyn Fn(Rectangle) -> Message + 'a>>,
     /// Per-frame diagnostics callback (element counts + CPU op timings).
     on_info: Option<Box<dyn Fn(GraphInfo) -> Message + 'a>>,
+    on_node_resized: Option<Box<dyn Fn(N, Size) -> Message + 'a>>,
+    on_visible_nodes: Option<Box<dyn Fn(Vec<(N, Rectangle)>) -> Message + 'a>>,
+    on_edge_hover: Option<Box<dyn Fn(Option<(PinRef<N, P>, PinRef<N, P>)>) -> Message + 'a>>,
+    on_graph_changed: Option<Box<dyn Fn(&Topology<N, P>) -> Message + 'a>>,
+    /// Fires once with the node count when it first exceeds the
+    /// performance hint's threshold.
+    on_performance_warning: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    /// Fires once with the nodes' world bounds after their first layout.
+    on_first_layout: Option<Box<dyn Fn(Rectangle) -> Message + 'a>>,
     /// Style callback for box selection overlay.
     /// Returns (fill_color, border_color).
-    pub(super) box_select_style_fn: Option<Box<dyn Fn(&Theme) -> (iced::Color, iced::Color) + 'a>>,
+    pub(super) box_select_style_fn: Option<Box<dyn Fn(&Theme) -> BoxSelectStyle + 'a>>,
     /// Style callback for edge cutting tool overlay.
     /// Returns the line color.
     pub(super) cutting_tool_style_fn: Option<Box<dyn Fn(&Theme) -> iced::Color + 'a>>,
     /// Style for the edge being dragged (theme -> resolved style). The graph
     /// injects the source pin's color for inheriting (TRANSPARENT) stroke ends.
     pub(super) dragging_edge_style_fn: Option<DragEdgeStyleFn<'a, P, UI, Theme>>,
+    /// Whether the drag preview takes its curve from the graph-wide edge style.
+    pub(super) preview_matches_default: bool,
     /// Host-controlled camera (world position + zoom). The widget syncs its
     /// internal camera to this whenever the host changes it, while still running
     /// pan/zoom interaction internally and committing via `on_pan`. Mirrors the
     /// `selection()` / `on_select` controlled pattern.
     pub(super) view: Option<(Point, f32)>,
+    /// Camera the widget state starts with when no `view()` is set; see
+    /// [`initial_camera`](Self::initial_camera).
+    pub(super) initial_camera: Option<(Point, f32)>,
+    /// Rubber-band zoom: overshoot the zoom limits and bounce back instead of
+    /// hard-clamping. Off by default.
+    pub(super) zoom_elastic: bool,
+    /// Pan the camera while a node or selection drag holds the cursor near
+    /// the widget edge. Off by default.
+    pub(super) edge_autopan: bool,
+    /// Whether time-driven visuals (flowing patterns, node appear) animate.
+    /// On by default.
+    pub(super) animations_enabled: bool,
+    /// Reduced-motion accessibility mode: no decorative animation and no
+    /// camera tweens. Defaults to [`prefers_reduced_motion`].
+    pub(super) reduced_motion: bool,
+    /// High-contrast accessibility mode over resolved styles. Off by default.
+    pub(super) high_contrast: bool,
+    /// Length of the fade/scale-in of newly pushed nodes; none by default.
+    pub(super) node_appear_animation: Option<Duration>,
+    /// Node-count threshold for low-detail drawing; none by default.
+    pub(super) performance_hint: Option<PerformanceHint>,
+    /// Antialiasing width of every SDF layer, in physical pixels.
+    pub(super) aa_width: f32,
+    /// Whether SDF layers snap to the pixel grid. Off by default.
+    pub(super) pixel_snap: bool,
     /// Custom validation callback for pin connection compatibility.
     /// When set, it is authoritative in `compute_valid_targets` (the built-in
     /// direction check only applies as the default when this is unset).
@@ -386,6 +1062,63 @@ pub struct NodeGraph<
     /// Key and pointer bindings; platform defaults unless overridden via
     /// [`keymap`](Self::keymap).
     pub(super) keymap: input::Keymap,
+    /// Cursor shown per interaction state; see [`cursors`](Self::cursors).
+    pub(super) cursors: input::CursorConfig,
+    /// Whether edges are made by dragging, clicking both pins, or either.
+    pub(super) connect_mode: ConnectMode,
+    /// What a left drag on empty canvas starts.
+    pub(super) background_click: BackgroundClick,
+    /// What the vertical wheel does.
+    pub(super) scroll_mode: ScrollMode,
+    /// Which pin indicators are drawn.
+    pub(super) pin_visibility: PinVisibility,
+    /// Which nodes a box select picks up.
+    pub(super) box_select_rule: SelectRule,
+    /// Whether reported connections are oriented output -> input instead of
+    /// in drag order.
+    pub(super) normalize_connections: bool,
+    /// Whether dropping a node onto an edge reports an `on_edge_split`.
+    pub(super) edge_splitting: bool,
+    /// Whether a dropped node is pushed out of the nodes it overlaps.
+    pub(super) prevent_overlap: bool,
+    /// Whether edges show draggable waypoint handles reported through
+    /// `on_edge_reshape`.
+    pub(super) editable_edges: bool,
+    /// Whether edge ends leave from the pin border facing the other node.
+    pub(super) auto_pin_side: bool,
+    /// Whether pins with several edges show how many.
+    pub(super) show_pin_connection_counts: bool,
+    /// How strongly edges between the same node clusters are pulled onto a
+    /// shared path; 0 disables bundling.
+    pub(super) edge_bundling: f32,
+    /// Edge count above which every edge draws as a thin straight line.
+    pub(super) edge_simplify_threshold: Option<usize>,
+    /// Whether the graph reacts to mouse, touch and keyboard input.
+    pub(super) input_enabled: bool,
+    /// Whether the select-all / clear / invert selection and group / ungroup
+    /// shortcuts are handled.
+    pub(super) standard_shortcuts: bool,
+    /// Per-node status badges keyed by internal index; set via
+    /// [`node_badges`](Self::node_badges).
+    pub(super) badges: HashMap<usize, Badge>,
+    /// Per-node halos keyed by internal index; set via
+    /// [`node_decorations`](Self::node_decorations).
+    pub(super) decorations: HashMap<usize, Decoration>,
+    /// Per-node opacity multipliers keyed by internal index; set via
+    /// [`node_opacities`](Self::node_opacities).
+    pub(super) opacities: HashMap<usize, f32>,
+    /// Screen anchors of nodes pushed via
+    /// [`push_node_screen_fixed`](Self::push_node_screen_fixed), keyed by
+    /// internal index. Layout places these from the camera instead of their
+    /// stored position.
+    pub(super) screen_fixed: HashMap<usize, ScreenAnchor>,
+    /// Routes of edges pushed via [`push_edge_via`](Self::push_edge_via),
+    /// keyed by edge index.
+    pub(super) edge_routes: HashMap<usize, EdgeRoute>,
+    /// Rotation, mirroring and outline of nodes pushed with
+    /// [`Node::rotation`], [`Node::flip_horizontal`] or [`Node::shape`],
+    /// keyed by internal index. Untransformed nodes have no entry.
+    pub(super) transforms: HashMap<usize, NodeTransform>,
 }
 
 impl<N, P, E, UI, Message, Theme, Renderer> Default
@@ -403,24 +1136,80 @@ where
             node_lookup: HashMap::new(),
             edges: Vec::new(),
             graph_style: None,
+            background_follows_theme: true,
+            node_style: None,
+            pin_style: None,
+            edge_style: None,
+            node_style_override: None,
+            pin_style_override: None,
+            edge_style_override: None,
             on_connect: None,
             on_disconnect: None,
             on_move: None,
             on_select: None,
             on_clone: None,
             on_delete: None,
+            on_group_create: None,
+            on_ungroup: None,
+            on_background_click: None,
+            on_canvas_double_click: None,
+            on_edge_split: None,
+            on_edge_reshape: None,
             external_selection: None,
             on_drag_start: None,
             on_drag_update: None,
             on_drag_end: None,
+            on_connection_state_change: None,
+            on_drag: None,
             on_pan: None,
             on_info: None,
+            on_zoom_change: None,
+            on_viewport_change: None,
+            on_node_resized: None,
+            on_visible_nodes: None,
+            on_edge_hover: None,
+            on_graph_changed: None,
+            on_performance_warning: None,
+            on_first_layout: None,
             box_select_style_fn: None,
             cutting_tool_style_fn: None,
             dragging_edge_style_fn: None,
+            preview_matches_default: false,
             view: None,
+            initial_camera: None,
+            zoom_elastic: false,
+            edge_autopan: false,
+            animations_enabled: true,
+            reduced_motion: prefers_reduced_motion(),
+            high_contrast: false,
+            node_appear_animation: None,
+            performance_hint: None,
+            aa_width: iced_nodegraph_sdf::DEFAULT_AA_WIDTH,
+            pixel_snap: false,
             can_connect: None,
             keymap: input::Keymap::default(),
+            cursors: input::CursorConfig::default(),
+            connect_mode: ConnectMode::default(),
+            background_click: BackgroundClick::default(),
+            scroll_mode: ScrollMode::default(),
+            pin_visibility: PinVisibility::default(),
+            box_select_rule: SelectRule::default(),
+            normalize_connections: true,
+            edge_splitting: false,
+            prevent_overlap: false,
+            editable_edges: false,
+            auto_pin_side: false,
+            show_pin_connection_counts: false,
+            edge_bundling: 0.0,
+            edge_simplify_threshold: None,
+            input_enabled: true,
+            standard_shortcuts: true,
+            badges: HashMap::new(),
+            decorations: HashMap::new(),
+            opacities: HashMap::new(),
+            screen_fixed: HashMap::new(),
+            edge_routes: HashMap::new(),
+            transforms: HashMap::new(),
         }
     }
 }
@@ -445,6 +1234,289 @@ where
         self
     }
 
+    /// Sets the camera (world position + zoom) the graph starts with, e.g.
+    /// restored from saved state.
+    ///
+    /// Applied when the widget state is created, so the very first frame is
+    /// drawn with it instead of the origin at zoom 1. Unlike
+    /// [`view`](Self::view) it is read only once: later pans and zooms stay
+    /// with the widget and are reported through [`on_pan`](Self::on_pan). A
+    /// `view` set alongside it takes precedence (and is itself applied before
+    /// the first frame).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced::Point;
+    /// use iced_nodegraph::node_graph;
+    /// # type Renderer = iced::Renderer;
+    ///
+    /// let graph =
+    ///     node_graph::<(), iced::Theme, Renderer>().initial_camera(Point::new(-120.0, 40.0), 1.5);
+    /// ```
+    pub fn initial_camera(mut self, position: Point, zoom: f32) -> Self {
+        self.initial_camera = Some((position, zoom));
+        self
+    }
+
+    /// Enables elastic zoom limits.
+    ///
+    /// Zooming past [`Camera2D::ZOOM_MIN`](camera::Camera2D::ZOOM_MIN) or
+    /// [`Camera2D::ZOOM_MAX`](camera::Camera2D::ZOOM_MAX) then overshoots with
+    /// growing resistance and springs back to the limit over the next frames,
+    /// which feels less abrupt on trackpads. The overshoot is a transient
+    /// render state: [`on_pan`](Self::on_pan) only ever reports zooms inside
+    /// the limits, committing once the bounce settles. Off by default (hard
+    /// clamp), and ignored under [`reduced_motion`](Self::reduced_motion).
+    pub fn zoom_elastic(mut self, enabled: bool) -> Self {
+        self.zoom_elastic = enabled;
+        self
+    }
+
+    /// Enables auto-panning at the widget edge while dragging nodes.
+    ///
+    /// During a node or selection drag, holding the cursor within a margin of
+    /// the widget edge (or past it) scrolls the canvas toward that edge, faster
+    /// the deeper the cursor sits in the margin, so nodes can be carried beyond
+    /// the current view. The dragged nodes stay under the cursor and the drop
+    /// reports their full world travel. Each panned frame commits through
+    /// [`on_pan`](Self::on_pan). Off by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::node_graph;
+    /// # type Renderer = iced::Renderer;
+    /// # #[derive(Clone)]
+    /// # enum Message { Pan }
+    ///
+    /// let graph = node_graph::<Message, iced::Theme, Renderer>()
+    ///     .edge_autopan(true)
+    ///     .on_pan(|_position, _zoom| Message::Pan);
+    /// ```
+    pub fn edge_autopan(mut self, enabled: bool) -> Self {
+        self.edge_autopan = enabled;
+        self
+    }
+
+    /// Turns time-driven animation on or off for the whole graph.
+    ///
+    /// Off freezes every [`Pattern::flow`](crate::Pattern::flow) (edge, border
+    /// and pin patterns hold their resting phase), skips the
+    /// [`node_appear_animation`](Self::node_appear_animation), and stops the
+    /// widget from requesting continuous redraws for them - useful for
+    /// screenshots, reduced-motion accessibility, or saving battery.
+    /// Interaction feedback that follows the pointer is unaffected.
+    ///
+    /// Defaults to on; [`reduced_motion`](Self::reduced_motion) also turns
+    /// it off.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::node_graph;
+    /// # type Renderer = iced::Renderer;
+    /// # #[derive(Clone)]
+    /// # enum Message {}
+    ///
+    /// let graph = node_graph::<Message, iced::Theme, Renderer>().animations_enabled(false);
+    /// ```
+    pub fn animations_enabled(mut self, enabled: bool) -> Self {
+        self.animations_enabled = enabled;
+        self
+    }
+
+    /// Enables reduced-motion accessibility mode.
+    ///
+    /// Drops motion that is only decoration: flowing patterns hold still, new
+    /// nodes appear without the [`node_appear_animation`](Self::node_appear_animation),
+    /// and the [`zoom_elastic`](Self::zoom_elastic) bounce becomes a hard
+    /// clamp so the camera never tweens on its own. Functional feedback stays:
+    /// hover and drag highlights, the edge being dragged, the selection box
+    /// and [`edge_autopan`](Self::edge_autopan) all still follow the pointer.
+    ///
+    /// Defaults to [`prefers_reduced_motion`], so browsers that ask for
+    /// reduced motion get it without any host code; pass `false` to override.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::node_graph;
+    /// # type Renderer = iced::Renderer;
+    /// # #[derive(Clone)]
+    /// # enum Message {}
+    ///
+    /// let graph = node_graph::<Message, iced::Theme, Renderer>().reduced_motion(true);
+    /// ```
+    pub fn reduced_motion(mut self, enabled: bool) -> Self {
+        self.reduced_motion = enabled;
+        self
+    }
+
+    /// Enables high-contrast accessibility mode.
+    ///
+    /// Post-adjusts every resolved node, edge and pin style at render time,
+    /// after the style callbacks ran, so it applies over any theme or custom
+    /// style: node bodies and edge/pin colors become opaque, node borders are
+    /// at least 2 px and black or white (whichever stands out against the
+    /// canvas background), edges get at least a 2 px stroke with a 1 px
+    /// black-or-white outline, pins get a black-or-white ring, and selected
+    /// nodes keep their selection color on a 3 px border ringed by a 2 px
+    /// outline. Widths are in each style's own
+    /// [`WidthSpace`](crate::WidthSpace). Off by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::node_graph;
+    /// # type Renderer = iced::Renderer;
+    /// # #[derive(Clone)]
+    /// # enum Message {}
+    ///
+    /// let graph = node_graph::<Message, iced::Theme, Renderer>().high_contrast(true);
+    /// ```
+    pub fn high_contrast(mut self, enabled: bool) -> Self {
+        self.high_contrast = enabled;
+        self
+    }
+
+    /// Animates nodes in as they are pushed: over `duration`, a new node
+    /// fades in and grows from 80% to its full size about its centre.
+    ///
+    /// A node counts as new when its id was not in the graph on the previous
+    /// rebuild (see the module's Node Identity section), wherever it is
+    /// pushed, while the nodes a graph starts with do not animate. Removing
+    /// a node and pushing one with another id in the same frame animates
+    /// the new one; a node that only moves in push order does not. Only the
+    /// drawing is animated; hit-testing uses the final bounds from the first
+    /// frame.
+    ///
+    /// There is no exit animation: removal is host-driven and the node is
+    /// gone on the next `view`. To fade a node out, keep pushing it for the
+    /// fade's length with a style that lowers
+    /// [`NodeStyle::opacity`](crate::NodeStyle::opacity), then drop it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use iced_nodegraph::node_graph;
+    /// # type Renderer = iced::Renderer;
+    /// # #[derive(Clone)]
+    /// # enum Message {}
+    ///
+    /// let graph = node_graph::<Message, iced::Theme, Renderer>()
+    ///     .node_appear_animation(Duration::from_millis(200));
+    /// ```
+    pub fn node_appear_animation(mut self, duration: Duration) -> Self {
+        self.node_appear_animation = Some(duration);
+        self
+    }
+
+    /// Sets a node-count threshold above which the graph degrades gracefully.
+    ///
+    /// Once the graph holds more than
+    /// [`node_threshold`](PerformanceHint::node_threshold) nodes,
+    /// [`on_performance_warning`](Self::on_performance_warning) fires once
+    /// and zooming out below [`lod_zoom`](PerformanceHint::lod_zoom) switches
+    /// to low-detail drawing (no shadows, no pins). Styles are left
+    /// untouched: zooming back in restores full detail, and a graph at or
+    /// under the threshold always draws in full. No hint by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::{PerformanceHint, node_graph};
+    /// # type Renderer = iced::Renderer;
+    /// # #[derive(Clone)]
+    /// # enum Message { TooManyNodes(usize) }
+    ///
+    /// let graph = node_graph::<Message, iced::Theme, Renderer>()
+    ///     .performance_hint(PerformanceHint::new(500))
+    ///     .on_performance_warning(Message::TooManyNodes);
+    /// ```
+    pub fn performance_hint(mut self, hint: PerformanceHint) -> Self {
+        self.performance_hint = Some(hint);
+        self
+    }
+
+    /// Sets how many physical pixels the edges of nodes, edges, pins and the
+    /// background fade over. The width holds on screen at every zoom level:
+    /// raise it for a softer look, lower it toward `0` for hard edges.
+    /// Defaults to [`DEFAULT_AA_WIDTH`](iced_nodegraph_sdf::DEFAULT_AA_WIDTH),
+    /// a little over one pixel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::node_graph;
+    /// # type Renderer = iced::Renderer;
+    /// # #[derive(Clone)]
+    /// # enum Message {}
+    ///
+    /// let graph = node_graph::<Message, iced::Theme, Renderer>().aa_width(2.0);
+    /// ```
+    pub fn aa_width(mut self, px: f32) -> Self {
+        self.aa_width = px.max(0.0);
+        self
+    }
+
+    /// Snaps the background grid and node borders to the pixel grid for
+    /// crisp 1px hairlines.
+    ///
+    /// Grid lines land on whole pixels at every zoom, their width rounded to
+    /// whole pixels. Node borders and edges keep their world geometry but
+    /// the canvas origin lands on a pixel edge, so borders at whole world
+    /// coordinates are crisp whenever zoom times DPI scale is a whole
+    /// number. Off by default: snapped content moves in whole-pixel steps,
+    /// which shimmers during a slow animated pan.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::node_graph;
+    /// # type Renderer = iced::Renderer;
+    /// # #[derive(Clone)]
+    /// # enum Message {}
+    ///
+    /// let graph = node_graph::<Message, iced::Theme, Renderer>().pixel_snap(true);
+    /// ```
+    pub fn pixel_snap(mut self, enabled: bool) -> Self {
+        self.pixel_snap = enabled;
+        self
+    }
+
+    /// Removes every node and edge, keeping the configuration: styles,
+    /// callbacks, camera and input settings stay as built.
+    ///
+    /// Also drops what was resolved against the removed nodes: badges,
+    /// decorations, opacities, the host selection, screen anchors, edge
+    /// routes and node transforms. Set [`selection`](Self::selection),
+    /// [`node_badges`](Self::node_badges),
+    /// [`node_decorations`](Self::node_decorations) and
+    /// [`node_opacities`](Self::node_opacities) again after pushing the new
+    /// content.
+    ///
+    /// ```ignore
+    /// let mut ng = configured_graph();
+    /// ng.clear();
+    /// for n in &model.nodes {
+    ///     ng.push_node(node(n.id, n.position, n.view()));
+    /// }
+    /// ```
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.node_lookup.clear();
+        self.edges.clear();
+        self.external_selection = None;
+        self.badges.clear();
+        self.decorations.clear();
+        self.opacities.clear();
+        self.screen_fixed.clear();
+        self.edge_routes.clear();
+        self.transforms.clear();
+    }
+
     /// Adds a node with the given ID and default styling.
     ///
     /// The node will use theme defaults from `default_node_style()`.
@@ -465,6 +1537,9 @@ where
             }
             std::collections::hash_map::Entry::Vacant(entry) => {
                 entry.insert(self.nodes.len());
+                if node.transform != NodeTransform::default() {
+                    self.transforms.insert(self.nodes.len(), node.transform);
+                }
                 self.nodes.push((
                     node.id,
                     node.position,
@@ -476,25 +1551,426 @@ where
         }
     }
 
+    /// Adds a node pinned to the widget instead of the canvas.
+    ///
+    /// The node stays at `anchor` while the camera pans and zooms (its content
+    /// still scales with the zoom, like every node), which suits legends and
+    /// inspectors. Its own position is ignored. It is otherwise a regular
+    /// node: it can be selected and hosts pins that connect like any other.
+    ///
+    /// Edges always route between the pins' current places. The graph
+    /// converts the fixed node's screen position to world space every time
+    /// the camera changes, so an edge between a fixed node and a canvas node
+    /// stretches and swings as the canvas pans beneath it, while an edge
+    /// between two fixed nodes stays put. Fixed nodes cannot be dragged and are
+    /// left out of group moves and `on_move`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// ng.push_node_screen_fixed(
+    ///     ScreenAnchor::TopRight(Vector::new(16.0, 16.0)),
+    ///     node(LEGEND_ID, Point::ORIGIN, legend_view()),
+    /// );
+    /// ```
+    pub fn push_node_screen_fixed(
+        &mut self,
+        anchor: ScreenAnchor,
+        node: Node<'a, N, P, UI, Message, Theme, Renderer>,
+    ) {
+        let index = self.nodes.len();
+        self.push_node(node);
+        if self.nodes.len() > index {
+            self.screen_fixed.insert(index, anchor);
+        }
+    }
+
     /// Adds an edge to the graph.
     ///
     /// Pin IDs are resolved to local indices at render time; the widget
     /// normalizes orientation so the output pin is the edge start (output ->
     /// input).
+    ///
+    /// Nodes and edges may be pushed in any order. An edge whose end names a
+    /// node that is never pushed, or a pin its node does not declare, is kept
+    /// but never drawn; debug builds report it once, with the [`EdgeError`]
+    /// message, when the graph is laid out. Use
+    /// [`try_push_edge`](Self::try_push_edge) to check both while pushing and
+    /// get the [`EdgeError`] instead, which needs the node pushed first.
     pub fn push_edge(&mut self, edge: Edge<'a, N, P, E, UI, Theme>) {
         self.edges
             .push((edge.id, edge.from, edge.to, edge.style_fn));
     }
 
-    /// The user node id stored at an internal index.
-    pub(super) fn node_id_at(&self, index: usize) -> Option<&N> {
-        self.nodes.get(index).map(|(id, ..)| id)
+    /// Adds many edges at once, reserving room for all of them up front.
+    ///
+    /// Items are [`Edge`]s or plain `(from, to)` [`PinRef`] pairs; each is
+    /// added as by [`push_edge`](Self::push_edge), debug report included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced::{Point, widget::text};
+    /// use iced_nodegraph::{PinRef, node, node_graph};
+    /// # type Renderer = iced::Renderer;
+    ///
+    /// let mut graph = node_graph::<(), iced::Theme, Renderer>();
+    /// for id in 0_usize..3 {
+    ///     graph.push_node(node(id, Point::new(id as f32 * 200.0, 0.0), text("n")));
+    /// }
+    /// graph.push_edges((0..2).map(|id| (PinRef::new(id, 0), PinRef::new(id + 1, 0))));
+    /// assert_eq!(graph.connected_component(&0), vec![0, 1, 2]);
+    /// ```
+    pub fn push_edges(
+        &mut self,
+        edges: impl IntoIterator<Item = impl Into<Edge<'a, N, P, E, UI, Theme>>>,
+    ) {
+        let edges = edges.into_iter();
+        self.edges.reserve(edges.size_hint().0);
+        for edge in edges {
+            self.push_edge(edge.into());
+        }
     }
 
-    /// Clones the user node id stored at an internal index.
-    pub(super) fn index_to_node_id(&self, index: usize) -> Option<N> {
-        self.node_id_at(index).cloned()
-    }
+    /// [`push_edges`](Self::push_edges) with one style closure shared by every
+    /// added edge, replacing any style an item already carries.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// ng.push_edges_styled(wires.iter().copied(), |theme, status, _, _| EdgeStyle {
+    ///     ..default_edge_style(theme, status)
+    /// });
+    /// ```
+    pub fn push_edges_styled(
+        &mut self,
+        edges: impl IntoIterator<Item = impl Into<Edge<'a, N, P, E, UI, Theme>>>,
+        style: impl Fn(&Theme, EdgeStatus, PinInfo<'_, P, UI>, PinInfo<'_, P, UI>) -> EdgeStyle + 'a,
+    ) {
+        let style = Rc::new(style);
+        self.push_edges(edges.into_iter().map(|edge| {
+            let edge: Edge<'a, N, P, E, UI, Theme> = edge.into();
+            let style = Rc::clone(&style);
+            edge.style(move |theme, status, from, to| style(theme, status, from, to))
+        }));
+    }
+
+    /// Adds an edge after checking that both ends exist: their nodes were
+    /// pushed and declare the named pins. A rejected edge is not added.
+    ///
+    /// Nodes that declare no pins at all accept any pin id, so graphs kept
+    /// only for their structure (e.g.
+    /// [`connected_component`](Self::connected_component)) stay valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced::{Point, widget::text};
+    /// use iced_nodegraph::{EdgeError, PinRef, edge, node, node_graph};
+    /// # type Renderer = iced::Renderer;
+    ///
+    /// let mut graph = node_graph::<(), iced::Theme, Renderer>();
+    /// graph.push_node(node(0, Point::ORIGIN, text("a")));
+    ///
+    /// let dangling = graph.try_push_edge(edge!(PinRef::new(0, 0), PinRef::new(7, 0)));
+    /// assert_eq!(dangling, Err(EdgeError::UnknownNode(7)));
+    /// ```
+    pub fn try_push_edge(
+        &mut self,
+        edge: Edge<'a, N, P, E, UI, Theme>,
+    ) -> Result<(), EdgeError<N, P>>
+    where
+        UI: 'static,
+    {
+        self.check_edge(&edge)?;
+        self.edges
+            .push((edge.id, edge.from, edge.to, edge.style_fn));
+        Ok(())
+    }
+
+    /// Adds an edge routed through `waypoints`.
+    ///
+    /// Waypoints are world coordinates listed from the edge's `from` pin to
+    /// its `to` pin; the widget reverses them when it normalizes the edge to
+    /// run output -> input. `curve` replaces the style's curve for this edge:
+    /// [`EdgeCurve::Catmull`] draws a smooth spline through every waypoint,
+    /// [`EdgeCurve::Line`] a polyline, and [`EdgeCurve::BezierCubic`] cannot
+    /// pass through points, so it ignores them. Cutting and drop-to-split
+    /// hit-test the routed path.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// ng.push_edge_via(
+    ///     edge!(PinRef::new(0, 0), PinRef::new(1, 0)),
+    ///     &[Point::new(200.0, 40.0), Point::new(260.0, 180.0)],
+    ///     EdgeCurve::Catmull,
+    /// );
+    /// ```
+    pub fn push_edge_via(
+        &mut self,
+        edge: Edge<'a, N, P, E, UI, Theme>,
+        waypoints: &[Point],
+        curve: EdgeCurve,
+    ) {
+        self.edge_routes.insert(
+            self.edges.len(),
+            EdgeRoute {
+                waypoints: waypoints.to_vec(),
+                curve,
+            },
+        );
+        self.push_edge(edge);
+    }
+
+    /// Number of pins node `node_id` declares in its content, or 0 when it
+    /// was never pushed.
+    ///
+    /// Pins are addressed by their user id ([`PinRef::pin_id`]); internally
+    /// the widget numbers a node's pins `0..pin_count` in the order a
+    /// depth-first walk of its content meets them, i.e. declaration order.
+    /// Only pins whose id and info types match the graph's `P` and `UI`
+    /// count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced::{Point, widget::{column, text}};
+    /// use iced_nodegraph::{PinDirection, PinRef, node, node_graph, pin};
+    /// # type Renderer = iced::Renderer;
+    ///
+    /// let mut graph = node_graph::<(), iced::Theme, Renderer>();
+    /// graph.push_node(node(
+    ///     0,
+    ///     Point::ORIGIN,
+    ///     column![
+    ///         pin!(Left, 0_usize, text("in"), Input),
+    ///         pin!(Right, 1_usize, text("out"), Output),
+    ///     ],
+    /// ));
+    ///
+    /// assert_eq!(graph.pin_count(&0), 2);
+    /// assert_eq!(graph.pin_direction(&PinRef::new(0, 1)), Some(PinDirection::Output));
+    /// assert_eq!(graph.pin_direction(&PinRef::new(0, 2)), None);
+    /// ```
+    pub fn pin_count(&self, node_id: &N) -> usize
+    where
+        UI: 'static,
+    {
+        self.node_index(node_id)
+            .map_or(0, |index| self.declared_pins(index).len())
+    }
+
+    /// Direction of the pin `pin` names, or `None` when its node was never
+    /// pushed or declares no pin with that id.
+    pub fn pin_direction(&self, pin: &PinRef<N, P>) -> Option<PinDirection>
+    where
+        UI: 'static,
+    {
+        let index = self.node_index(&pin.node_id)?;
+        self.declared_pins(index)
+            .into_iter()
+            .find_map(|(id, direction)| (id == pin.pin_id).then_some(direction))
+    }
+
+    /// The node ids and edge pins pushed so far, indexed for adjacency
+    /// queries, as [`on_graph_changed`](Self::on_graph_changed) reports them.
+    pub fn topology(&self) -> Topology<N, P> {
+        Topology::new(
+            self.nodes.iter().map(|(id, ..)| id.clone()),
+            self.edges
+                .iter()
+                .map(|(_, from, to, _)| (from.clone(), to.clone())),
+        )
+    }
+
+    /// A hash of [`topology`](Self::topology), compared across rebuilds to
+    /// notice structural changes without keeping the previous one.
+    pub(super) fn topology_key(&self) -> u64 {
+        let mut hasher = std::hash::DefaultHasher::new();
+        for (id, ..) in &self.nodes {
+            id.hash(&mut hasher);
+        }
+        for (_, from, to, _) in &self.edges {
+            from.hash(&mut hasher);
+            to.hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+
+    /// The `(pin_id, direction)` pins of the node at `index`, read from a
+    /// throwaway widget tree of its content.
+    fn declared_pins(&self, index: usize) -> Vec<(P, PinDirection)>
+    where
+        UI: 'static,
+    {
+        widget::declared_pins::<P, UI>(&Tree::new(&self.nodes[index].2))
+    }
+
+    /// Both ends of `edge` name a pushed node and, unless that node declares
+    /// no pins, one of its pins.
+    fn check_edge(&self, edge: &Edge<'a, N, P, E, UI, Theme>) -> Result<(), EdgeError<N, P>>
+    where
+        UI: 'static,
+    {
+        self.check_ends([&edge.from, &edge.to], |index| self.declared_pins(index))
+    }
+
+    /// The first pushed edge whose ends fail [`check_edge`](Self::check_edge),
+    /// read from the widget's built node trees (`children`, one per node)
+    /// instead of throwaway ones. Edges may be pushed in any order, so this
+    /// only means something once the whole graph is built.
+    pub(crate) fn unresolved_edge(&self, children: &[Tree]) -> Option<EdgeError<N, P>>
+    where
+        UI: 'static,
+    {
+        let mut pins: HashMap<usize, Vec<(P, PinDirection)>> = HashMap::new();
+        self.edges.iter().find_map(|(_, from, to, _)| {
+            self.check_ends([from, to], |index| {
+                pins.entry(index)
+                    .or_insert_with(|| {
+                        children
+                            .get(index)
+                            .map(widget::declared_pins::<P, UI>)
+                            .unwrap_or_default()
+                    })
+                    .clone()
+            })
+            .err()
+        })
+    }
+
+    fn check_ends(
+        &self,
+        ends: [&PinRef<N, P>; 2],
+        mut declared_pins: impl FnMut(usize) -> Vec<(P, PinDirection)>,
+    ) -> Result<(), EdgeError<N, P>> {
+        for end in ends {
+            let index = self
+                .node_index(&end.node_id)
+                .ok_or_else(|| EdgeError::UnknownNode(end.node_id.clone()))?;
+            let pins = declared_pins(index);
+            if !pins.is_empty() && !pins.iter().any(|(id, _)| *id == end.pin_id) {
+                return Err(EdgeError::UnknownPin(end.clone()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the IDs of every node connected to `node_id` through pushed
+    /// edges, in either direction, starting with `node_id` itself.
+    ///
+    /// A plain traversal of the edge list: pin IDs and edge direction are
+    /// ignored. Returns an empty list when `node_id` was never pushed. The
+    /// widget uses it to select a whole component on a node double-click.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced::{Point, widget::text};
+    /// use iced_nodegraph::{PinRef, edge, node, node_graph};
+    /// # type Renderer = iced::Renderer;
+    ///
+    /// let mut graph = node_graph::<(), iced::Theme, Renderer>();
+    /// for id in 0..3 {
+    ///     graph.push_node(node(id, Point::ORIGIN, text("node")));
+    /// }
+    /// graph.push_edge(edge!(PinRef::new(0, 0), PinRef::new(1, 0)));
+    ///
+    /// assert_eq!(graph.connected_component(&1), vec![1, 0]);
+    /// assert_eq!(graph.connected_component(&2), vec![2]);
+    /// ```
+    pub fn connected_component(&self, node_id: &N) -> Vec<N> {
+        if self.node_index(node_id).is_none() {
+            return Vec::new();
+        }
+        let mut component = vec![node_id.clone()];
+        let mut next = 0;
+        while let Some(current) = component.get(next).cloned() {
+            next += 1;
+            for (_, from, to, _) in &self.edges {
+                let neighbor = if from.node_id == current {
+                    &to.node_id
+                } else if to.node_id == current {
+                    &from.node_id
+                } else {
+                    continue;
+                };
+                if !component.contains(neighbor) {
+                    component.push(neighbor.clone());
+                }
+            }
+        }
+        component
+    }
+
+    /// Captures the graph's structure as a [`GraphDocument`](crate::document::GraphDocument)
+    /// (requires the `serde` feature).
+    ///
+    /// Nodes and edges keep their push order; `data` supplies each node's
+    /// payload, since the widget cannot serialize content. The camera is the
+    /// one set with [`view`](Self::view) (origin at zoom 1 otherwise) and the
+    /// style is left empty for the host to fill in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced::{Point, widget::text};
+    /// use iced_nodegraph::{PinRef, edge, node, node_graph};
+    /// # type Renderer = iced::Renderer;
+    ///
+    /// let mut graph = node_graph::<(), iced::Theme, Renderer>().view(Point::ORIGIN, 2.0);
+    /// graph.push_node(node(0, Point::new(10.0, 20.0), text("a")));
+    /// graph.push_node(node(1, Point::new(200.0, 20.0), text("b")));
+    /// graph.push_edge(edge!(PinRef::new(0, 0), PinRef::new(1, 0)));
+    ///
+    /// let doc = graph.to_document(|id| format!("node {id}"));
+    /// assert_eq!(doc.nodes[1].data, "node 1");
+    /// assert_eq!(doc.edges[0].to, PinRef::new(1, 0));
+    /// assert_eq!(doc.camera.zoom, 2.0);
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn to_document<D>(
+        &self,
+        data: impl Fn(&N) -> D,
+    ) -> crate::document::GraphDocument<N, P, D> {
+        use crate::document::{DocumentCamera, DocumentEdge, DocumentNode, GraphDocument};
+
+        GraphDocument {
+            nodes: self
+                .nodes
+                .iter()
+                .map(|(id, position, ..)| DocumentNode {
+                    id: id.clone(),
+                    position: *position,
+                    data: data(id),
+                })
+                .collect(),
+            edges: self
+                .edges
+                .iter()
+                .map(|(_, from, to, _)| DocumentEdge {
+                    from: from.clone(),
+                    to: to.clone(),
+                })
+                .collect(),
+            camera: self
+                .view
+                .map(|(position, zoom)| DocumentCamera { position, zoom })
+                .unwrap_or_default(),
+            ..GraphDocument::default()
+        }
+    }
+
+    /// The user node id stored at an internal index.
+    pub(super) fn node_id_at(&self, index: usize) -> Option<&N> {
+        self.nodes.get(index).map(|(id, ..)| id)
+    }
+
+    /// Clones the user node id stored at an internal index.
+    pub(super) fn index_to_node_id(&self, index: usize) -> Option<N> {
+        self.node_id_at(index).cloned()
+    }
 
     /// The internal index of a node by its user id. Linear scan: the node Vec is
     /// the single source of truth, the index is a transient render-time detail.
@@ -502,6 +1978,12 @@ where
         self.node_lookup.get(id).copied()
     }
 
+    /// Each node's id in push order, which the widget compares across
+    /// rebuilds to keep per-node state with its node.
+    pub(super) fn node_ids(&self) -> impl ExactSizeIterator<Item = &N> {
+        self.nodes.iter().map(|(id, ..)| id)
+    }
+
     /// Sets the graph chrome style (background, etc.) as a theme-derived closure.
     ///
     /// Mirrors the other style setters (`box_select_style`, `dragging_edge_style`,
@@ -513,21 +1995,147 @@ where
         self
     }
 
-    /// Sets a style callback for the box selection overlay.
+    /// Whether the canvas background takes the theme's `background.base`
+    /// color each frame, so light themes get a light canvas. On by default.
     ///
-    /// The callback receives the theme and returns (fill_color, border_color).
+    /// A [`graph_style`](Self::graph_style) is an explicit override and wins
+    /// either way. Turned off without one, the canvas keeps the fixed dark
+    /// color of [`GraphStyle::default`] while the rest of the chrome still
+    /// follows the theme.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::node_graph;
+    /// # type Renderer = iced::Renderer;
+    ///
+    /// let graph = node_graph::<(), iced::Theme, Renderer>().background_follows_theme(false);
+    /// ```
+    pub fn background_follows_theme(mut self, enabled: bool) -> Self {
+        self.background_follows_theme = enabled;
+        self
+    }
+
+    /// Sets the style of every node pushed without its own
+    /// [`Node::style`], in place of [`default_node_style`](crate::default_node_style).
+    ///
+    /// Together with [`pin_style`](Self::pin_style),
+    /// [`edge_style`](Self::edge_style) and [`graph_style`](Self::graph_style)
+    /// this sets a graph's look in one place. A node's own closure replaces
+    /// this one; to layer over it, build both from a shared function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::{NodeStyle, default_node_style, node_graph};
+    /// # type Renderer = iced::Renderer;
+    ///
+    /// let graph = node_graph::<(), iced::Theme, Renderer>().node_style(|theme, status| NodeStyle {
+    ///     corner_radius: 8.0,
+    ///     ..default_node_style(theme, status)
+    /// });
+    /// ```
+    pub fn node_style(mut self, f: impl Fn(&Theme, NodeStatus) -> NodeStyle + 'a) -> Self {
+        self.node_style = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the style of the pins of every node pushed without its own
+    /// [`Node::pin_style`], in place of [`PinStyle::from_theme`]. The closure
+    /// receives the same arguments as [`Node::pin_style`].
+    pub fn pin_style(
+        mut self,
+        f: impl Fn(&Theme, &PinInfo<'_, P, UI>, Option<&PinInfo<'_, P, UI>>, PinStatus) -> PinStyle + 'a,
+    ) -> Self {
+        self.pin_style = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the style of every edge pushed without its own [`Edge::style`],
+    /// in place of [`default_edge_style`](crate::default_edge_style). The
+    /// closure receives the same arguments as [`Edge::style`].
+    pub fn edge_style(
+        mut self,
+        f: impl Fn(&Theme, EdgeStatus, PinInfo<'_, P, UI>, PinInfo<'_, P, UI>) -> EdgeStyle + 'a,
+    ) -> Self {
+        self.edge_style = Some(Box::new(f));
+        self
+    }
+
+    /// Adjusts the style of specific nodes by id. The closure receives the
+    /// node's id and its resolved style (own closure, graph-wide
+    /// [`node_style`](Self::node_style) or theme default, for the current
+    /// status) and returns the style drawn, so per-node overrides layer over
+    /// the graph's look by struct update instead of replacing it.
+    ///
+    /// # Examples
     ///
-    /// # Example
-    /// ```ignore
-    /// node_graph()
-    ///     .box_select_style(|theme| {
-    ///         (Color::from_rgba(0.3, 0.6, 1.0, 0.2), Color::from_rgb(0.3, 0.6, 1.0))
-    ///     })
     /// ```
-    pub fn box_select_style(
+    /// use std::collections::HashMap;
+    /// use iced::Color;
+    /// use iced_nodegraph::{ColorQuad, NodeStyle, node_graph};
+    /// # type Renderer = iced::Renderer;
+    ///
+    /// let tints = HashMap::from([(2_usize, Color::from_rgb(0.6, 0.2, 0.2))]);
+    /// let graph = node_graph::<(), iced::Theme, Renderer>().node_style_override(
+    ///     move |id, base| match tints.get(id) {
+    ///         Some(&tint) => NodeStyle {
+    ///             fill_color: ColorQuad::solid(tint),
+    ///             ..base
+    ///         },
+    ///         None => base,
+    ///     },
+    /// );
+    /// ```
+    pub fn node_style_override(mut self, f: impl Fn(&N, NodeStyle) -> NodeStyle + 'a) -> Self {
+        self.node_style_override = Some(Box::new(f));
+        self
+    }
+
+    /// Adjusts the style of specific pins, keyed by their [`PinRef`]. Like
+    /// [`node_style_override`](Self::node_style_override), the closure maps
+    /// the pin's resolved style to the one drawn.
+    pub fn pin_style_override(
         mut self,
-        f: impl Fn(&Theme) -> (iced::Color, iced::Color) + 'a,
+        f: impl Fn(&PinRef<N, P>, PinStyle) -> PinStyle + 'a,
     ) -> Self {
+        self.pin_style_override = Some(Box::new(f));
+        self
+    }
+
+    /// Adjusts the style of specific edges, keyed by their endpoints as
+    /// pushed (`from`, `to`). Like
+    /// [`node_style_override`](Self::node_style_override), the closure maps
+    /// the edge's resolved style to the one drawn.
+    pub fn edge_style_override(
+        mut self,
+        f: impl Fn(&PinRef<N, P>, &PinRef<N, P>, EdgeStyle) -> EdgeStyle + 'a,
+    ) -> Self {
+        self.edge_style_override = Some(Box::new(f));
+        self
+    }
+
+    /// Sets a style callback for the box selection overlay.
+    ///
+    /// The callback receives the theme and returns the rectangle's fill,
+    /// border color and border stroke pattern (in screen pixels, so a dashed
+    /// border keeps its rhythm at any zoom). Unset, the overlay follows the
+    /// graph style's [`SelectionStyle::box_select`](crate::SelectionStyle::box_select).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::{BoxSelectStyle, Pattern, SelectionStyle, node_graph};
+    /// # type Renderer = iced::Renderer;
+    ///
+    /// let graph = node_graph::<(), iced::Theme, Renderer>().box_select_style(|theme| {
+    ///     BoxSelectStyle {
+    ///         pattern: Pattern::dashed(1.5, 6.0, 4.0),
+    ///         ..SelectionStyle::from_theme(theme).box_select()
+    ///     }
+    /// });
+    /// ```
+    pub fn box_select_style(mut self, f: impl Fn(&Theme) -> BoxSelectStyle + 'a) -> Self {
         self.box_select_style_fn = Some(Box::new(f));
         self
     }
@@ -543,6 +2151,31 @@ where
         self
     }
 
+    /// Draws the edge being dragged with the curve and stub length of the
+    /// graph-wide [`edge_style`](Self::edge_style) (or
+    /// [`default_edge_style`](crate::default_edge_style)), so the preview
+    /// and the edge committed on release have the same shape. Colors and
+    /// stroke still come from [`dragging_edge_style`](Self::dragging_edge_style)
+    /// when it is set. Off by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::{EdgeCurve, EdgeStyle, node_graph};
+    /// # type Renderer = iced::Renderer;
+    ///
+    /// let graph = node_graph::<(), iced::Theme, Renderer>()
+    ///     .edge_style(|theme, status, _, _| EdgeStyle {
+    ///         curve: EdgeCurve::Line,
+    ///         ..iced_nodegraph::default_edge_style(theme, status)
+    ///     })
+    ///     .preview_matches_default(true);
+    /// ```
+    pub fn preview_matches_default(mut self, enabled: bool) -> Self {
+        self.preview_matches_default = enabled;
+        self
+    }
+
     /// Sets a style callback for the edge cutting tool overlay.
     ///
     /// The callback receives the theme and returns the line color.
@@ -611,6 +2244,371 @@ where
         self
     }
 
+    /// Overrides the mouse cursor shown for each interaction state.
+    ///
+    /// The defaults show a grab hand over draggable nodes, a crosshair over
+    /// pins and while connecting, and a move cursor while panning; see
+    /// [`CursorConfig`](input::CursorConfig).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::{CursorConfig, node_graph};
+    /// use iced::mouse::Interaction;
+    /// # type Renderer = iced::Renderer;
+    ///
+    /// let cursors = CursorConfig {
+    ///     node: Interaction::Pointer,
+    ///     ..CursorConfig::default()
+    /// };
+    /// let graph = node_graph::<(), iced::Theme, Renderer>().cursors(cursors);
+    /// ```
+    pub fn cursors(mut self, cursors: input::CursorConfig) -> Self {
+        self.cursors = cursors;
+        self
+    }
+
+    /// Sets how edges are created from pins.
+    ///
+    /// With [`ConnectMode::ClickClick`] a click on a pin arms an edge that
+    /// follows the cursor until a second click: on a valid target it keeps
+    /// the connection (reported through `on_connect` on snap, as for drags),
+    /// anywhere else it cancels. Escape also cancels. [`ConnectMode::Both`]
+    /// keeps drag-to-connect and arms only when the pin is clicked without
+    /// moving. Defaults to [`ConnectMode::Drag`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::{ConnectMode, node_graph};
+    /// # type Renderer = iced::Renderer;
+    ///
+    /// let graph = node_graph::<(), iced::Theme, Renderer>().connect_mode(ConnectMode::Both);
+    /// ```
+    pub fn connect_mode(mut self, mode: ConnectMode) -> Self {
+        self.connect_mode = mode;
+        self
+    }
+
+    /// Sets what a left-button drag on empty canvas does, for apps that want
+    /// that gesture for something other than box selection.
+    ///
+    /// [`BackgroundClick::Pan`] pans the camera like the pan button, and
+    /// [`BackgroundClick::Custom`] reports the press position through
+    /// [`on_background_click`](Self::on_background_click) and does nothing
+    /// else, so a host-drawn annotation or tool can take it. The edge-cut
+    /// chord still starts edge cutting, and touch still pans. Defaults to
+    /// [`BackgroundClick::BoxSelect`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::{BackgroundClick, node_graph};
+    /// # type Renderer = iced::Renderer;
+    ///
+    /// let graph = node_graph::<(), iced::Theme, Renderer>().background_click(BackgroundClick::Pan);
+    /// ```
+    pub fn background_click(mut self, mode: BackgroundClick) -> Self {
+        self.background_click = mode;
+        self
+    }
+
+    /// Sets what the vertical mouse wheel does: zoom about the cursor
+    /// ([`ScrollMode::Zoom`], the default) or pan ([`ScrollMode::Pan`]).
+    ///
+    /// Horizontal scroll deltas always pan, and holding the keymap's
+    /// [`scroll_pan_modifiers`](crate::Keymap::scroll_pan_modifiers) (Shift by
+    /// default) swaps the vertical wheel to the other mode. A wheel pan
+    /// commits through [`on_pan`](Self::on_pan) like a zoom does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::{ScrollMode, node_graph};
+    /// # type Renderer = iced::Renderer;
+    ///
+    /// let graph = node_graph::<(), iced::Theme, Renderer>().scroll_mode(ScrollMode::Pan);
+    /// ```
+    pub fn scroll_mode(mut self, mode: ScrollMode) -> Self {
+        self.scroll_mode = mode;
+        self
+    }
+
+    /// Sets which pins are drawn, to declutter dense graphs.
+    ///
+    /// [`PinVisibility::OnHover`] reveals a node's pins while the cursor is
+    /// over it (or it is selected); [`PinVisibility::Connected`] draws only
+    /// pins that carry an edge. In every mode an edge drag still shows its
+    /// source pin and all valid drop targets. Defaults to
+    /// [`PinVisibility::Always`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::{PinVisibility, node_graph};
+    /// # type Renderer = iced::Renderer;
+    ///
+    /// let graph = node_graph::<(), iced::Theme, Renderer>().pin_visibility(PinVisibility::OnHover);
+    /// ```
+    pub fn pin_visibility(mut self, visibility: PinVisibility) -> Self {
+        self.pin_visibility = visibility;
+        self
+    }
+
+    /// Sets which nodes a box select picks up: every node the box touches
+    /// ([`SelectRule::Intersect`], the default), only nodes fully inside it
+    /// ([`SelectRule::Contain`]), or either by drag direction
+    /// ([`SelectRule::ByDirection`]: left to right contains, right to left
+    /// intersects). The live preview follows the same rule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::{SelectRule, node_graph};
+    /// # type Renderer = iced::Renderer;
+    ///
+    /// let graph = node_graph::<(), iced::Theme, Renderer>().box_select_rule(SelectRule::Contain);
+    /// ```
+    pub fn box_select_rule(mut self, rule: SelectRule) -> Self {
+        self.box_select_rule = rule;
+        self
+    }
+
+    /// Reports connections output-first: with normalization on, the pair
+    /// passed to [`on_connect`](Self::on_connect),
+    /// [`on_connect_with`](Self::on_connect_with) and the matching
+    /// [`on_disconnect`](Self::on_disconnect) always has the output pin as
+    /// `from` and the input pin as `to`, whichever end the drag started on.
+    /// Pairs of two [`PinDirection::Both`] pins keep their drag order.
+    ///
+    /// On by default. Turn it off to receive the pins in drag order (the
+    /// pressed pin first), e.g. for graphs without a data-flow direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::node_graph;
+    /// # type Renderer = iced::Renderer;
+    /// # #[derive(Clone)]
+    /// # enum Message { Connect }
+    ///
+    /// let graph = node_graph::<Message, iced::Theme, Renderer>()
+    ///     .normalize_connections(true)
+    ///     .on_connect(|_from, _to| Message::Connect);
+    /// ```
+    pub fn normalize_connections(mut self, enabled: bool) -> Self {
+        self.normalize_connections = enabled;
+        self
+    }
+
+    /// Enables edge splitting: dropping a dragged node onto an edge reports
+    /// it through [`on_edge_split`](Self::on_edge_split).
+    ///
+    /// Off by default, so plain moves never touch edges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::node_graph;
+    /// # type Renderer = iced::Renderer;
+    /// # #[derive(Clone)]
+    /// # enum Message { Split }
+    ///
+    /// let graph = node_graph::<Message, iced::Theme, Renderer>()
+    ///     .edge_splitting(true)
+    ///     .on_edge_split(|_edge, _node| Message::Split);
+    /// ```
+    pub fn edge_splitting(mut self, enabled: bool) -> Self {
+        self.edge_splitting = enabled;
+        self
+    }
+
+    /// Keeps dropped nodes from overlapping: a node released on top of others
+    /// is pushed out along the shortest way clear of each, and
+    /// [`on_move`](Self::on_move) reports the corrected move.
+    ///
+    /// Off by default. Only single-node drags are resolved; screen-fixed
+    /// nodes are never obstacles.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced::Vector;
+    /// use iced_nodegraph::node_graph;
+    /// # type Renderer = iced::Renderer;
+    /// # #[derive(Clone)]
+    /// # enum Message { Move(Vector, Vec<usize>) }
+    ///
+    /// let graph = node_graph::<Message, iced::Theme, Renderer>()
+    ///     .prevent_overlap(true)
+    ///     .on_move(Message::Move);
+    /// ```
+    pub fn prevent_overlap(mut self, enabled: bool) -> Self {
+        self.prevent_overlap = enabled;
+        self
+    }
+
+    /// Makes edges reshapable: every edge shows a handle on each waypoint and
+    /// a smaller insert handle halfway between neighbouring points. Dragging
+    /// a handle reports the edge's new waypoints through
+    /// [`on_edge_reshape`](Self::on_edge_reshape).
+    ///
+    /// Off by default. Handles sit under nodes and above edges, and a press
+    /// on one wins over box selection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::node_graph;
+    /// # type Renderer = iced::Renderer;
+    /// # #[derive(Clone)]
+    /// # enum Message { Reshape }
+    ///
+    /// let graph = node_graph::<Message, iced::Theme, Renderer>()
+    ///     .editable_edges(true)
+    ///     .on_edge_reshape(|_edge, _waypoints| Message::Reshape);
+    /// ```
+    pub fn editable_edges(mut self, enabled: bool) -> Self {
+        self.editable_edges = enabled;
+        self
+    }
+
+    /// Lets each edge end leave from whichever border faces the node at its
+    /// other end, so an edge to a node "behind" its source no longer loops
+    /// around it.
+    ///
+    /// A left or right pin (and a row pin) picks between the left and right
+    /// borders at its own height, a top or bottom pin between the top and
+    /// bottom borders at its own offset; the edge's tangent follows. Only
+    /// edge curves change: pins are still drawn and hit-tested where they
+    /// were laid out. Off by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::node_graph;
+    /// # type Renderer = iced::Renderer;
+    ///
+    /// let graph = node_graph::<(), iced::Theme, Renderer>().auto_pin_side(true);
+    /// ```
+    pub fn auto_pin_side(mut self, enabled: bool) -> Self {
+        self.auto_pin_side = enabled;
+        self
+    }
+
+    /// Labels every pin with more than one edge with its edge count, so a
+    /// fan-in or fan-out is readable without tracing the wires.
+    ///
+    /// Counts come from the edges pushed to the graph. The label is a small
+    /// themed circle of constant screen size beside the pin, drawn only
+    /// while the pin itself is drawn. Off by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::node_graph;
+    /// # type Renderer = iced::Renderer;
+    ///
+    /// let graph = node_graph::<(), iced::Theme, Renderer>().show_pin_connection_counts(true);
+    /// ```
+    pub fn show_pin_connection_counts(mut self, enabled: bool) -> Self {
+        self.show_pin_connection_counts = enabled;
+        self
+    }
+
+    /// Bundles edges that run between the same two clusters of nodes, so
+    /// densely interconnected groups read as a few strands.
+    ///
+    /// Nodes are clustered by the 300 unit grid cell their centre falls in.
+    /// Every edge between the same two clusters (when there are at least
+    /// two) is pulled toward the straight path between the group's mean
+    /// ends by `strength`, from 0 (off, the default) to 1 (all such edges
+    /// share one path), and draws as a [`Catmull`](crate::EdgeCurve::Catmull)
+    /// spline so it still leaves and enters its pins along their sides.
+    /// Edges pushed with [`push_edge_via`](Self::push_edge_via) keep their
+    /// route. Hit tests follow the bundled path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::node_graph;
+    /// # type Renderer = iced::Renderer;
+    ///
+    /// let graph = node_graph::<(), iced::Theme, Renderer>().edge_bundling(0.8);
+    /// ```
+    pub fn edge_bundling(mut self, strength: f32) -> Self {
+        self.edge_bundling = if strength.is_finite() {
+            strength.clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        self
+    }
+
+    /// Draws every edge as a one-pixel straight line once the graph holds
+    /// more than `count` edges: no curve, outline, border, pattern or
+    /// shadow. A blunt lever for graphs with thousands of edges.
+    ///
+    /// While active it overrides every edge style: per-edge styles, the
+    /// graph-wide [`edge_style`](Self::edge_style) and
+    /// [`edge_bundling`](Self::edge_bundling) are all ignored. The line takes
+    /// the default edge color (the cut color while pending a cut), and routed
+    /// edges run straight between their waypoints. Hit tests follow the
+    /// lines. Off by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::node_graph;
+    /// # type Renderer = iced::Renderer;
+    ///
+    /// let graph = node_graph::<(), iced::Theme, Renderer>().edge_simplify_threshold(5000);
+    /// ```
+    pub fn edge_simplify_threshold(mut self, count: usize) -> Self {
+        self.edge_simplify_threshold = Some(count);
+        self
+    }
+
+    /// Whether the graph reacts to input. Turned off, mouse, touch, keyboard
+    /// and input method events are ignored, node content included, and the
+    /// cursor stays the default: what a host wants while a modal overlay
+    /// (a command palette stacked over the canvas) is open. The widget keeps
+    /// its state (camera, selection, focus), unlike tearing it down. A drag
+    /// in flight when input goes off is cancelled. On by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::node_graph;
+    /// # type Renderer = iced::Renderer;
+    ///
+    /// let palette_open = true;
+    /// let graph = node_graph::<(), iced::Theme, Renderer>().input_enabled(!palette_open);
+    /// ```
+    pub fn input_enabled(mut self, enabled: bool) -> Self {
+        self.input_enabled = enabled;
+        self
+    }
+
+    /// Enables the built-in selection shortcuts: select all (Cmd/Ctrl+A),
+    /// clear (Escape) and invert (Cmd/Ctrl+I), each reported through
+    /// [`on_select`](Self::on_select), plus group (Cmd/Ctrl+G) and ungroup
+    /// (Cmd/Ctrl+Shift+G), reported through
+    /// [`on_group_create`](Self::on_group_create) and
+    /// [`on_ungroup`](Self::on_ungroup).
+    ///
+    /// Select all, invert, group and ungroup act only while the graph holds
+    /// keyboard focus (a press inside it), after node content had its turn,
+    /// so a text input inside a node keeps Cmd/Ctrl+A and the like.
+    ///
+    /// On by default. Pass `false` when the app handles these keys itself;
+    /// the keys then pass through uncaptured. Individual bindings are
+    /// remapped through [`keymap`](Self::keymap).
+    pub fn standard_shortcuts(mut self, enabled: bool) -> Self {
+        self.standard_shortcuts = enabled;
+        self
+    }
+
     /// Sets a callback for when an edge is connected between two pins.
     ///
     /// `from` is always the OUTPUT pin and `to` always the INPUT pin, whichever way
@@ -625,6 +2623,34 @@ where
     /// Required to start an edge drag: without this callback, pressing a pin selects
     /// its node instead (a dropped edge could not be persisted anyway).
     pub fn on_connect(mut self, f: impl Fn(PinRef<N, P>, PinRef<N, P>) -> Message + 'a) -> Self {
+        self.on_connect = Some(Box::new(move |connection| {
+            f(connection.from, connection.to)
+        }));
+        self
+    }
+
+    /// Like [`on_connect`](Self::on_connect), but reports a [`Connection`]
+    /// carrying each end's direction and pin payload alongside its
+    /// [`PinRef`], so the host needs no lookup to tell what was connected.
+    ///
+    /// Fires at the same moments as `on_connect`, with the same output-first
+    /// orientation; the two share one slot, so the one set last wins.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::{Connection, PinRef, node_graph};
+    /// # type Renderer = iced::Renderer;
+    ///
+    /// #[derive(Clone)]
+    /// enum Message {
+    ///     Connected(PinRef<usize, usize>, PinRef<usize, usize>),
+    /// }
+    ///
+    /// let graph = node_graph::<Message, iced::Theme, Renderer>()
+    ///     .on_connect_with(|c: Connection| Message::Connected(c.from, c.to));
+    /// ```
+    pub fn on_connect_with(mut self, f: impl Fn(Connection<N, P, UI>) -> Message + 'a) -> Self {
         self.on_connect = Some(Box::new(f));
         self
     }
@@ -657,6 +2683,8 @@ where
     ///
     /// The callback receives the list of currently selected node IDs.
     /// Fires on click-select, box-select, and Shift+click multi-select.
+    /// Only user interaction fires it; a selection set through
+    /// [`selection`](Self::selection) is never echoed back.
     ///
     /// The widget keeps its own selection regardless; to make the host the source
     /// of truth, feed the reported value back via [`selection`](Self::selection).
@@ -674,6 +2702,83 @@ where
         self
     }
 
+    /// Sets a callback for when the user asks to group the selected nodes
+    /// (Cmd/Ctrl+G).
+    ///
+    /// The callback receives the selected node IDs and their world-space
+    /// bounding box, for sizing a frame around them. The application owns the
+    /// group model and draws the frame. The shortcut is one of the
+    /// [`standard_shortcuts`](Self::standard_shortcuts) and only fires while
+    /// this handler is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced::Rectangle;
+    /// use iced_nodegraph::node_graph;
+    /// use iced_wgpu::Renderer;
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum Message {
+    ///     Group(Vec<usize>, Rectangle),
+    /// }
+    ///
+    /// let graph = node_graph::<Message, iced::Theme, Renderer>().on_group_create(Message::Group);
+    /// ```
+    pub fn on_group_create(mut self, f: impl Fn(Vec<N>, Rectangle) -> Message + 'a) -> Self {
+        self.on_group_create = Some(Box::new(f));
+        self
+    }
+
+    /// Sets a callback for when the user asks to ungroup the selected nodes
+    /// (Cmd/Ctrl+Shift+G).
+    ///
+    /// The callback receives the selected node IDs; the application dissolves
+    /// whichever of its groups hold them. Like
+    /// [`on_group_create`](Self::on_group_create), it is one of the
+    /// [`standard_shortcuts`](Self::standard_shortcuts).
+    pub fn on_ungroup(mut self, f: impl Fn(Vec<N>) -> Message + 'a) -> Self {
+        self.on_ungroup = Some(Box::new(f));
+        self
+    }
+
+    /// Sets a callback for a left press on empty canvas under
+    /// [`BackgroundClick::Custom`]; it receives the world position of the
+    /// press. The press is captured only while this handler is set.
+    pub fn on_background_click(mut self, f: impl Fn(Point) -> Message + 'a) -> Self {
+        self.on_background_click = Some(Box::new(f));
+        self
+    }
+
+    /// Sets a callback for a double-click on empty canvas, receiving its world
+    /// position: the usual "add a node here" gesture.
+    ///
+    /// The first click of the pair acts as a plain click (with the default
+    /// [`BackgroundClick::BoxSelect`] it clears the selection); the second
+    /// reports here instead of starting a box select. Presses on nodes, pins
+    /// and edge handles, and presses with the edge-cut chord held, never
+    /// fire it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced::Point;
+    /// use iced_nodegraph::node_graph;
+    /// use iced_wgpu::Renderer;
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum Message {
+    ///     AddNodeAt(Point),
+    /// }
+    ///
+    /// let graph =
+    ///     node_graph::<Message, iced::Theme, Renderer>().on_canvas_double_click(Message::AddNodeAt);
+    /// ```
+    pub fn on_canvas_double_click(mut self, f: impl Fn(Point) -> Message + 'a) -> Self {
+        self.on_canvas_double_click = Some(Box::new(f));
+        self
+    }
+
     /// Sets a callback for when the user requests to delete selected nodes (Delete key).
     ///
     /// The callback receives the list of node IDs to delete.
@@ -683,6 +2788,112 @@ where
         self
     }
 
+    /// Sets a callback for when a dragged node is dropped onto an edge while
+    /// [`edge_splitting`](Self::edge_splitting) is enabled.
+    ///
+    /// The callback receives the edge as `(from, to)` (output first, as
+    /// pushed) and the dropped node's ID. It fires after the drop's
+    /// [`on_move`](Self::on_move); the widget changes no edges itself, so the
+    /// app removes the old edge and adds two new ones through the node.
+    /// Edges attached to the dropped node never split.
+    pub fn on_edge_split(
+        mut self,
+        f: impl Fn((PinRef<N, P>, PinRef<N, P>), N) -> Message + 'a,
+    ) -> Self {
+        self.on_edge_split = Some(Box::new(f));
+        self
+    }
+
+    /// Sets a callback for when the user drags a waypoint handle of an edge
+    /// while [`editable_edges`](Self::editable_edges) is enabled.
+    ///
+    /// The callback receives the edge as `(from, to)` (as pushed) and its
+    /// full list of world-space waypoints in `from -> to` order, on every
+    /// cursor move of the drag. Dragging an insert handle adds a waypoint
+    /// there; a click without moving changes nothing. The widget previews the
+    /// drag itself, and the app stores the waypoints and pushes the edge
+    /// through [`push_edge_via`](Self::push_edge_via). Edges drawn as a bezier
+    /// ignore their waypoints, so their reshape starts from none and previews
+    /// as [`EdgeCurve::Catmull`].
+    pub fn on_edge_reshape(
+        mut self,
+        f: impl Fn((PinRef<N, P>, PinRef<N, P>), Vec<Point>) -> Message + 'a,
+    ) -> Self {
+        self.on_edge_reshape = Some(Box::new(f));
+        self
+    }
+
+    /// Sets a single callback for every discrete interaction, delivered as a
+    /// [`NodeGraphEvent`].
+    ///
+    /// Convenient for hosts that funnel everything into one message variant.
+    /// It covers connect, disconnect, move, select, clone, delete, group and
+    /// ungroup; pan, the drag hooks and `on_info` keep their own callbacks.
+    ///
+    /// Granular callbacks take precedence: an interaction whose own handler is
+    /// set (e.g. [`on_select`](Self::on_select)) reports only through that
+    /// handler, whichever order the two were configured in. Everything else
+    /// falls through to this one. Like the granular handlers, setting this
+    /// also enables the interactions gated on them (edge drags, node moves,
+    /// clone, group).
+    pub fn on_event(mut self, f: impl Fn(NodeGraphEvent<N, P>) -> Message + 'a) -> Self
+    where
+        Message: 'a,
+    {
+        let f: Rc<dyn Fn(NodeGraphEvent<N, P>) -> Message + 'a> = Rc::new(f);
+        if self.on_connect.is_none() {
+            let f = f.clone();
+            self.on_connect = Some(Box::new(move |connection| {
+                f(NodeGraphEvent::Connect {
+                    from: connection.from,
+                    to: connection.to,
+                })
+            }));
+        }
+        if self.on_disconnect.is_none() {
+            let f = f.clone();
+            self.on_disconnect = Some(Box::new(move |from, to| {
+                f(NodeGraphEvent::Disconnect { from, to })
+            }));
+        }
+        if self.on_move.is_none() {
+            let f = f.clone();
+            self.on_move = Some(Box::new(move |delta, node_ids| {
+                f(NodeGraphEvent::Move { delta, node_ids })
+            }));
+        }
+        if self.on_select.is_none() {
+            let f = f.clone();
+            self.on_select = Some(Box::new(move |node_ids| {
+                f(NodeGraphEvent::Select { node_ids })
+            }));
+        }
+        if self.on_clone.is_none() {
+            let f = f.clone();
+            self.on_clone = Some(Box::new(move |node_ids| {
+                f(NodeGraphEvent::Clone { node_ids })
+            }));
+        }
+        if self.on_delete.is_none() {
+            let f = f.clone();
+            self.on_delete = Some(Box::new(move |node_ids| {
+                f(NodeGraphEvent::Delete { node_ids })
+            }));
+        }
+        if self.on_group_create.is_none() {
+            let f = f.clone();
+            self.on_group_create = Some(Box::new(move |node_ids, bounds| {
+                f(NodeGraphEvent::GroupCreate { node_ids, bounds })
+            }));
+        }
+        if self.on_ungroup.is_none() {
+            self.on_ungroup = Some(Box::new(move |node_ids| {
+                f(NodeGraphEvent::Ungroup { node_ids })
+            }));
+        }
+        self
+    }
+
     /// Sets a callback for when a drag operation starts.
     /// Used for real-time collaboration to broadcast drag state to other users.
     pub fn on_drag_start(mut self, f: impl Fn(DragInfo<N, P>) -> Message + 'a) -> Self {
@@ -706,6 +2917,46 @@ where
         self
     }
 
+    /// Sets a callback for when an edge drag starts, changes kind or ends.
+    ///
+    /// Fires with [`ConnectionState::Connecting`] when a pin press starts a
+    /// new edge, [`ConnectionState::Rerouting`] when a connected pin is
+    /// grabbed, and [`ConnectionState::Idle`] once the edge is dropped,
+    /// completed or cancelled. Snapping to and off targets mid-drag does not
+    /// fire; use `on_connect`/`on_disconnect` for those.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::{ConnectionState, node_graph};
+    /// # type Renderer = iced::Renderer;
+    /// # #[derive(Clone)]
+    /// # enum Message { Wiring(bool) }
+    ///
+    /// let graph = node_graph::<Message, iced::Theme, Renderer>().on_connection_state_change(
+    ///     |state| Message::Wiring(state != ConnectionState::Idle),
+    /// );
+    /// ```
+    pub fn on_connection_state_change(
+        mut self,
+        f: impl Fn(ConnectionState<N, P>) -> Message + 'a,
+    ) -> Self {
+        self.on_connection_state_change = Some(Box::new(f));
+        self
+    }
+
+    /// Sets a callback fired on every cursor move of an active drag.
+    ///
+    /// Receives what is being dragged and a [`DragProgress`] with the world
+    /// start position, the travel so far and the held modifiers, e.g. to show
+    /// a live delta readout. Covers node, group, edge (including reroute) and
+    /// box-select drags; panning reports through [`on_pan`](Self::on_pan)
+    /// instead.
+    pub fn on_drag(mut self, f: impl Fn(DragInfo<N, P>, DragProgress) -> Message + 'a) -> Self {
+        self.on_drag = Some(Box::new(f));
+        self
+    }
+
     /// Sets the commit callback for pan/zoom.
     ///
     /// Fires with the new camera position and zoom when the user finishes a pan
@@ -717,6 +2968,61 @@ where
         self
     }
 
+    /// Sets a callback fired with the new zoom factor whenever the user's
+    /// zoom changes, without the pan that [`on_pan`](Self::on_pan) also
+    /// reports.
+    ///
+    /// Suits swapping node detail by zoom level. Pans never fire it, and an
+    /// elastic overshoot (see [`zoom_elastic`](Self::zoom_elastic)) reports
+    /// only once its bounce settles, not on every frame of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::node_graph;
+    /// use iced_wgpu::Renderer;
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum Message {
+    ///     Zoomed(f32),
+    /// }
+    ///
+    /// let graph = node_graph::<Message, iced::Theme, Renderer>().on_zoom_change(Message::Zoomed);
+    /// ```
+    pub fn on_zoom_change(mut self, f: impl Fn(f32) -> Message + 'a) -> Self {
+        self.on_zoom_change = Some(Box::new(f));
+        self
+    }
+
+    /// Sets a callback fired with the world-space rectangle in view whenever
+    /// it changes, as [`Camera2D::visible_world_bounds`](camera::Camera2D::visible_world_bounds)
+    /// computes it.
+    ///
+    /// Suits streamed or very large graphs: load the nodes near the reported
+    /// rectangle and drop the rest. Fires on the first event and then
+    /// whenever a pan, zoom or widget resize moves the view; a camera set
+    /// through [`view`](Self::view) is reported on the event after it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced::Rectangle;
+    /// use iced_nodegraph::node_graph;
+    /// use iced_wgpu::Renderer;
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum Message {
+    ///     ViewportChanged(Rectangle),
+    /// }
+    ///
+    /// let graph = node_graph::<Message, iced::Theme, Renderer>()
+    ///     .on_viewport_change(Message::ViewportChanged);
+    /// ```
+    pub fn on_viewport_change(mut self, f: impl Fn(Rectangle) -> Message + 'a) -> Self {
+        self.on_viewport_change = Some(Box::new(f));
+        self
+    }
+
     /// Sets the per-frame diagnostics callback.
     ///
     /// Fires once per redraw with a [`GraphInfo`]: element counts (total / in
@@ -729,6 +3035,140 @@ where
         self
     }
 
+    /// Sets the callback fired when a node's laid-out size changes, with the
+    /// node's id and its new size in world units.
+    ///
+    /// Content that grows or shrinks (an expanded options panel, longer
+    /// text) is measured on the next layout and reported on the event
+    /// after it, so a host can keep its own model of node bounds for
+    /// fit-to-view or collision avoidance. A node's first layout is not a
+    /// change and does not fire; read initial sizes from
+    /// [`layout_snapshot`](Self::layout_snapshot).
+    pub fn on_node_resized(mut self, f: impl Fn(N, Size) -> Message + 'a) -> Self {
+        self.on_node_resized = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the callback fired when the nodes in view change, with the id and
+    /// screen rectangle of each node that overlaps the widget.
+    ///
+    /// Rectangles are in screen pixels relative to the widget's top-left, as
+    /// in [`layout_snapshot`](Self::layout_snapshot), and follow a node being
+    /// dragged, so a host can stack its own labels or badges over the graph
+    /// without repeating the camera projection. Nodes outside the view are
+    /// left out, as the renderer culls them. Fires on the first event and
+    /// then whenever a pan, zoom, move, resize or rebuild changes the list;
+    /// a camera set through [`view`](Self::view) is reported on the event
+    /// after it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced::Rectangle;
+    /// use iced_nodegraph::node_graph;
+    /// use iced_wgpu::Renderer;
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum Message {
+    ///     VisibleNodes(Vec<(usize, Rectangle)>),
+    /// }
+    ///
+    /// let graph = node_graph::<Message, iced::Theme, Renderer>()
+    ///     .on_visible_nodes(Message::VisibleNodes);
+    /// ```
+    pub fn on_visible_nodes(mut self, f: impl Fn(Vec<(N, Rectangle)>) -> Message + 'a) -> Self {
+        self.on_visible_nodes = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the callback fired when the cursor moves onto an edge or off it,
+    /// with the hovered edge's `(from, to)` pins as pushed, or `None`.
+    ///
+    /// An edge is hovered within the same screen distance of its curve that
+    /// a Command+Click cut uses. Pins and node bodies under the cursor take
+    /// precedence, so hovering a pin to pick an edge end up reports no edge,
+    /// and no edge is hovered during a drag. Fires only on change, e.g. to
+    /// show connection details in a tooltip.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::{PinRef, node_graph};
+    /// use iced_wgpu::Renderer;
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum Message {
+    ///     EdgeHovered(Option<(PinRef<usize, usize>, PinRef<usize, usize>)>),
+    /// }
+    ///
+    /// let graph = node_graph::<Message, iced::Theme, Renderer>()
+    ///     .on_edge_hover(Message::EdgeHovered);
+    /// ```
+    pub fn on_edge_hover(
+        mut self,
+        f: impl Fn(Option<(PinRef<N, P>, PinRef<N, P>)>) -> Message + 'a,
+    ) -> Self {
+        self.on_edge_hover = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the callback fired when the set of nodes or edges changes, with
+    /// the new [`Topology`].
+    ///
+    /// The graph is rebuilt in each `view()`, so a node added or removed or
+    /// an edge connected or cut is noticed on the first event after the
+    /// rebuild and reported once; moves, styles and content changes do not
+    /// fire. The starting topology is recorded without firing; read it with
+    /// [`topology`](Self::topology). Suits hosts that recompile or validate
+    /// the graph on every structural change instead of diffing edges in each
+    /// connect and delete handler.
+    pub fn on_graph_changed(mut self, f: impl Fn(&Topology<N, P>) -> Message + 'a) -> Self {
+        self.on_graph_changed = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the callback fired when the node count exceeds the
+    /// [`performance_hint`](Self::performance_hint) threshold, with that
+    /// count.
+    ///
+    /// Fires once per widget, on the first event that sees the graph over
+    /// the threshold, so the host can show a notice. It re-arms only after
+    /// the graph drops back to the threshold or below. Never fires without
+    /// a hint.
+    pub fn on_performance_warning(mut self, f: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_performance_warning = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the callback fired once, after the first layout that measured
+    /// the graph's nodes, with their world-space bounding box.
+    ///
+    /// Node sizes come from their content and are only known once laid out,
+    /// so this is the moment for size-dependent setup such as a one-off
+    /// fit-to-view through [`view`](Self::view). Fires on the first event
+    /// after that layout; a graph that starts empty waits for its first
+    /// node. Later rebuilds never fire it again for the same widget.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced::Rectangle;
+    /// use iced_nodegraph::node_graph;
+    /// use iced_wgpu::Renderer;
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum Message {
+    ///     FitToView(Rectangle),
+    /// }
+    ///
+    /// let graph = node_graph::<Message, iced::Theme, Renderer>()
+    ///     .on_first_layout(Message::FitToView);
+    /// ```
+    pub fn on_first_layout(mut self, f: impl Fn(Rectangle) -> Message + 'a) -> Self {
+        self.on_first_layout = Some(Box::new(f));
+        self
+    }
+
     /// Sets the host-controlled selection using user node IDs.
     ///
     /// The IDs are converted to internal indices; unknown IDs are ignored.
@@ -739,6 +3179,13 @@ where
     /// programmatically (select-all, clear, restore from a save). This is the
     /// controlled-component counterpart to `on_select`, exactly like
     /// [`view`](Self::view) is to `on_pan`.
+    ///
+    /// A set passed here is adopted silently: it never fires
+    /// [`on_select`](Self::on_select), so a host that stores what `on_select`
+    /// reports and feeds it back cannot loop. The widget adopts a set only
+    /// when it differs from the one passed last time, so feeding back a
+    /// stale value while a user change is still on its way to the host does
+    /// not undo that change.
     pub fn selection<'b>(mut self, selection: impl IntoIterator<Item = &'b N>) -> Self
     where
         N: 'b,
@@ -751,6 +3198,79 @@ where
         self
     }
 
+    /// Sets per-node status badges using user node IDs.
+    ///
+    /// Each [`Badge`] is drawn as a small dot (or a number, when it has a
+    /// count) inside the given corner of its node, at a constant screen size
+    /// and clipped to the node. Like [`selection`](Self::selection) this is
+    /// host state: derive it from e.g. validation results on every `view`.
+    /// Unknown IDs are ignored; call this after pushing the nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let badges: HashMap<usize, Badge> = errors
+    ///     .iter()
+    ///     .map(|(id, n)| (*id, Badge::new(Color::from_rgb(0.9, 0.2, 0.2)).count(*n)))
+    ///     .collect();
+    /// ng = ng.node_badges(&badges);
+    /// ```
+    pub fn node_badges(mut self, badges: &HashMap<N, Badge>) -> Self {
+        self.badges = badges
+            .iter()
+            .filter_map(|(id, badge)| Some((self.node_index(id)?, *badge)))
+            .collect();
+        self
+    }
+
+    /// Sets per-node halos using user node IDs.
+    ///
+    /// Each [`Decoration`] glows behind its node, following the node's
+    /// outline, independent of the node's own style and shadow - e.g. the
+    /// node a debugger is stopped at. Like [`node_badges`](Self::node_badges)
+    /// this is host state, passed on every `view`. Unknown IDs are ignored;
+    /// call this after pushing the nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let halos = HashMap::from([(current_node, Decoration::halo(Color::from_rgb(1.0, 0.8, 0.2)))]);
+    /// ng = ng.node_decorations(&halos);
+    /// ```
+    pub fn node_decorations(mut self, decorations: &HashMap<N, Decoration>) -> Self {
+        self.decorations = decorations
+            .iter()
+            .filter_map(|(id, decoration)| Some((self.node_index(id)?, *decoration)))
+            .collect();
+        self
+    }
+
+    /// Sets per-node opacity using user node IDs.
+    ///
+    /// Each value (clamped to 0.0..=1.0) multiplies its node's style
+    /// [`opacity`](NodeStyle::opacity) at render time, fading the body,
+    /// border, shadow and halo; nodes without an entry are drawn as styled.
+    /// Like [`node_badges`](Self::node_badges) this is host state, passed on
+    /// every `view`, so a host with its own animation system fades nodes in
+    /// or out by updating the map each frame. Unknown IDs are ignored; call
+    /// this after pushing the nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// // Fade in the nodes a search just revealed.
+    /// let opacities: HashMap<usize, f32> =
+    ///     revealed.iter().map(|id| (*id, tween.progress())).collect();
+    /// ng = ng.node_opacities(&opacities);
+    /// ```
+    pub fn node_opacities(mut self, opacities: &HashMap<N, f32>) -> Self {
+        self.opacities = opacities
+            .iter()
+            .filter_map(|(id, opacity)| Some((self.node_index(id)?, opacity.clamp(0.0, 1.0))))
+            .collect();
+        self
+    }
+
     /// Sets the width of the node graph widget.
     pub fn width(mut self, width: impl Into<Length>) -> Self {
         self.size.width = width.into();
@@ -777,7 +3297,7 @@ where
 
     pub(super) fn on_connect_handler(
         &self,
-    ) -> Option<&Box<dyn Fn(PinRef<N, P>, PinRef<N, P>) -> Message + 'a>> {
+    ) -> Option<&Box<dyn Fn(Connection<N, P, UI>) -> Message + 'a>> {
         self.on_connect.as_ref()
     }
     pub(super) fn on_disconnect_handler(
@@ -797,6 +3317,34 @@ where
     pub(super) fn on_delete_handler(&self) -> Option<&Box<dyn Fn(Vec<N>) -> Message + 'a>> {
         self.on_delete.as_ref()
     }
+    pub(super) fn on_group_create_handler(
+        &self,
+    ) -> Option<&Box<dyn Fn(Vec<N>, Rectangle) -> Message + 'a>> {
+        self.on_group_create.as_ref()
+    }
+    pub(super) fn on_background_click_handler(
+        &self,
+    ) -> Option<&Box<dyn Fn(Point) -> Message + 'a>> {
+        self.on_background_click.as_ref()
+    }
+    pub(super) fn on_canvas_double_click_handler(
+        &self,
+    ) -> Option<&Box<dyn Fn(Point) -> Message + 'a>> {
+        self.on_canvas_double_click.as_ref()
+    }
+    pub(super) fn on_ungroup_handler(&self) -> Option<&Box<dyn Fn(Vec<N>) -> Message + 'a>> {
+        self.on_ungroup.as_ref()
+    }
+    pub(super) fn on_edge_reshape_handler(
+        &self,
+    ) -> Option<&Box<dyn Fn((PinRef<N, P>, PinRef<N, P>), Vec<Point>) -> Message + 'a>> {
+        self.on_edge_reshape.as_ref()
+    }
+    pub(super) fn on_edge_split_handler(
+        &self,
+    ) -> Option<&Box<dyn Fn((PinRef<N, P>, PinRef<N, P>), N) -> Message + 'a>> {
+        self.on_edge_split.as_ref()
+    }
     pub(super) fn on_drag_start_handler(
         &self,
     ) -> Option<&Box<dyn Fn(DragInfo<N, P>) -> Message + 'a>> {
@@ -805,9 +3353,44 @@ where
     pub(super) fn on_drag_update_handler(&self) -> Option<&Box<dyn Fn(Point) -> Message + 'a>> {
         self.on_drag_update.as_ref()
     }
+    pub(super) fn on_drag_handler(
+        &self,
+    ) -> Option<&Box<dyn Fn(DragInfo<N, P>, DragProgress) -> Message + 'a>> {
+        self.on_drag.as_ref()
+    }
     pub(super) fn on_drag_end_handler(&self) -> Option<&Box<dyn Fn() -> Message + 'a>> {
         self.on_drag_end.as_ref()
     }
+    pub(super) fn on_connection_state_change_handler(
+        &self,
+    ) -> Option<&Box<dyn Fn(ConnectionState<N, P>) -> Message + 'a>> {
+        self.on_connection_state_change.as_ref()
+    }
+    /// Whether the node at `index` was pushed screen-fixed (and so never
+    /// moves with a drag).
+    pub(super) fn is_screen_fixed(&self, index: usize) -> bool {
+        self.screen_fixed.contains_key(&index)
+    }
+    /// `style` for pin `pin_id` of the node at `index`, with the
+    /// [`pin_style_override`](Self::pin_style_override) applied if set.
+    pub(super) fn adjust_pin_style(&self, index: usize, pin_id: &P, style: PinStyle) -> PinStyle {
+        match (&self.pin_style_override, self.nodes.get(index)) {
+            (Some(adjust), Some((node_id, ..))) => {
+                adjust(&PinRef::new(node_id.clone(), pin_id.clone()), style)
+            }
+            _ => style,
+        }
+    }
+    /// Rotation, mirroring and outline of the node at `index` (the identity
+    /// unless pushed with [`Node::rotation`], [`Node::flip_horizontal`] or
+    /// [`Node::shape`]).
+    pub(super) fn node_transform(&self, index: usize) -> NodeTransform {
+        self.transforms.get(&index).copied().unwrap_or_default()
+    }
+    /// Route of the edge at `index`, if it was pushed with waypoints.
+    pub(super) fn edge_route(&self, index: usize) -> Option<&EdgeRoute> {
+        self.edge_routes.get(&index)
+    }
     pub(super) fn get_external_selection(&self) -> Option<&HashSet<usize>> {
         self.external_selection.as_ref()
     }
@@ -818,9 +3401,78 @@ where
     pub(super) fn on_info_handler(&self) -> Option<&Box<dyn Fn(GraphInfo) -> Message + 'a>> {
         self.on_info.as_ref()
     }
+    pub(super) fn on_zoom_change_handler(&self) -> Option<&Box<dyn Fn(f32) -> Message + 'a>> {
+        self.on_zoom_change.as_ref()
+    }
+    pub(super) fn on_viewport_change_handler(
+        &self,
+    ) -> Option<&Box<dyn Fn(Rectangle) -> Message + 'a>> {
+        self.on_viewport_change.as_ref()
+    }
+    pub(super) fn on_node_resized_handler(&self) -> Option<&Box<dyn Fn(N, Size) -> Message + 'a>> {
+        self.on_node_resized.as_ref()
+    }
+    pub(super) fn on_visible_nodes_handler(
+        &self,
+    ) -> Option<&Box<dyn Fn(Vec<(N, Rectangle)>) -> Message + 'a>> {
+        self.on_visible_nodes.as_ref()
+    }
+    #[allow(clippy::type_complexity)]
+    pub(super) fn on_edge_hover_handler(
+        &self,
+    ) -> Option<&Box<dyn Fn(Option<(PinRef<N, P>, PinRef<N, P>)>) -> Message + 'a>> {
+        self.on_edge_hover.as_ref()
+    }
+    pub(super) fn on_graph_changed_handler(
+        &self,
+    ) -> Option<&Box<dyn Fn(&Topology<N, P>) -> Message + 'a>> {
+        self.on_graph_changed.as_ref()
+    }
+    pub(super) fn on_performance_warning_handler(
+        &self,
+    ) -> Option<&Box<dyn Fn(usize) -> Message + 'a>> {
+        self.on_performance_warning.as_ref()
+    }
+    pub(super) fn on_first_layout_handler(
+        &self,
+    ) -> Option<&Box<dyn Fn(Rectangle) -> Message + 'a>> {
+        self.on_first_layout.as_ref()
+    }
     pub(super) fn view_value(&self) -> Option<(Point, f32)> {
         self.view
     }
+    /// Camera a fresh widget state starts from: the host's `view()`, else
+    /// the [`initial_camera`](Self::initial_camera).
+    pub(super) fn starting_camera(&self) -> Option<(Point, f32)> {
+        self.view.or(self.initial_camera)
+    }
+    /// Whether the node count is over the performance hint's threshold.
+    pub(super) fn over_performance_threshold(&self) -> bool {
+        self.performance_hint
+            .is_some_and(|hint| self.nodes.len() > hint.node_threshold)
+    }
+    /// Whether time-driven visuals animate: on unless turned off directly or
+    /// by [`reduced_motion`](Self::reduced_motion).
+    pub(super) fn animates(&self) -> bool {
+        self.animations_enabled && !self.reduced_motion
+    }
+    /// Whether edges draw simplified; see
+    /// [`edge_simplify_threshold`](Self::edge_simplify_threshold).
+    pub(super) fn simplified_edges(&self) -> bool {
+        self.edge_simplify_threshold
+            .is_some_and(|threshold| self.edges.len() > threshold)
+    }
+    /// Whether zoom limits rubber-band; reduced motion forces the hard clamp.
+    pub(super) fn elastic_zoom(&self) -> bool {
+        self.zoom_elastic && !self.reduced_motion
+    }
+    /// Whether to draw in low detail at `zoom`; see [`PerformanceHint`].
+    pub(super) fn low_detail(&self, zoom: f32) -> bool {
+        self.over_performance_threshold()
+            && self
+                .performance_hint
+                .is_some_and(|hint| zoom < hint.lod_zoom)
+    }
 
     /// Translates a list of internal node indices to user IDs.
     /// Returns empty vec if any translation fails.
@@ -831,3 +3483,98 @@ where
             .collect()
     }
 }
+
+impl<N, P, E, UI, Message, Renderer> NodeGraph<'_, N, P, UI, Message, iced::Theme, Renderer, E>
+where
+    N: NodeId + 'static,
+    P: PinId + 'static,
+    E: EdgeId + 'static,
+    UI: 'static,
+    Renderer: iced_wgpu::core::renderer::Renderer,
+{
+    /// The style the node `id` draws with under `theme` in `status`, or
+    /// `None` when it was never pushed.
+    ///
+    /// This is the final merge the widget renders: the node's own
+    /// [`Node::style`] (else the graph-wide [`node_style`](Self::node_style),
+    /// else [`default_node_style`](crate::default_node_style)), then
+    /// [`node_style_override`](Self::node_style_override),
+    /// [`high_contrast`](Self::high_contrast), the node's
+    /// [`node_opacities`](Self::node_opacities) entry and, when selected,
+    /// the selection shadow. Widths are as declared, before the zoom
+    /// conversion of [`WidthSpace::Screen`](crate::WidthSpace::Screen), and
+    /// the appear animation is left out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::{NodeStatus, NodeStyle, node, node_graph};
+    /// use iced::widget::text;
+    /// use iced::{Point, Theme};
+    /// # type Renderer = iced::Renderer;
+    ///
+    /// let mut graph = node_graph::<(), Theme, Renderer>()
+    ///     .node_style_override(|_, style| NodeStyle { opacity: 0.5, ..style });
+    /// graph.push_node(node(0, Point::ORIGIN, text("A")));
+    ///
+    /// let style = graph.resolved_node_style(&0, &Theme::Dark, NodeStatus::Idle);
+    /// assert_eq!(style.map(|style| style.opacity), Some(0.5));
+    /// assert!(graph.resolved_node_style(&1, &Theme::Dark, NodeStatus::Idle).is_none());
+    /// ```
+    pub fn resolved_node_style(
+        &self,
+        id: &N,
+        theme: &iced::Theme,
+        status: NodeStatus,
+    ) -> Option<NodeStyle> {
+        let index = self.node_index(id)?;
+        Some(self.node_style_for(index, theme, status, &self.graph_style_for(theme)))
+    }
+
+    /// The style the first edge pushed from `from` to `to` draws with under
+    /// `theme` in `status`, or `None` when there is no such edge or one of
+    /// its pins does not resolve (the widget does not draw it either).
+    ///
+    /// The final merge the widget renders: the one-pixel line past the
+    /// [`edge_simplify_threshold`](Self::edge_simplify_threshold), else the
+    /// edge's own style (else the graph-wide
+    /// [`edge_style`](Self::edge_style), else
+    /// [`default_edge_style`](crate::default_edge_style)) and
+    /// [`edge_style_override`](Self::edge_style_override); then
+    /// [`high_contrast`](Self::high_contrast). Widths are as declared, before
+    /// the zoom conversion, and the curve is the style's own: routes,
+    /// bundling and [`auto_straighten`](crate::EdgeStyle::auto_straighten)
+    /// shape the path at draw time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::{EdgeStatus, PinRef, edge, node, node_graph, pin};
+    /// use iced::widget::text;
+    /// use iced::{Point, Theme};
+    /// # type Renderer = iced::Renderer;
+    ///
+    /// let mut graph = node_graph::<(), Theme, Renderer>()
+    ///     .edge_style_override(|_, _, style| style.stub_length(12.0));
+    /// graph.push_node(node(0, Point::ORIGIN, pin!(Right, 0_usize, text("out"), Output)));
+    /// graph.push_node(node(1, Point::new(200.0, 0.0), pin!(Left, 0_usize, text("in"), Input)));
+    /// graph.push_edge(edge!(PinRef::new(0, 0), PinRef::new(1, 0)));
+    ///
+    /// let (from, to) = (PinRef::new(0, 0), PinRef::new(1, 0));
+    /// let style = graph.resolved_edge_style(&from, &to, &Theme::Dark, EdgeStatus::Idle);
+    /// assert_eq!(style.map(|style| style.stub_length), Some(12.0));
+    /// ```
+    pub fn resolved_edge_style(
+        &self,
+        from: &PinRef<N, P>,
+        to: &PinRef<N, P>,
+        theme: &iced::Theme,
+        status: EdgeStatus,
+    ) -> Option<EdgeStyle> {
+        let index = self
+            .edges
+            .iter()
+            .position(|(_, edge_from, edge_to, _)| edge_from == from && edge_to == to)?;
+        self.edge_style_at(index, theme, status)
+    }
+}
@@ -9,10 +9,14 @@
 
 use super::GraphInfo;
 use super::camera::Camera2D;
-use super::euclid::WorldPoint;
-use iced::{Point, keyboard, touch};
+use super::euclid::{ScreenPoint, WorldPoint};
+use crate::style::EdgeCurve;
+use iced::{Point, Rectangle, Size, keyboard, touch};
+use iced_wgpu::core::mouse;
+use std::any::Any;
 use std::cell::{Cell, RefCell};
 use std::collections::{HashMap, HashSet};
+use std::fmt;
 use web_time::Instant;
 
 #[derive(Debug, Clone, Default, PartialEq)]
@@ -25,6 +29,13 @@ pub(crate) enum Dragging {
     EdgeOver(usize, usize, usize, usize), // from_node, from_pin, to_node and to_pin
     BoxSelect(WorldPoint, WorldPoint),    // start point, current point (left mouse on empty space)
     GroupMove(WorldPoint),                // origin point (when dragging a selected node, all move)
+    /// Edge handle drag: edge index, index of the waypoint being moved, and
+    /// the edge's world-space waypoints as they are reported.
+    Waypoint {
+        edge: usize,
+        index: usize,
+        waypoints: Vec<WorldPoint>,
+    },
     /// Fruit Ninja edge cutting: trail of cursor positions and pending edges to cut
     EdgeCutting {
         trail: Vec<WorldPoint>,
@@ -32,6 +43,32 @@ pub(crate) enum Dragging {
     },
 }
 
+/// The node ids of the last rebuild, in push order. Type-erased because the
+/// state is not generic over the id type; the widget stores and reads back
+/// its own `Vec<N>`.
+#[derive(Default)]
+pub(super) struct NodeIds(Option<Box<dyn Any>>);
+
+impl NodeIds {
+    pub(super) fn new<N: 'static>(ids: Vec<N>) -> Self {
+        Self(Some(Box::new(ids)))
+    }
+
+    /// The stored ids, or an empty slice before the first store.
+    pub(super) fn get<N: 'static>(&self) -> &[N] {
+        self.0
+            .as_ref()
+            .and_then(|ids| ids.downcast_ref::<Vec<N>>())
+            .map_or(&[], Vec::as_slice)
+    }
+}
+
+impl fmt::Debug for NodeIds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("NodeIds(..)")
+    }
+}
+
 #[derive(Debug)]
 pub(super) struct NodeGraphState {
     pub(super) camera: Camera2D,
@@ -62,6 +99,14 @@ pub(super) struct NodeGraphState {
     /// Latest per-frame diagnostics, written during draw() and taken during
     /// update() to publish via the `on_info` callback (one frame behind).
     pub(super) last_info: RefCell<Option<GraphInfo>>,
+    /// Resolved curve and stub length of each edge by index, written during
+    /// draw() so that update() hit-tests edges and their handles on the drawn
+    /// path.
+    pub(super) edge_geometry: RefCell<Vec<(EdgeCurve, f32)>>,
+    /// Layout-absolute waypoints each bundled edge was drawn through, by
+    /// index, in `from -> to` order as pushed; `None` for unbundled edges.
+    /// Written during draw() alongside `edge_geometry`.
+    pub(super) edge_bundles: RefCell<Vec<Option<[[f32; 2]; 2]>>>,
     /// Per-node z-order timestamp. Higher = more recently moved (or newly added).
     /// Indexed by internal node index. Newly seen indices are auto-assigned the
     /// next counter value so freshly pushed nodes spawn on top of older ones.
@@ -76,6 +121,65 @@ pub(super) struct NodeGraphState {
     /// Tap candidate: (finger, press position, press time from `time`).
     /// Cleared when the finger travels or a second finger joins.
     pub(super) touch_tap: Option<(touch::Finger, Point, f32)>,
+    /// Screen anchor of an elastic zoom overshoot that is springing back to
+    /// the zoom limit. Set while the camera is past a limit; cleared (and the
+    /// settled camera committed) once the bounce lands.
+    pub(super) zoom_bounce: Option<ScreenPoint>,
+    /// World cursor position where the current drag began, reported through
+    /// `on_drag`. Recorded on the press that enters a drag and cleared once
+    /// `dragging` returns to `None`.
+    pub(super) drag_origin: Option<WorldPoint>,
+    /// Set while a click-to-connect edge is armed: the edge drag outlives
+    /// its release and the next press completes or cancels it.
+    pub(super) connect_armed: bool,
+    /// Positional `(node, pin)` an edge drag was unplugged from, set while a
+    /// connected pin is being rerouted and cleared once the drag ends.
+    pub(super) rerouting: Option<(usize, usize)>,
+    /// Whether the navigation keys (Tab, arrows) act on this graph: taken by
+    /// a press inside the widget, given up by a press anywhere else.
+    pub(super) focused: bool,
+    /// Node the keyboard navigation last moved to, shown with the focus
+    /// ring. Independent of the selection; cleared by any press.
+    pub(super) focused_node: Option<usize>,
+    /// The previous left press, for double-click detection.
+    pub(super) last_click: Option<mouse::Click>,
+    /// Set once `on_performance_warning` fired for the current stretch over
+    /// the performance hint's threshold; cleared when the graph drops back.
+    pub(super) performance_warned: bool,
+    /// Set once a debug build reported an edge it cannot resolve; cleared
+    /// when every edge resolves again.
+    pub(super) edge_error_reported: bool,
+    /// Set once `on_first_layout` fired; never cleared.
+    pub(super) first_layout_reported: bool,
+    /// Node count seen by the previous update; `None` until the first, so
+    /// the nodes a graph starts with never animate in.
+    pub(super) seen_node_count: Option<usize>,
+    /// Animation time at which each appearing node index was first seen.
+    /// Entries are dropped once their appear animation has finished.
+    pub(super) node_appear: HashMap<usize, f32>,
+    /// Topmost node under the cursor, tracked while pins are shown on hover
+    /// (`PinVisibility::OnHover`); `None` otherwise.
+    pub(super) hovered_node: Option<usize>,
+    /// Laid-out size of each node by index as the previous update saw it,
+    /// tracked while `on_node_resized` is set; `None` for a node not yet
+    /// measured.
+    pub(super) node_sizes: Vec<Option<Size>>,
+    /// Widget-relative screen rectangle of each node in view by index, as
+    /// last reported through `on_visible_nodes`.
+    pub(super) visible_nodes: Vec<(usize, Rectangle)>,
+    /// World rectangle in view as last reported through
+    /// `on_viewport_change`.
+    pub(super) viewport_bounds: Option<Rectangle>,
+    /// Index of the edge under the cursor as last reported through
+    /// `on_edge_hover`.
+    pub(super) hovered_edge: Option<usize>,
+    /// Each node's id by index as of the last rebuild. A rebuild that
+    /// reorders or filters the ids moves the index-keyed state above along
+    /// with its node (see [`NodeGraphState::follow_nodes`]).
+    pub(super) node_ids: NodeIds,
+    /// Hash of the node ids and edge pins the previous update saw, tracked
+    /// while `on_graph_changed` is set; `None` until the first.
+    pub(super) topology_key: Option<u64>,
 }
 
 impl Default for NodeGraphState {
@@ -92,15 +196,48 @@ impl Default for NodeGraphState {
             last_synced_view: None,
             sdf_animated: Cell::new(false),
             last_info: RefCell::new(None),
+            edge_geometry: RefCell::new(Vec::new()),
+            edge_bundles: RefCell::new(Vec::new()),
             node_z: HashMap::new(),
             z_counter: 0,
             fingers: Vec::new(),
             touch_tap: None,
+            zoom_bounce: None,
+            drag_origin: None,
+            connect_armed: false,
+            rerouting: None,
+            focused: false,
+            focused_node: None,
+            last_click: None,
+            performance_warned: false,
+            edge_error_reported: false,
+            first_layout_reported: false,
+            seen_node_count: None,
+            node_appear: HashMap::new(),
+            hovered_node: None,
+            node_sizes: Vec::new(),
+            visible_nodes: Vec::new(),
+            viewport_bounds: None,
+            hovered_edge: None,
+            node_ids: NodeIds::default(),
+            topology_key: None,
         }
     }
 }
 
 impl NodeGraphState {
+    /// Positional identity of the edge drag in progress: the source
+    /// `(node, pin)` and, when rerouting, the pin it was unplugged from.
+    /// Changes exactly when the reported `ConnectionState` does.
+    pub(super) fn connection_key(&self) -> Option<((usize, usize), Option<(usize, usize)>)> {
+        match self.dragging {
+            Dragging::Edge(node, pin, _) | Dragging::EdgeOver(node, pin, _, _) => {
+                Some(((node, pin), self.rerouting))
+            }
+            _ => None,
+        }
+    }
+
     /// Ensure every index in `0..node_count` has a z entry. Newly seen indices
     /// receive the next counter value, so freshly pushed nodes render on top.
     pub(super) fn ensure_z_entries(&mut self, node_count: usize) {
@@ -112,6 +249,106 @@ impl NodeGraphState {
         }
     }
 
+    /// Track node indices that appeared since the previous update for the
+    /// appear animation: indices past the last seen count start at `time`,
+    /// indices past `node_count` are forgotten, and entries older than
+    /// `duration` seconds are dropped. With no `duration` nothing is recorded.
+    pub(super) fn track_appearing(&mut self, node_count: usize, duration: Option<f32>) {
+        let previous = self.seen_node_count.replace(node_count);
+        self.node_appear.retain(|&idx, _| idx < node_count);
+        let Some(duration) = duration else {
+            self.node_appear.clear();
+            return;
+        };
+        let time = self.time;
+        self.node_appear.retain(|_, start| time - *start < duration);
+        if let Some(previous) = previous {
+            for idx in previous..node_count {
+                self.node_appear.insert(idx, time);
+            }
+        }
+    }
+
+    /// Re-index per-node state after a rebuild changed the node order.
+    /// `moved_from[new]` is the index the node had before, or `None` for a
+    /// node that was not there. Removed nodes drop out of the selection,
+    /// z-order and hover; a drag of a removed node is cancelled. Newly added
+    /// nodes start their appear animation wherever they land.
+    pub(super) fn follow_nodes(&mut self, moved_from: &[Option<usize>]) {
+        let moved_to: HashMap<usize, usize> = moved_from
+            .iter()
+            .enumerate()
+            .filter_map(|(new, old)| old.map(|old| (old, new)))
+            .collect();
+        let to = |old: usize| moved_to.get(&old).copied();
+
+        self.selected_nodes = self.selected_nodes.iter().filter_map(|&i| to(i)).collect();
+        if let Some(synced) = &mut self.last_synced_external {
+            *synced = synced.iter().filter_map(|&i| to(i)).collect();
+        }
+        self.valid_drop_targets = self
+            .valid_drop_targets
+            .iter()
+            .filter_map(|&(node, pin)| Some((to(node)?, pin)))
+            .collect();
+        self.node_z = self
+            .node_z
+            .iter()
+            .filter_map(|(&i, &z)| Some((to(i)?, z)))
+            .collect();
+        self.node_appear = self
+            .node_appear
+            .iter()
+            .filter_map(|(&i, &start)| Some((to(i)?, start)))
+            .collect();
+        if self.seen_node_count.is_some() {
+            for (new, _) in moved_from
+                .iter()
+                .enumerate()
+                .filter(|(_, old)| old.is_none())
+            {
+                self.node_appear.insert(new, self.time);
+            }
+            self.seen_node_count = Some(moved_from.len());
+        }
+        self.hovered_node = self.hovered_node.and_then(to);
+        self.focused_node = self.focused_node.and_then(to);
+        self.visible_nodes = self
+            .visible_nodes
+            .iter()
+            .filter_map(|&(i, rect)| Some((to(i)?, rect)))
+            .collect();
+        self.node_sizes = moved_from
+            .iter()
+            .map(|old| old.and_then(|old| self.node_sizes.get(old).copied().flatten()))
+            .collect();
+        self.rerouting = self
+            .rerouting
+            .and_then(|(node, pin)| Some((to(node)?, pin)));
+
+        let dragging = match std::mem::take(&mut self.dragging) {
+            Dragging::Node(node, origin) => to(node).map(|node| Dragging::Node(node, origin)),
+            Dragging::Edge(node, pin, cursor) => {
+                to(node).map(|node| Dragging::Edge(node, pin, cursor))
+            }
+            Dragging::EdgeOver(node, pin, target, target_pin) => {
+                to(node).map(|node| match to(target) {
+                    Some(target) => Dragging::EdgeOver(node, pin, target, target_pin),
+                    // The hovered target left; keep dragging from the source.
+                    None => Dragging::Edge(node, pin, self.drag_origin.unwrap_or_default()),
+                })
+            }
+            other => Some(other),
+        };
+        self.dragging = dragging.unwrap_or_else(|| {
+            self.valid_drop_targets.clear();
+            self.rerouting = None;
+            self.connect_armed = false;
+            self.drag_origin = None;
+            Dragging::None
+        });
+    }
+
     /// Promote a single node to the top of the z-order.
     pub(super) fn promote_z(&mut self, idx: usize) {
         self.node_z.insert(idx, self.z_counter);
@@ -304,6 +541,47 @@ mod tests {
         assert_eq!(state.z_counter, 0);
     }
 
+    #[test]
+    fn track_appearing_records_only_nodes_added_after_the_first_update() {
+        let mut state = NodeGraphState::default();
+        state.track_appearing(3, Some(0.25));
+        assert!(state.node_appear.is_empty(), "initial nodes animated");
+
+        state.time = 1.0;
+        state.track_appearing(5, Some(0.25));
+        assert_eq!(state.node_appear.len(), 2);
+        assert_eq!(state.node_appear.get(&3), Some(&1.0));
+        assert_eq!(state.node_appear.get(&4), Some(&1.0));
+
+        // Removing the tail forgets it; finished animations are dropped.
+        state.track_appearing(4, Some(0.25));
+        assert_eq!(state.node_appear.keys().collect::<Vec<_>>(), vec![&3]);
+        state.time = 1.5;
+        state.track_appearing(4, Some(0.25));
+        assert!(state.node_appear.is_empty());
+    }
+
+    #[test]
+    fn follow_nodes_moves_state_with_reordered_and_removed_nodes() {
+        let mut state = NodeGraphState::default();
+        state.selected_nodes.extend([0, 2]);
+        state.node_z.extend([(0, 5), (1, 6), (2, 7)]);
+        state.hovered_node = Some(2);
+        state.dragging = Dragging::Node(2, Point2D::new(1.0, 1.0));
+
+        // Node 0 is filtered out; node 2 moves to the front; a new node joins.
+        state.follow_nodes(&[Some(2), Some(1), None]);
+        assert_eq!(state.selected_nodes, HashSet::from([0]));
+        assert_eq!(state.node_z, HashMap::from([(0, 7), (1, 6)]));
+        assert_eq!(state.hovered_node, Some(0));
+        assert_eq!(state.dragging, Dragging::Node(0, Point2D::new(1.0, 1.0)));
+
+        // Removing the dragged node cancels the drag.
+        state.follow_nodes(&[Some(1)]);
+        assert_eq!(state.dragging, Dragging::None);
+        assert!(state.selected_nodes.is_empty());
+    }
+
     #[test]
     fn test_ensure_z_entries_assigns_new_indices() {
         let mut state = NodeGraphState::default();
@@ -10,7 +10,8 @@
 //! The widget renders in three tiers for correct z-ordering:
 //! 1. Solid background color.
 //! 2. Graph background: ONE batched SDF draw under all nodes, internally
-//!    ordered grid (z0), node + edge shadows (z1), edge strokes (z2).
+//!    ordered grid (z0), node + edge shadows (z1), edge strokes (z2) and,
+//!    with `editable_edges`, waypoint handles (z3).
 //! 3. Per node, composited by Iced in z-order: node background (fill) -> node
 //!    content (Iced widgets) -> node foreground (border + pins). Embedding Iced
 //!    widgets between the two SDF node layers lets nodes overlap correctly.
@@ -21,28 +22,34 @@ use iced_wgpu::core::{
     Clipboard, Layout, Shell, layout, mouse, overlay, renderer,
     widget::{self, Tree, tree},
 };
+use std::collections::HashMap;
 use web_time::Instant;
 
 use super::{
-    Counts, DragInfo, GraphInfo, NodeGraph, OpTiming, RenderContext,
+    BackgroundClick, ConnectMode, Connection, ConnectionState, Counts, DragInfo, DragProgress,
+    GraphInfo, NodeGraph, NodeShape, NodeTransform, OpTiming, PinVisibility, RenderContext,
+    ScrollMode, SelectRule,
+    camera::Camera2D,
     euclid::{IntoIced, WorldVector},
-    state::{Dragging, NodeGraphState, z_render_indices},
+    state::{Dragging, NodeGraphState, NodeIds, z_render_indices},
 };
 use super::{EdgeStyleFn, NodeStyleFn, PinStyleFn};
 use crate::{
     PinDirection, PinRef, PinSide,
     ids::{EdgeId, NodeId, PinId},
     node_graph::euclid::{IntoEuclid, ScreenPoint, WorldPoint},
-    node_pin::{NodePinState, PinEnd, PinInfo},
+    node_pin::{CollapsePins, NodePinState, PinEnd, PinInfo},
     style::{
-        EdgeGeometry, EdgeStatus, EdgeStyle, GraphStyle, NodeStatus, NodeStyle, PinStatus,
-        PinStyle, TilingKind,
+        BackgroundSpace, EdgeCurve, EdgeGeometry, EdgeStatus, EdgeStyle, GraphStyle, NodeStatus,
+        NodeStyle, PinStatus, PinStyle, TilingKind,
     },
 };
 use iced_nodegraph_sdf::{Pattern, SdfPrimitive, Shape, Style, Tiling};
 
+mod bundling;
 mod camera_overlay;
 mod draw;
+mod snapshot;
 mod update;
 
 use camera_overlay::CameraOverlay;
@@ -63,6 +70,343 @@ fn adaptive_bezier_length(start: [f32; 2], end: [f32; 2]) -> f32 {
     BEZIER_SEGMENT_LENGTH.min(d * 0.5).max(1.0)
 }
 
+/// Points a [`EdgeCurve::Catmull`](crate::EdgeCurve::Catmull) spline passes
+/// through: the start pin, a short lead-out along its side, the waypoints, a
+/// lead-in along the end pin's side, and the end pin. The leads make the spline
+/// leave and enter pins the way the bezier does.
+fn catmull_route(
+    start: [f32; 2],
+    start_side: u32,
+    end: [f32; 2],
+    end_side: u32,
+    waypoints: &[[f32; 2]],
+) -> Vec<[f32; 2]> {
+    let first = waypoints.first().copied().unwrap_or(end);
+    let last = waypoints.last().copied().unwrap_or(start);
+    let lead_out = adaptive_bezier_length(start, first) * 0.5;
+    let lead_in = adaptive_bezier_length(last, end) * 0.5;
+    let d0 = pin_side_direction(start_side);
+    let d1 = pin_side_direction(end_side);
+
+    let mut points = Vec::with_capacity(waypoints.len() + 4);
+    points.push(start);
+    points.push([start[0] + d0[0] * lead_out, start[1] + d0[1] * lead_out]);
+    points.extend_from_slice(waypoints);
+    points.push([end[0] + d1[0] * lead_in, end[1] + d1[1] * lead_in]);
+    points.push(end);
+    points
+}
+
+/// Stub an edge actually draws for a requested
+/// [`stub_length`](crate::EdgeStyle::stub_length): at most a third of the pin
+/// distance, so the stubs of close pins never cross.
+fn clamped_stub(start: [f32; 2], end: [f32; 2], stub: f32) -> f32 {
+    let d = ((end[0] - start[0]).powi(2) + (end[1] - start[1]).powi(2)).sqrt();
+    stub.min(d / 3.0).max(0.0)
+}
+
+/// Whether an edge from `start` to `end` is within `tolerance` of a straight
+/// run along the start pin's side axis, with both pins facing each other;
+/// see [`EdgeStyle::auto_straighten`](crate::EdgeStyle::auto_straighten).
+fn nearly_aligned(
+    start: [f32; 2],
+    start_side: u32,
+    end: [f32; 2],
+    end_side: u32,
+    tolerance: f32,
+) -> bool {
+    let d = [end[0] - start[0], end[1] - start[1]];
+    let out = pin_side_direction(start_side);
+    let back = pin_side_direction(end_side);
+    let along = d[0] * out[0] + d[1] * out[1];
+    let across = (d[0] * out[1] - d[1] * out[0]).abs();
+    let facing = d[0] * back[0] + d[1] * back[1] < 0.0;
+    tolerance > 0.0 && along > 0.0 && facing && across <= tolerance
+}
+
+/// Cubic bezier spans `[p0, c1, c2, p3]` of an edge's path. Mirrors the draw
+/// path: a bezier between the pins, a (poly)line for
+/// [`EdgeCurve::Line`](crate::EdgeCurve::Line) with each straight span as a
+/// degenerate cubic, or the Catmull-Rom spline through the waypoints. With a
+/// `stub`, straight spans lead out of and into the pins along their sides and
+/// the curve runs between the stub ends.
+fn edge_spans(
+    start: [f32; 2],
+    start_side: u32,
+    end: [f32; 2],
+    end_side: u32,
+    curve: EdgeCurve,
+    stub: f32,
+    waypoints: &[[f32; 2]],
+) -> Vec<[[f32; 2]; 4]> {
+    let stub = clamped_stub(start, end, stub);
+    if stub > 0.0 {
+        let d0 = pin_side_direction(start_side);
+        let d1 = pin_side_direction(end_side);
+        let lead_out = [start[0] + d0[0] * stub, start[1] + d0[1] * stub];
+        let lead_in = [end[0] + d1[0] * stub, end[1] + d1[1] * stub];
+        let mut spans = vec![[start, start, lead_out, lead_out]];
+        spans.extend(edge_spans(
+            lead_out, start_side, lead_in, end_side, curve, 0.0, waypoints,
+        ));
+        spans.push([lead_in, lead_in, end, end]);
+        return spans;
+    }
+    match curve {
+        EdgeCurve::Line => std::iter::once(start)
+            .chain(waypoints.iter().copied())
+            .chain(std::iter::once(end))
+            .collect::<Vec<_>>()
+            .windows(2)
+            .map(|w| [w[0], w[0], w[1], w[1]])
+            .collect(),
+        EdgeCurve::Catmull => iced_nodegraph_sdf::Curve::catmull_rom_beziers(&catmull_route(
+            start, start_side, end, end_side, waypoints,
+        )),
+        EdgeCurve::BezierCubic => {
+            let dir_from = pin_side_direction(start_side);
+            let dir_to = pin_side_direction(end_side);
+            let l = adaptive_bezier_length(start, end);
+            let c1 = [start[0] + dir_from[0] * l, start[1] + dir_from[1] * l];
+            let c2 = [end[0] + dir_to[0] * l, end[1] + dir_to[1] * l];
+            vec![[start, c1, c2, end]]
+        }
+    }
+}
+
+/// Box `[min_x, min_y, max_x, max_y]` around every span point and control
+/// point of [`edge_spans`]; each span stays inside its control points, so the
+/// drawn curve stays inside the box (before its stroke width).
+fn edge_bounds(
+    start: [f32; 2],
+    start_side: u32,
+    end: [f32; 2],
+    end_side: u32,
+    curve: EdgeCurve,
+    stub: f32,
+    waypoints: &[[f32; 2]],
+) -> [f32; 4] {
+    edge_spans(start, start_side, end, end_side, curve, stub, waypoints)
+        .iter()
+        .flatten()
+        .fold(
+            [start[0], start[1], start[0], start[1]],
+            |[x0, y0, x1, y1], p| [x0.min(p[0]), y0.min(p[1]), x1.max(p[0]), y1.max(p[1])],
+        )
+}
+
+impl EdgeCurve {
+    /// Point at `t` along the curve an edge of this type draws between a pin
+    /// at `from` and one at `to`, each given with the side it sits on (which
+    /// sets the direction the curve leaves or enters it).
+    ///
+    /// `t` runs from 0 at `from` to 1 at `to` (clamped) and is split evenly
+    /// across the curve's pieces, so it is not proportional to arc length.
+    /// This is the plain pin-to-pin curve: no stubs or waypoints. Together
+    /// with [`distance_to`](Self::distance_to) it lets a host build its own
+    /// edge interactions, such as labels that follow an edge.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::{EdgeCurve, PinSide};
+    /// use iced_nodegraph::iced::Point;
+    ///
+    /// let from = (Point::new(0.0, 0.0), PinSide::Right);
+    /// let to = (Point::new(200.0, 100.0), PinSide::Left);
+    /// assert_eq!(EdgeCurve::Line.sample(0.5, from, to), Point::new(100.0, 50.0));
+    /// assert_eq!(EdgeCurve::BezierCubic.sample(1.0, from, to), to.0);
+    /// ```
+    pub fn sample(self, t: f32, from: (Point, PinSide), to: (Point, PinSide)) -> Point {
+        let spans = self.spans(from, to);
+        let scaled = t.clamp(0.0, 1.0) * spans.len() as f32;
+        let index = (scaled as usize).min(spans.len() - 1);
+        let t = scaled - index as f32;
+        match spans[index] {
+            // A straight span is a degenerate cubic; interpolated linearly so
+            // `t` moves evenly along it.
+            [p0, p1, p2, p3] if p0 == p1 && p2 == p3 => {
+                Point::new(p0.x + (p3.x - p0.x) * t, p0.y + (p3.y - p0.y) * t)
+            }
+            span => cubic_point(span, t),
+        }
+    }
+
+    /// Shortest distance from `point` to the curve an edge of this type
+    /// draws between `from` and `to`, as for [`sample`](Self::sample). The
+    /// widget's own edge hit tests (cutting, hover, splitting) measure the
+    /// same way.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::{EdgeCurve, PinSide};
+    /// use iced_nodegraph::iced::Point;
+    ///
+    /// let from = (Point::new(0.0, 0.0), PinSide::Right);
+    /// let to = (Point::new(200.0, 0.0), PinSide::Left);
+    /// let d = EdgeCurve::Line.distance_to(Point::new(50.0, 8.0), from, to);
+    /// assert!((d - 8.0).abs() < 1e-3);
+    /// ```
+    pub fn distance_to(self, point: Point, from: (Point, PinSide), to: (Point, PinSide)) -> f32 {
+        self.spans(from, to)
+            .into_iter()
+            .map(|[p0, p1, p2, p3]| point_to_bezier_distance(point, p0, p1, p2, p3))
+            .fold(f32::INFINITY, f32::min)
+    }
+
+    /// Whether `point` lies within `tolerance` of the curve between `from`
+    /// and `to`: [`distance_to`](Self::distance_to) as a hit test.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::{EdgeCurve, PinSide};
+    /// use iced_nodegraph::iced::Point;
+    ///
+    /// let from = (Point::new(0.0, 0.0), PinSide::Right);
+    /// let to = (Point::new(200.0, 0.0), PinSide::Left);
+    /// assert!(EdgeCurve::BezierCubic.contains_point(Point::new(100.0, 4.0), from, to, 5.0));
+    /// assert!(!EdgeCurve::BezierCubic.contains_point(Point::new(100.0, 40.0), from, to, 5.0));
+    /// ```
+    pub fn contains_point(
+        self,
+        point: Point,
+        from: (Point, PinSide),
+        to: (Point, PinSide),
+        tolerance: f32,
+    ) -> bool {
+        self.distance_to(point, from, to) <= tolerance
+    }
+
+    /// Cubic spans of the plain pin-to-pin curve, as [`edge_spans`] builds
+    /// them for drawing.
+    fn spans(self, from: (Point, PinSide), to: (Point, PinSide)) -> Vec<[Point; 4]> {
+        let ((start, start_side), (end, end_side)) = (from, to);
+        edge_spans(
+            [start.x, start.y],
+            start_side.into(),
+            [end.x, end.y],
+            end_side.into(),
+            self,
+            0.0,
+            &[],
+        )
+        .into_iter()
+        .map(|span| span.map(|[x, y]| Point::new(x, y)))
+        .collect()
+    }
+}
+
+/// Point at `t` on the cubic bezier `[p0, p1, p2, p3]`.
+fn cubic_point([p0, p1, p2, p3]: [Point; 4], t: f32) -> Point {
+    let it = 1.0 - t;
+    let a = it * it * it;
+    let b = 3.0 * it * it * t;
+    let c = 3.0 * it * t * t;
+    let d = t * t * t;
+    Point::new(
+        a * p0.x + b * p1.x + c * p2.x + d * p3.x,
+        a * p0.y + b * p1.y + c * p2.y + d * p3.y,
+    )
+}
+
+/// Minimum distance from a point to a cubic bezier, via uniform flattening.
+///
+/// 32 segments keep the flattening error far below the 10px cut threshold
+/// for edge-scale curves; no allocation.
+fn point_to_bezier_distance(point: Point, p0: Point, p1: Point, p2: Point, p3: Point) -> f32 {
+    const SEGMENTS: u32 = 32;
+    let mut prev = p0;
+    let mut min_dist = f32::MAX;
+    for i in 1..=SEGMENTS {
+        let t = i as f32 / SEGMENTS as f32;
+        let cur = cubic_point([p0, p1, p2, p3], t);
+        min_dist = min_dist.min(point_to_line_distance(point, prev, cur));
+        prev = cur;
+    }
+    min_dist
+}
+
+/// Calculates the distance from a point to a line segment
+fn point_to_line_distance(point: Point, line_start: Point, line_end: Point) -> f32 {
+    let dx = line_end.x - line_start.x;
+    let dy = line_end.y - line_start.y;
+    let line_length_sq = dx * dx + dy * dy;
+
+    if line_length_sq < 0.001 {
+        // Line segment is essentially a point
+        return ((point.x - line_start.x).powi(2) + (point.y - line_start.y).powi(2)).sqrt();
+    }
+
+    // Calculate projection of point onto line
+    let t = ((point.x - line_start.x) * dx + (point.y - line_start.y) * dy) / line_length_sq;
+    let t = t.clamp(0.0, 1.0);
+
+    // Find closest point on line segment
+    let closest_x = line_start.x + t * dx;
+    let closest_y = line_start.y + t * dy;
+
+    // Return distance from point to closest point on line
+    ((point.x - closest_x).powi(2) + (point.y - closest_y).powi(2)).sqrt()
+}
+
+/// A draggable handle on an editable edge; see
+/// [`NodeGraph::editable_edges`](crate::NodeGraph::editable_edges).
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum EdgeHandle {
+    /// Moves the waypoint at this index.
+    Waypoint(usize),
+    /// Inserts a new waypoint at this index, between two neighbouring points
+    /// of the route.
+    Insert(usize),
+}
+
+/// Waypoints an edge's curve actually passes through: a bezier ignores them,
+/// so reshaping it starts from none.
+fn reshapable_waypoints(curve: EdgeCurve, waypoints: &[[f32; 2]]) -> &[[f32; 2]] {
+    match curve {
+        EdgeCurve::BezierCubic => &[],
+        EdgeCurve::Line | EdgeCurve::Catmull => waypoints,
+    }
+}
+
+/// Handles of an editable edge with their positions: one per waypoint, then
+/// one insert handle halfway along each stretch between the pins and
+/// waypoints. Waypoint handles come first so they win overlapping hit tests.
+fn edge_handles(
+    start: [f32; 2],
+    start_side: u32,
+    end: [f32; 2],
+    end_side: u32,
+    curve: EdgeCurve,
+    stub: f32,
+    waypoints: &[[f32; 2]],
+) -> Vec<(EdgeHandle, [f32; 2])> {
+    let waypoints = reshapable_waypoints(curve, waypoints);
+    let spans = edge_spans(start, start_side, end, end_side, curve, stub, waypoints);
+    // A stub and a Catmull lead-out each add a span before the first
+    // waypoint, so stretch `g` is span `g + skip`.
+    let skip = usize::from(clamped_stub(start, end, stub) > 0.0)
+        + usize::from(curve == EdgeCurve::Catmull);
+    let midpoint = |[p0, p1, p2, p3]: [[f32; 2]; 4]| {
+        let mid = |i: usize| 0.125 * (p0[i] + 3.0 * p1[i] + 3.0 * p2[i] + p3[i]);
+        [mid(0), mid(1)]
+    };
+
+    let mut handles: Vec<(EdgeHandle, [f32; 2])> = waypoints
+        .iter()
+        .enumerate()
+        .map(|(i, &p)| (EdgeHandle::Waypoint(i), p))
+        .collect();
+    handles.extend((0..=waypoints.len()).filter_map(|g| {
+        spans
+            .get(g + skip)
+            .map(|&span| (EdgeHandle::Insert(g), midpoint(span)))
+    }));
+    handles
+}
+
 /// Returns the tangent direction vector for a pin side in the shader's `u32`
 /// side encoding (matches `get_pin_direction` in the WGSL).
 /// Left=(-1,0), Right=(1,0), Top=(0,-1), Bottom=(0,1); anything else (Row,
@@ -84,14 +428,26 @@ where
     P: PinId + 'static,
     E: EdgeId + 'static,
     UI: Clone + 'static,
-    Renderer: iced_wgpu::core::renderer::Renderer + iced_wgpu::primitive::Renderer,
+    Renderer: iced_wgpu::core::renderer::Renderer
+        + iced_wgpu::primitive::Renderer
+        + iced_wgpu::core::text::Renderer<Font = iced::Font>,
 {
     fn tag(&self) -> tree::Tag {
         tree::Tag::of::<NodeGraphState>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(NodeGraphState::default())
+        // Seed the camera so the first frame, drawn before any update has
+        // synced `view()`, already shows the restored view. Marking the view
+        // synced keeps that first update from re-applying it.
+        let mut state = NodeGraphState::default();
+        if let Some((position, zoom)) = self.starting_camera() {
+            state.camera =
+                Camera2D::with_zoom_and_position(zoom, WorldPoint::new(position.x, position.y));
+            state.last_synced_view = self.view_value();
+        }
+        state.node_ids = NodeIds::new(self.node_ids().cloned().collect::<Vec<N>>());
+        tree::State::new(state)
     }
 
     fn size(&self) -> Size<Length> {
@@ -109,14 +465,52 @@ where
         // Use loose limits for nodes so they can shrink-to-fit their content
         // This prevents Length::Fill children from expanding to full graph size
         let node_limits = layout::Limits::new(Size::ZERO, Size::INFINITE);
+        // Screen-fixed nodes are placed from the current camera: their anchor
+        // resolves in widget-local screen space, which maps back to world as
+        // `screen / zoom - camera_position`. `update` re-lays out whenever the
+        // camera moves so they stay put.
+        let camera = tree.state.downcast_ref::<NodeGraphState>().camera;
+        let (zoom, camera_position) = (camera.zoom(), camera.position());
+        // Debug builds report an edge that is never drawn once per stretch
+        // of frames it stays broken. Edges may be pushed before their nodes,
+        // so it is checked here, against the whole built graph.
+        if cfg!(debug_assertions) {
+            let error = self.unresolved_edge(&tree.children);
+            let state = tree.state.downcast_mut::<NodeGraphState>();
+            if let Some(error) = &error
+                && !state.edge_error_reported
+            {
+                eprintln!("iced_nodegraph: edge not drawn: {error}");
+            }
+            state.edge_error_reported = error.is_some();
+        }
+        let screen_fixed = &self.screen_fixed;
+        let transforms = &self.transforms;
         let nodes = self
-            .elements_iter_mut()
+            .nodes
+            .iter_mut()
             .zip(&mut tree.children)
-            .map(|((position, element), node_tree)| {
-                element
+            .enumerate()
+            .map(|(index, ((_, position, element, _, _), node_tree))| {
+                let node = element
                     .as_widget_mut()
-                    .layout(node_tree, renderer, &node_limits)
-                    .move_to(position)
+                    .layout(node_tree, renderer, &node_limits);
+                let node = if transforms.get(&index).is_some_and(|t| t.collapsed) {
+                    collapse_to_title(node)
+                } else {
+                    node
+                };
+                let position = match screen_fixed.get(&index) {
+                    Some(anchor) => {
+                        let screen = anchor.resolve(size, node.size() * zoom);
+                        Point::new(
+                            screen.x / zoom - camera_position.x,
+                            screen.y / zoom - camera_position.y,
+                        )
+                    }
+                    None => *position,
+                };
+                node.move_to(position)
             })
             .collect();
         layout::Node::with_children(size, nodes)
@@ -146,6 +540,35 @@ where
     }
 
     fn diff(&self, tree: &mut Tree) {
+        // Nodes are matched across rebuilds by id, not push position: when
+        // the host reorders or filters its nodes, each node's content state
+        // and interaction state move to wherever the node now sits.
+        let state = tree.state.downcast_mut::<NodeGraphState>();
+        let old_ids = state.node_ids.get::<N>();
+        if !self.node_ids().eq(old_ids) {
+            let old_index: HashMap<&N, usize> = old_ids
+                .iter()
+                .enumerate()
+                .map(|(index, id)| (id, index))
+                .collect();
+            let moved_from: Vec<Option<usize>> = self
+                .node_ids()
+                .map(|id| old_index.get(id).copied())
+                .collect();
+            state.follow_nodes(&moved_from);
+            state.node_ids = NodeIds::new(self.node_ids().cloned().collect::<Vec<N>>());
+            let mut old_children: Vec<Option<Tree>> = std::mem::take(&mut tree.children)
+                .into_iter()
+                .map(Some)
+                .collect();
+            tree.children = moved_from
+                .iter()
+                .map(|old| {
+                    old.and_then(|old| old_children.get_mut(old)?.take())
+                        .unwrap_or_else(Tree::empty)
+                })
+                .collect();
+        }
         let children: Vec<&Element<'_, Message, iced::Theme, Renderer>> =
             self.elements_iter().map(|(_, e)| e).collect();
         tree.diff_children(&children);
@@ -230,6 +653,42 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) {
+        if let Some(handler) = self.on_node_resized_handler() {
+            // Sizes only change through `layout`, which runs between events,
+            // so comparing on entry reports each resize once.
+            let state = tree.state.downcast_mut::<NodeGraphState>();
+            let node_count = layout.children().count();
+            for (index, node_layout) in layout.children().enumerate() {
+                let size = node_layout.bounds().size();
+                match state.node_sizes.get_mut(index) {
+                    Some(Some(seen)) if *seen != size => {
+                        *seen = size;
+                        if let Some(id) = self.index_to_node_id(index) {
+                            shell.publish(handler(id, size));
+                        }
+                    }
+                    Some(Some(_)) => {}
+                    Some(unmeasured) => *unmeasured = Some(size),
+                    None => state.node_sizes.push(Some(size)),
+                }
+            }
+            state.node_sizes.truncate(node_count);
+        }
+        if let Some(handler) = self.on_graph_changed_handler() {
+            // Like sizes, the node and edge sets only change between events
+            // (the host rebuilds the graph), so each change is seen once.
+            let key = self.topology_key();
+            let state = tree.state.downcast_mut::<NodeGraphState>();
+            if state
+                .topology_key
+                .replace(key)
+                .is_some_and(|seen| seen != key)
+            {
+                shell.publish(handler(&self.topology()));
+            }
+        }
+        let camera_before = tree.state.downcast_ref::<NodeGraphState>().camera;
+        let connection_before = tree.state.downcast_ref::<NodeGraphState>().connection_key();
         self.update_impl(
             tree,
             event,
@@ -240,17 +699,83 @@ where
             shell,
             viewport,
         );
+        // Screen-fixed nodes take their world position from the camera in
+        // `layout`, so any pan or zoom has to re-run it.
+        let camera_after = tree.state.downcast_ref::<NodeGraphState>().camera;
+        if !self.screen_fixed.is_empty()
+            && (camera_before.position() != camera_after.position()
+                || camera_before.zoom() != camera_after.zoom())
+        {
+            shell.invalidate_layout();
+        }
+
+        let state = tree.state.downcast_mut::<NodeGraphState>();
+        if let Some(handler) = self.on_zoom_change_handler() {
+            // Report only a zoom at rest: an elastic overshoot keeps
+            // `zoom_bounce` set until the frame it lands back in range.
+            let zoom = camera_after.zoom();
+            if zoom != camera_before.zoom() && state.zoom_bounce.is_none() {
+                shell.publish(handler(zoom));
+            }
+        }
+        if let Some(handler) = self.on_viewport_change_handler() {
+            let bounds = camera_after.visible_world_bounds(layout.bounds().size());
+            if state.viewport_bounds.replace(bounds) != Some(bounds) {
+                shell.publish(handler(bounds));
+            }
+        }
+        if let Some(handler) = self.on_visible_nodes_handler() {
+            let visible = self.visible_node_rects(state, layout, screen_cursor);
+            if visible != state.visible_nodes {
+                let ids = visible
+                    .iter()
+                    .filter_map(|&(index, rect)| Some((self.index_to_node_id(index)?, rect)))
+                    .collect();
+                shell.publish(handler(ids));
+                state.visible_nodes = visible;
+            }
+        }
+        if let Some(handler) = self.on_edge_hover_handler()
+            && let Event::Mouse(_) = event
+        {
+            let hovered = match state.dragging {
+                Dragging::None => self.edge_at_screen(tree, layout, screen_cursor),
+                _ => None,
+            };
+            let state = tree.state.downcast_mut::<NodeGraphState>();
+            if hovered != state.hovered_edge {
+                state.hovered_edge = hovered;
+                shell.publish(handler(hovered.and_then(|index| {
+                    let (_, from, to, _) = self.edges.get(index)?;
+                    Some((from.clone(), to.clone()))
+                })));
+            }
+        }
+        let state = tree.state.downcast_mut::<NodeGraphState>();
+        if state.connection_key().is_none() {
+            state.rerouting = None;
+        }
+        let connection_after = state.connection_key();
+        if connection_after != connection_before
+            && let Some(handler) = self.on_connection_state_change_handler()
+        {
+            shell.publish(handler(self.connection_state(
+                tree,
+                layout,
+                connection_after,
+            )));
+        }
     }
 
     fn mouse_interaction(
         &self,
-        _tree: &Tree,
-        _layout: Layout<'_>,
-        _cursor: mouse::Cursor,
-        _viewport: &Rectangle,
-        _renderer: &Renderer,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
     ) -> mouse::Interaction {
-        mouse::Interaction::default()
+        self.mouse_interaction_impl(tree, layout, cursor, viewport, renderer)
     }
 }
 
@@ -262,7 +787,10 @@ where
     P: PinId + 'static,
     E: EdgeId + 'static,
     UI: Clone + 'static,
-    Renderer: iced_wgpu::core::renderer::Renderer + 'a + iced_wgpu::primitive::Renderer,
+    Renderer: iced_wgpu::core::renderer::Renderer
+        + 'a
+        + iced_wgpu::primitive::Renderer
+        + iced_wgpu::core::text::Renderer<Font = iced::Font>,
     Message: 'static,
 {
     fn from(graph: NodeGraph<'a, N, P, UI, Message, iced::Theme, Renderer, E>) -> Self {
@@ -296,6 +824,106 @@ fn find_pins<'a, P: 'static, UI: 'static>(
     flat
 }
 
+/// [`find_pins`] for a node placed by `transform`: every pin position is
+/// moved onto the outline, then mirrored and turned about the node's centre
+/// with it.
+fn find_pins_placed<'a, P: 'static, UI: 'static>(
+    tree: &'a Tree,
+    layout: Layout<'a>,
+    transform: NodeTransform,
+) -> Vec<(usize, &'a NodePinState<P, UI>, (Point, Point))> {
+    let mut pins = find_pins::<P, UI>(tree, layout);
+    if transform != NodeTransform::default() {
+        let center = layout.bounds().center();
+        for (_, state, (a, b)) in &mut pins {
+            if transform.collapsed {
+                *a = stack_pin(*a, state.side, center);
+                *b = stack_pin(*b, state.side, center);
+            }
+            *a = transform.shape.attach(*a, state.side, layout.bounds());
+            *b = transform.shape.attach(*b, state.side, layout.bounds());
+            *a = place_pin(*a, center, transform);
+            *b = place_pin(*b, center, transform);
+        }
+    }
+    pins
+}
+
+/// Shrinks a node's content layout to its title row for
+/// [`Node::collapsed`](super::Node::collapsed). Single-child wrappers
+/// (containers, padding) are looked through to the first layout with several
+/// children - typically the column of header and body - whose first child
+/// ends the title. Content without such a column keeps its full height. The
+/// children keep their places; drawing clips them to the shorter body.
+fn collapse_to_title(node: layout::Node) -> layout::Node {
+    let mut top = 0.0;
+    let mut current = &node;
+    let title_bottom = loop {
+        match current.children() {
+            [only] => {
+                top += only.bounds().y;
+                current = only;
+            }
+            [first, _, ..] => break Some(top + first.bounds().y + first.bounds().height),
+            [] => break None,
+        }
+    };
+    match title_bottom {
+        Some(height) if height < node.size().height => {
+            let size = Size::new(node.size().width, height);
+            layout::Node::with_children(size, node.children().to_vec())
+                .move_to(node.bounds().position())
+        }
+        _ => node,
+    }
+}
+
+/// Where a pin laid out at `point` renders on a node centred on `center` and
+/// placed by `transform`: mirrored first, then turned.
+fn place_pin(point: Point, center: Point, transform: NodeTransform) -> Point {
+    let point = if transform.flip_horizontal {
+        Point::new(2.0 * center.x - point.x, point.y)
+    } else {
+        point
+    };
+    if transform.rotation == 0.0 {
+        return point;
+    }
+    rotate_about(point, center, transform.rotation)
+}
+
+/// Moves a side pin of a collapsed node onto its title row, the middle of
+/// the collapsed body centred on `center`. Top and bottom pins stay on their
+/// border.
+fn stack_pin(point: Point, side: PinSide, center: Point) -> Point {
+    match side {
+        PinSide::Left | PinSide::Right | PinSide::Row => Point::new(point.x, center.y),
+        PinSide::Top | PinSide::Bottom => point,
+    }
+}
+
+/// Turns `point` by `angle` radians about `center` (clockwise on screen,
+/// where y points down).
+fn rotate_about(point: Point, center: Point, angle: f32) -> Point {
+    let (sin, cos) = angle.sin_cos();
+    let (dx, dy) = (point.x - center.x, point.y - center.y);
+    Point::new(
+        center.x + dx * cos - dy * sin,
+        center.y + dx * sin + dy * cos,
+    )
+}
+
+/// Whether `point` lies on a node body laid out at `bounds`, drawn with the
+/// outline of `transform` and turned by its rotation about its centre.
+fn body_contains(bounds: Rectangle, transform: NodeTransform, point: Point) -> bool {
+    let point = if transform.rotation == 0.0 {
+        point
+    } else {
+        rotate_about(point, bounds.center(), -transform.rotation)
+    };
+    transform.shape.contains(bounds, point)
+}
+
 fn inner_find_pins<'a, P: 'static, UI: 'static>(
     flat: &mut Vec<(usize, &'a NodePinState<P, UI>, (Point, Point))>,
     pin_index: &mut usize,
@@ -315,19 +943,68 @@ fn inner_find_pins<'a, P: 'static, UI: 'static>(
     }
 }
 
+/// The `(pin_id, direction)` of every pin in a node's `tree`, in [`find_pins`]
+/// walk order (the widget's positional pin numbering). Needs no layout, so a
+/// freshly built `Tree` answers it before the first frame.
+pub(super) fn declared_pins<P: PinId + 'static, UI: 'static>(
+    tree: &Tree,
+) -> Vec<(P, PinDirection)> {
+    let mut pins = Vec::new();
+    let mut stack = vec![tree];
+    while let Some(tree) = stack.pop() {
+        if tree.tag == tree::Tag::of::<NodePinState<P, UI>>() {
+            let state = tree.state.downcast_ref::<NodePinState<P, UI>>();
+            pins.push((state.pin_id.clone(), state.direction));
+        }
+        stack.extend(tree.children.iter().rev());
+    }
+    pins
+}
+
+/// The state of the pin `pin_id` in a node's `tree`, found without a layout
+/// like [`declared_pins`].
+fn pin_state<'t, P: PinId + 'static, UI: 'static>(
+    tree: &'t Tree,
+    pin_id: &P,
+) -> Option<&'t NodePinState<P, UI>> {
+    let mut stack = vec![tree];
+    while let Some(tree) = stack.pop() {
+        if tree.tag == tree::Tag::of::<NodePinState<P, UI>>() {
+            let state = tree.state.downcast_ref::<NodePinState<P, UI>>();
+            if state.pin_id == *pin_id {
+                return Some(state);
+            }
+        }
+        stack.extend(tree.children.iter().rev());
+    }
+    None
+}
+
+/// Whether a `from -> to` pair runs against the data flow: `to` is an output
+/// and `from` is not, or `from` is an input and `to` a bidirectional pin. Two
+/// `Both` pins (or any already ordered pair) keep their order.
+fn flows_backwards(from_dir: PinDirection, to_dir: PinDirection) -> bool {
+    matches!(
+        (from_dir, to_dir),
+        (
+            PinDirection::Input | PinDirection::Both,
+            PinDirection::Output
+        ) | (PinDirection::Input, PinDirection::Both)
+    )
+}
+
 /// Orients a connected pair so the OUTPUT pin is `from` (output -> input),
 /// independent of which side the drag started on. Mirrors the edge-rendering
 /// normalization (`swap` in `draw`), so the endpoints reported to
 /// `on_connect`/`on_disconnect` match the visual data-flow direction. Order is
-/// only swapped when `from` is a non-output and `to` is an output.
-fn orient_connection<N, P>(
-    from_dir: PinDirection,
-    to_dir: PinDirection,
-    from: PinRef<N, P>,
-    to: PinRef<N, P>,
-) -> (PinRef<N, P>, PinRef<N, P>) {
-    let swap = !matches!(from_dir, PinDirection::Output) && matches!(to_dir, PinDirection::Output);
-    if swap { (to, from) } else { (from, to) }
+/// only swapped when the pair [`flows_backwards`]. The ends are usually
+/// `PinRef`s, or anything carried along with them.
+fn orient_connection<T>(from_dir: PinDirection, to_dir: PinDirection, from: T, to: T) -> (T, T) {
+    if flows_backwards(from_dir, to_dir) {
+        (to, from)
+    } else {
+        (from, to)
+    }
 }
 
 fn pin_positions<P, UI>(state: &NodePinState<P, UI>, node_bounds: Rectangle) -> (Point, Point) {
@@ -342,6 +1019,35 @@ fn pin_positions<P, UI>(state: &NodePinState<P, UI>, node_bounds: Rectangle) ->
     }
 }
 
+/// Anchor and side an edge end leaves from under
+/// [`auto_pin_side`](NodeGraph::auto_pin_side): of the two borders on the
+/// pin's axis (left/right, or top/bottom for top and bottom pins), the one
+/// facing `toward`, with `anchor` mirrored across the node's `center` when
+/// it moves to the opposite border. Level with `center`, the pin keeps its
+/// own side.
+fn facing_side(anchor: Point, side: PinSide, center: Point, toward: Point) -> (Point, PinSide) {
+    let mirror = |a: f32, c: f32, forward: bool| {
+        if (a > c) == forward { a } else { 2.0 * c - a }
+    };
+    if matches!(side, PinSide::Top | PinSide::Bottom) {
+        if toward.y == center.y {
+            return (anchor, side);
+        }
+        let down = toward.y > center.y;
+        let y = mirror(anchor.y, center.y, down);
+        let side = if down { PinSide::Bottom } else { PinSide::Top };
+        (Point::new(anchor.x, y), side)
+    } else {
+        if toward.x == center.x {
+            return (anchor, side);
+        }
+        let right = toward.x > center.x;
+        let x = mirror(anchor.x, center.x, right);
+        let side = if right { PinSide::Right } else { PinSide::Left };
+        (Point::new(x, anchor.y), side)
+    }
+}
+
 fn pin_position(position: Point, side: PinSide, node_bounds: Rectangle) -> Point {
     match side {
         PinSide::Row => panic!("Row pin is supposed to be handled separately"),
@@ -352,6 +1058,101 @@ fn pin_position(position: Point, side: PinSide, node_bounds: Rectangle) -> Point
     }
 }
 
+#[cfg(test)]
+mod stub_tests {
+    use super::{clamped_stub, edge_bounds, edge_spans};
+    use crate::style::EdgeCurve;
+
+    // Right-side output at the origin, left-side input 200px to the right.
+    const START: [f32; 2] = [0.0, 0.0];
+    const END: [f32; 2] = [200.0, 60.0];
+    const RIGHT: u32 = 1;
+    const LEFT: u32 = 0;
+
+    // Every curve type leaves and enters its pins along a straight stub
+    // perpendicular to the node side, then curves between the stub ends.
+    #[test]
+    fn stubs_lead_straight_out_of_each_pin() {
+        for curve in [EdgeCurve::BezierCubic, EdgeCurve::Line, EdgeCurve::Catmull] {
+            let spans = edge_spans(START, RIGHT, END, LEFT, curve, 20.0, &[[100.0, 90.0]]);
+            let first = spans[0];
+            let last = spans[spans.len() - 1];
+            assert_eq!(first, [START, START, [20.0, 0.0], [20.0, 0.0]], "{curve:?}");
+            assert_eq!(last, [[180.0, 60.0], [180.0, 60.0], END, END], "{curve:?}");
+            assert_eq!(
+                spans[1][0],
+                [20.0, 0.0],
+                "{curve:?} curve starts at the stub"
+            );
+            assert_eq!(spans[spans.len() - 2][3], [180.0, 60.0], "{curve:?}");
+        }
+    }
+
+    // Without a stub the path is the plain curve; close pins shrink the stub so
+    // the two never cross.
+    #[test]
+    fn stub_is_optional_and_clamped_between_close_pins() {
+        let plain = edge_spans(START, RIGHT, END, LEFT, EdgeCurve::BezierCubic, 0.0, &[]);
+        assert_eq!(plain.len(), 1);
+        assert_eq!(plain[0][0], START);
+
+        assert_eq!(clamped_stub(START, [30.0, 0.0], 40.0), 10.0);
+        assert_eq!(clamped_stub(START, START, 40.0), 0.0);
+    }
+
+    // The cull box reaches the bezier's control points past both pins and
+    // every waypoint, not just the two ends.
+    #[test]
+    fn bounds_cover_control_points_and_waypoints() {
+        let bezier = edge_bounds(START, RIGHT, END, LEFT, EdgeCurve::BezierCubic, 0.0, &[]);
+        // Controls 80 out along each side: (80, 0) and (120, 60).
+        assert_eq!(bezier, [0.0, 0.0, 200.0, 60.0]);
+        let bezier = edge_bounds(START, LEFT, END, RIGHT, EdgeCurve::BezierCubic, 0.0, &[]);
+        assert_eq!(bezier, [-80.0, 0.0, 280.0, 60.0]);
+
+        let line = edge_bounds(
+            START,
+            RIGHT,
+            END,
+            LEFT,
+            EdgeCurve::Line,
+            0.0,
+            &[[100.0, -50.0]],
+        );
+        assert_eq!(line, [0.0, -50.0, 200.0, 60.0]);
+    }
+}
+
+#[cfg(test)]
+mod straighten_tests {
+    use super::nearly_aligned;
+
+    const LEFT: u32 = 0;
+    const RIGHT: u32 = 1;
+    const TOP: u32 = 2;
+    const BOTTOM: u32 = 3;
+
+    // Right pin to left pin a few pixels off its row: straightened within
+    // the tolerance, not past it, and never when off means 0.
+    #[test]
+    fn facing_pins_within_tolerance_straighten() {
+        assert!(nearly_aligned([0.0, 0.0], RIGHT, [200.0, 3.0], LEFT, 4.0));
+        assert!(!nearly_aligned([0.0, 0.0], RIGHT, [200.0, 6.0], LEFT, 4.0));
+        assert!(!nearly_aligned([0.0, 0.0], RIGHT, [200.0, 0.0], LEFT, 0.0));
+        // Columns count too, for top/bottom pins.
+        assert!(nearly_aligned([0.0, 0.0], BOTTOM, [2.0, 150.0], TOP, 4.0));
+    }
+
+    // A line through the nodes would not read as intentional: the end must
+    // lie ahead of the start pin and the end pin must face back.
+    #[test]
+    fn pins_facing_away_stay_curved() {
+        assert!(!nearly_aligned([0.0, 0.0], RIGHT, [-200.0, 0.0], LEFT, 4.0));
+        assert!(!nearly_aligned([0.0, 0.0], RIGHT, [200.0, 0.0], RIGHT, 4.0));
+        assert!(!nearly_aligned([0.0, 0.0], RIGHT, [200.0, 0.0], TOP, 4.0));
+    }
+}
+
 #[cfg(test)]
 mod orient_tests {
     use super::orient_connection;
@@ -389,4 +1190,85 @@ mod orient_tests {
         assert_eq!(from, PinRef::new(0, 0));
         assert_eq!(to, PinRef::new(1, 0));
     }
+
+    // A bidirectional pin acts as the output against an input and as the input
+    // against an output, whichever end the drag started from.
+    #[test]
+    fn both_takes_the_free_role_against_a_directed_pin() {
+        let both = PinRef::new(0usize, 0usize);
+        let other = PinRef::new(1usize, 0usize);
+        let (from, _) = orient_connection(PinDirection::Input, PinDirection::Both, other, both);
+        assert_eq!(from, PinRef::new(0, 0));
+        let (from, _) = orient_connection(PinDirection::Both, PinDirection::Input, both, other);
+        assert_eq!(from, PinRef::new(0, 0));
+        let (from, _) = orient_connection(PinDirection::Both, PinDirection::Output, both, other);
+        assert_eq!(from, PinRef::new(1, 0));
+        let (from, _) = orient_connection(PinDirection::Output, PinDirection::Both, other, both);
+        assert_eq!(from, PinRef::new(1, 0));
+    }
+}
+
+#[cfg(test)]
+mod curve_tests {
+    use crate::PinSide;
+    use crate::style::EdgeCurve;
+    use iced::Point;
+
+    // Right-side output at the origin, left-side input down and to the right;
+    // every curve is point-symmetric about the midpoint (100, 50).
+    const FROM: (Point, PinSide) = (Point::new(0.0, 0.0), PinSide::Right);
+    const TO: (Point, PinSide) = (Point::new(200.0, 100.0), PinSide::Left);
+    const CURVES: [EdgeCurve; 3] = [EdgeCurve::BezierCubic, EdgeCurve::Line, EdgeCurve::Catmull];
+
+    fn near(a: Point, b: Point) -> bool {
+        a.distance(b) < 1e-3
+    }
+
+    #[test]
+    fn samples_run_from_pin_to_pin_through_the_midpoint() {
+        for curve in CURVES {
+            assert!(near(curve.sample(0.0, FROM, TO), FROM.0), "{curve:?}");
+            assert!(near(curve.sample(1.0, FROM, TO), TO.0), "{curve:?}");
+            assert!(
+                near(curve.sample(0.5, FROM, TO), Point::new(100.0, 50.0)),
+                "{curve:?}"
+            );
+            // Out-of-range t clamps to the pins.
+            assert!(near(curve.sample(-1.0, FROM, TO), FROM.0), "{curve:?}");
+            assert!(near(curve.sample(2.0, FROM, TO), TO.0), "{curve:?}");
+        }
+    }
+
+    #[test]
+    fn samples_match_each_curve_shape() {
+        // Line: straight interpolation.
+        assert!(near(
+            EdgeCurve::Line.sample(0.25, FROM, TO),
+            Point::new(50.0, 25.0)
+        ));
+        // Bezier: controls 80px out along each side (the adaptive length for
+        // a 224px span), so a quarter along is (27 p0 + 27 c1 + 9 c2 + p3) / 64.
+        assert!(near(
+            EdgeCurve::BezierCubic.sample(0.25, FROM, TO),
+            Point::new(53.75, 15.625)
+        ));
+        // Catmull leaves along the pin side: just past the start it is still
+        // level with the pin while already moving right.
+        let early = EdgeCurve::Catmull.sample(0.01, FROM, TO);
+        assert!(early.x > 0.0 && early.y.abs() < 0.05, "{early:?}");
+    }
+
+    #[test]
+    fn distance_is_zero_on_the_curve_and_grows_off_it() {
+        for curve in CURVES {
+            for t in [0.0, 0.2, 0.5, 0.7, 1.0] {
+                let on = curve.sample(t, FROM, TO);
+                assert!(curve.distance_to(on, FROM, TO) < 0.5, "{curve:?} at {t}");
+            }
+            let d = curve.distance_to(Point::new(100.0, 50.0 + 30.0), FROM, TO);
+            assert!(d > 5.0 && d <= 30.0 + 1e-3, "{curve:?}: {d}");
+        }
+        let off = EdgeCurve::Line.distance_to(Point::new(-30.0, -40.0), FROM, TO);
+        assert!((off - 50.0).abs() < 1e-3);
+    }
 }
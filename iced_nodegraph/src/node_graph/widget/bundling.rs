@@ -0,0 +1,158 @@
+//! Edge bundling: edges running between the same two node clusters are
+//! pulled toward a shared path, so dense cluster-to-cluster wiring reads as
+//! a few strands instead of a tangle.
+//!
+//! The hierarchy is one level deep: nodes are grouped into clusters by the
+//! world-space [`BUNDLE_CLUSTER`] grid cell their centre falls in, and every
+//! edge between the same pair of clusters shares one control path, the
+//! straight line between the group's mean end points. Each bundled edge then
+//! draws through two waypoints on its own straight line, moved toward the
+//! matching points of the shared path by the bundling strength.
+
+use std::collections::HashMap;
+
+/// Side length in world units of the grid cells nodes are clustered by.
+pub(super) const BUNDLE_CLUSTER: f32 = 300.0;
+
+/// Where along an edge (as a fraction of start to end) its two bundling
+/// waypoints sit: the middle half runs with the bundle, the ends fan out
+/// to their pins.
+const BUNDLE_SPAN: [f32; 2] = [0.25, 0.75];
+
+/// Shared control points for each edge, by index like `ends`.
+///
+/// `ends` holds each edge's node centres, `(from, to)` as pushed, or `None`
+/// for an edge that takes no part (routed, or missing a node). Centres are
+/// layout-absolute; the grid is laid from `origin`, the graph's layout
+/// origin, so clusters stay put in world space wherever the widget sits. The
+/// shared paths come back in the same layout space as `ends`. An edge gets
+/// the two points of its cluster pair's shared path, in `from -> to` order,
+/// when at least one other edge joins the same two clusters; lone edges and
+/// edges inside a single cluster get `None`. Edges between the same
+/// clusters bundle whichever way they were pushed.
+pub(super) fn shared_paths(
+    ends: &[Option<([f32; 2], [f32; 2])>],
+    origin: [f32; 2],
+) -> Vec<Option<[[f32; 2]; 2]>> {
+    let cell = |p: [f32; 2]| {
+        (
+            ((p[0] - origin[0]) / BUNDLE_CLUSTER).floor() as i64,
+            ((p[1] - origin[1]) / BUNDLE_CLUSTER).floor() as i64,
+        )
+    };
+
+    // Group by the unordered cluster pair; sums run in the pair's own order.
+    let mut bundles: HashMap<_, (usize, [f32; 2], [f32; 2])> = HashMap::new();
+    let mut keys = Vec::with_capacity(ends.len());
+    for end in ends {
+        let key = end.and_then(|(from, to)| {
+            let (a, b) = (cell(from), cell(to));
+            if a == b {
+                return None;
+            }
+            let (key, from, to) = if a <= b {
+                ((a, b), from, to)
+            } else {
+                ((b, a), to, from)
+            };
+            let entry = bundles.entry(key).or_insert((0, [0.0; 2], [0.0; 2]));
+            entry.0 += 1;
+            entry.1 = [entry.1[0] + from[0], entry.1[1] + from[1]];
+            entry.2 = [entry.2[0] + to[0], entry.2[1] + to[1]];
+            Some((key, a > b))
+        });
+        keys.push(key);
+    }
+
+    keys.into_iter()
+        .map(|key| {
+            let (key, reversed) = key?;
+            let &(count, from_sum, to_sum) = bundles.get(&key)?;
+            if count < 2 {
+                return None;
+            }
+            let n = count as f32;
+            let (mut from, mut to) = (
+                [from_sum[0] / n, from_sum[1] / n],
+                [to_sum[0] / n, to_sum[1] / n],
+            );
+            if reversed {
+                std::mem::swap(&mut from, &mut to);
+            }
+            Some(BUNDLE_SPAN.map(|t| lerp(from, to, t)))
+        })
+        .collect()
+}
+
+/// The two waypoints an edge from `start` to `end` draws through: the points
+/// of its own straight line at [`BUNDLE_SPAN`], moved toward `shared` by
+/// `strength` (0 keeps the straight line, 1 lands on the shared path).
+pub(super) fn bundled_waypoints(
+    start: [f32; 2],
+    end: [f32; 2],
+    shared: [[f32; 2]; 2],
+    strength: f32,
+) -> [[f32; 2]; 2] {
+    [0, 1].map(|i| lerp(lerp(start, end, BUNDLE_SPAN[i]), shared[i], strength))
+}
+
+fn lerp(a: [f32; 2], b: [f32; 2], t: f32) -> [f32; 2] {
+    [a[0] + (b[0] - a[0]) * t, a[1] + (b[1] - a[1]) * t]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edges_between_the_same_clusters_share_a_path() {
+        let ends = [
+            Some(([0.0, 0.0], [600.0, 0.0])),
+            Some(([0.0, 100.0], [600.0, 100.0])),
+            // Pushed the other way round: same bundle, reversed points.
+            Some(([600.0, 200.0], [0.0, 200.0])),
+            // Alone between its clusters, inside one cluster, or left out.
+            Some(([0.0, 0.0], [0.0, 900.0])),
+            Some(([0.0, 0.0], [100.0, 100.0])),
+            None,
+        ];
+        let paths = shared_paths(&ends, [0.0, 0.0]);
+        // Mean ends (0, 100) -> (600, 100).
+        assert_eq!(paths[0], Some([[150.0, 100.0], [450.0, 100.0]]));
+        assert_eq!(paths[1], paths[0]);
+        assert_eq!(paths[2], Some([[450.0, 100.0], [150.0, 100.0]]));
+        assert_eq!(&paths[3..], [None, None, None]);
+    }
+
+    #[test]
+    fn clusters_follow_world_space_not_the_widget_position() {
+        // Two nodes 100 apart in world space share a cell. With the graph
+        // laid out at x = 250 their layout centres straddle x = 300, which
+        // must not split them.
+        let origin = [250.0, 40.0];
+        let at = |x: f32, y: f32| [x + origin[0], y + origin[1]];
+        let ends = [
+            Some((at(0.0, 0.0), at(600.0, 0.0))),
+            Some((at(100.0, 100.0), at(600.0, 100.0))),
+        ];
+        let paths = shared_paths(&ends, origin);
+        // Mean ends (50, 50) -> (600, 50) in world, shifted back to layout.
+        assert_eq!(paths[0], Some([at(187.5, 50.0), at(462.5, 50.0)]));
+        assert_eq!(paths[1], paths[0]);
+    }
+
+    #[test]
+    fn strength_blends_between_own_line_and_shared_path() {
+        let shared = [[150.0, 100.0], [450.0, 100.0]];
+        let (start, end) = ([0.0, 0.0], [600.0, 0.0]);
+        assert_eq!(
+            bundled_waypoints(start, end, shared, 0.0),
+            [[150.0, 0.0], [450.0, 0.0]]
+        );
+        assert_eq!(bundled_waypoints(start, end, shared, 1.0), shared);
+        assert_eq!(
+            bundled_waypoints(start, end, shared, 0.5),
+            [[150.0, 50.0], [450.0, 50.0]]
+        );
+    }
+}
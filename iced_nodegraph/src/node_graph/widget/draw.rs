@@ -4,10 +4,33 @@
 //! rendering-layer overview.
 
 use super::*;
+use crate::style::{Badge, BadgeCorner};
+use iced::Transformation;
+use std::collections::HashMap;
 
 /// Line width for the edge cutting overlay (in world-space pixels).
 const EDGE_CUT_LINE_WIDTH: f32 = 3.0;
 
+/// Screen radius of a plain (count-less) node badge.
+const BADGE_DOT_RADIUS: f32 = 5.0;
+/// Screen radius of a node badge holding a count.
+const BADGE_COUNT_RADIUS: f32 = 8.0;
+/// Screen gap between a badge and its node's edges.
+const BADGE_INSET: f32 = 3.0;
+/// Screen radius of a pin's connection count label.
+const PIN_COUNT_RADIUS: f32 = 7.0;
+/// Screen distance, along and across the pin's side, from a pin to its count.
+const PIN_COUNT_OFFSET: f32 = 10.0;
+/// Screen radius of an editable edge's waypoint handle.
+const EDGE_HANDLE_RADIUS: f32 = 5.0;
+/// Screen radius of an editable edge's insert handle.
+const EDGE_INSERT_HANDLE_RADIUS: f32 = 3.5;
+/// Screen margin an edge's bounds keep around the viewport before the edge
+/// is culled.
+const EDGE_CULL_MARGIN: f32 = 16.0;
+/// Scale a node's appear animation grows from.
+const APPEAR_START_SCALE: f32 = 0.8;
+
 /// Convert a world-space bounding box to screen-space bounds for SdfPrimitive.
 ///
 /// Formula: screen = (world + camera_position) * zoom
@@ -28,6 +51,16 @@ fn clipped_shape_bounds(b: [f32; 4], clip: Rectangle) -> Option<Rectangle> {
     ))
 }
 
+/// Centre-origin SDF outline of a node body of `size` drawn as `shape`.
+fn body_outline(shape: NodeShape, size: Size, corner_radius: f32) -> Shape {
+    match shape {
+        NodeShape::Rect => Shape::rounded_box([size.width, size.height], [0.0; 4]),
+        NodeShape::RoundedRect => Shape::rounded_box([size.width, size.height], [corner_radius; 4]),
+        NodeShape::Circle => Shape::circle(size.width.min(size.height) * 0.5),
+        NodeShape::Hexagon => Shape::hexagon([size.width, size.height]),
+    }
+}
+
 /// Camera offset for an SDF layer drawn into the sub-rectangle `clip`.
 ///
 /// The shader uses `clip` as its `bounds_origin`, so the world->screen mapping
@@ -45,6 +78,29 @@ fn layer_camera(
     (cx, cy)
 }
 
+/// Camera position and zoom that draw layout-absolute geometry scaled by
+/// `scale` about `center`, so a node's SDF batches can shrink in place
+/// without rebuilding their shapes.
+///
+/// Solves `(a + camera) * zoom + origin * (1 - zoom)` for the camera that
+/// maps `center` to the same screen point at `zoom * scale`.
+fn scaled_camera(ctx: &RenderContext, center: [f32; 2], scale: f32) -> (WorldPoint, f32) {
+    let zoom = ctx.camera_zoom;
+    let scaled = zoom * scale;
+    let origin = ctx.viewport_origin;
+    let solve = |c: f32, camera: f32, o: f32| {
+        let screen = (c + camera) * zoom + o * (1.0 - zoom);
+        (screen - o * (1.0 - scaled)) / scaled - c
+    };
+    (
+        WorldPoint::new(
+            solve(center[0], ctx.camera_position.x, origin.x),
+            solve(center[1], ctx.camera_position.y, origin.y),
+        ),
+        scaled,
+    )
+}
+
 /// Submits an SDF primitive and records whether it animates into `animated`.
 ///
 /// Routing every primitive through one boundary keeps the on-demand redraw flag
@@ -101,22 +157,204 @@ fn world_bbox_to_screen_bounds(
     ]
 }
 
-/// Construct the open `Shape` for an edge based on curve type and pin sides. The
-/// geometry is world-space (edges are ephemeral, never deduped), so callers push
-/// it with a zero placement.
+/// Draws a node status badge inside the given corner of a node body laid
+/// out at `body` and turned by `rotation` about its centre.
+///
+/// The badge keeps a constant screen size, shrinking only when the node itself
+/// is too small on screen to hold it. It turns with the body, so the corner is
+/// the body's own, and is clipped to the box around the turned body so it never
+/// paints past it (or the graph).
+fn draw_badge<Renderer>(
+    renderer: &mut Renderer,
+    animated: &std::cell::Cell<bool>,
+    badge: &Badge,
+    body: Rectangle,
+    rotation: f32,
+    ctx: &RenderContext,
+    graph_bounds: Rectangle,
+) where
+    Renderer: iced_wgpu::primitive::Renderer + iced_wgpu::core::text::Renderer<Font = iced::Font>,
+{
+    let zoom = ctx.camera_zoom;
+    let body_center = body.center();
+    let (sin, cos) = (rotation.sin().abs(), rotation.cos().abs());
+    let (half_w, half_h) = (
+        (body.width * cos + body.height * sin) * 0.5,
+        (body.width * sin + body.height * cos) * 0.5,
+    );
+    let node_bb = world_bbox_to_screen_bounds(
+        body_center.x - half_w,
+        body_center.y - half_h,
+        body_center.x + half_w,
+        body_center.y + half_h,
+        0.0,
+        ctx,
+    );
+    let Some(node_clip) = clipped_shape_bounds(node_bb, graph_bounds) else {
+        return;
+    };
+
+    let nominal = if badge.count.is_some() {
+        BADGE_COUNT_RADIUS
+    } else {
+        BADGE_DOT_RADIUS
+    };
+    let radius = nominal.min(body.width.min(body.height) * zoom * 0.5 - BADGE_INSET);
+    if radius < 1.0 {
+        return;
+    }
+
+    // Place in world space so the SDF camera maps it like every other layer.
+    let reach = (radius + BADGE_INSET) / zoom;
+    let (left, top) = (body.x + reach, body.y + reach);
+    let (right, bottom) = (body.x + body.width - reach, body.y + body.height - reach);
+    let corner = match badge.corner {
+        BadgeCorner::TopLeft => Point::new(left, top),
+        BadgeCorner::TopRight => Point::new(right, top),
+        BadgeCorner::BottomLeft => Point::new(left, bottom),
+        BadgeCorner::BottomRight => Point::new(right, bottom),
+    };
+    let corner = if rotation == 0.0 {
+        corner
+    } else {
+        rotate_about(corner, body_center, rotation)
+    };
+    let center = [corner.x, corner.y];
+
+    // The number only goes in when the badge is near full size; a shrunken
+    // counter degrades to a dot rather than unreadable glyphs.
+    let count = badge.count.filter(|_| radius >= BADGE_COUNT_RADIUS * 0.75);
+    draw_counter(
+        renderer,
+        animated,
+        center,
+        radius,
+        badge.color,
+        count,
+        ctx,
+        graph_bounds,
+        node_clip,
+    );
+}
+
+/// Draw a filled counter circle of screen `radius` centred on world
+/// `center`, with `count` (if any) written inside, clipped to `clip_to`.
+#[allow(clippy::too_many_arguments)]
+fn draw_counter<Renderer>(
+    renderer: &mut Renderer,
+    animated: &std::cell::Cell<bool>,
+    center: [f32; 2],
+    radius: f32,
+    color: iced::Color,
+    count: Option<u32>,
+    ctx: &RenderContext,
+    graph_bounds: Rectangle,
+    clip_to: Rectangle,
+) where
+    Renderer: iced_wgpu::primitive::Renderer + iced_wgpu::core::text::Renderer<Font = iced::Font>,
+{
+    use iced_wgpu::core::text::{self, LineHeight, Shaping, Text, Wrapping};
+
+    let zoom = ctx.camera_zoom;
+    let pad = radius / zoom + 2.0 / zoom;
+    let bb = world_bbox_to_screen_bounds(
+        center[0] - pad,
+        center[1] - pad,
+        center[0] + pad,
+        center[1] + pad,
+        0.0,
+        ctx,
+    );
+    let Some(clip) = clipped_shape_bounds(bb, clip_to) else {
+        return;
+    };
+
+    let mut batch = SdfPrimitive::with_capacity(1);
+    batch.push(&Shape::circle(radius / zoom), &Style::solid(color), center);
+    let (cx, cy) = layer_camera(ctx.camera_position, zoom, graph_bounds.position(), clip);
+    renderer.with_layer(graph_bounds, |renderer| {
+        draw_sdf(
+            renderer,
+            animated,
+            clip,
+            batch
+                .camera(cx, cy, zoom)
+                .time(ctx.time)
+                .aa_width(ctx.aa_width)
+                .pixel_snap(ctx.pixel_snap),
+        );
+    });
+
+    let Some(count) = count else {
+        return;
+    };
+    let label = if count > 99 {
+        "99+".to_string()
+    } else {
+        count.to_string()
+    };
+    // Light text on dark counters, dark text on light ones.
+    let c = color;
+    let luminance = 0.299 * c.r + 0.587 * c.g + 0.114 * c.b;
+    let text_color = if luminance > 0.6 {
+        iced::Color::BLACK
+    } else {
+        iced::Color::WHITE
+    };
+    let size = if label.len() > 2 {
+        radius
+    } else {
+        radius * 1.3
+    };
+    renderer.with_layer(clip, |renderer| {
+        renderer.fill_text(
+            Text {
+                content: label,
+                bounds: Size::new(radius * 4.0, radius * 2.0),
+                size: size.into(),
+                line_height: LineHeight::default(),
+                font: renderer.default_font(),
+                align_x: text::Alignment::Center,
+                align_y: iced::alignment::Vertical::Center,
+                shaping: Shaping::Basic,
+                wrapping: Wrapping::None,
+            },
+            Point::new(bb[0] + bb[2] * 0.5, bb[1] + bb[3] * 0.5),
+            text_color,
+            clip,
+        );
+    });
+}
+
+/// Construct the open `Shape` for an edge based on curve type, pin sides,
+/// stub length and waypoints. The geometry is world-space (edges are
+/// ephemeral, never deduped), so callers push it with a zero placement.
 fn edge_shape(
     start: &WorldPoint,
     end: &WorldPoint,
     start_side: u32,
     end_side: u32,
     curve: &crate::style::EdgeCurve,
+    stub: f32,
+    waypoints: &[WorldPoint],
 ) -> Shape {
     let p0 = [start.x, start.y];
     let p1 = [end.x, end.y];
+    let wps: Vec<[f32; 2]> = waypoints.iter().map(|p| [p.x, p.y]).collect();
 
+    // Stubs mix straight and curved spans, so they draw as one span chain.
+    if stub > 0.0 {
+        return Shape::spans(edge_spans(p0, start_side, p1, end_side, *curve, stub, &wps));
+    }
     match curve {
-        crate::style::EdgeCurve::Line => Shape::line(p0, p1),
-        _ => {
+        crate::style::EdgeCurve::Line if wps.is_empty() => Shape::line(p0, p1),
+        crate::style::EdgeCurve::Line => {
+            Shape::polyline(std::iter::once(p0).chain(wps).chain(std::iter::once(p1)))
+        }
+        crate::style::EdgeCurve::Catmull => {
+            Shape::catmull_rom(catmull_route(p0, start_side, p1, end_side, &wps))
+        }
+        crate::style::EdgeCurve::BezierCubic => {
             // Bezier: compute control points from pin tangent directions
             let dir_from = pin_side_direction(start_side);
             let dir_to = pin_side_direction(end_side);
@@ -138,15 +376,31 @@ fn edge_shapes(
     start_side: u32,
     end_side: u32,
     style: &EdgeStyle,
+    waypoints: &[WorldPoint],
 ) -> (Shape, Shape) {
-    let shape = edge_shape(start, end, start_side, end_side, &style.curve);
+    let shape = edge_shape(
+        start,
+        end,
+        start_side,
+        end_side,
+        &style.curve,
+        style.stub_length,
+        waypoints,
+    );
     let has_shadow = style.shadow_blur > 0.0
         && (style.shadow_color.near_start.a > 0.0 || style.shadow_color.near_end.a > 0.0);
     let shadow_shape = if has_shadow && style.shadow_offset != (0.0, 0.0) {
-        let (ox, oy) = style.shadow_offset;
-        let s_start = WorldPoint::new(start.x + ox, start.y + oy);
-        let s_end = WorldPoint::new(end.x + ox, end.y + oy);
-        edge_shape(&s_start, &s_end, start_side, end_side, &style.curve)
+        let offset = WorldVector::new(style.shadow_offset.0, style.shadow_offset.1);
+        let s_waypoints: Vec<WorldPoint> = waypoints.iter().map(|p| *p + offset).collect();
+        edge_shape(
+            &(*start + offset),
+            &(*end + offset),
+            start_side,
+            end_side,
+            &style.curve,
+            style.stub_length,
+            &s_waypoints,
+        )
     } else {
         shape.clone()
     };
@@ -207,20 +461,32 @@ fn pin_info<'s, P, UI>(state: &'s NodePinState<P, UI>) -> Option<PinInfo<'s, P,
 }
 
 /// Resolves a pin's drawn style: theme base merged with the per-pin overlay,
-/// then the indicator fill color forced to the pin's `color`.
+/// then the indicator fill color forced to the pin's `color`. `high_contrast`
+/// carries the canvas background when that mode is on, and `adjust` applies
+/// the graph's per-pin override before it. Sizes come back in world space for
+/// `zoom`.
+#[allow(clippy::too_many_arguments)]
 fn resolve_pin_style<P: PinId + 'static, UI>(
     pin_style_fn: Option<&PinStyleFn<'_, P, UI, Theme>>,
     state: &NodePinState<P, UI>,
     other: Option<&NodePinState<P, UI>>,
     theme: &Theme,
     status: PinStatus,
+    high_contrast: Option<iced::Color>,
+    zoom: f32,
+    adjust: impl FnOnce(PinStyle) -> PinStyle,
 ) -> PinStyle {
-    if let (Some(f), Some(this)) = (pin_style_fn, pin_info::<P, UI>(state)) {
+    let style = if let (Some(f), Some(this)) = (pin_style_fn, pin_info::<P, UI>(state)) {
         let other_info = other.and_then(pin_info::<P, UI>);
         f(theme, &this, other_info.as_ref(), status)
     } else {
-        crate::style::default_pin_style(theme, status)
+        PinStyle::from_theme(theme, state.direction)
+    };
+    match high_contrast {
+        Some(background) => adjust(style).high_contrast(background),
+        None => adjust(style),
     }
+    .in_world_space(zoom)
 }
 
 /// Circular pin cutouts that puncture a node body, translated by `(tx, ty)`.
@@ -228,28 +494,41 @@ fn resolve_pin_style<P: PinId + 'static, UI>(
 /// Shared by the node fill (drag offset only) and the shadow (drag offset plus
 /// shadow offset) so the shadow's holes line up exactly with the body's. The
 /// cutout radius tracks the drawn pin indicator. `is_valid_target(pin_idx)`
-/// selects the valid-target pin style; the cutout radius is static (no pulse).
+/// selects the valid-target pin style and `adjust` applies the graph's per-pin
+/// override; the cutout radius is static (no pulse).
 /// World-space `(center, radius)` of each pin cutout - the single source for the
 /// recipe cuts (`ShapeExpr::Circle` at local offsets) that punch the pin holes,
 /// so the body and its shadow punch identical holes.
+#[allow(clippy::too_many_arguments)]
 fn pin_cutout_params<P: PinId + 'static, UI>(
     pins: &[(usize, &NodePinState<P, UI>, (Point, Point))],
     pin_style_fn: Option<&PinStyleFn<'_, P, UI, Theme>>,
     other: Option<&NodePinState<P, UI>>,
     theme: &Theme,
+    high_contrast: Option<iced::Color>,
+    zoom: f32,
     offset: WorldVector,
     mut is_valid_target: impl FnMut(usize) -> bool,
+    adjust: impl Fn(&P, PinStyle) -> PinStyle,
 ) -> Vec<([f32; 2], f32)> {
     let mut cuts = Vec::new();
-    for (pin_idx, (_pin_index, pin_state, (pos_a, pos_b))) in pins.iter().enumerate() {
+    for &(pin_idx, pin_state, (pos_a, pos_b)) in pins {
         let valid = is_valid_target(pin_idx);
         let pin_status = if valid {
             PinStatus::ValidTarget
         } else {
             PinStatus::Idle
         };
-        let pin_style =
-            resolve_pin_style::<P, UI>(pin_style_fn, pin_state, other, theme, pin_status);
+        let pin_style = resolve_pin_style::<P, UI>(
+            pin_style_fn,
+            pin_state,
+            other,
+            theme,
+            pin_status,
+            high_contrast,
+            zoom,
+            |style| adjust(&pin_state.pin_id, style),
+        );
         let indicator_r = pin_style.radius * 0.4;
         // Cut a hole roughly twice the drawn pin's visual extent, so pins sit in
         // a clear well rather than hugging the body edge.
@@ -259,9 +538,9 @@ fn pin_cutout_params<P: PinId + 'static, UI>(
         }
         // Row pins project onto two borders, yielding two cutout centers.
         let positions: &[Point] = if pin_state.side == crate::PinSide::Row {
-            &[*pos_a, *pos_b]
+            &[pos_a, pos_b]
         } else {
-            std::slice::from_ref(pos_a)
+            std::slice::from_ref(&pos_a)
         };
         for pos in positions {
             cuts.push(([pos.x + offset.x, pos.y + offset.y], cutout_r));
@@ -270,14 +549,172 @@ fn pin_cutout_params<P: PinId + 'static, UI>(
     cuts
 }
 
+impl<N, P, E, UI, Message, Renderer> NodeGraph<'_, N, P, UI, Message, iced::Theme, Renderer, E>
+where
+    N: NodeId + 'static,
+    P: PinId + 'static,
+    E: EdgeId + 'static,
+    UI: 'static,
+    Renderer: iced_wgpu::core::renderer::Renderer,
+{
+    /// Graph chrome for `theme`: the `graph_style` callback, else the theme's
+    /// own, on the fixed dark canvas unless the background follows the theme.
+    pub(crate) fn graph_style_for(&self, theme: &iced::Theme) -> GraphStyle {
+        if let Some(ref style_fn) = self.graph_style {
+            style_fn(theme)
+        } else if self.background_follows_theme {
+            GraphStyle::from_theme(theme)
+        } else {
+            GraphStyle {
+                background_color: GraphStyle::default().background_color,
+                ..GraphStyle::from_theme(theme)
+            }
+        }
+    }
+
+    /// Style of the node at `node_index` before zoom and its appear
+    /// animation: its own or the graph-wide callback (else the theme
+    /// default), the node override, high-contrast mode over `graph`'s
+    /// canvas, its `node_opacities` entry and, when selected, the selection
+    /// shadow.
+    pub(crate) fn node_style_for(
+        &self,
+        node_index: usize,
+        theme: &iced::Theme,
+        status: NodeStatus,
+        graph: &GraphStyle,
+    ) -> NodeStyle {
+        let (id, _, _, node_style, _) = &self.nodes[node_index];
+        let mut resolved = resolve_node_style(
+            node_style.as_ref().or(self.node_style.as_ref()),
+            theme,
+            status,
+        );
+        if let Some(adjust) = &self.node_style_override {
+            resolved = adjust(id, resolved);
+        }
+        if self.high_contrast {
+            resolved = resolved.high_contrast(graph.background_color, status);
+        }
+        if let Some(opacity) = self.opacities.get(&node_index) {
+            resolved.opacity *= opacity;
+        }
+        if status == NodeStatus::Selected
+            && let Some(shadow) = graph.selection_style.shadow
+        {
+            resolved = resolved.with_shadow(shadow);
+        }
+        resolved
+    }
+
+    /// [`edge_style_for`](Self::edge_style_for) the edge at `edge_idx`, its
+    /// pins read from throwaway trees of their nodes' content. `None` when
+    /// a pin does not resolve, as such an edge is not drawn.
+    pub(crate) fn edge_style_at(
+        &self,
+        edge_idx: usize,
+        theme: &iced::Theme,
+        status: EdgeStatus,
+    ) -> Option<EdgeStyle> {
+        let (_, from, to, _) = self.edges.get(edge_idx)?;
+        let from_tree = Tree::new(&self.nodes[self.node_index(&from.node_id)?].2);
+        let to_tree = Tree::new(&self.nodes[self.node_index(&to.node_id)?].2);
+        let from_state = pin_state::<P, UI>(&from_tree, &from.pin_id)?;
+        let to_state = pin_state::<P, UI>(&to_tree, &to.pin_id)?;
+        let (start, end) = if flows_backwards(from_state.direction, to_state.direction) {
+            (to_state, from_state)
+        } else {
+            (from_state, to_state)
+        };
+        Some(self.edge_style_for(
+            edge_idx,
+            theme,
+            status,
+            pin_info(start),
+            pin_info(end),
+            &self.graph_style_for(theme),
+        ))
+    }
+
+    /// Style of the edge at `edge_idx` before zoom: the simplified line past
+    /// the edge threshold, else its own or the graph-wide callback (else the
+    /// theme default) and the edge override; then high-contrast mode over
+    /// `graph`'s canvas. `start`/`end` are its pins in data-flow order.
+    pub(crate) fn edge_style_for(
+        &self,
+        edge_idx: usize,
+        theme: &iced::Theme,
+        status: EdgeStatus,
+        start: Option<PinInfo<'_, P, UI>>,
+        end: Option<PinInfo<'_, P, UI>>,
+        graph: &GraphStyle,
+    ) -> EdgeStyle {
+        let (_, from, to, edge_style_fn) = &self.edges[edge_idx];
+        let style = if self.simplified_edges() {
+            EdgeStyle::simplified(crate::style::default_edge_style(theme, status).stroke_color)
+        } else {
+            let style = resolve_edge_style(
+                edge_style_fn.as_ref().or(self.edge_style.as_ref()),
+                theme,
+                status,
+                start,
+                end,
+            );
+            match &self.edge_style_override {
+                Some(adjust) => adjust(from, to, style),
+                None => style,
+            }
+        };
+        if self.high_contrast {
+            style.high_contrast(graph.background_color)
+        } else {
+            style
+        }
+    }
+}
+
 impl<N, P, E, UI, Message, Renderer> NodeGraph<'_, N, P, UI, Message, iced::Theme, Renderer, E>
 where
     N: NodeId + 'static,
     P: PinId + 'static,
     E: EdgeId + 'static,
     UI: Clone + 'static,
-    Renderer: iced_wgpu::core::renderer::Renderer + iced_wgpu::primitive::Renderer,
+    Renderer: iced_wgpu::core::renderer::Renderer
+        + iced_wgpu::primitive::Renderer
+        + iced_wgpu::core::text::Renderer<Font = iced::Font>,
 {
+    /// Style of the loose edge dragged out of `source`: the
+    /// `dragging_edge_style` callback, or the Idle default. With
+    /// `preview_matches_default`, its curve and stub length come from the
+    /// graph-wide edge style instead (resolved with `source` at both ends,
+    /// as the loose end has no pin yet), so the committed edge keeps the
+    /// preview's shape.
+    pub(crate) fn drag_edge_style(
+        &self,
+        theme: &iced::Theme,
+        source: &NodePinState<P, UI>,
+    ) -> EdgeStyle {
+        let style = match (self.dragging_edge_style_fn.as_ref(), pin_info(source)) {
+            (Some(f), Some(info)) => f(theme, info),
+            _ => crate::style::default_edge_style(theme, EdgeStatus::Idle),
+        };
+        if !self.preview_matches_default {
+            return style;
+        }
+        let graph_default = resolve_edge_style(
+            self.edge_style.as_ref(),
+            theme,
+            EdgeStatus::Idle,
+            pin_info(source),
+            pin_info(source),
+        );
+        EdgeStyle {
+            curve: graph_default.curve,
+            stub_length: graph_default.stub_length,
+            ..style
+        }
+    }
+
     /// Signature mirrors the corresponding `Widget` trait method it backs.
     #[allow(clippy::too_many_arguments)]
     pub(super) fn draw_impl(
@@ -303,8 +740,8 @@ where
             .with_viewport_origin(layout.bounds().position().into_euclid().to_vector());
         let z_indices = z_render_indices(state, self.nodes.len());
 
-        // Update time for animations
-        let time = {
+        // Update time for animations; frozen at rest while they are off.
+        let time = if self.animates() {
             let now = Instant::now();
             if let Some(last_update) = state.last_update {
                 let delta = now.duration_since(last_update).as_secs_f32();
@@ -313,6 +750,8 @@ where
             } else {
                 state.time
             }
+        } else {
+            0.0
         };
 
         // Create RenderContext (will be finalized after camera panning is applied)
@@ -321,6 +760,8 @@ where
             camera_position: state.camera.position(),
             viewport_origin: camera.viewport_origin(),
             time,
+            aa_width: self.aa_width,
+            pixel_snap: self.pixel_snap,
         };
 
         // Handle panning when dragging the graph
@@ -337,13 +778,29 @@ where
         render_context.camera_zoom = camera.zoom();
         render_context.camera_position = camera.position();
 
-        // Resolve styles
-        let resolved_graph = if let Some(ref style_fn) = self.graph_style {
-            style_fn(theme)
-        } else {
-            GraphStyle::from_theme(theme)
+        // Over the performance hint's threshold and zoomed out: skip shadows
+        // and pins (indicators and body cutouts) this frame.
+        let low_detail = self.low_detail(camera.zoom());
+
+        // Eased (cubic out) progress of a node's appear animation, `None`
+        // when it is not animating.
+        let appear = |node_index: usize| -> Option<f32> {
+            if !self.animates() {
+                return None;
+            }
+            let duration = self.node_appear_animation?.as_secs_f32();
+            let start = state.node_appear.get(&node_index)?;
+            let t = ((time - start) / duration).clamp(0.0, 1.0);
+            (t < 1.0).then(|| 1.0 - (1.0 - t).powi(3))
         };
 
+        // Resolve styles
+        let resolved_graph = self.graph_style_for(theme);
+        // Canvas background the high-contrast mode picks its ink against.
+        let high_contrast = self
+            .high_contrast
+            .then_some(resolved_graph.background_color);
+
         // Check if we're edge dragging
         let is_edge_dragging = matches!(
             state.dragging,
@@ -357,10 +814,62 @@ where
         let mut node_pins: Vec<Vec<(usize, &NodePinState<P, UI>, (Point, Point))>> = layout
             .children()
             .zip(&tree.children)
-            .map(|(node_layout, node_tree)| find_pins::<P, UI>(node_tree, node_layout))
+            .enumerate()
+            .map(|(node_index, (node_layout, node_tree))| {
+                find_pins_placed::<P, UI>(node_tree, node_layout, self.node_transform(node_index))
+            })
             .collect();
         node_pins.resize_with(self.nodes.len(), Vec::new);
 
+        // Edges on each pin, shared by `PinVisibility::Connected` and the
+        // connection count labels.
+        let mut pin_edge_counts: HashMap<(usize, &P), usize> = HashMap::new();
+        if self.pin_visibility == PinVisibility::Connected || self.show_pin_connection_counts {
+            for end in self.edges.iter().flat_map(|(_, from, to, _)| [from, to]) {
+                if let Some(node_index) = self.node_index(&end.node_id) {
+                    *pin_edge_counts
+                        .entry((node_index, &end.pin_id))
+                        .or_default() += 1;
+                }
+            }
+        }
+
+        // Pins `pin_visibility` hides skip their indicator and body cutout.
+        // Edges and hit tests keep using the full `node_pins`, so a hidden
+        // pin still anchors its edges. `None` while every pin is shown.
+        let visible_pins = (self.pin_visibility != PinVisibility::Always).then(|| {
+            let edge_drag = match state.dragging {
+                Dragging::Edge(from_node, from_pin, _)
+                | Dragging::EdgeOver(from_node, from_pin, _, _) => Some((from_node, from_pin)),
+                _ => None,
+            };
+            node_pins
+                .iter()
+                .enumerate()
+                .map(|(node_index, pins)| {
+                    pins.iter()
+                        .filter(|(pin_index, pin_state, _)| {
+                            let end = (node_index, *pin_index);
+                            // An edge drag always shows its source and targets.
+                            edge_drag == Some(end)
+                                || (edge_drag.is_some() && state.valid_drop_targets.contains(&end))
+                                || match self.pin_visibility {
+                                    PinVisibility::Always => true,
+                                    PinVisibility::OnHover => {
+                                        state.hovered_node == Some(node_index)
+                                            || state.selected_nodes.contains(&node_index)
+                                    }
+                                    PinVisibility::Connected => pin_edge_counts
+                                        .contains_key(&(node_index, &pin_state.pin_id)),
+                                }
+                        })
+                        .copied()
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>()
+        });
+        let shown_pins: &[Vec<_>] = visible_pins.as_deref().unwrap_or(&node_pins);
+
         // The pin an edge drag started from, surfaced as `other` to pin_style so
         // candidate pins can react to what is being dragged toward them.
         let drag_source: Option<NodePinState<P, UI>> = match state.dragging {
@@ -387,9 +896,9 @@ where
             );
         });
 
-        // The tiling grid is folded into the single graph-background draw below
-        // (z0, under the node + edge shadows and the edge strokes) so the whole
-        // below-nodes layer is one fullscreen SDF pass.
+        // The tiling grid (z0) gets its own SDF draw just before the graph
+        // background below, so the pipeline can serve it from its background
+        // cache (see `iced_nodegraph_sdf`'s `pipeline/background.rs`).
 
         // ========================================
         // Collect edge data with resolved positions
@@ -406,27 +915,17 @@ where
             WorldPoint::new(w.x + vo.x, w.y + vo.y)
         };
         let compute_node_offset = |node_idx: usize| -> WorldVector {
-            let mut offset = WorldVector::zero();
-            let is_selected = state.selected_nodes.contains(&node_idx);
-
-            // Single node drag
-            if let (Dragging::Node(drag_idx, origin), Some(cursor_pos)) =
-                (&state.dragging, cursor.position())
-                && *drag_idx == node_idx
-            {
-                offset = cursor_layout(cursor_pos) - *origin;
-            }
-
-            // Group move
-            if let (Dragging::GroupMove(origin), Some(cursor_pos)) =
-                (&state.dragging, cursor.position())
-                && is_selected
-            {
-                offset = cursor_layout(cursor_pos) - *origin;
-            }
-
-            offset
+            self.node_drag_offset(state, node_idx, cursor.position().map(cursor_layout))
         };
+        // While a box select is dragged, nodes show the selection its
+        // release would leave, updated live as the box changes.
+        let box_preview = match &state.dragging {
+            Dragging::BoxSelect(start, _) => cursor
+                .position()
+                .map(|p| self.box_selection(state, layout, *start, cursor_layout(p))),
+            _ => None,
+        };
+        let selected_nodes = box_preview.as_ref().unwrap_or(&state.selected_nodes);
 
         // ========================================
         // Per-node geometry, built once and shared by the node shadows (below)
@@ -466,45 +965,77 @@ where
         let t_geom_start = Instant::now();
         let node_geoms: Vec<Option<NodeGeom>> = (0..self.nodes.len())
             .map(|node_index| {
-                let (_id, _position, _element, node_style, node_pin_style) =
+                let (_id, _position, _element, _node_style, node_pin_style) =
                     &self.nodes[node_index];
                 let node_layout = layout.children().nth(node_index)?;
                 // Gate only: a node without a tree child gets no geometry
                 // (its pins are already absent from `node_pins`).
                 let _node_tree = tree.children.get(node_index)?;
-                let status = if state.selected_nodes.contains(&node_index) {
+                let status = if selected_nodes.contains(&node_index) {
                     NodeStatus::Selected
                 } else {
                     NodeStatus::Idle
                 };
-                let resolved = resolve_node_style(node_style.as_ref(), theme, status);
+                let mut resolved = self
+                    .node_style_for(node_index, theme, status, &resolved_graph)
+                    .in_world_space(camera.zoom());
+                if let Some(progress) = appear(node_index) {
+                    resolved.opacity *= progress;
+                }
                 let offset = compute_node_offset(node_index);
-                let position: WorldPoint =
+                let mut position: WorldPoint =
                     (node_layout.bounds().position().into_euclid().to_vector() + offset).to_point();
-                let size = node_layout.bounds().size();
-                let pins = &node_pins[node_index];
+                let body_size = node_layout.bounds().size();
+                let mut size = body_size;
+                let pins: &[_] = if low_detail {
+                    &[]
+                } else {
+                    &shown_pins[node_index]
+                };
                 let center = [
                     position.x + size.width * 0.5,
                     position.y + size.height * 0.5,
                 ];
+                // A rotated body culls and reports against the box around its
+                // turned outline.
+                let transform = self.node_transform(node_index);
+                let rotation = transform.rotation;
+                if rotation != 0.0 {
+                    let (sin, cos) = (rotation.sin().abs(), rotation.cos().abs());
+                    size = Size::new(
+                        body_size.width * cos + body_size.height * sin,
+                        body_size.width * sin + body_size.height * cos,
+                    );
+                    position = WorldPoint::new(
+                        center[0] - size.width * 0.5,
+                        center[1] - size.height * 0.5,
+                    );
+                }
                 let cut_params = pin_cutout_params(
                     pins,
-                    node_pin_style.as_ref(),
+                    node_pin_style.as_ref().or(self.pin_style.as_ref()),
                     drag_source.as_ref(),
                     theme,
+                    high_contrast,
+                    camera.zoom(),
                     offset,
                     |pin_idx| {
                         is_edge_dragging
                             && state.valid_drop_targets.contains(&(node_index, pin_idx))
                     },
+                    |pin_id, style| self.adjust_pin_style(node_index, pin_id, style),
                 );
 
-                // Body = a centre-origin rounded box; each pin cut sits at a LOCAL
-                // offset relative to the body centre, so two identical nodes at
+                // Body = a centre-origin outline (a rounded box unless the node
+                // picked another shape); each pin cut sits at a LOCAL offset
+                // relative to the body centre, so two identical nodes at
                 // different positions share a recipe (the position lives entirely
-                // in `center`). `box - cut0 - cut1 - ...` as authored.
-                let mut shape =
-                    Shape::rounded_box([size.width, size.height], [resolved.corner_radius; 4]);
+                // in `center`). `body - cut0 - cut1 - ...` as authored. The pin
+                // positions are already turned, so only the body rotates.
+                let mut shape = body_outline(transform.shape, body_size, resolved.corner_radius);
+                if rotation != 0.0 {
+                    shape = shape.rotate(rotation);
+                }
                 for &(c, r) in &cut_params {
                     shape =
                         shape - Shape::circle(r).translate([c[0] - center[0], c[1] - center[1]]);
@@ -527,14 +1058,15 @@ where
         // SDF primitive the FIRST-pushed entry composites in FRONT (the cull
         // sorts slots ascending by push index and the fragment blends them
         // front-to-back), so entries are pushed FRONT-TO-BACK here: edge strokes
-        // (z2, top), then all shadows (z1, edge + node), then the grid (z0,
-        // bottom). Folding the grid, every node shadow and every edge into one
-        // primitive collapses the whole below-nodes layer into a single
-        // fullscreen fragment pass. Pushing ALL strokes before ANY shadow keeps
-        // every edge line above every shadow. The node bodies (Layer 4) paint
-        // over all of it. The grid is no longer marked cacheable: the dynamic
-        // shadows/edges sharing this draw would never let the static-background
-        // texture cache hit. Node shadows within the z1 band are pushed in
+        // (z2, top), then all shadows (z1, edge + node). Folding every node
+        // shadow and every edge into one primitive collapses the below-nodes
+        // layer into a single fullscreen fragment pass. Pushing ALL strokes
+        // before ANY shadow keeps every edge line above every shadow. The node
+        // bodies (Layer 4) paint over all of it. The grid (z0) is NOT folded
+        // in: alone in its own primitive it is tiling-only, which the SDF
+        // pipeline caches as a texture and pans by offset, whereas the dynamic
+        // shadows/edges sharing this draw would never let that cache hit.
+        // Node shadows within the z1 band are pushed in
         // STABLE node-index order rather than the selection-sorted `z_indices`
         // (see below) - bg_layer is a single SdfPrimitive whose geometry hash
         // covers entry push order, so ordering shadows by `z_indices` would
@@ -542,8 +1074,10 @@ where
         // (all edge biarcs included) just to reshuffle translucent shadows
         // that composite the same either way.
         // ========================================
+        // Edges that survive culling, for the `on_info` counts.
+        let mut edges_in_view = 0usize;
         let bg_layer = {
-            let mut bg = SdfPrimitive::with_capacity(self.nodes.len() + self.edges.len() * 4 + 1);
+            let mut bg = SdfPrimitive::with_capacity(self.nodes.len() + self.edges.len() * 4);
 
             // Edge layers split by geometry: strokes (z2) collected to push first
             // (front), shadows (z1) collected to push behind them. Each edge's own
@@ -553,9 +1087,39 @@ where
                 _ => None,
             };
             let mut edge_strokes: Vec<(Shape, Style)> = Vec::with_capacity(self.edges.len() * 2);
+            let mut edge_handle_dots: Vec<(Shape, Style, [f32; 2])> = Vec::new();
+            let handle_color = resolved_graph.selection_style.selected_border_color;
+            let mut edge_geometry = state.edge_geometry.borrow_mut();
+            edge_geometry.clear();
+            edge_geometry.resize(self.edges.len(), (EdgeCurve::default(), 0.0));
+            let mut edge_bundles = state.edge_bundles.borrow_mut();
+            edge_bundles.clear();
+            edge_bundles.resize(self.edges.len(), None);
+
+            // Bundling clusters by node centre, so the shared paths are known
+            // before any edge draws; routed edges keep their own path.
+            let simplified = self.simplified_edges();
+            let shared_paths = (self.edge_bundling > 0.0 && !simplified).then(|| {
+                let ends: Vec<_> = self
+                    .edges
+                    .iter()
+                    .enumerate()
+                    .map(|(edge_idx, (_, from, to, _))| {
+                        if self.edge_route(edge_idx).is_some() {
+                            return None;
+                        }
+                        let center = |id: &N| {
+                            let geom = node_geoms.get(self.node_index(id)?)?.as_ref()?;
+                            Some(geom.center)
+                        };
+                        Some((center(&from.node_id)?, center(&to.node_id)?))
+                    })
+                    .collect();
+                bundling::shared_paths(&ends, [vo.x, vo.y])
+            });
             let mut edge_shadows: Vec<(Shape, Style)> = Vec::with_capacity(self.edges.len());
 
-            for (edge_idx, (_edge_id, from, to, edge_style_fn)) in self.edges.iter().enumerate() {
+            for (edge_idx, (_edge_id, from, to, _)) in self.edges.iter().enumerate() {
                 let Some(from_node_idx) = self.node_index(&from.node_id) else {
                     continue;
                 };
@@ -581,18 +1145,36 @@ where
                     continue;
                 };
 
-                let from_pos = (from_pin_pos.into_euclid().to_vector() + from_offset).to_point();
-                let to_pos = (to_pin_pos.into_euclid().to_vector() + to_offset).to_point();
-                let from_side: u32 = from_pin_state.side.into();
-                let to_side: u32 = to_pin_state.side.into();
+                let mut from_pos =
+                    (from_pin_pos.into_euclid().to_vector() + from_offset).to_point();
+                let mut to_pos = (to_pin_pos.into_euclid().to_vector() + to_offset).to_point();
+                let mut from_side = self.node_transform(from_node_idx).side(from_pin_state.side);
+                let mut to_side = self.node_transform(to_node_idx).side(to_pin_state.side);
+                let center = |node_idx: usize| {
+                    node_geoms[node_idx]
+                        .as_ref()
+                        .map(|geom| Point::new(geom.center[0], geom.center[1]))
+                };
+                if self.auto_pin_side
+                    && let (Some(from_center), Some(to_center)) =
+                        (center(from_node_idx), center(to_node_idx))
+                {
+                    let (anchor, side) =
+                        facing_side(from_pos.into_iced(), from_side, from_center, to_center);
+                    (from_pos, from_side) = (anchor.into_euclid(), side);
+                    let (anchor, side) =
+                        facing_side(to_pos.into_iced(), to_side, to_center, from_center);
+                    (to_pos, to_side) = (anchor.into_euclid(), side);
+                }
+                let from_side: u32 = from_side.into();
+                let to_side: u32 = to_side.into();
                 let from_info = pin_info::<P, UI>(from_pin_state);
                 let to_info = pin_info::<P, UI>(to_pin_state);
 
                 // Normalize orientation so the OUTPUT pin is the edge start
                 // (output -> input). Gradient, arrow and flow then follow the
                 // data-flow direction regardless of which side was dragged from.
-                let swap = !matches!(from_pin_state.direction, PinDirection::Output)
-                    && matches!(to_pin_state.direction, PinDirection::Output);
+                let swap = flows_backwards(from_pin_state.direction, to_pin_state.direction);
                 let (start_pos, end_pos, start_side, end_side, start_info, end_info) = if swap {
                     (to_pos, from_pos, to_side, from_side, to_info, from_info)
                 } else {
@@ -604,32 +1186,202 @@ where
                 } else {
                     EdgeStatus::Idle
                 };
-                let edge_style = resolve_edge_style(
-                    edge_style_fn.as_ref(),
-                    theme,
-                    edge_status,
-                    start_info,
-                    end_info,
+                let mut edge_style = self
+                    .edge_style_for(
+                        edge_idx,
+                        theme,
+                        edge_status,
+                        start_info,
+                        end_info,
+                        &resolved_graph,
+                    )
+                    .in_world_space(camera.zoom());
+
+                // Routed edges: waypoints are world-space, pins layout-absolute.
+                let mut waypoints: Vec<WorldPoint> = Vec::new();
+                if let Some(route) = self.edge_route(edge_idx) {
+                    if !simplified {
+                        edge_style.curve = route.curve;
+                    }
+                    waypoints.extend(
+                        route
+                            .waypoints
+                            .iter()
+                            .map(|p| WorldPoint::new(p.x + vo.x, p.y + vo.y)),
+                    );
+                    if swap {
+                        waypoints.reverse();
+                    }
+                }
+                // Bundled edges bend through their waypoints, which a bezier
+                // cannot, so they draw as splines.
+                if waypoints.is_empty()
+                    && let Some(shared) = shared_paths
+                        .as_ref()
+                        .and_then(|paths| paths.get(edge_idx).copied().flatten())
+                {
+                    let points = bundling::bundled_waypoints(
+                        [from_pos.x, from_pos.y],
+                        [to_pos.x, to_pos.y],
+                        shared,
+                        self.edge_bundling,
+                    );
+                    edge_bundles[edge_idx] = Some(points);
+                    edge_style.curve = EdgeCurve::Catmull;
+                    waypoints = points.iter().map(|&[x, y]| WorldPoint::new(x, y)).collect();
+                    if swap {
+                        waypoints.reverse();
+                    }
+                }
+                if waypoints.is_empty()
+                    && nearly_aligned(
+                        [start_pos.x, start_pos.y],
+                        start_side,
+                        [end_pos.x, end_pos.y],
+                        end_side,
+                        edge_style.auto_straighten,
+                    )
+                {
+                    edge_style.curve = EdgeCurve::Line;
+                    edge_style.stub_length = 0.0;
+                }
+                edge_geometry[edge_idx] = (edge_style.curve, edge_style.stub_length);
+
+                // A handle drag previews its waypoints before the host pushes
+                // them back; a bezier cannot show them, so it previews as a
+                // spline.
+                if let Dragging::Waypoint {
+                    edge,
+                    waypoints: dragged,
+                    ..
+                } = &state.dragging
+                    && *edge == edge_idx
+                {
+                    if edge_style.curve == EdgeCurve::BezierCubic {
+                        edge_style.curve = EdgeCurve::Catmull;
+                    }
+                    waypoints = dragged
+                        .iter()
+                        .map(|p| WorldPoint::new(p.x + vo.x, p.y + vo.y))
+                        .collect();
+                    if swap {
+                        waypoints.reverse();
+                    }
+                }
+
+                // Cull edges whose curve box (ends, controls, waypoints, plus
+                // the widest layer and the shadow offset) misses the viewport.
+                let points: Vec<[f32; 2]> = waypoints.iter().map(|p| [p.x, p.y]).collect();
+                let layers = edge_style.sdf_layers();
+                let reach = layers
+                    .iter()
+                    .map(|layer| layer.style.extent(false))
+                    .fold(0.0_f32, f32::max)
+                    + edge_style
+                        .shadow_offset
+                        .0
+                        .abs()
+                        .max(edge_style.shadow_offset.1.abs());
+                let [x0, y0, x1, y1] = edge_bounds(
+                    [start_pos.x, start_pos.y],
+                    start_side,
+                    [end_pos.x, end_pos.y],
+                    end_side,
+                    edge_style.curve,
+                    edge_style.stub_length,
+                    &points,
                 );
+                let bb = world_bbox_to_screen_bounds(
+                    x0,
+                    y0,
+                    x1,
+                    y1,
+                    reach + EDGE_CULL_MARGIN / camera.zoom(),
+                    &render_context,
+                );
+                let bounds = Rectangle {
+                    x: bb[0],
+                    y: bb[1],
+                    width: bb[2],
+                    height: bb[3],
+                };
+                if !bounds.intersects(&layout.bounds()) {
+                    continue;
+                }
+                edges_in_view += 1;
+
+                if self.editable_edges {
+                    for (handle, center) in edge_handles(
+                        [start_pos.x, start_pos.y],
+                        start_side,
+                        [end_pos.x, end_pos.y],
+                        end_side,
+                        edge_style.curve,
+                        edge_style.stub_length,
+                        &points,
+                    ) {
+                        let (radius, color) = match handle {
+                            EdgeHandle::Waypoint(_) => (EDGE_HANDLE_RADIUS, handle_color),
+                            EdgeHandle::Insert(_) => {
+                                (EDGE_INSERT_HANDLE_RADIUS, handle_color.scale_alpha(0.5))
+                            }
+                        };
+                        edge_handle_dots.push((
+                            Shape::circle(radius / camera.zoom()),
+                            Style::solid(color),
+                            center,
+                        ));
+                    }
+                }
 
-                let (shape, shadow_shape) =
-                    edge_shapes(&start_pos, &end_pos, start_side, end_side, &edge_style);
+                let (shape, shadow_shape) = edge_shapes(
+                    &start_pos,
+                    &end_pos,
+                    start_side,
+                    end_side,
+                    &edge_style,
+                    &waypoints,
+                );
 
                 // Collect this edge's layers by geometry; both groups are pushed
                 // in z order after the loop.
-                for layer in edge_style.sdf_layers() {
+                for layer in layers {
                     match layer.geometry {
                         EdgeGeometry::Stroke => {
                             edge_strokes.push((shape.clone(), layer.style));
                         }
-                        EdgeGeometry::Shadow => {
+                        EdgeGeometry::Shadow if !low_detail => {
                             edge_shadows.push((shadow_shape.clone(), layer.style));
                         }
+                        EdgeGeometry::Shadow => {}
                     }
                 }
             }
 
-            // z2: edge strokes (frontmost in the background layer).
+            drop(edge_geometry);
+            drop(edge_bundles);
+
+            // Focus ring in front of everything else in the layer, so no
+            // edge hides which node has keyboard focus.
+            let ring = resolved_graph.selection_style.focus_ring;
+            if let Some(geom) = state
+                .focused_node
+                .filter(|_| state.focused && ring.width > 0.0)
+                .and_then(|index| node_geoms.get(index)?.as_ref())
+            {
+                let from = ring.offset / camera.zoom();
+                let to = (ring.offset + ring.width) / camera.zoom();
+                let color =
+                    crate::style::ColorQuad::solid(ring.color).with_opacity(geom.resolved.opacity);
+                geom.push_body(&mut bg, &Style::quad_band(&color, from, to), (0.0, 0.0));
+            }
+
+            // z3: edge handles (frontmost in the background layer).
+            for (shape, style, center) in &edge_handle_dots {
+                bg.push(shape, style, *center);
+            }
+
+            // z2: edge strokes.
             for (shape, style) in &edge_strokes {
                 bg.push(shape, style, [0.0, 0.0]);
             }
@@ -639,6 +1391,15 @@ where
             for (shape, style) in &edge_shadows {
                 bg.push(shape, style, [0.0, 0.0]);
             }
+            // Host halos in front of the node shadows, so a lifted shadow
+            // does not swallow them. Kept at low detail: they carry state.
+            for (node_index, geom) in node_geoms.iter().enumerate() {
+                if let (Some(geom), Some(decoration)) = (geom, self.decorations.get(&node_index)) {
+                    let style = decoration.sdf_style(geom.resolved.opacity);
+                    geom.push_body(&mut bg, &style, (0.0, 0.0));
+                }
+            }
+
             // Node shadows are pushed in STABLE node-index order, not
             // `z_indices` (which re-sorts by (selected, z) on every selection
             // change) - see the rationale above. Shadow-over-shadow blending
@@ -648,7 +1409,7 @@ where
             // marginally, an accepted trade for not rebuilding the whole
             // bg_layer (edge biarcs included) on every selection click.
             for geom in node_geoms.iter().flatten() {
-                if !geom.resolved.has_shadow() {
+                if low_detail || !geom.resolved.has_shadow() {
                     continue;
                 }
                 let (ox, oy) = geom.resolved.shadow_offset;
@@ -657,49 +1418,60 @@ where
                 }
             }
 
-            // z0: tiling grid/dots/triangles/hex (backmost).
-            if let Some(tiling) = resolved_graph.tiling {
-                let tiling_shape = Shape::tiling(match tiling.kind {
-                    TilingKind::Grid => {
-                        Tiling::grid(tiling.spacing, tiling.spacing, tiling.thickness)
-                    }
-                    TilingKind::Dots => {
-                        Tiling::dots(tiling.spacing, tiling.spacing, tiling.thickness)
-                    }
-                    TilingKind::Triangles => Tiling::triangles(tiling.spacing, tiling.thickness),
-                    TilingKind::Hex => Tiling::hex(tiling.spacing, tiling.thickness),
-                });
-                // Thickness is baked into the tiling SDF (params.z) for all
-                // kinds: grid/triangle/hex subtract half the line thickness,
-                // dots bake the radius in.
-                let style = Style::solid(tiling.color);
-                bg.push(&tiling_shape, &style, [0.0, 0.0]);
-            }
-
             bg
         };
 
+        // z0: tiling grid/dots/triangles/hex (backmost), its own primitive so
+        // the SDF pipeline's background cache can take it.
+        let tiling_layer = resolved_graph.tiling.map(|tiling| {
+            let tiling_shape = Shape::tiling(match tiling.kind {
+                TilingKind::Grid => Tiling::grid(tiling.spacing, tiling.spacing, tiling.thickness),
+                TilingKind::Dots => Tiling::dots(tiling.spacing, tiling.spacing, tiling.thickness),
+                TilingKind::Triangles => Tiling::triangles(tiling.spacing, tiling.thickness),
+                TilingKind::Hex => Tiling::hex(tiling.spacing, tiling.thickness),
+            });
+            // Thickness is baked into the tiling SDF (params.z) for all
+            // kinds: grid/triangle/hex subtract half the line thickness,
+            // dots bake the radius in.
+            let mut layer = SdfPrimitive::with_capacity(1);
+            layer.push(&tiling_shape, &Style::solid(tiling.color), [0.0, 0.0]);
+            layer
+        });
+
         // Batches clipped to the full graph bounds use the bounds origin as the
         // shader's `bounds_origin`, so the camera offset compensates with
         // `camera_position - widget_origin` (the general formula reduced for a
         // full-bounds clip). No-op when the graph is at the window origin.
-        if !bg_layer.is_empty() {
+        if tiling_layer.is_some() || !bg_layer.is_empty() {
             let wo = layout.bounds().position();
-            let (cx, cy) = layer_camera(
-                render_context.camera_position,
-                render_context.camera_zoom,
-                wo,
-                layout.bounds(),
-            );
+            let world_camera = (render_context.camera_position, render_context.camera_zoom);
+            // A screen-space tiling draws through an identity camera, so its
+            // world units are pixels from the widget's top-left.
+            let tiling_camera = match resolved_graph.tiling.map(|tiling| tiling.space) {
+                Some(BackgroundSpace::Screen) => (WorldPoint::origin(), 1.0),
+                _ => world_camera,
+            };
             renderer.with_layer(layout.bounds(), |renderer| {
-                draw_sdf(
-                    renderer,
-                    &state.sdf_animated,
-                    layout.bounds(),
-                    bg_layer
-                        .camera(cx, cy, render_context.camera_zoom)
-                        .time(render_context.time),
-                );
+                let layers = tiling_layer
+                    .map(|layer| (layer, tiling_camera))
+                    .into_iter()
+                    .chain(Some((bg_layer, world_camera)));
+                for (layer, (position, zoom)) in layers {
+                    if layer.is_empty() {
+                        continue;
+                    }
+                    let (cx, cy) = layer_camera(position, zoom, wo, layout.bounds());
+                    draw_sdf(
+                        renderer,
+                        &state.sdf_animated,
+                        layout.bounds(),
+                        layer
+                            .camera(cx, cy, zoom)
+                            .time(render_context.time)
+                            .aa_width(render_context.aa_width)
+                            .pixel_snap(render_context.pixel_snap),
+                    );
+                }
             });
         }
 
@@ -718,16 +1490,18 @@ where
                 // graph is off the window origin.
                 let end_pos: WorldPoint = cursor_layout(cursor_pos);
 
-                let drag_edge_style = match (
-                    self.dragging_edge_style_fn.as_ref(),
-                    pin_info::<P, UI>(from_pin_state),
-                ) {
-                    (Some(f), Some(info)) => f(theme, info),
-                    _ => crate::style::default_edge_style(theme, EdgeStatus::Idle),
-                };
+                let drag_edge_style = self.drag_edge_style(theme, from_pin_state);
+                let drag_edge_style = match high_contrast {
+                    Some(background) => drag_edge_style.high_contrast(background),
+                    None => drag_edge_style,
+                }
+                .in_world_space(render_context.camera_zoom);
 
-                let from_side: u32 = from_pin_state.side.into();
-                let cursor_side: u32 = match from_pin_state.side {
+                let from_pin_side = self
+                    .node_transform(*from_node_idx)
+                    .side(from_pin_state.side);
+                let from_side: u32 = from_pin_side.into();
+                let cursor_side: u32 = match from_pin_side {
                     PinSide::Left => 1,
                     PinSide::Right => 0,
                     PinSide::Top => 3,
@@ -745,8 +1519,14 @@ where
                         (start_pos, end_pos, from_side, cursor_side)
                     };
 
-                let (shape, shadow_shape) =
-                    edge_shapes(&start_pos, &end_pos, start_side, end_side, &drag_edge_style);
+                let (shape, shadow_shape) = edge_shapes(
+                    &start_pos,
+                    &end_pos,
+                    start_side,
+                    end_side,
+                    &drag_edge_style,
+                    &[],
+                );
 
                 let mut drag_batch = SdfPrimitive::new();
                 push_edge_layers(&mut drag_batch, &shape, &shadow_shape, &drag_edge_style);
@@ -765,7 +1545,9 @@ where
                         layout.bounds(),
                         drag_batch
                             .camera(cx, cy, render_context.camera_zoom)
-                            .time(render_context.time),
+                            .time(render_context.time)
+                            .aa_width(render_context.aa_width)
+                            .pixel_snap(render_context.pixel_snap),
                     );
                 });
             }
@@ -797,10 +1579,22 @@ where
 
             let opacity = resolved.opacity;
             let cam_zoom = render_context.camera_zoom;
+            // An appearing node draws its fill, content and foreground scaled
+            // about its centre through its own camera.
+            let appear_scale =
+                appear(node_index).map(|p| APPEAR_START_SCALE + (1.0 - APPEAR_START_SCALE) * p);
+            let (node_cam_position, node_zoom) = match appear_scale {
+                Some(scale) => scaled_camera(&render_context, geom.center, scale),
+                None => (render_context.camera_position, cam_zoom),
+            };
 
             // Pins drive the foreground (border halo plus indicators); the body
             // cutouts they imply are already baked into `node_outline`.
-            let pins = &node_pins[node_index];
+            let pins: &[_] = if low_detail {
+                &[]
+            } else {
+                &shown_pins[node_index]
+            };
 
             // Layer 4a: Node Fill
             let fill_pad = 2.0 / cam_zoom;
@@ -814,8 +1608,8 @@ where
             );
             if let Some(fill_clip) = clipped_shape_bounds(fb, layout.bounds()) {
                 let (cx, cy) = layer_camera(
-                    render_context.camera_position,
-                    cam_zoom,
+                    node_cam_position,
+                    node_zoom,
                     layout.bounds().position(),
                     fill_clip,
                 );
@@ -831,8 +1625,10 @@ where
                         &state.sdf_animated,
                         fill_clip,
                         fill_batch
-                            .camera(cx, cy, cam_zoom)
-                            .time(render_context.time),
+                            .camera(cx, cy, node_zoom)
+                            .time(render_context.time)
+                            .aa_width(render_context.aa_width)
+                            .pixel_snap(render_context.pixel_snap),
                     );
                 });
             }
@@ -852,16 +1648,20 @@ where
                     |renderer, viewport, cursor| {
                         let bounds = node_layout.bounds();
                         let screen_offset: Vector = offset.into_iced();
-                        // Clip content to the full node bounds (the body edge).
-                        // The border sits outside the silhouette, so it never
-                        // narrows the content area: selection thickening the
-                        // border no longer shrinks the node interior.
-                        let node_clip = Rectangle {
-                            x: bounds.x + screen_offset.x,
-                            y: bounds.y + screen_offset.y,
-                            width: bounds.width,
-                            height: bounds.height,
-                        };
+                        // Clip content to the full node bounds (the body edge),
+                        // or the upright rectangle inside a round or hexagonal
+                        // outline. The border sits outside the silhouette, so it
+                        // never narrows the content area: selection thickening
+                        // the border no longer shrinks the node interior.
+                        let node_clip =
+                            self.node_transform(node_index)
+                                .shape
+                                .content_bounds(Rectangle {
+                                    x: bounds.x + screen_offset.x,
+                                    y: bounds.y + screen_offset.y,
+                                    width: bounds.width,
+                                    height: bounds.height,
+                                });
 
                         // push_clip replaces (does not intersect) the parent
                         // clip, so intersect with the graph viewport here;
@@ -886,17 +1686,28 @@ where
                             height: viewport.height,
                         };
 
-                        renderer.with_layer(clip_bounds, |renderer| {
-                            renderer.with_translation(screen_offset, |renderer| {
-                                element.as_widget().draw(
-                                    node_tree,
-                                    renderer,
-                                    theme,
-                                    style,
-                                    node_layout,
-                                    cursor,
-                                    &child_viewport,
-                                );
+                        let appear_transformation = match appear_scale {
+                            Some(scale) => {
+                                let center = node_clip.center();
+                                Transformation::translate(center.x, center.y)
+                                    * Transformation::scale(scale)
+                                    * Transformation::translate(-center.x, -center.y)
+                            }
+                            None => Transformation::IDENTITY,
+                        };
+                        renderer.with_transformation(appear_transformation, |renderer| {
+                            renderer.with_layer(clip_bounds, |renderer| {
+                                renderer.with_translation(screen_offset, |renderer| {
+                                    element.as_widget().draw(
+                                        node_tree,
+                                        renderer,
+                                        theme,
+                                        style,
+                                        node_layout,
+                                        cursor,
+                                        &child_viewport,
+                                    );
+                                });
                             });
                         });
                     },
@@ -945,7 +1756,7 @@ where
                 }
 
                 // Pins
-                for (pin_idx, (_pin_index, pin_state, (pin_pos, _))) in pins.iter().enumerate() {
+                for &(pin_idx, pin_state, (pin_pos, _)) in pins {
                     let is_valid_target = is_edge_dragging
                         && state.valid_drop_targets.contains(&(node_index, pin_idx));
                     let pin_status = if is_valid_target {
@@ -954,11 +1765,14 @@ where
                         PinStatus::Idle
                     };
                     let pin_style = resolve_pin_style(
-                        node_pin_style.as_ref(),
+                        node_pin_style.as_ref().or(self.pin_style.as_ref()),
                         pin_state,
                         drag_source.as_ref(),
                         theme,
                         pin_status,
+                        high_contrast,
+                        cam_zoom,
+                        |style| self.adjust_pin_style(node_index, &pin_state.pin_id, style),
                     );
                     let indicator_r = pin_style.radius * 0.4;
                     let pin_world: WorldPoint =
@@ -1010,8 +1824,8 @@ where
                     layout.bounds(),
                 ) {
                     let (cx, cy) = layer_camera(
-                        render_context.camera_position,
-                        cam_zoom,
+                        node_cam_position,
+                        node_zoom,
                         layout.bounds().position(),
                         fg_clip,
                     );
@@ -1020,11 +1834,70 @@ where
                             renderer,
                             &state.sdf_animated,
                             fg_clip,
-                            fg_batch.camera(cx, cy, cam_zoom).time(render_context.time),
+                            fg_batch
+                                .camera(cx, cy, node_zoom)
+                                .time(render_context.time)
+                                .aa_width(render_context.aa_width)
+                                .pixel_snap(render_context.pixel_snap),
                         );
                     });
                 }
             }
+
+            // Layer 4d: Status badge (host-driven, constant screen size)
+            if let Some(badge) = self.badges.get(&node_index) {
+                let body_size = node_layout.bounds().size();
+                let body = Rectangle::new(
+                    Point::new(
+                        geom.center[0] - body_size.width * 0.5,
+                        geom.center[1] - body_size.height * 0.5,
+                    ),
+                    body_size,
+                );
+                draw_badge(
+                    renderer,
+                    &state.sdf_animated,
+                    badge,
+                    body,
+                    self.node_transform(node_index).rotation,
+                    &render_context,
+                    layout.bounds(),
+                );
+            }
+
+            // Layer 4e: Connection counts on busy pins (constant screen size)
+            if self.show_pin_connection_counts {
+                let color = theme.extended_palette().background.strong.color;
+                for &(_, pin_state, (pin_pos, _)) in pins {
+                    let Some(&count) = pin_edge_counts.get(&(node_index, &pin_state.pin_id)) else {
+                        continue;
+                    };
+                    if count < 2 {
+                        continue;
+                    }
+                    // Out past the pin along its side and off to one flank,
+                    // so the label sits beside the edges rather than on them.
+                    let side = self.node_transform(node_index).side(pin_state.side);
+                    let [dx, dy] = pin_side_direction(side.into());
+                    let reach = PIN_COUNT_OFFSET / cam_zoom;
+                    let pin_world = (pin_pos.into_euclid().to_vector() + offset).to_point();
+                    let center = [
+                        pin_world.x + (dx + dy.abs()) * reach,
+                        pin_world.y + (dy - dx.abs()) * reach,
+                    ];
+                    draw_counter(
+                        renderer,
+                        &state.sdf_animated,
+                        center,
+                        PIN_COUNT_RADIUS,
+                        color,
+                        Some(u32::try_from(count).unwrap_or(u32::MAX)),
+                        &render_context,
+                        layout.bounds(),
+                        layout.bounds(),
+                    );
+                }
+            }
         }
         let t_after_fg = Instant::now();
 
@@ -1037,13 +1910,10 @@ where
             let cursor_world = cursor.position().map(cursor_layout).unwrap_or(*start);
 
             // Resolve box select colors: use callback if provided, otherwise use selection_style
-            let (fill_color, border_color) = if let Some(ref style_fn) = self.box_select_style_fn {
+            let box_style = if let Some(ref style_fn) = self.box_select_style_fn {
                 style_fn(theme)
             } else {
-                (
-                    resolved_graph.selection_style.box_select_fill,
-                    resolved_graph.selection_style.box_select_border,
-                )
+                resolved_graph.selection_style.box_select()
             };
 
             let center = [
@@ -1054,7 +1924,9 @@ where
                 ((cursor_world.x - start.x) * 0.5).abs(),
                 ((cursor_world.y - start.y) * 0.5).abs(),
             ];
-            let border_width = 1.5 / camera.zoom();
+            // Sized in screen pixels: constant on screen at any zoom.
+            let border_pattern = box_style.pattern.scale(1.0 / camera.zoom());
+            let border_width = border_pattern.thickness;
 
             let select_bounds = world_bbox_to_screen_bounds(
                 start.x,
@@ -1073,10 +1945,10 @@ where
                 // Border (front), fill (behind)
                 select_batch.push(
                     &select_shape,
-                    &Style::stroke(border_color, Pattern::solid(border_width)),
+                    &Style::stroke(box_style.border, border_pattern),
                     select_place,
                 );
-                select_batch.push(&select_shape, &Style::solid(fill_color), select_place);
+                select_batch.push(&select_shape, &Style::solid(box_style.fill), select_place);
 
                 let (cx, cy) = layer_camera(
                     render_context.camera_position,
@@ -1086,7 +1958,9 @@ where
                 );
                 let select_primitive = select_batch
                     .camera(cx, cy, render_context.camera_zoom)
-                    .time(render_context.time);
+                    .time(render_context.time)
+                    .aa_width(render_context.aa_width)
+                    .pixel_snap(render_context.pixel_snap);
 
                 renderer.with_layer(layout.bounds(), |renderer| {
                     draw_sdf(renderer, &state.sdf_animated, select_clip, select_primitive);
@@ -1135,7 +2009,9 @@ where
                 );
                 let cutting_primitive = cutting_batch
                     .camera(cx, cy, render_context.camera_zoom)
-                    .time(render_context.time);
+                    .time(render_context.time)
+                    .aa_width(render_context.aa_width)
+                    .pixel_snap(render_context.pixel_snap);
 
                 renderer.with_layer(layout.bounds(), |renderer| {
                     draw_sdf(
@@ -1153,7 +2029,6 @@ where
         // them; cheap otherwise (a few elapsed reads + one bbox test per node).
         if self.on_info.is_some() {
             let viewport = layout.bounds();
-            let mut node_in_view = vec![false; node_geoms.len()];
             let mut nodes_in = 0usize;
             let mut pins_total = 0usize;
             let mut pins_in = 0usize;
@@ -1174,7 +2049,6 @@ where
                     height: bb[3],
                 };
                 let in_view = rect.intersects(&viewport);
-                node_in_view[i] = in_view;
                 if in_view {
                     nodes_in += 1;
                 }
@@ -1184,14 +2058,6 @@ where
                     pins_in += pin_count;
                 }
             }
-            let edges_in = self
-                .edges
-                .iter()
-                .filter(|(_, from, to, _)| {
-                    let visible = |id| self.node_index(id).is_some_and(|idx| node_in_view[idx]);
-                    visible(&from.node_id) || visible(&to.node_id)
-                })
-                .count();
 
             let counts = |total: usize, in_view: usize| Counts {
                 total,
@@ -1202,7 +2068,7 @@ where
             let info = GraphInfo {
                 nodes: counts(node_geoms.len(), nodes_in),
                 pins: counts(pins_total, pins_in),
-                edges: counts(self.edges.len(), edges_in),
+                edges: counts(self.edges.len(), edges_in_view),
                 timings: vec![
                     OpTiming {
                         label: "geometry",
@@ -0,0 +1,210 @@
+//! [`NodeGraph::layout_snapshot`]: the screen geometry of nodes and edges,
+//! laid out and projected like the renderer does, without drawing; and
+//! [`NodeGraph::pin_world_position`], the world anchor of one pin.
+
+use super::*;
+use crate::node_graph::{EdgeSnapshot, LayoutSnapshot, camera::Camera2D};
+use iced_wgpu::core::Widget;
+
+impl<N, P, E, UI, Message, Renderer> NodeGraph<'_, N, P, UI, Message, iced::Theme, Renderer, E>
+where
+    N: NodeId + 'static,
+    P: PinId + 'static,
+    E: EdgeId + 'static,
+    UI: Clone + 'static,
+    Renderer: iced_wgpu::core::renderer::Renderer
+        + iced_wgpu::primitive::Renderer
+        + iced_wgpu::core::text::Renderer<Font = iced::Font>,
+{
+    /// Lays the graph out in a widget of `viewport_size` and projects every
+    /// node and edge through the camera at `position` and `zoom` (as for
+    /// [`view`](Self::view)), on the CPU.
+    ///
+    /// Node sizes come from their content, so `renderer` is only used to
+    /// measure it; nothing is drawn and no GPU is needed, which makes a
+    /// headless or mock renderer enough. The snapshot uses the given camera
+    /// (the graph's own `view()` is ignored) and a fresh widget state, so
+    /// selection and drags play no part. Screen-fixed nodes resolve their
+    /// anchors against `viewport_size`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let snapshot = graph.layout_snapshot(&renderer, Size::new(800.0, 600.0), Point::new(10.0, 0.0), 2.0);
+    /// // screen = (world + position) * zoom
+    /// assert_eq!(snapshot.node(&0).map(|r| r.position()), Some(Point::new(80.0, 80.0)));
+    /// ```
+    pub fn layout_snapshot(
+        &mut self,
+        renderer: &Renderer,
+        viewport_size: Size,
+        position: Point,
+        zoom: f32,
+    ) -> LayoutSnapshot<N, P> {
+        let camera = Camera2D::with_zoom_and_position(zoom, position.into_euclid());
+        let mut tree = Tree::new(&*self as &dyn Widget<Message, iced::Theme, Renderer>);
+        tree.state.downcast_mut::<NodeGraphState>().camera = camera;
+        let node = Widget::layout(
+            self,
+            &mut tree,
+            renderer,
+            &layout::Limits::new(Size::ZERO, viewport_size),
+        );
+        // Laid out at the origin, layout-absolute positions are world positions.
+        let layout = Layout::new(&node);
+        let to_screen = camera.world_to_screen();
+        let project =
+            |p: Point| -> Point { to_screen.transform_point(p.into_euclid()).into_iced() };
+        let zoom = camera.zoom();
+
+        let nodes = layout
+            .children()
+            .enumerate()
+            .filter_map(|(node_index, node_layout)| {
+                let bounds = node_layout.bounds();
+                let id = self.index_to_node_id(node_index)?;
+                Some((
+                    id,
+                    Rectangle::new(project(bounds.position()), bounds.size() * zoom),
+                ))
+            })
+            .collect();
+
+        let anchors: Vec<Vec<(&P, Point, PinSide)>> = layout
+            .children()
+            .zip(&tree.children)
+            .enumerate()
+            .map(|(node_index, (node_layout, node_tree))| {
+                let mut pins = Vec::new();
+                let transform = self.node_transform(node_index);
+                pin_anchors::<P, UI>(
+                    &mut pins,
+                    node_tree,
+                    node_layout,
+                    node_layout.bounds(),
+                    transform.collapsed,
+                );
+                place_anchors(&mut pins, node_layout.bounds(), transform);
+                pins
+            })
+            .collect();
+        // Anchor, side and node centre of a pin, all in world space.
+        let anchor = |pin_ref: &PinRef<N, P>| {
+            let node_index = self.node_index(&pin_ref.node_id)?;
+            let center = layout.children().nth(node_index)?.bounds().center();
+            let side = |side| self.node_transform(node_index).side(side);
+            anchors
+                .get(node_index)?
+                .iter()
+                .find(|(pin_id, ..)| **pin_id == pin_ref.pin_id)
+                .map(|&(_, position, pin_side)| (position, side(pin_side), center))
+        };
+        let edges = self
+            .edges
+            .iter()
+            .filter_map(|(_, from, to, _)| {
+                let (mut start, start_side, start_center) = anchor(from)?;
+                let (mut end, end_side, end_center) = anchor(to)?;
+                if self.auto_pin_side {
+                    start = facing_side(start, start_side, start_center, end_center).0;
+                    end = facing_side(end, end_side, end_center, start_center).0;
+                }
+                Some(EdgeSnapshot {
+                    start: project(start),
+                    end: project(end),
+                    from: from.clone(),
+                    to: to.clone(),
+                })
+            })
+            .collect();
+
+        LayoutSnapshot { nodes, edges }
+    }
+
+    /// World-space position of `pin`'s marker after layout: the point its
+    /// edges attach to (a row pin's left marker). `None` when the node or pin
+    /// id is unknown.
+    ///
+    /// Pass the graph's tree and layout from a wrapper widget or test, as for
+    /// [`pin_at_screen`](Self::pin_at_screen). The position is the laid-out
+    /// one, without any in-progress drag offset.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// // Node 0 at world (30, 40) with a 40x20 body and a right-side pin.
+    /// let at = graph.pin_world_position(&tree, layout, &PinRef::new(0, 0));
+    /// assert_eq!(at, Some(Point::new(70.0, 50.0)));
+    /// ```
+    pub fn pin_world_position(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        pin: &PinRef<N, P>,
+    ) -> Option<Point> {
+        let node_index = self.node_index(&pin.node_id)?;
+        let node_layout = layout.children().nth(node_index)?;
+        let node_tree = tree.children.get(node_index)?;
+        let transform = self.node_transform(node_index);
+        let mut anchors = Vec::new();
+        pin_anchors::<P, UI>(
+            &mut anchors,
+            node_tree,
+            node_layout,
+            node_layout.bounds(),
+            transform.collapsed,
+        );
+        place_anchors(&mut anchors, node_layout.bounds(), transform);
+        let (_, anchor, _) = anchors
+            .into_iter()
+            .find(|(pin_id, ..)| **pin_id == pin.pin_id)?;
+        // Layout-absolute minus the widget origin is world space.
+        Some(anchor - (layout.bounds().position() - Point::ORIGIN))
+    }
+}
+
+/// Moves collected anchors to where a node placed by `transform` renders
+/// them: onto its outline, then mirrored and turned about the centre of its
+/// body.
+fn place_anchors<P>(
+    anchors: &mut [(&P, Point, PinSide)],
+    node_bounds: Rectangle,
+    transform: NodeTransform,
+) {
+    if transform == NodeTransform::default() {
+        return;
+    }
+    for (_, position, side) in anchors {
+        *position = transform.shape.attach(*position, *side, node_bounds);
+        *position = place_pin(*position, node_bounds.center(), transform);
+    }
+}
+
+/// Collects the edge anchor and side of every pin under `tree`, walking the widget
+/// tree and its layout in step. Pins record their position only once they see
+/// an event, so the anchor is derived from the pin's own layout instead; a
+/// row pin anchors on its left end, as edges draw it. On a `collapsed` node
+/// side pins anchor on the title row.
+fn pin_anchors<'a, P: 'static, UI: 'static>(
+    out: &mut Vec<(&'a P, Point, PinSide)>,
+    tree: &'a Tree,
+    layout: Layout<'_>,
+    node_bounds: Rectangle,
+    collapsed: bool,
+) {
+    if tree.tag == tree::Tag::of::<NodePinState<P, UI>>() {
+        let pin_state = tree.state.downcast_ref::<NodePinState<P, UI>>();
+        let side = match pin_state.side {
+            PinSide::Row => PinSide::Left,
+            side => side,
+        };
+        let mut position = pin_position(layout.bounds().center(), side, node_bounds);
+        if collapsed {
+            position = stack_pin(position, side, node_bounds.center());
+        }
+        out.push((&pin_state.pin_id, position, pin_state.side));
+    }
+    for (child_tree, child_layout) in tree.children.iter().zip(layout.children()) {
+        pin_anchors::<P, UI>(out, child_tree, child_layout, node_bounds, collapsed);
+    }
+}
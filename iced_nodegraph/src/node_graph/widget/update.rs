@@ -1,9 +1,11 @@
 //! The `update` event path of [`NodeGraph`]: the `Dragging` state machine
-//! and its update-exclusive hit-test helpers.
+//! and its update-exclusive hit-test helpers, plus `mouse_interaction`,
+//! which reuses those hit tests to pick the hover cursor.
 //!
 //! Split out of `widget.rs` mechanically.
 
 use super::*;
+use crate::node_graph::camera::Camera2D;
 use crate::node_graph::input::KeyAction;
 use iced::touch;
 
@@ -19,11 +21,48 @@ const UNSNAP_THRESHOLD: f32 = 15.0; // Distance to leave snap zone (larger = mor
 // Edge-cut click distance (screen px, scaled by 1/zoom like the above)
 const EDGE_CUT_THRESHOLD: f32 = 10.0;
 
+// Edge handle grab distance (screen px, scaled by 1/zoom like the above)
+const EDGE_HANDLE_THRESHOLD: f32 = 8.0;
+
 // Touch gesture thresholds: maximum travel (screen px) and duration for a
 // press+lift pair to count as a tap.
 const TOUCH_TAP_TRAVEL: f32 = 8.0;
 const TOUCH_TAP_MAX_SECS: f32 = 0.3;
 
+// Elastic zoom spring-back: exponential decay rate (1/s) of the overshoot,
+// and the remaining overshoot (fraction of the limit) at which it snaps home.
+const ZOOM_BOUNCE_RATE: f32 = 20.0;
+const ZOOM_BOUNCE_SETTLE: f32 = 0.002;
+
+// Edge auto-pan while dragging nodes: width of the band along the widget edge
+// (screen px) and the pan speed at its outer edge (screen px per second).
+const AUTOPAN_MARGIN: f32 = 40.0;
+const AUTOPAN_SPEED: f32 = 600.0;
+
+// Margin kept between a keyboard-selected node and the view edge when the
+// camera pans to reveal it.
+const REVEAL_MARGIN: f32 = 24.0;
+
+// Screen pixels the canvas pans per wheel line, for mice that report lines
+// rather than pixels.
+const WHEEL_LINE_PIXELS: f32 = 40.0;
+
+/// Auto-pan velocity in screen px/s for a cursor at `cursor`: per axis,
+/// pointing toward the nearby edge of `bounds` and scaled by how deep the
+/// cursor sits in that edge's [`AUTOPAN_MARGIN`] band (full speed at or past
+/// the edge). Zero away from the edges.
+fn autopan_velocity(bounds: Rectangle, cursor: Point) -> Vector {
+    let axis = |pos: f32, min: f32, max: f32| {
+        let toward_min = ((min + AUTOPAN_MARGIN - pos) / AUTOPAN_MARGIN).clamp(0.0, 1.0);
+        let toward_max = ((pos - (max - AUTOPAN_MARGIN)) / AUTOPAN_MARGIN).clamp(0.0, 1.0);
+        (toward_max - toward_min) * AUTOPAN_SPEED
+    };
+    Vector::new(
+        axis(cursor.x, bounds.x, bounds.x + bounds.width),
+        axis(cursor.y, bounds.y, bounds.y + bounds.height),
+    )
+}
+
 /// Mutable per-event context threaded through the `update` handlers.
 ///
 /// One instance is built at the top of the `update_with` closure and passed
@@ -72,11 +111,11 @@ where
             && state.last_synced_view != Some(view)
         {
             let (position, zoom) = view;
-            state.camera = crate::node_graph::camera::Camera2D::with_zoom_and_position(
-                zoom,
-                WorldPoint::new(position.x, position.y),
-            );
+            state.camera =
+                Camera2D::with_zoom_and_position(zoom, WorldPoint::new(position.x, position.y));
             state.last_synced_view = Some(view);
+            // A pushed view supersedes any in-flight elastic bounce.
+            state.zoom_bounce = None;
         }
 
         // Refresh the viewport origin so screen->layout mapping (cursor hit-tests,
@@ -107,24 +146,97 @@ where
             state.last_synced_external = Some(external.clone());
         }
 
+        // Warn once per stretch over the performance hint's node threshold.
+        if self.over_performance_threshold() {
+            if !state.performance_warned {
+                state.performance_warned = true;
+                if let Some(handler) = self.on_performance_warning_handler() {
+                    shell.publish(handler(self.nodes.len()));
+                }
+            }
+        } else {
+            state.performance_warned = false;
+        }
+
+        // Report the first layout that measured any node, once per widget.
+        if !state.first_layout_reported
+            && let Some(handler) = self.on_first_layout_handler()
+        {
+            let all: Vec<usize> = (0..self.nodes.len()).collect();
+            if let Some(bounds) = self.world_bounds_of(layout, &all) {
+                state.first_layout_reported = true;
+                shell.publish(handler(bounds));
+            }
+        }
+
         // Update time for animations
         // Cap delta to prevent large time jumps when app is in background
         let now = Instant::now();
+        let mut frame_delta = 0.0;
 
         if let Some(last_update) = state.last_update {
             let delta = now.duration_since(last_update).as_secs_f32();
             // Cap at 100ms to prevent freeze after background
-            let capped_delta = delta.min(0.1);
-            state.time += capped_delta;
+            frame_delta = delta.min(0.1);
+            state.time += frame_delta;
         }
         state.last_update = Some(now);
 
+        // Start the appear animation of nodes pushed since the last update.
+        let appearing = state.node_appear.len();
+        state.track_appearing(
+            self.nodes.len(),
+            self.node_appear_animation
+                .filter(|_| self.animates())
+                .map(|d| d.as_secs_f32()),
+        );
+        if state.node_appear.len() > appearing {
+            shell.request_redraw();
+        }
+
         // On each frame, drive continuous redraws for SDF animations and deliver
         // the diagnostics measured during the previous draw().
         if let Event::Window(iced::window::Event::RedrawRequested(_)) = event {
-            if state.sdf_animated.get() {
+            if self.animates() && (state.sdf_animated.get() || !state.node_appear.is_empty()) {
+                shell.request_redraw();
+            }
+            // Spring an elastic zoom overshoot back to the limit, committing
+            // the camera only once it has landed inside the range.
+            if let Some(anchor) = state.zoom_bounce {
+                let t = 1.0 - (-ZOOM_BOUNCE_RATE * frame_delta).exp();
+                let camera = state.camera.relax_zoom(anchor, t);
+                let zoom = camera.zoom();
+                let limit = zoom.clamp(Camera2D::ZOOM_MIN, Camera2D::ZOOM_MAX);
+                if (zoom - limit).abs() <= limit * ZOOM_BOUNCE_SETTLE {
+                    state.camera = camera.relax_zoom(anchor, 1.0);
+                    state.zoom_bounce = None;
+                    if let Some(handler) = self.on_pan_handler() {
+                        let pos = state.camera.position();
+                        shell.publish(handler(Point::new(pos.x, pos.y), state.camera.zoom()));
+                    }
+                } else {
+                    state.camera = camera;
+                }
                 shell.request_redraw();
             }
+            // Auto-pan toward the edge a node drag holds the cursor at. Drag
+            // offsets are re-derived from the cursor through the camera, so the
+            // dragged nodes follow the pan without touching the drag origin.
+            if self.edge_autopan
+                && matches!(state.dragging, Dragging::Node(..) | Dragging::GroupMove(_))
+                && let Some(cursor) = screen_cursor.position()
+            {
+                let velocity = autopan_velocity(layout.bounds(), cursor);
+                if velocity != Vector::ZERO {
+                    let step = velocity * (frame_delta / state.camera.zoom());
+                    state.camera = state.camera.move_by(WorldVector::new(-step.x, -step.y));
+                    if let Some(handler) = self.on_pan_handler() {
+                        let pos = state.camera.position();
+                        shell.publish(handler(Point::new(pos.x, pos.y), state.camera.zoom()));
+                    }
+                    shell.request_redraw();
+                }
+            }
             // Publish the stashed GraphInfo (set during draw) one frame behind,
             // mirroring the controlled on_pan pattern. A host showing live
             // diagnostics needs a steady frame stream, so keep redraws flowing.
@@ -139,11 +251,79 @@ where
         // Track keyboard modifiers for Shift/Ctrl selection
         if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) = event {
             state.modifiers = *modifiers;
+            // The axis lock and the box select preview (add vs replace)
+            // apply at once, not on the next cursor move.
+            if matches!(
+                state.dragging,
+                Dragging::Node(..) | Dragging::GroupMove(_) | Dragging::BoxSelect(..)
+            ) {
+                shell.request_redraw();
+            }
+        }
+
+        // A modal overlay holds the input: drop any drag in flight and let
+        // neither the graph nor node content see the event.
+        if !self.input_enabled
+            && matches!(
+                event,
+                Event::Mouse(_) | Event::Touch(_) | Event::Keyboard(_) | Event::InputMethod(_)
+            )
+        {
+            if state.dragging != Dragging::None || state.connect_armed {
+                self.cancel_drag(tree, layout, shell);
+            }
+            return;
+        }
+
+        // Keyboard focus for the navigation keys follows the last press:
+        // inside the graph takes it, anywhere else gives it up.
+        // The focus ring only marks keyboard focus, so any press hides it.
+        if let Event::Mouse(mouse::Event::ButtonPressed(_)) = event {
+            state.focused = screen_cursor.is_over(layout.bounds());
+            state.focused_node = None;
+        }
+        if let Event::Touch(touch::Event::FingerPressed { position, .. }) = event {
+            state.focused = layout.bounds().contains(*position);
+            state.focused_node = None;
+        }
+
+        // Pins shown on hover follow the topmost node under the cursor; pins
+        // sit on the node border, so the hover area reaches the pin hit radius
+        // past it.
+        if self.pin_visibility == PinVisibility::OnHover
+            && let Event::Mouse(mouse::Event::CursorMoved { .. } | mouse::Event::CursorLeft) = event
+        {
+            let margin = PIN_CLICK_THRESHOLD / state.camera.zoom();
+            let hovered = screen_cursor
+                .position_over(layout.bounds())
+                .and_then(|position| {
+                    let world: WorldPoint = state
+                        .camera
+                        .screen_to_world()
+                        .transform_point(position.into_euclid());
+                    z_indices.iter().rev().copied().find(|&node_index| {
+                        layout
+                            .children()
+                            .nth(node_index)
+                            .is_some_and(|node_layout| {
+                                body_contains(
+                                    node_layout.bounds().expand(margin),
+                                    self.node_transform(node_index),
+                                    world.into_iced(),
+                                )
+                            })
+                    })
+                });
+            if hovered != state.hovered_node {
+                state.hovered_node = hovered;
+                shell.request_redraw();
+            }
         }
 
         // Handle keyboard shortcuts through the host-configurable keymap
-        // (`NodeGraph::keymap`). DeleteSelection is handled AFTER child
-        // widgets (further down) so text inputs can consume the key first.
+        // (`NodeGraph::keymap`). DeleteSelection, SelectAll, InvertSelection
+        // and (Un)GroupSelection are handled AFTER child widgets (further
+        // down) so text inputs can consume the key first.
         if let Event::Keyboard(keyboard::Event::KeyPressed {
             key,
             physical_key,
@@ -151,7 +331,14 @@ where
             ..
         }) = event
         {
-            match self.keymap.key_action(key, *physical_key, *modifiers) {
+            let action = self.keymap.key_action(key, *physical_key, *modifiers);
+            // The clear-selection key cancels an armed click-to-connect edge
+            // first, leaving the selection alone.
+            if action == Some(KeyAction::ClearSelection) && state.connect_armed {
+                self.cancel_drag(tree, layout, shell);
+                return;
+            }
+            match action {
                 // Gated on on_clone: without a handler the clone cannot be
                 // persisted, so leave the shortcut unhandled and let the key
                 // fall through instead of silently swallowing it.
@@ -165,18 +352,9 @@ where
                     }
                     shell.capture_event();
                 }
-                Some(KeyAction::SelectAll) => {
-                    let count = self.nodes.len();
-                    state.selected_nodes = (0..count).collect();
-                    let indices: Vec<usize> = state.selected_nodes.iter().copied().collect();
-                    let selected = self.translate_node_ids(&indices);
-                    if let Some(handler) = self.on_select_handler() {
-                        shell.publish(handler(selected));
-                    }
-                    shell.capture_event();
-                    shell.request_redraw();
-                }
-                Some(KeyAction::ClearSelection) if !state.selected_nodes.is_empty() => {
+                Some(KeyAction::ClearSelection)
+                    if self.standard_shortcuts && !state.selected_nodes.is_empty() =>
+                {
                     state.selected_nodes.clear();
                     if let Some(handler) = self.on_select_handler() {
                         shell.publish(handler(vec![]));
@@ -201,17 +379,43 @@ where
                 mouse::ScrollDelta::Pixels { y, .. } => *y,
                 mouse::ScrollDelta::Lines { y, .. } => *y * 10.0,
             };
+            // Screen distance a pan moves the canvas by.
+            let scrolled = match delta {
+                mouse::ScrollDelta::Pixels { x, y } => Vector::new(*x, *y),
+                mouse::ScrollDelta::Lines { x, y } => Vector::new(*x, *y) * WHEEL_LINE_PIXELS,
+            };
+            let swap = self.keymap.scroll_pan_modifiers;
+            let swapped = !swap.is_empty() && state.modifiers.contains(swap);
+            let wheel_pans = (self.scroll_mode == ScrollMode::Pan) != swapped;
+
+            // Horizontal scroll always pans; the vertical wheel only in pan
+            // mode.
+            let pan_y = if wheel_pans { scrolled.y } else { 0.0 };
+            if scrolled.x != 0.0 || pan_y != 0.0 {
+                let pan = WorldVector::new(scrolled.x, pan_y) / state.camera.zoom();
+                state.camera = state.camera.move_by(pan);
+            }
 
-            // Different zoom speeds for WASM vs native
-            #[cfg(target_arch = "wasm32")]
-            let zoom_delta = scroll_amount * 0.001 * state.camera.zoom();
-            #[cfg(not(target_arch = "wasm32"))]
-            let zoom_delta = scroll_amount * 0.01 * state.camera.zoom();
+            if !wheel_pans && scroll_amount != 0.0 {
+                // Different zoom speeds for WASM vs native
+                #[cfg(target_arch = "wasm32")]
+                let zoom_delta = scroll_amount * 0.001 * state.camera.zoom();
+                #[cfg(not(target_arch = "wasm32"))]
+                let zoom_delta = scroll_amount * 0.01 * state.camera.zoom();
 
-            state.camera = state.camera.zoom_at(cursor_pos, zoom_delta);
+                if self.elastic_zoom() {
+                    state.camera = state.camera.zoom_at_elastic(cursor_pos, zoom_delta);
+                    state.zoom_bounce = state.camera.is_overshooting().then_some(cursor_pos);
+                } else {
+                    state.camera = state.camera.zoom_at(cursor_pos, zoom_delta);
+                }
+            }
 
-            // Commit the new camera (zoom shifts position too).
-            if let Some(handler) = self.on_pan_handler() {
+            // Commit the new camera (zoom shifts position too). An elastic
+            // overshoot is committed by the bounce once it settles instead.
+            if let Some(handler) = self.on_pan_handler()
+                && state.zoom_bounce.is_none()
+            {
                 let pos = state.camera.position();
                 shell.publish(handler(Point::new(pos.x, pos.y), state.camera.zoom()));
             }
@@ -269,6 +473,10 @@ where
                         shell,
                     };
                     let state = ctx.tree.state.downcast_mut::<NodeGraphState>();
+                    if state.dragging == Dragging::None {
+                        state.drag_origin = None;
+                        state.connect_armed = false;
+                    }
 
                     if state.dragging != Dragging::None
                         && let Event::Mouse(mouse::Event::CursorMoved { .. }) = event
@@ -279,10 +487,28 @@ where
                         {
                             ctx.shell.publish(handler(cursor_position));
                         }
+                        if let Some(cursor_position) = world_cursor.position()
+                            && let Some(handler) = self.on_drag_handler()
+                            && let Some(origin) = state.drag_origin
+                        {
+                            // The origin is layout-absolute like the cursor;
+                            // the published start is world space.
+                            let vo = state.camera.viewport_origin();
+                            let progress = DragProgress {
+                                start: Point::new(origin.x - vo.x, origin.y - vo.y),
+                                delta: cursor_position - origin.into_iced(),
+                                modifiers: state.modifiers,
+                            };
+                            let dragging = state.dragging.clone();
+                            if let Some(info) = self.drag_info(ctx.tree, layout, &dragging) {
+                                ctx.shell.publish(handler(info, progress));
+                            }
+                        }
                         ctx.shell.capture_event();
                         ctx.shell.request_redraw();
                     }
 
+                    let state = ctx.tree.state.downcast_mut::<NodeGraphState>();
                     // The `Dragging` state machine, part 1: transitions of an
                     // in-progress drag, one handler per variant. Part 2 - the
                     // `None -> *` entry transitions - is the button-press
@@ -305,6 +531,9 @@ where
                             self.handle_box_select(&mut ctx, start)
                         }
                         Dragging::GroupMove(origin) => self.handle_group_move(&mut ctx, origin),
+                        Dragging::Waypoint { edge, index, .. } => {
+                            self.handle_waypoint_drag(&mut ctx, edge, index)
+                        }
                     }
 
                     // Iterate top-first so the topmost node's child widgets get a
@@ -330,16 +559,38 @@ where
                         let Some(child_layout) = layout.children().nth(node_index) else {
                             continue;
                         };
+                        // A collapsed node's body below the title is clipped
+                        // away, so its content must not see the cursor there.
+                        let collapsed = self
+                            .transforms
+                            .get(&node_index)
+                            .is_some_and(|t| t.collapsed);
+                        let node_cursor = match world_cursor.position() {
+                            Some(position)
+                                if collapsed && !child_layout.bounds().contains(position) =>
+                            {
+                                mouse::Cursor::Unavailable
+                            }
+                            _ => world_cursor,
+                        };
                         element.as_widget_mut().update(
                             child_tree,
                             event,
                             child_layout,
-                            world_cursor,
+                            node_cursor,
                             renderer,
                             clipboard,
                             ctx.shell,
                             viewport,
                         );
+                        if collapsed {
+                            element.as_widget_mut().operate(
+                                child_tree,
+                                child_layout,
+                                renderer,
+                                &mut CollapsePins,
+                            );
+                        }
                         if !pre_captured && ctx.shell.is_event_captured() {
                             break;
                         }
@@ -375,6 +626,90 @@ where
                         ctx.shell.request_redraw();
                     }
 
+                    // Select all / invert selection, likewise after child
+                    // widgets so a text input keeps Ctrl+A / Ctrl+I, and only
+                    // while the graph holds keyboard focus.
+                    if let Event::Keyboard(keyboard::Event::KeyPressed {
+                        key,
+                        physical_key,
+                        modifiers,
+                        ..
+                    }) = event
+                        && state.focused
+                        && self.standard_shortcuts
+                    {
+                        let action = self.keymap.key_action(key, *physical_key, *modifiers);
+                        let selected = match action {
+                            Some(KeyAction::SelectAll) => Some((0..self.nodes.len()).collect()),
+                            Some(KeyAction::InvertSelection) => Some(
+                                (0..self.nodes.len())
+                                    .filter(|index| !state.selected_nodes.contains(index))
+                                    .collect(),
+                            ),
+                            _ => None,
+                        };
+                        if let Some(selected) = selected {
+                            state.selected_nodes = selected;
+                            let indices: Vec<usize> =
+                                state.selected_nodes.iter().copied().collect();
+                            let node_ids = self.translate_node_ids(&indices);
+                            if let Some(handler) = self.on_select_handler() {
+                                ctx.shell.publish(handler(node_ids));
+                            }
+                            ctx.shell.capture_event();
+                            ctx.shell.request_redraw();
+                        }
+                    }
+
+                    // Group / ungroup, after child widgets and focus-gated like
+                    // select all. Gated like clone: the host owns the group
+                    // model, so without its handler the key falls through.
+                    if let Event::Keyboard(keyboard::Event::KeyPressed {
+                        key,
+                        physical_key,
+                        modifiers,
+                        ..
+                    }) = event
+                        && state.focused
+                        && self.standard_shortcuts
+                        && !state.selected_nodes.is_empty()
+                    {
+                        let indices: Vec<usize> = state.selected_nodes.iter().copied().collect();
+                        match self.keymap.key_action(key, *physical_key, *modifiers) {
+                            Some(KeyAction::GroupSelection) => {
+                                if let Some(handler) = self.on_group_create_handler() {
+                                    if let Some(bounds) = self.world_bounds_of(layout, &indices) {
+                                        let node_ids = self.translate_node_ids(&indices);
+                                        ctx.shell.publish(handler(node_ids, bounds));
+                                    }
+                                    ctx.shell.capture_event();
+                                }
+                            }
+                            Some(KeyAction::UngroupSelection) => {
+                                if let Some(handler) = self.on_ungroup_handler() {
+                                    let node_ids = self.translate_node_ids(&indices);
+                                    ctx.shell.publish(handler(node_ids));
+                                    ctx.shell.capture_event();
+                                }
+                            }
+                            _ => {}
+                        }
+                    }
+
+                    // Tab / arrow selection navigation, likewise after child
+                    // widgets so a focused text input keeps its caret keys.
+                    if let Event::Keyboard(keyboard::Event::KeyPressed {
+                        key,
+                        physical_key,
+                        modifiers,
+                        ..
+                    }) = event
+                        && state.focused
+                        && let Some(action) = self.keymap.key_action(key, *physical_key, *modifiers)
+                    {
+                        self.navigate_selection(&mut ctx, action, viewport);
+                    }
+
                     // Only process mouse events if cursor is within our bounds
                     if !screen_cursor.is_over(layout.bounds()) {
                         return;
@@ -393,10 +728,228 @@ where
                         }
                         _ => {}
                     }
+
+                    let state = ctx.tree.state.downcast_mut::<NodeGraphState>();
+                    if state.dragging != Dragging::None && state.drag_origin.is_none() {
+                        state.drag_origin = world_cursor.position().map(IntoEuclid::into_euclid);
+                    }
                 },
             );
     }
 
+    /// Returns the ID of the topmost node whose body is under `point`, in
+    /// window (screen) coordinates.
+    ///
+    /// `tree` and `layout` are the graph's own widget tree and layout, as a
+    /// wrapping widget or a test harness driving the graph sees them. Uses
+    /// the camera and z-order the graph last updated with; pins are not part
+    /// of a node's body here (see [`pin_at_screen`](Self::pin_at_screen)).
+    pub fn node_at_screen(&self, tree: &Tree, layout: Layout<'_>, point: Point) -> Option<N> {
+        let cursor = self.screen_to_layout(tree, layout, point)?;
+        let state = tree.state.downcast_ref::<NodeGraphState>();
+        z_render_indices(state, self.nodes.len())
+            .into_iter()
+            .rev()
+            .find(|&node_index| {
+                layout
+                    .children()
+                    .nth(node_index)
+                    .is_some_and(|node_layout| {
+                        body_contains(
+                            node_layout.bounds(),
+                            self.node_transform(node_index),
+                            cursor,
+                        )
+                    })
+            })
+            .and_then(|node_index| self.index_to_node_id(node_index))
+    }
+
+    /// Returns the pin under `point`, in window (screen) coordinates, using
+    /// the same hit test as a press that starts a connection drag.
+    ///
+    /// Nodes are tried top-first: the first one with an enabled pin within
+    /// reach of `point` yields it, and a node body covering `point` blocks the
+    /// pins beneath. The reach is a constant screen distance at any zoom. Pins
+    /// record their place whenever the graph updates, so query after at least
+    /// one event has gone through. `tree` and `layout` are as for
+    /// [`node_at_screen`](Self::node_at_screen).
+    pub fn pin_at_screen(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        point: Point,
+    ) -> Option<PinRef<N, P>> {
+        let cursor = self.screen_to_layout(tree, layout, point)?;
+        let state = tree.state.downcast_ref::<NodeGraphState>();
+        let click_threshold = PIN_CLICK_THRESHOLD / state.camera.zoom();
+        for node_index in z_render_indices(state, self.nodes.len()).into_iter().rev() {
+            let (Some(node_tree), Some(node_layout)) = (
+                tree.children.get(node_index),
+                layout.children().nth(node_index),
+            ) else {
+                continue;
+            };
+            let transform = self.node_transform(node_index);
+            let hit = find_pins_placed::<P, UI>(node_tree, node_layout, transform)
+                .into_iter()
+                .find(|(_, pin_state, (a, b))| {
+                    !pin_state.interactions_disabled
+                        && a.distance(cursor).min(b.distance(cursor)) < click_threshold
+                });
+            if let Some((_, pin_state, _)) = hit {
+                let node_id = self.index_to_node_id(node_index)?;
+                return Some(PinRef::new(node_id, pin_state.pin_id.clone()));
+            }
+            if body_contains(node_layout.bounds(), transform, cursor) {
+                return None;
+            }
+        }
+        None
+    }
+
+    /// Index of the edge whose curve passes within `EDGE_CUT_THRESHOLD`
+    /// screen pixels of `cursor`, the closest if several do. A pin or node
+    /// body under the cursor hides the edges beneath it.
+    pub(super) fn edge_at_screen(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> Option<usize> {
+        let point = cursor.position_over(layout.bounds())?;
+        if self.pin_at_screen(tree, layout, point).is_some()
+            || self.node_at_screen(tree, layout, point).is_some()
+        {
+            return None;
+        }
+        let cursor = self.screen_to_layout(tree, layout, point)?;
+        let threshold =
+            EDGE_CUT_THRESHOLD / tree.state.downcast_ref::<NodeGraphState>().camera.zoom();
+        self.edges
+            .iter()
+            .enumerate()
+            .filter_map(|(edge_idx, (_id, from_ref, to_ref, _style))| {
+                let distance = self
+                    .edge_path(tree, layout, edge_idx, from_ref, to_ref)
+                    .into_iter()
+                    .map(|[p0, p1, p2, p3]| point_to_bezier_distance(cursor, p0, p1, p2, p3))
+                    .fold(f32::INFINITY, f32::min);
+                (distance < threshold).then_some((edge_idx, distance))
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(edge_idx, _)| edge_idx)
+    }
+
+    /// Maps a window point into layout-absolute space through the graph's
+    /// camera, or `None` when it lies outside the widget.
+    fn screen_to_layout(&self, tree: &Tree, layout: Layout<'_>, point: Point) -> Option<Point> {
+        let camera = tree
+            .state
+            .downcast_ref::<NodeGraphState>()
+            .camera
+            .with_viewport_origin(layout.bounds().position().into_euclid().to_vector());
+        camera
+            .cursor_screen_to_layout(mouse::Cursor::Available(point))
+            .position()
+            .filter(|_| layout.bounds().contains(point))
+    }
+
+    /// Backs `Widget::mouse_interaction`: the cursor for the active drag, or
+    /// for whatever the pointer hovers, mapped through the configured
+    /// [`CursorConfig`](crate::CursorConfig).
+    ///
+    /// Hover resolution mirrors the press dispatch in `handle_left_press`:
+    /// top-first by z-order, the first node owning the cursor (its content
+    /// widgets, then its pins, then its body) decides.
+    pub(super) fn mouse_interaction_impl(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        screen_cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if !self.input_enabled {
+            return mouse::Interaction::default();
+        }
+        let state = tree.state.downcast_ref::<NodeGraphState>();
+        let cursors = &self.cursors;
+        match state.dragging {
+            Dragging::None => {}
+            Dragging::Graph(_) => return cursors.panning,
+            Dragging::Node(..) | Dragging::GroupMove(_) | Dragging::Waypoint { .. } => {
+                return cursors.node_dragging;
+            }
+            Dragging::Edge(..) | Dragging::EdgeOver(..) => return cursors.edge_dragging,
+            Dragging::BoxSelect(..) => return cursors.box_select,
+            Dragging::EdgeCutting { .. } => return cursors.edge_cutting,
+        }
+        if screen_cursor.position_over(layout.bounds()).is_none() {
+            return mouse::Interaction::default();
+        }
+
+        let mut interaction = cursors.canvas;
+        let pin_threshold = PIN_CLICK_THRESHOLD / state.camera.zoom();
+        state
+            .camera
+            .update_with(viewport, screen_cursor, |world_viewport, world_cursor| {
+                let Some(cursor_position) = world_cursor.position() else {
+                    return;
+                };
+                for node_index in z_render_indices(state, self.nodes.len()).into_iter().rev() {
+                    let (Some((_, _, element, _, _)), Some(node_tree), Some(node_layout)) = (
+                        self.nodes.get(node_index),
+                        tree.children.get(node_index),
+                        layout.children().nth(node_index),
+                    ) else {
+                        continue;
+                    };
+                    let transform = self.node_transform(node_index);
+                    let over_body = body_contains(node_layout.bounds(), transform, cursor_position);
+                    if over_body {
+                        let child = element.as_widget().mouse_interaction(
+                            node_tree,
+                            node_layout,
+                            world_cursor,
+                            world_viewport,
+                            renderer,
+                        );
+                        if child != mouse::Interaction::default() {
+                            interaction = child;
+                            return;
+                        }
+                    }
+                    let over_pin = find_pins_placed::<P, UI>(node_tree, node_layout, transform)
+                        .iter()
+                        .any(|(_, pin_state, (a, b))| {
+                            !pin_state.interactions_disabled
+                                && a.distance(cursor_position).min(b.distance(cursor_position))
+                                    < pin_threshold
+                        });
+                    if over_pin {
+                        if self.on_connect_handler().is_some() {
+                            interaction = cursors.pin;
+                        }
+                        return;
+                    }
+                    if over_body {
+                        if self.on_move_handler().is_some() {
+                            interaction = cursors.node;
+                        }
+                        return;
+                    }
+                }
+                if self
+                    .edge_handle_at(tree, layout, cursor_position, state.camera.zoom())
+                    .is_some()
+                {
+                    interaction = cursors.node;
+                }
+            });
+        interaction
+    }
+
     /// Folds one touch event into the finger list and returns the pointer
     /// event to process in its place, if any.
     ///
@@ -459,8 +1012,9 @@ where
                     ));
                 }
                 if index < 2 {
-                    // Pinch: zoom by the contact-distance ratio at the new
-                    // midpoint, then pan by the midpoint travel.
+                    // Pinch: zoom by the contact-distance ratio at the old
+                    // midpoint, then pan by the midpoint travel, so the world
+                    // point between the fingers stays between them.
                     let prev = (state.fingers[0].1, state.fingers[1].1);
                     state.fingers[index].1 = position;
                     let next = (state.fingers[0].1, state.fingers[1].1);
@@ -475,8 +1029,16 @@ where
                     if prev_distance > 1.0 && next_distance > 1.0 {
                         let zoom_delta =
                             (next_distance / prev_distance - 1.0) * state.camera.zoom();
-                        let mid: ScreenPoint = next_mid.into_euclid();
-                        state.camera = state.camera.zoom_at(mid, zoom_delta);
+                        let mid: ScreenPoint = prev_mid.into_euclid();
+                        if self.elastic_zoom() {
+                            state.camera = state.camera.zoom_at_elastic(mid, zoom_delta);
+                            state.zoom_bounce = state
+                                .camera
+                                .is_overshooting()
+                                .then_some(next_mid.into_euclid());
+                        } else {
+                            state.camera = state.camera.zoom_at(mid, zoom_delta);
+                        }
                     }
                     let zoom = state.camera.zoom();
                     let pan = WorldPoint::new(next_mid.x / zoom, next_mid.y / zoom)
@@ -484,7 +1046,9 @@ where
                     state.camera = state.camera.move_by(pan);
 
                     // Commit continuously, mirroring wheel zoom.
-                    if let Some(handler) = self.on_pan_handler() {
+                    if let Some(handler) = self.on_pan_handler()
+                        && state.zoom_bounce.is_none()
+                    {
                         let pos = state.camera.position();
                         shell.publish(handler(Point::new(pos.x, pos.y), state.camera.zoom()));
                     }
@@ -534,6 +1098,76 @@ where
         }
     }
 
+    /// Resolves a [`NodeGraphState::connection_key`] into the user-facing
+    /// [`ConnectionState`] for `on_connection_state_change`. Pins that no
+    /// longer resolve report `Idle`.
+    pub(super) fn connection_state(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        key: Option<((usize, usize), Option<(usize, usize)>)>,
+    ) -> ConnectionState<N, P> {
+        let pin_ref = |(node, pin): (usize, usize)| {
+            let (pin_id, _) = pin_at::<P, UI>(tree, layout, node, pin)?;
+            Some(PinRef::new(self.index_to_node_id(node)?, pin_id))
+        };
+        let Some((from, rerouting)) = key else {
+            return ConnectionState::Idle;
+        };
+        match (pin_ref(from), rerouting.map(pin_ref)) {
+            (Some(from), None) => ConnectionState::Connecting { from },
+            (Some(from), Some(Some(detached))) => ConnectionState::Rerouting { from, detached },
+            _ => ConnectionState::Idle,
+        }
+    }
+
+    /// Describes the active drag for `on_drag`, or `None` for drags that
+    /// report elsewhere (pan via `on_pan`, edge cutting via `on_disconnect`).
+    fn drag_info(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        dragging: &Dragging,
+    ) -> Option<DragInfo<N, P>> {
+        match dragging {
+            Dragging::Node(node_index, _) => Some(DragInfo::Node {
+                node_id: self.index_to_node_id(*node_index)?,
+            }),
+            Dragging::GroupMove(_) => {
+                let state = tree.state.downcast_ref::<NodeGraphState>();
+                let selected: Vec<usize> = state
+                    .selected_nodes
+                    .iter()
+                    .copied()
+                    .filter(|&i| !self.is_screen_fixed(i))
+                    .collect();
+                Some(DragInfo::Group {
+                    node_ids: self.translate_node_ids(&selected),
+                })
+            }
+            Dragging::Edge(from_node, from_pin, _)
+            | Dragging::EdgeOver(from_node, from_pin, ..) => Some(DragInfo::Edge {
+                from_node: self.index_to_node_id(*from_node)?,
+                from_pin: pin_at::<P, UI>(tree, layout, *from_node, *from_pin)?.0,
+            }),
+            Dragging::BoxSelect(start, _) => {
+                let vo = tree
+                    .state
+                    .downcast_ref::<NodeGraphState>()
+                    .camera
+                    .viewport_origin();
+                Some(DragInfo::BoxSelect {
+                    start_x: start.x - vo.x,
+                    start_y: start.y - vo.y,
+                })
+            }
+            Dragging::None
+            | Dragging::Graph(_)
+            | Dragging::EdgeCutting { .. }
+            | Dragging::Waypoint { .. } => None,
+        }
+    }
+
     /// Handles an in-progress edge-cutting drag: extends the cut trail on cursor
     /// move and commits every pending cut on release.
     fn handle_edge_cutting(&self, ctx: &mut UpdateCtx<'_, '_, '_, Message>) {
@@ -545,95 +1179,58 @@ where
             shell,
             ..
         } = &mut *ctx;
-        let state = tree.state.downcast_mut::<NodeGraphState>();
         match event {
             Event::Mouse(mouse::Event::CursorMoved { .. }) => {
                 if let Some(cursor_position) = world_cursor.position() {
                     let cursor_position: WorldPoint = cursor_position.into_euclid();
 
-                    // Update trail and check which edges intersect with cutting line
+                    // Cutting line: from the trail's start point to the cursor
+                    let cut_start = match &tree.state.downcast_ref::<NodeGraphState>().dragging {
+                        Dragging::EdgeCutting { trail, .. } => {
+                            trail.first().copied().unwrap_or(cursor_position)
+                        }
+                        _ => cursor_position,
+                    };
+                    let cut_end = cursor_position;
+
+                    // Only edges intersecting the cutting line are highlighted
+                    let crossed: Vec<usize> = self
+                        .edges
+                        .iter()
+                        .enumerate()
+                        .filter(|(edge_idx, (_id, from_ref, to_ref, _style))| {
+                            self.edge_path(tree, *layout, *edge_idx, from_ref, to_ref)
+                                .into_iter()
+                                .any(|[p0, p1, p2, p3]| {
+                                    line_intersects_bezier(
+                                        cut_start.into_iced(),
+                                        cut_end.into_iced(),
+                                        p0,
+                                        p1,
+                                        p2,
+                                        p3,
+                                    )
+                                })
+                        })
+                        .map(|(edge_idx, _)| edge_idx)
+                        .collect();
+
+                    let state = tree.state.downcast_mut::<NodeGraphState>();
                     if let Dragging::EdgeCutting {
                         ref mut trail,
                         ref mut pending_cuts,
                     } = state.dragging
                     {
                         trail.push(cursor_position);
-
-                        // Get cutting line: from start point to current cursor
-                        let cut_start = trail.first().copied().unwrap_or(cursor_position);
-                        let cut_end = cursor_position;
-
-                        // Clear and recalculate - only edges intersecting cutting line are highlighted
                         pending_cuts.clear();
-
-                        // Check each edge for intersection with the cutting line
-                        for (edge_idx, (_id, from_ref, to_ref, _style)) in
-                            self.edges.iter().enumerate()
-                        {
-                            // Resolve user IDs to indices
-                            let from_node_idx = match self.node_index(&from_ref.node_id) {
-                                Some(idx) => idx,
-                                None => continue,
-                            };
-                            let to_node_idx = match self.node_index(&to_ref.node_id) {
-                                Some(idx) => idx,
-                                None => continue,
-                            };
-
-                            // Get pin positions and sides for bezier calculation
-                            let from_pin_data =
-                                layout
-                                    .children()
-                                    .nth(from_node_idx)
-                                    .and_then(|node_layout| {
-                                        tree.children.get(from_node_idx).and_then(|node_tree| {
-                                            let pins = find_pins::<P, UI>(node_tree, node_layout);
-                                            pins.iter()
-                                                .find(|(_, state, _)| {
-                                                    state.pin_id == from_ref.pin_id
-                                                })
-                                                .map(|(_, state, (pos, _))| (*pos, state.side))
-                                        })
-                                    });
-                            let to_pin_data =
-                                layout.children().nth(to_node_idx).and_then(|node_layout| {
-                                    tree.children.get(to_node_idx).and_then(|node_tree| {
-                                        let pins = find_pins::<P, UI>(node_tree, node_layout);
-                                        pins.iter()
-                                            .find(|(_, state, _)| state.pin_id == to_ref.pin_id)
-                                            .map(|(_, state, (pos, _))| (*pos, state.side))
-                                    })
-                                });
-
-                            if let (Some((p0, from_side)), Some((p3, to_side))) =
-                                (from_pin_data, to_pin_data)
-                            {
-                                // Calculate bezier control points
-                                let dir_from = pin_side_direction(from_side.into());
-                                let dir_to = pin_side_direction(to_side.into());
-                                let l = adaptive_bezier_length([p0.x, p0.y], [p3.x, p3.y]);
-                                let p1 = Point::new(p0.x + dir_from[0] * l, p0.y + dir_from[1] * l);
-                                let p2 = Point::new(p3.x + dir_to[0] * l, p3.y + dir_to[1] * l);
-
-                                // Check if cutting line intersects this bezier edge
-                                if line_intersects_bezier(
-                                    cut_start.into_iced(),
-                                    cut_end.into_iced(),
-                                    p0,
-                                    p1,
-                                    p2,
-                                    p3,
-                                ) {
-                                    pending_cuts.insert(edge_idx);
-                                }
-                            }
-                        }
+                        pending_cuts.extend(crossed);
                     }
                 }
                 shell.request_redraw();
             }
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
                 // Delete all pending edges on release
+                let state = tree.state.downcast_mut::<NodeGraphState>();
                 if let Dragging::EdgeCutting { pending_cuts, .. } = &state.dragging {
                     for &edge_idx in pending_cuts.iter() {
                         if let Some((_id, from_ref, to_ref, _)) = self.edges.get(edge_idx) {
@@ -653,7 +1250,8 @@ where
     }
 
     /// Handles an in-progress graph pan: commits the camera offset on
-    /// right-button release.
+    /// release of the pan button, or of the left button when
+    /// [`BackgroundClick::Pan`] lets it pan.
     fn handle_graph_pan(&self, ctx: &mut UpdateCtx<'_, '_, '_, Message>, origin: WorldPoint) {
         let UpdateCtx {
             tree,
@@ -664,7 +1262,9 @@ where
         } = &mut *ctx;
         let state = tree.state.downcast_mut::<NodeGraphState>();
         if let Event::Mouse(mouse::Event::ButtonReleased(button)) = event
-            && *button == self.keymap.pan_button
+            && (*button == self.keymap.pan_button
+                || (*button == mouse::Button::Left
+                    && self.background_click == BackgroundClick::Pan))
         {
             if let Some(cursor_position) = screen_cursor.position() {
                 let screen_to_world = state.camera.screen_to_world();
@@ -695,22 +1295,29 @@ where
     ) {
         let UpdateCtx {
             tree,
+            layout,
             event,
             world_cursor,
             shell,
             ..
         } = &mut *ctx;
-        let state = tree.state.downcast_mut::<NodeGraphState>();
         if let Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) = event {
             if let Some(cursor_position) = world_cursor.position() {
-                let cursor_position = cursor_position.into_euclid();
-                let offset = cursor_position - origin;
+                let state = tree.state.downcast_ref::<NodeGraphState>();
+                let mut offset = self.drag_delta(state, origin, cursor_position.into_euclid());
 
                 // A press+release without motion is a click, not
                 // a move: don't emit a spurious move (which would
                 // dirty host state / undo history on a plain
                 // selection click). Only report an actual drag.
                 let moved = offset.x.abs() > f32::EPSILON || offset.y.abs() > f32::EPSILON;
+                if moved
+                    && self.prevent_overlap
+                    && let Some(node_layout) = layout.children().nth(node_index)
+                {
+                    let dropped = node_layout.bounds() + offset.into_iced();
+                    offset += self.overlap_push(*layout, node_index, dropped);
+                }
 
                 // Translate internal index to user ID
                 if let Some(node_id) = self.index_to_node_id(node_index)
@@ -718,10 +1325,23 @@ where
                 {
                     // Call on_move handler if set
                     if let Some(handler) = self.on_move_handler() {
-                        shell.publish(handler(offset.into_iced(), vec![node_id]));
+                        shell.publish(handler(offset.into_iced(), vec![node_id.clone()]));
+                    }
+                    // Layout still holds the pre-drag position (the drag
+                    // offset is a draw-time translation), so test the body
+                    // where it was dropped.
+                    if self.edge_splitting
+                        && let Some(handler) = self.on_edge_split_handler()
+                        && let Some(node_layout) = layout.children().nth(node_index)
+                    {
+                        let bounds = node_layout.bounds() + offset.into_iced();
+                        if let Some(edge) = self.edge_under_node(tree, *layout, &node_id, bounds) {
+                            shell.publish(handler(edge, node_id));
+                        }
                     }
                 }
             }
+            let state = tree.state.downcast_mut::<NodeGraphState>();
             // Promote this node to the top of the z-order on drop.
             state.promote_z(node_index);
             state.dragging = Dragging::None;
@@ -743,100 +1363,227 @@ where
         from_node: usize,
         from_pin: usize,
     ) {
+        match ctx.event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                self.try_snap_edge(ctx, from_node, from_pin);
+                ctx.shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                let UpdateCtx {
+                    tree,
+                    world_cursor,
+                    shell,
+                    ..
+                } = &mut *ctx;
+                let state = tree.state.downcast_mut::<NodeGraphState>();
+                // Click-to-connect: a release can arm the edge instead of
+                // dropping it; the next click completes or cancels it.
+                let travelled = match (state.drag_origin, world_cursor.position()) {
+                    (Some(origin), Some(cursor)) => {
+                        origin.into_iced().distance(cursor)
+                            >= PIN_CLICK_THRESHOLD / state.camera.zoom()
+                    }
+                    _ => false,
+                };
+                let arm = !state.connect_armed
+                    && match self.connect_mode {
+                        ConnectMode::Drag => false,
+                        ConnectMode::ClickClick => true,
+                        ConnectMode::Both => !travelled,
+                    };
+                if arm {
+                    state.connect_armed = true;
+                } else {
+                    state.dragging = Dragging::None;
+                    // Emit drag end event
+                    if let Some(handler) = self.on_drag_end_handler() {
+                        shell.publish(handler());
+                    }
+                }
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    /// Orders a connected pair for reporting: output-first via
+    /// [`orient_connection`] when
+    /// [`normalize_connections`](NodeGraph::normalize_connections) is on,
+    /// drag order (started pin first) otherwise.
+    fn orient_connection<T>(
+        &self,
+        from_dir: PinDirection,
+        to_dir: PinDirection,
+        from: T,
+        to: T,
+    ) -> (T, T) {
+        if self.normalize_connections {
+            orient_connection(from_dir, to_dir, from, to)
+        } else {
+            (from, to)
+        }
+    }
+
+    /// Snaps the dragged edge from `(from_node, from_pin)` onto the first
+    /// valid drop target within `SNAP_THRESHOLD` of the cursor, firing
+    /// `on_connect` and entering `EdgeOver`. Returns whether it snapped.
+    fn try_snap_edge(
+        &self,
+        ctx: &mut UpdateCtx<'_, '_, '_, Message>,
+        from_node: usize,
+        from_pin: usize,
+    ) -> bool {
         let UpdateCtx {
             tree,
             layout,
-            event,
             world_cursor,
             shell,
             ..
         } = &mut *ctx;
         let state = tree.state.downcast_mut::<NodeGraphState>();
-        match event {
-            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
-                // Check if cursor is over a valid target pin to transition to EdgeOver
-                if let Some(cursor_position) = world_cursor.position() {
-                    // Copy valid_drop_targets before iterating over tree.children
-                    let valid_targets = state.valid_drop_targets.clone();
-                    // Screen-space threshold: constant hit target at any zoom.
-                    let snap_threshold = SNAP_THRESHOLD / state.camera.zoom();
+        // Check if cursor is over a valid target pin to transition to EdgeOver
+        let Some(cursor_position) = world_cursor.position() else {
+            return false;
+        };
+        // Copy valid_drop_targets before iterating over tree.children
+        let valid_targets = state.valid_drop_targets.clone();
+        // Screen-space threshold: constant hit target at any zoom.
+        let snap_threshold = SNAP_THRESHOLD / state.camera.zoom();
 
-                    // Extract from_pin_id while iterating (need access to tree.children)
-                    let mut from_pin_id: Option<P> = None;
-                    let mut from_dir: Option<PinDirection> = None;
-                    let mut target_info: Option<(usize, usize, P, PinDirection)> = None;
+        // Extract from_pin_id while iterating (need access to tree.children)
+        let mut from_pin_id: Option<(P, UI)> = None;
+        let mut from_dir: Option<PinDirection> = None;
+        let mut target_info: Option<(usize, usize, P, PinDirection, UI)> = None;
 
-                    // Check all pins for proximity and validity (use SNAP_THRESHOLD to enter)
-                    for (node_index, (node_layout, node_tree)) in
-                        layout.children().zip(&tree.children).enumerate()
-                    {
-                        for (pin_index, pin_state, (a, b)) in
-                            find_pins::<P, UI>(node_tree, node_layout)
-                        {
-                            // Extract from_pin_id when we find the source pin
-                            if node_index == from_node && pin_index == from_pin {
-                                from_pin_id = Some(pin_state.pin_id.clone());
-                                from_dir = Some(pin_state.direction);
-                            }
+        // Check all pins for proximity and validity (use SNAP_THRESHOLD to enter)
+        for (node_index, (node_layout, node_tree)) in
+            layout.children().zip(&tree.children).enumerate()
+        {
+            let transform = self.node_transform(node_index);
+            for (pin_index, pin_state, (a, b)) in
+                find_pins_placed::<P, UI>(node_tree, node_layout, transform)
+            {
+                // Extract from_pin_id when we find the source pin
+                if node_index == from_node && pin_index == from_pin {
+                    from_pin_id = Some((pin_state.pin_id.clone(), pin_state.user_info.clone()));
+                    from_dir = Some(pin_state.direction);
+                }
 
-                            // Pin positions are already in world space (from layout)
-                            let distance =
-                                a.distance(cursor_position).min(b.distance(cursor_position));
-
-                            // Use SNAP_THRESHOLD for entering snap zone
-                            if distance < snap_threshold && target_info.is_none() {
-                                // Check if this pin is in valid_drop_targets
-                                if valid_targets.contains(&(node_index, pin_index)) {
-                                    target_info = Some((
-                                        node_index,
-                                        pin_index,
-                                        pin_state.pin_id.clone(),
-                                        pin_state.direction,
-                                    ));
-                                }
-                            }
-                        }
+                // Pin positions are already in world space (from layout)
+                let distance = a.distance(cursor_position).min(b.distance(cursor_position));
+
+                // Use SNAP_THRESHOLD for entering snap zone
+                if distance < snap_threshold && target_info.is_none() {
+                    // Check if this pin is in valid_drop_targets
+                    if valid_targets.contains(&(node_index, pin_index)) {
+                        target_info = Some((
+                            node_index,
+                            pin_index,
+                            pin_state.pin_id.clone(),
+                            pin_state.direction,
+                            pin_state.user_info.clone(),
+                        ));
                     }
+                }
+            }
+        }
 
-                    if let Some((to_node, to_pin, to_pin_id, to_dir)) = target_info {
-                        // Fire EdgeConnected event immediately on snap (plug behavior)
-                        let from_node_id = self.index_to_node_id(from_node);
-                        let to_node_id = self.index_to_node_id(to_node);
-
-                        if let (Some(from_nid), Some(to_nid), Some(from_pid)) =
-                            (from_node_id, to_node_id, from_pin_id)
-                        {
-                            // Normalize to output -> input so the reported
-                            // endpoints match the rendered data-flow direction,
-                            // independent of which pin the drag started on.
-                            let (from_ref, to_ref) = orient_connection(
-                                from_dir.unwrap_or(PinDirection::Both),
-                                to_dir,
-                                PinRef::new(from_nid.clone(), from_pid),
-                                PinRef::new(to_nid.clone(), to_pin_id),
-                            );
+        let Some((to_node, to_pin, to_pin_id, to_dir, to_info)) = target_info else {
+            return false;
+        };
+        // Fire EdgeConnected event immediately on snap (plug behavior)
+        let from_node_id = self.index_to_node_id(from_node);
+        let to_node_id = self.index_to_node_id(to_node);
 
-                            if let Some(handler) = self.on_connect_handler() {
-                                shell.publish(handler(from_ref, to_ref));
-                            }
-                        }
+        if let (Some(from_nid), Some(to_nid), Some((from_pid, from_info))) =
+            (from_node_id, to_node_id, from_pin_id)
+        {
+            // Normalize to output -> input so the reported
+            // endpoints match the rendered data-flow direction,
+            // independent of which pin the drag started on.
+            let from_dir = from_dir.unwrap_or(PinDirection::Both);
+            let (from, to) = self.orient_connection(
+                from_dir,
+                to_dir,
+                (PinRef::new(from_nid.clone(), from_pid), from_dir, from_info),
+                (PinRef::new(to_nid.clone(), to_pin_id), to_dir, to_info),
+            );
 
-                        state.dragging = Dragging::EdgeOver(from_node, from_pin, to_node, to_pin);
-                    }
-                }
-                shell.request_redraw();
-            }
-            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
-                state.dragging = Dragging::None;
-                // Emit drag end event
-                if let Some(handler) = self.on_drag_end_handler() {
-                    shell.publish(handler());
-                }
-                shell.capture_event();
-                shell.request_redraw();
+            if let Some(handler) = self.on_connect_handler() {
+                shell.publish(handler(Connection {
+                    from: from.0,
+                    from_direction: from.1,
+                    from_info: from.2,
+                    to: to.0,
+                    to_direction: to.1,
+                    to_info: to.2,
+                }));
             }
-            _ => {}
         }
+
+        let state = tree.state.downcast_mut::<NodeGraphState>();
+        state.dragging = Dragging::EdgeOver(from_node, from_pin, to_node, to_pin);
+        true
+    }
+
+    /// Resolves a click while a click-to-connect edge is armed: a click on
+    /// (or snapping to) a valid target keeps the connection reported on snap
+    /// and ends the drag; any other click cancels it.
+    fn finish_click_connect(&self, ctx: &mut UpdateCtx<'_, '_, '_, Message>) {
+        let state = ctx.tree.state.downcast_mut::<NodeGraphState>();
+        if let Dragging::Edge(from_node, from_pin, _) = state.dragging {
+            self.try_snap_edge(ctx, from_node, from_pin);
+        }
+        let state = ctx.tree.state.downcast_mut::<NodeGraphState>();
+        state.dragging = Dragging::None;
+        state.connect_armed = false;
+        if let Some(handler) = self.on_drag_end_handler() {
+            ctx.shell.publish(handler());
+        }
+        ctx.shell.capture_event();
+        ctx.shell.request_redraw();
+    }
+
+    /// Cancels the drag in flight, an armed click-to-connect edge included,
+    /// undoing the live `on_connect` when an edge is snapped to a target.
+    /// `on_drag_end` fires for the drags that reported a start.
+    fn cancel_drag(&self, tree: &mut Tree, layout: Layout<'_>, shell: &mut Shell<'_, Message>) {
+        let state = tree.state.downcast_mut::<NodeGraphState>();
+        let dragging = std::mem::take(&mut state.dragging);
+        state.connect_armed = false;
+        let reported_start = matches!(
+            dragging,
+            Dragging::Node(..)
+                | Dragging::GroupMove(_)
+                | Dragging::Edge(..)
+                | Dragging::EdgeOver(..)
+                | Dragging::BoxSelect(..)
+        );
+        if let Dragging::EdgeOver(from_node, from_pin, to_node, to_pin) = dragging
+            && let (Some(from_nid), Some(to_nid)) = (
+                self.index_to_node_id(from_node),
+                self.index_to_node_id(to_node),
+            )
+            && let (Some((from_pid, from_dir)), Some((to_pid, to_dir))) = (
+                pin_at::<P, UI>(tree, layout, from_node, from_pin),
+                pin_at::<P, UI>(tree, layout, to_node, to_pin),
+            )
+            && let Some(handler) = self.on_disconnect_handler()
+        {
+            let (from_ref, to_ref) = self.orient_connection(
+                from_dir,
+                to_dir,
+                PinRef::new(from_nid, from_pid),
+                PinRef::new(to_nid, to_pid),
+            );
+            shell.publish(handler(from_ref, to_ref));
+        }
+        if reported_start && let Some(handler) = self.on_drag_end_handler() {
+            shell.publish(handler());
+        }
+        shell.capture_event();
+        shell.request_redraw();
     }
 
     /// Handles the snapped state of an edge drag: unsnap hysteresis
@@ -874,8 +1621,9 @@ where
                     for (node_index, (node_layout, node_tree)) in
                         layout.children().zip(&tree.children).enumerate()
                     {
+                        let transform = self.node_transform(node_index);
                         for (pin_index, pin_state, (a, b)) in
-                            find_pins::<P, UI>(node_tree, node_layout)
+                            find_pins_placed::<P, UI>(node_tree, node_layout, transform)
                         {
                             // Extract from_pin_id
                             if node_index == from_node && pin_index == from_pin {
@@ -904,7 +1652,7 @@ where
                             // Match the output -> input order used when the
                             // edge connected, so the user's edge list lookup
                             // removes the same pair it inserted.
-                            let (from_ref, to_ref) = orient_connection(
+                            let (from_ref, to_ref) = self.orient_connection(
                                 from_dir.unwrap_or(PinDirection::Both),
                                 to_dir.unwrap_or(PinDirection::Both),
                                 PinRef::new(from_nid.clone(), from_pid),
@@ -961,20 +1709,7 @@ where
                 // Complete box selection - find nodes that intersect the selection rectangle
                 if let Some(cursor_position) = world_cursor.position() {
                     let end: WorldPoint = cursor_position.into_euclid();
-                    let selection_rect = selection_rect_from_points(start, end);
-
-                    // Without the multi-select modifier (keymap, default
-                    // Shift): replace selection. With it: add to selection.
-                    if !state.modifiers.contains(self.keymap.multi_select_modifiers) {
-                        state.selected_nodes.clear();
-                    }
-
-                    // Find all nodes that intersect the selection rectangle
-                    for (node_index, node_layout) in layout.children().enumerate() {
-                        if rects_intersect(&selection_rect, &node_layout.bounds()) {
-                            state.selected_nodes.insert(node_index);
-                        }
-                    }
+                    state.selected_nodes = self.box_selection(state, *layout, start, end);
 
                     // Notify selection change
                     let indices: Vec<usize> = state.selected_nodes.iter().copied().collect();
@@ -995,6 +1730,47 @@ where
         }
     }
 
+    /// Node indices a box select dragged from `start` to `end` (layout
+    /// space) leaves selected: every node the box picks up under the
+    /// [`SelectRule`] (intersecting or fully contained), added to the
+    /// current selection while the multi-select modifier (keymap, default
+    /// Shift) is held and replacing it otherwise. The release commits this
+    /// set; `draw` previews it while the box is still being dragged.
+    pub(super) fn box_selection(
+        &self,
+        state: &NodeGraphState,
+        layout: Layout<'_>,
+        start: WorldPoint,
+        end: WorldPoint,
+    ) -> std::collections::HashSet<usize> {
+        let selection_rect = selection_rect_from_points(start, end);
+        let contain = match self.box_select_rule {
+            SelectRule::Intersect => false,
+            SelectRule::Contain => true,
+            SelectRule::ByDirection => end.x >= start.x,
+        };
+        let mut selected = if state.modifiers.contains(self.keymap.multi_select_modifiers) {
+            state.selected_nodes.clone()
+        } else {
+            std::collections::HashSet::new()
+        };
+        selected.extend(
+            layout
+                .children()
+                .enumerate()
+                .filter(|(_, node_layout)| {
+                    let bounds = node_layout.bounds();
+                    if contain {
+                        rect_contains(&selection_rect, &bounds)
+                    } else {
+                        rects_intersect(&selection_rect, &bounds)
+                    }
+                })
+                .map(|(node_index, _)| node_index),
+        );
+        selected
+    }
+
     /// Handles an in-progress group move: reports one shared delta for every
     /// selected node on release.
     fn handle_group_move(&self, ctx: &mut UpdateCtx<'_, '_, '_, Message>, origin: WorldPoint) {
@@ -1012,10 +1788,14 @@ where
             }
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
                 // Complete group move - notify all selected nodes moved
-                let indices: Vec<usize> = state.selected_nodes.iter().copied().collect();
+                let indices: Vec<usize> = state
+                    .selected_nodes
+                    .iter()
+                    .copied()
+                    .filter(|&i| !self.is_screen_fixed(i))
+                    .collect();
                 if let Some(cursor_position) = world_cursor.position() {
-                    let cursor_position: WorldPoint = cursor_position.into_euclid();
-                    let offset = cursor_position - origin;
+                    let offset = self.drag_delta(state, origin, cursor_position.into_euclid());
 
                     // Translate internal indices to user IDs
                     let node_ids = self.translate_node_ids(&indices);
@@ -1048,6 +1828,10 @@ where
         // Multi-select-modifier+drag from an occupied pin forks a NEW edge
         // instead of unplugging the existing one.
         let state = ctx.tree.state.downcast_mut::<NodeGraphState>();
+        if state.connect_armed {
+            self.finish_click_connect(ctx);
+            return;
+        }
         // A press while another drag is in progress (e.g. left press during a
         // pan) must not hijack the state machine mid-drag.
         if state.dragging != Dragging::None {
@@ -1055,12 +1839,21 @@ where
         }
         let multi_select_held = state.modifiers.contains(self.keymap.multi_select_modifiers);
         let edge_cut_held = state.modifiers.contains(self.keymap.edge_cut_modifiers);
+        let double_click = ctx.screen_cursor.position().is_some_and(|position| {
+            let click = mouse::Click::new(position, mouse::Button::Left, state.last_click);
+            state.last_click = Some(click);
+            click.kind() == mouse::click::Kind::Double
+        });
 
         // Edge-cut chord (keymap, default Cmd/Ctrl+Click): edge cut tool.
         if edge_cut_held && self.try_cut_edge_at_cursor(ctx) {
             return;
         }
 
+        if double_click && self.try_select_component(ctx, z_indices) {
+            return;
+        }
+
         if let Some(cursor_position) = ctx.world_cursor.position() {
             // Per-node hit-test, top-first by z-order: check this node's pins
             // first, then its body. The first node to own the cursor - pin OR
@@ -1075,11 +1868,89 @@ where
             }
         }
 
-        // Nothing hit - start box selection on empty space, unless COMMAND is
+        // Edge handles lie under the nodes but claim the press before the
+        // empty-space tools.
+        if !edge_cut_held && self.try_press_edge_handle(ctx) {
+            return;
+        }
+
+        // Nothing hit. A double-click there goes to the host (typically to
+        // add a node); the first click of the pair already ran the empty-space
+        // tool as a motionless press, so this one starts nothing.
+        if double_click
+            && !edge_cut_held
+            && let Some(handler) = self.on_canvas_double_click_handler()
+            && let Some(cursor_position) = ctx.world_cursor.position()
+        {
+            // The cursor is layout-absolute; the host gets the world point.
+            let vo = ctx
+                .tree
+                .state
+                .downcast_ref::<NodeGraphState>()
+                .camera
+                .viewport_origin();
+            ctx.shell.publish(handler(Point::new(
+                cursor_position.x - vo.x,
+                cursor_position.y - vo.y,
+            )));
+            ctx.shell.capture_event();
+            return;
+        }
+
+        // Otherwise start box selection on empty space, unless COMMAND is
         // held (reserved for edge cutting).
         self.start_box_select_or_cut(ctx);
     }
 
+    /// Selects the connected component of the topmost node under the cursor
+    /// for a press that completes a double-click on its body, when the
+    /// component-select modifiers are held. Returns whether it consumed the
+    /// press.
+    fn try_select_component(
+        &self,
+        ctx: &mut UpdateCtx<'_, '_, '_, Message>,
+        z_indices: &[usize],
+    ) -> bool {
+        let state = ctx.tree.state.downcast_mut::<NodeGraphState>();
+        if !state
+            .modifiers
+            .contains(self.keymap.component_select_modifiers)
+        {
+            return false;
+        }
+        let Some(cursor_position) = ctx.world_cursor.position() else {
+            return false;
+        };
+        let Some(node_id) = z_indices
+            .iter()
+            .rev()
+            .find(|&&index| {
+                ctx.layout.children().nth(index).is_some_and(|node_layout| {
+                    body_contains(
+                        node_layout.bounds(),
+                        self.node_transform(index),
+                        cursor_position,
+                    )
+                })
+            })
+            .and_then(|&index| self.index_to_node_id(index))
+        else {
+            return false;
+        };
+
+        let component = self.connected_component(&node_id);
+        state.selected_nodes = component
+            .iter()
+            .filter_map(|id| self.node_index(id))
+            .collect();
+        if let Some(handler) = self.on_select_handler() {
+            ctx.shell.publish(handler(component));
+        }
+        ctx.shell.capture_event();
+        ctx.shell.request_redraw();
+        true
+    }
+
     /// Cuts the first edge within `EDGE_CUT_THRESHOLD` of the cursor
     /// (Command+Click edge cut). Returns whether a cut consumed the press.
     fn try_cut_edge_at_cursor(&self, ctx: &mut UpdateCtx<'_, '_, '_, Message>) -> bool {
@@ -1097,61 +1968,555 @@ where
         let cut_threshold =
             EDGE_CUT_THRESHOLD / tree.state.downcast_ref::<NodeGraphState>().camera.zoom();
         // Check if click is near any edge
-        for (_id, from_ref, to_ref, _style) in &self.edges {
-            // Resolve user IDs to indices
-            let from_node_idx = match self.node_index(&from_ref.node_id) {
-                Some(idx) => idx,
-                None => continue,
+        for (edge_idx, (_id, from_ref, to_ref, _style)) in self.edges.iter().enumerate() {
+            let near = self
+                .edge_path(tree, *layout, edge_idx, from_ref, to_ref)
+                .into_iter()
+                .any(|[p0, p1, p2, p3]| {
+                    point_to_bezier_distance(cursor_position, p0, p1, p2, p3) < cut_threshold
+                });
+            if near {
+                // Edges already store user IDs
+                if let Some(handler) = self.on_disconnect_handler() {
+                    shell.publish(handler(from_ref.clone(), to_ref.clone()));
+                }
+                shell.capture_event();
+                shell.request_redraw();
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Positions (layout-absolute) and sides of the pins at both ends of the
+    /// edge `from_ref -> to_ref`, turned to face each other under
+    /// [`auto_pin_side`](NodeGraph::auto_pin_side). `None` when either
+    /// endpoint no longer resolves to a pin.
+    #[allow(clippy::type_complexity)]
+    fn edge_endpoints(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        from_ref: &PinRef<N, P>,
+        to_ref: &PinRef<N, P>,
+    ) -> Option<(([f32; 2], u32), ([f32; 2], u32))> {
+        let pin_data = |pin_ref: &PinRef<N, P>| {
+            let node_idx = self.node_index(&pin_ref.node_id)?;
+            let node_layout = layout.children().nth(node_idx)?;
+            let node_tree = tree.children.get(node_idx)?;
+            let transform = self.node_transform(node_idx);
+            find_pins_placed::<P, UI>(node_tree, node_layout, transform)
+                .iter()
+                .find(|(_, state, _)| state.pin_id == pin_ref.pin_id)
+                .map(|(_, state, (a, _))| {
+                    (
+                        *a,
+                        transform.side(state.side),
+                        node_layout.bounds().center(),
+                    )
+                })
+        };
+        let (mut from, mut from_side, from_center) = pin_data(from_ref)?;
+        let (mut to, mut to_side, to_center) = pin_data(to_ref)?;
+        if self.auto_pin_side {
+            (from, from_side) = facing_side(from, from_side, from_center, to_center);
+            (to, to_side) = facing_side(to, to_side, to_center, from_center);
+        }
+        Some((
+            ([from.x, from.y], from_side.into()),
+            ([to.x, to.y], to_side.into()),
+        ))
+    }
+
+    /// Curve, stub length and layout-absolute waypoints of the edge at
+    /// `edge_idx`: its route when pushed via `push_edge_via`, otherwise the
+    /// curve the last draw resolved from its style, through the waypoints it
+    /// was bundled along (if any). The stub always comes from the last draw.
+    fn edge_route_points(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        edge_idx: usize,
+    ) -> (EdgeCurve, f32, Vec<[f32; 2]>) {
+        let state = tree.state.downcast_ref::<NodeGraphState>();
+        let (drawn_curve, stub) = state
+            .edge_geometry
+            .borrow()
+            .get(edge_idx)
+            .copied()
+            .unwrap_or_default();
+        // Waypoints are world-space; pins are layout-absolute.
+        let origin = layout.bounds().position();
+        match self.edge_route(edge_idx) {
+            Some(route) => (
+                route.curve,
+                stub,
+                route
+                    .waypoints
+                    .iter()
+                    .map(|p| [p.x + origin.x, p.y + origin.y])
+                    .collect(),
+            ),
+            None => (
+                drawn_curve,
+                stub,
+                state
+                    .edge_bundles
+                    .borrow()
+                    .get(edge_idx)
+                    .copied()
+                    .flatten()
+                    .map(Vec::from)
+                    .unwrap_or_default(),
+            ),
+        }
+    }
+
+    /// World-space cubic spans of the edge at `edge_idx` (`from_ref ->
+    /// to_ref`), built like the draw path, waypoints included, so hit tests
+    /// match what is rendered. Empty when either endpoint no longer resolves
+    /// to a pin.
+    fn edge_path(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        edge_idx: usize,
+        from_ref: &PinRef<N, P>,
+        to_ref: &PinRef<N, P>,
+    ) -> Vec<[Point; 4]> {
+        let Some(((start, start_side), (end, end_side))) =
+            self.edge_endpoints(tree, layout, from_ref, to_ref)
+        else {
+            return Vec::new();
+        };
+        let (curve, stub, waypoints) = self.edge_route_points(tree, layout, edge_idx);
+        edge_spans(start, start_side, end, end_side, curve, stub, &waypoints)
+            .into_iter()
+            .map(|span| span.map(|[x, y]| Point::new(x, y)))
+            .collect()
+    }
+
+    /// The edge handle within `EDGE_HANDLE_THRESHOLD` of `cursor_position`
+    /// (layout-absolute), as the drag it starts: edge index, index of the
+    /// waypoint to move, and the edge's world-space waypoints with an insert
+    /// handle's new waypoint already in place. `None` unless
+    /// [`editable_edges`](NodeGraph::editable_edges) is on.
+    fn edge_handle_at(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        zoom: f32,
+    ) -> Option<(usize, usize, Vec<WorldPoint>)> {
+        if !self.editable_edges {
+            return None;
+        }
+        let threshold = EDGE_HANDLE_THRESHOLD / zoom;
+        let origin = layout.bounds().position();
+        let to_world = |[x, y]: [f32; 2]| WorldPoint::new(x - origin.x, y - origin.y);
+        self.edges
+            .iter()
+            .enumerate()
+            .find_map(|(edge_idx, (_, from_ref, to_ref, _))| {
+                let ((start, start_side), (end, end_side)) =
+                    self.edge_endpoints(tree, layout, from_ref, to_ref)?;
+                let (curve, stub, waypoints) = self.edge_route_points(tree, layout, edge_idx);
+                let (handle, at) =
+                    edge_handles(start, start_side, end, end_side, curve, stub, &waypoints)
+                        .into_iter()
+                        .find(|(_, [x, y])| {
+                            cursor_position.distance(Point::new(*x, *y)) < threshold
+                        })?;
+                let mut points: Vec<WorldPoint> = reshapable_waypoints(curve, &waypoints)
+                    .iter()
+                    .copied()
+                    .map(to_world)
+                    .collect();
+                let index = match handle {
+                    EdgeHandle::Waypoint(index) => index,
+                    EdgeHandle::Insert(index) => {
+                        points.insert(index, to_world(at));
+                        index
+                    }
+                };
+                Some((edge_idx, index, points))
+            })
+    }
+
+    /// Starts dragging the edge handle under the cursor. Returns whether it
+    /// consumed the press.
+    fn try_press_edge_handle(&self, ctx: &mut UpdateCtx<'_, '_, '_, Message>) -> bool {
+        let UpdateCtx {
+            tree,
+            layout,
+            world_cursor,
+            shell,
+            ..
+        } = &mut *ctx;
+        let Some(cursor_position) = world_cursor.position() else {
+            return false;
+        };
+        let zoom = tree.state.downcast_ref::<NodeGraphState>().camera.zoom();
+        let Some((edge, index, waypoints)) =
+            self.edge_handle_at(tree, *layout, cursor_position, zoom)
+        else {
+            return false;
+        };
+        let state = tree.state.downcast_mut::<NodeGraphState>();
+        state.dragging = Dragging::Waypoint {
+            edge,
+            index,
+            waypoints,
+        };
+        shell.capture_event();
+        shell.request_redraw();
+        true
+    }
+
+    /// Handles an in-progress edge handle drag: moves the held waypoint to the
+    /// cursor and reports the edge's waypoints on every move.
+    fn handle_waypoint_drag(
+        &self,
+        ctx: &mut UpdateCtx<'_, '_, '_, Message>,
+        edge: usize,
+        index: usize,
+    ) {
+        let UpdateCtx {
+            tree,
+            layout,
+            event,
+            world_cursor,
+            shell,
+            ..
+        } = &mut *ctx;
+        let state = tree.state.downcast_mut::<NodeGraphState>();
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(cursor_position) = world_cursor.position()
+                    && let Dragging::Waypoint { waypoints, .. } = &mut state.dragging
+                    && let Some(waypoint) = waypoints.get_mut(index)
+                {
+                    let origin = layout.bounds().position();
+                    *waypoint =
+                        WorldPoint::new(cursor_position.x - origin.x, cursor_position.y - origin.y);
+                    if let Some((_, from_ref, to_ref, _)) = self.edges.get(edge)
+                        && let Some(handler) = self.on_edge_reshape_handler()
+                    {
+                        let points = waypoints.iter().map(|p| p.into_iced()).collect();
+                        shell.publish(handler((from_ref.clone(), to_ref.clone()), points));
+                    }
+                }
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                state.dragging = Dragging::None;
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    /// Move that pushes node `node_index`, dropped at `bounds` (world space),
+    /// clear of every node it overlaps, each time along the axis with the
+    /// smallest overlap. Screen-fixed nodes are not obstacles. Passes are
+    /// capped at the node count, so a crowded drop settles on a best effort.
+    fn overlap_push(
+        &self,
+        layout: Layout<'_>,
+        node_index: usize,
+        bounds: Rectangle,
+    ) -> WorldVector {
+        let obstacles: Vec<Rectangle> = layout
+            .children()
+            .take(self.nodes.len())
+            .enumerate()
+            .filter(|&(index, _)| index != node_index && !self.is_screen_fixed(index))
+            .map(|(_, node_layout)| node_layout.bounds())
+            .collect();
+        let mut bounds = bounds;
+        let mut push = WorldVector::zero();
+        for _ in 0..self.nodes.len() {
+            let Some(other) = obstacles
+                .iter()
+                .find(|other| rects_intersect(&bounds, other))
+            else {
+                break;
             };
-            let to_node_idx = match self.node_index(&to_ref.node_id) {
-                Some(idx) => idx,
-                None => continue,
+            let left = other.x - (bounds.x + bounds.width);
+            let right = other.x + other.width - bounds.x;
+            let up = other.y - (bounds.y + bounds.height);
+            let down = other.y + other.height - bounds.y;
+            let dx = if -left < right { left } else { right };
+            let dy = if -up < down { up } else { down };
+            let step = if dx.abs() <= dy.abs() {
+                WorldVector::new(dx, 0.0)
+            } else {
+                WorldVector::new(0.0, dy)
             };
+            bounds = bounds + step.into_iced();
+            push += step;
+        }
+        push
+    }
 
-            // Get pin positions and sides for both ends of the edge
-            let from_pin_data = layout
-                .children()
-                .nth(from_node_idx)
-                .and_then(|node_layout| {
-                    tree.children.get(from_node_idx).and_then(|node_tree| {
-                        let pins = find_pins::<P, UI>(node_tree, node_layout);
-                        pins.iter()
-                            .find(|(_, state, _)| state.pin_id == from_ref.pin_id)
-                            .map(|(_, state, (a, _))| (*a, state.side))
+    /// Finds the edge a node dropped at `bounds` (world space) lands on: among
+    /// the edges crossing the body, the one passing closest to its center.
+    /// Edges attached to `node_id` are skipped.
+    fn edge_under_node(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        node_id: &N,
+        bounds: Rectangle,
+    ) -> Option<(PinRef<N, P>, PinRef<N, P>)> {
+        let (x0, y0) = (bounds.x, bounds.y);
+        let (x1, y1) = (bounds.x + bounds.width, bounds.y + bounds.height);
+        let sides = [
+            (Point::new(x0, y0), Point::new(x1, y0)),
+            (Point::new(x1, y0), Point::new(x1, y1)),
+            (Point::new(x1, y1), Point::new(x0, y1)),
+            (Point::new(x0, y1), Point::new(x0, y0)),
+        ];
+        let center = bounds.center();
+
+        self.edges
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, from_ref, to_ref, _))| {
+                from_ref.node_id != *node_id && to_ref.node_id != *node_id
+            })
+            .filter_map(|(edge_idx, (_, from_ref, to_ref, _))| {
+                let path = self.edge_path(tree, layout, edge_idx, from_ref, to_ref);
+                path.iter()
+                    .any(|&[p0, p1, p2, p3]| {
+                        sides
+                            .iter()
+                            .any(|(a, b)| line_intersects_bezier(*a, *b, p0, p1, p2, p3))
                     })
-                });
-            let to_pin_data = layout.children().nth(to_node_idx).and_then(|node_layout| {
-                tree.children.get(to_node_idx).and_then(|node_tree| {
-                    let pins = find_pins::<P, UI>(node_tree, node_layout);
-                    pins.iter()
-                        .find(|(_, state, _)| state.pin_id == to_ref.pin_id)
-                        .map(|(_, state, (a, _))| (*a, state.side))
-                })
-            });
+                    .then(|| {
+                        let distance = path
+                            .iter()
+                            .map(|&[p0, p1, p2, p3]| {
+                                point_to_bezier_distance(center, p0, p1, p2, p3)
+                            })
+                            .fold(f32::INFINITY, f32::min);
+                        (distance, from_ref, to_ref)
+                    })
+            })
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(_, from_ref, to_ref)| (from_ref.clone(), to_ref.clone()))
+    }
 
-            if let (Some((from_pos, from_side)), Some((to_pos, to_side))) =
-                (from_pin_data, to_pin_data)
+    /// World offset of the node at `node_index` from its laid-out position
+    /// while a node or group drag carries it, with `cursor` in layout space.
+    pub(super) fn node_drag_offset(
+        &self,
+        state: &NodeGraphState,
+        node_index: usize,
+        cursor: Option<WorldPoint>,
+    ) -> WorldVector {
+        let Some(cursor) = cursor else {
+            return WorldVector::zero();
+        };
+        match &state.dragging {
+            Dragging::Node(drag_idx, origin) if *drag_idx == node_index => {
+                self.drag_delta(state, *origin, cursor)
+            }
+            // Screen-fixed nodes sit out group moves.
+            Dragging::GroupMove(origin)
+                if state.selected_nodes.contains(&node_index)
+                    && !self.is_screen_fixed(node_index) =>
             {
-                // Measure against the rendered bezier, not the straight
-                // chord: same control-point construction as the draw path.
-                let dir_from = pin_side_direction(from_side.into());
-                let dir_to = pin_side_direction(to_side.into());
-                let l = adaptive_bezier_length([from_pos.x, from_pos.y], [to_pos.x, to_pos.y]);
-                let p1 = Point::new(from_pos.x + dir_from[0] * l, from_pos.y + dir_from[1] * l);
-                let p2 = Point::new(to_pos.x + dir_to[0] * l, to_pos.y + dir_to[1] * l);
-                let distance = point_to_bezier_distance(cursor_position, from_pos, p1, p2, to_pos);
-                if distance < cut_threshold {
-                    // Edges already store user IDs
-                    if let Some(handler) = self.on_disconnect_handler() {
-                        shell.publish(handler(from_ref.clone(), to_ref.clone()));
-                    }
-                    shell.capture_event();
-                    shell.request_redraw();
-                    return true;
+                self.drag_delta(state, *origin, cursor)
+            }
+            _ => WorldVector::zero(),
+        }
+    }
+
+    /// Move of a node or group drag from `origin` to `cursor`, locked to its
+    /// dominant axis while the keymap's axis-lock modifiers are held.
+    fn drag_delta(
+        &self,
+        state: &NodeGraphState,
+        origin: WorldPoint,
+        cursor: WorldPoint,
+    ) -> WorldVector {
+        let delta = cursor - origin;
+        let lock = self.keymap.axis_lock_modifiers;
+        if lock.is_empty() || !state.modifiers.contains(lock) {
+            delta
+        } else if delta.x.abs() >= delta.y.abs() {
+            WorldVector::new(delta.x, 0.0)
+        } else {
+            WorldVector::new(0.0, delta.y)
+        }
+    }
+
+    /// Widget-relative screen rectangle of each node overlapping the widget,
+    /// by index, placed where the renderer draws it (drag offset included).
+    pub(super) fn visible_node_rects(
+        &self,
+        state: &NodeGraphState,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> Vec<(usize, Rectangle)> {
+        let bounds = layout.bounds();
+        let origin = bounds.position() - Point::ORIGIN;
+        let camera = state.camera.with_viewport_origin(origin.into_euclid());
+        let cursor = camera
+            .cursor_screen_to_layout(cursor)
+            .position()
+            .map(|p| WorldPoint::new(p.x, p.y));
+        let widget = Rectangle::new(Point::ORIGIN, bounds.size());
+        layout
+            .children()
+            .take(self.nodes.len())
+            .enumerate()
+            .filter_map(|(index, node_layout)| {
+                let node = node_layout.bounds();
+                let offset = self.node_drag_offset(state, index, cursor);
+                let world = Rectangle::new(
+                    node.position() - origin + Vector::new(offset.x, offset.y),
+                    node.size(),
+                );
+                let screen = camera.world_rect_to_screen(world) - origin;
+                screen.intersects(&widget).then_some((index, screen))
+            })
+            .collect()
+    }
+
+    /// World-space bounding box of the nodes at `indices`, from the graph's
+    /// `layout`; `None` when none of them is laid out.
+    fn world_bounds_of(&self, layout: Layout<'_>, indices: &[usize]) -> Option<Rectangle> {
+        let origin = layout.bounds().position() - Point::ORIGIN;
+        let children: Vec<Layout<'_>> = layout.children().take(self.nodes.len()).collect();
+        indices
+            .iter()
+            .filter_map(|&index| children.get(index))
+            .map(|node_layout| {
+                let bounds = node_layout.bounds();
+                Rectangle::new(bounds.position() - origin, bounds.size())
+            })
+            .reduce(|a, b| a.union(&b))
+    }
+
+    /// Moves the selection for a navigation [`KeyAction`]: Tab order is
+    /// reading order (top to bottom, then left to right), arrows pick the
+    /// nearest node in that direction. Reports the new single selection
+    /// through `on_select` and pans the camera (committing via `on_pan`) when
+    /// the node is outside `viewport` (layout space). Other actions are
+    /// ignored.
+    fn navigate_selection(
+        &self,
+        ctx: &mut UpdateCtx<'_, '_, '_, Message>,
+        action: KeyAction,
+        viewport: &Rectangle,
+    ) {
+        let bounds: Vec<Rectangle> = ctx
+            .layout
+            .children()
+            .take(self.nodes.len())
+            .map(|node_layout| node_layout.bounds())
+            .collect();
+        let mut order: Vec<usize> = (0..bounds.len()).collect();
+        order.sort_by(|&a, &b| {
+            (bounds[a].y, bounds[a].x, a)
+                .partial_cmp(&(bounds[b].y, bounds[b].x, b))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let state = ctx.tree.state.downcast_mut::<NodeGraphState>();
+        // The anchor is the focused node, else the selected node earliest in
+        // reading order.
+        let current = match state.focused_node {
+            Some(focused) => order.iter().position(|&index| index == focused),
+            None => order
+                .iter()
+                .position(|index| state.selected_nodes.contains(index)),
+        };
+        let len = order.len();
+        if len == 0 {
+            return;
+        }
+        let target = match action {
+            KeyAction::SelectNext => Some(current.map_or(order[0], |at| order[(at + 1) % len])),
+            KeyAction::SelectPrevious => {
+                Some(current.map_or(order[len - 1], |at| order[(at + len - 1) % len]))
+            }
+            KeyAction::SelectLeft
+            | KeyAction::SelectRight
+            | KeyAction::SelectUp
+            | KeyAction::SelectDown => match current {
+                None => Some(order[0]),
+                Some(at) => {
+                    let from = bounds[order[at]].center();
+                    (0..len)
+                        .filter(|&index| index != order[at])
+                        .filter_map(|index| {
+                            let d = bounds[index].center() - from;
+                            let (primary, secondary) = match action {
+                                KeyAction::SelectLeft => (-d.x, d.y),
+                                KeyAction::SelectRight => (d.x, d.y),
+                                KeyAction::SelectUp => (-d.y, d.x),
+                                _ => (d.y, d.x),
+                            };
+                            // Off-axis travel counts double, so a node
+                            // straight ahead beats a closer one off to the side.
+                            (primary > 0.0).then(|| (primary + 2.0 * secondary.abs(), index))
+                        })
+                        .min_by(|a, b| a.0.total_cmp(&b.0))
+                        .map(|(_, index)| index)
                 }
+            },
+            _ => return,
+        };
+        let Some(target) = target else {
+            return;
+        };
+
+        state.focused_node = Some(target);
+        state.selected_nodes.clear();
+        state.selected_nodes.insert(target);
+        if let Some(handler) = self.on_select_handler() {
+            ctx.shell
+                .publish(handler(self.translate_node_ids(&[target])));
+        }
+
+        // Reveal: pan the least distance that brings the node into view.
+        let margin = REVEAL_MARGIN / state.camera.zoom();
+        let node = bounds[target];
+        let shift = |lo: f32, hi: f32, view_lo: f32, view_hi: f32| {
+            if lo < view_lo || hi - lo > view_hi - view_lo {
+                view_lo - lo + margin
+            } else if hi > view_hi {
+                view_hi - hi - margin
+            } else {
+                0.0
+            }
+        };
+        let dx = shift(
+            node.x,
+            node.x + node.width,
+            viewport.x,
+            viewport.x + viewport.width,
+        );
+        let dy = shift(
+            node.y,
+            node.y + node.height,
+            viewport.y,
+            viewport.y + viewport.height,
+        );
+        if dx != 0.0 || dy != 0.0 {
+            state.camera = state.camera.move_by(WorldVector::new(dx, dy));
+            if let Some(handler) = self.on_pan_handler() {
+                let pos = state.camera.position();
+                ctx.shell
+                    .publish(handler(Point::new(pos.x, pos.y), state.camera.zoom()));
             }
         }
-        false
+        ctx.shell.capture_event();
+        ctx.shell.request_redraw();
     }
 
     /// Hit-tests one node's pins and body for a left press.
@@ -1177,7 +2542,7 @@ where
         // (state downcast, compute_valid_targets), so borrowed pin states
         // cannot stay alive across those calls.
         let pins: Vec<(usize, P, bool, (Point, Point))> =
-            find_pins::<P, UI>(node_tree, node_layout)
+            find_pins_placed::<P, UI>(node_tree, node_layout, self.node_transform(node_index))
                 .into_iter()
                 .map(|(i, s, pos)| (i, s.pin_id.clone(), s.interactions_disabled, pos))
                 .collect();
@@ -1246,7 +2611,11 @@ where
         }
 
         // Body check for this same node (still top-first).
-        if ctx.world_cursor.is_over(node_layout.bounds()) {
+        if body_contains(
+            node_layout.bounds(),
+            self.node_transform(node_index),
+            cursor_position,
+        ) {
             self.select_or_drag_node(ctx, node_index, cursor_position);
             return true;
         }
@@ -1291,6 +2660,13 @@ where
         // Anchor at the kept end, hold the grabbed pin snapped (still
         // connected).
         state.dragging = Dragging::EdgeOver(anchor_node_idx, anchor_pin_idx, grabbed.0, grabbed.1);
+        state.rerouting = Some(grabbed);
+        if let Some(handler) = self.on_drag_start_handler() {
+            ctx.shell.publish(handler(DragInfo::Edge {
+                from_node: anchor.node_id.clone(),
+                from_pin: anchor.pin_id.clone(),
+            }));
+        }
         ctx.shell.capture_event();
         true
     }
@@ -1367,10 +2743,15 @@ where
         // from the host, so without on_move a drag would move
         // the node visually then snap back on the next frame;
         // gate it off (selection below still fires).
-        if self.on_move_handler().is_some() {
+        if self.on_move_handler().is_some() && !self.is_screen_fixed(node_index) {
             if state.selected_nodes.len() > 1 && state.selected_nodes.contains(&node_index) {
                 // Multiple nodes selected, start group move
-                let selected: Vec<usize> = state.selected_nodes.iter().copied().collect();
+                let selected: Vec<usize> = state
+                    .selected_nodes
+                    .iter()
+                    .copied()
+                    .filter(|&i| !self.is_screen_fixed(i))
+                    .collect();
                 state.dragging = Dragging::GroupMove(cursor_position.into_euclid());
                 // Emit drag start event for group
                 if let Some(handler) = self.on_drag_start_handler() {
@@ -1402,7 +2783,9 @@ where
     }
 
     /// Starts the empty-space press interaction: edge-cutting with COMMAND
-    /// held, box selection otherwise (Shift keeps the current selection).
+    /// held, otherwise whatever [`BackgroundClick`] asks for - box selection
+    /// (Shift keeps the current selection), a pan, or the host's
+    /// `on_background_click`.
     fn start_box_select_or_cut(&self, ctx: &mut UpdateCtx<'_, '_, '_, Message>) {
         let UpdateCtx {
             tree,
@@ -1434,6 +2817,27 @@ where
                 return;
             }
 
+            match self.background_click {
+                BackgroundClick::BoxSelect => {}
+                BackgroundClick::Pan => {
+                    state.dragging = Dragging::Graph(cursor_position);
+                    shell.capture_event();
+                    return;
+                }
+                BackgroundClick::Custom => {
+                    if let Some(handler) = self.on_background_click_handler() {
+                        // The cursor is layout-absolute; report world space.
+                        let vo = state.camera.viewport_origin();
+                        shell.publish(handler(Point::new(
+                            cursor_position.x - vo.x,
+                            cursor_position.y - vo.y,
+                        )));
+                        shell.capture_event();
+                    }
+                    return;
+                }
+            }
+
             // Clear selection unless the multi-select modifier is held
             if !state.modifiers.contains(self.keymap.multi_select_modifiers) {
                 state.selected_nodes.clear();
@@ -1442,9 +2846,10 @@ where
             state.dragging = Dragging::BoxSelect(cursor_position, cursor_position);
             // Emit drag start event for box select
             if let Some(handler) = self.on_drag_start_handler() {
+                let vo = state.camera.viewport_origin();
                 shell.publish(handler(DragInfo::BoxSelect {
-                    start_x: cursor_position.x,
-                    start_y: cursor_position.y,
+                    start_x: cursor_position.x - vo.x,
+                    start_y: cursor_position.y - vo.y,
                 }));
             }
             shell.capture_event();
@@ -1603,6 +3008,21 @@ fn resolve_pin_index<P: PinId + 'static, UI: 'static>(
         .position(|(_, s, _)| s.pin_id == *pin_id)
 }
 
+/// Resolves the user pin id and direction at positional `pin_idx` within
+/// `node_idx`; the inverse of [`resolve_pin_index`].
+fn pin_at<P: PinId + 'static, UI: 'static>(
+    tree: &Tree,
+    layout: Layout<'_>,
+    node_idx: usize,
+    pin_idx: usize,
+) -> Option<(P, PinDirection)> {
+    let node_tree = tree.children.get(node_idx)?;
+    let node_layout = layout.children().nth(node_idx)?;
+    find_pins::<P, UI>(node_tree, node_layout)
+        .get(pin_idx)
+        .map(|(_, s, _)| (s.pin_id.clone(), s.direction))
+}
+
 /// Creates a selection rectangle from two corner points (handles any corner order)
 fn selection_rect_from_points(a: WorldPoint, b: WorldPoint) -> Rectangle {
     let min_x = a.x.min(b.x);
@@ -1622,52 +3042,12 @@ fn rects_intersect(a: &Rectangle, b: &Rectangle) -> bool {
     a.x < b.x + b.width && a.x + a.width > b.x && a.y < b.y + b.height && a.y + a.height > b.y
 }
 
-/// Minimum distance from a point to a cubic bezier, via uniform flattening.
-///
-/// 32 segments keep the flattening error far below the 10px cut threshold
-/// for edge-scale curves; no allocation.
-fn point_to_bezier_distance(point: Point, p0: Point, p1: Point, p2: Point, p3: Point) -> f32 {
-    const SEGMENTS: u32 = 32;
-    let mut prev = p0;
-    let mut min_dist = f32::MAX;
-    for i in 1..=SEGMENTS {
-        let t = i as f32 / SEGMENTS as f32;
-        let it = 1.0 - t;
-        let a = it * it * it;
-        let b = 3.0 * it * it * t;
-        let c = 3.0 * it * t * t;
-        let d = t * t * t;
-        let cur = Point::new(
-            a * p0.x + b * p1.x + c * p2.x + d * p3.x,
-            a * p0.y + b * p1.y + c * p2.y + d * p3.y,
-        );
-        min_dist = min_dist.min(point_to_line_distance(point, prev, cur));
-        prev = cur;
-    }
-    min_dist
-}
-
-/// Calculates the distance from a point to a line segment
-fn point_to_line_distance(point: Point, line_start: Point, line_end: Point) -> f32 {
-    let dx = line_end.x - line_start.x;
-    let dy = line_end.y - line_start.y;
-    let line_length_sq = dx * dx + dy * dy;
-
-    if line_length_sq < 0.001 {
-        // Line segment is essentially a point
-        return ((point.x - line_start.x).powi(2) + (point.y - line_start.y).powi(2)).sqrt();
-    }
-
-    // Calculate projection of point onto line
-    let t = ((point.x - line_start.x) * dx + (point.y - line_start.y) * dy) / line_length_sq;
-    let t = t.clamp(0.0, 1.0);
-
-    // Find closest point on line segment
-    let closest_x = line_start.x + t * dx;
-    let closest_y = line_start.y + t * dy;
-
-    // Return distance from point to closest point on line
-    ((point.x - closest_x).powi(2) + (point.y - closest_y).powi(2)).sqrt()
+/// Whether `inner` lies entirely within `outer`.
+fn rect_contains(outer: &Rectangle, inner: &Rectangle) -> bool {
+    inner.x >= outer.x
+        && inner.y >= outer.y
+        && inner.x + inner.width <= outer.x + outer.width
+        && inner.y + inner.height <= outer.y + outer.height
 }
 
 /// Checks if a line segment intersects a cubic bezier curve.
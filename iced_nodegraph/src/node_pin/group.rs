@@ -0,0 +1,306 @@
+//! Collapsible sections of pins within a node.
+//!
+//! A [`PinGroup`] stacks a header above rows of pins (usually [`pin!`] rows,
+//! or rows holding a pin next to a value readout). Collapsed, it shows only
+//! the header: the rows stay in the widget tree so their edges are kept, but
+//! every pin inside them moves onto the header row and stops taking drags.
+//! Edges to hidden pins therefore converge on the header, which acts as the
+//! section's proxy connection point until it is expanded again.
+//!
+//! The collapsed flag is host state, like the rest of a node's content:
+//! toggle it from a button in the header and rebuild the view.
+//!
+//! ```ignore
+//! pin_group(
+//!     button(text("Advanced")).on_press(Message::Toggle(id)),
+//!     [pin!(Left, GAIN, text("gain"), Input).into(), pin!(Left, BIAS, text("bias"), Input).into()],
+//! )
+//! .collapsed(!expanded)
+//! ```
+//!
+//! [`pin!`]: crate::pin
+
+use iced::{Alignment, Element, Event, Length, Padding, Point, Rectangle, Size};
+use iced_wgpu::core::{
+    Clipboard, Layout, Shell, Widget, layout, mouse, renderer,
+    widget::{Operation, Tree},
+};
+
+/// A header followed by rows of pins that can collapse onto the header.
+///
+/// Build with [`pin_group`]. Pin ids and the node's pin indices are the same
+/// whether the group is open or collapsed.
+pub struct PinGroup<'a, Message, Theme, Renderer> {
+    /// The header first, then the pin rows.
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+    collapsed: bool,
+    spacing: f32,
+    width: Length,
+}
+
+/// Creates an expanded [`PinGroup`] of `rows` under `header`.
+pub fn pin_group<'a, Message, Theme, Renderer>(
+    header: impl Into<Element<'a, Message, Theme, Renderer>>,
+    rows: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+) -> PinGroup<'a, Message, Theme, Renderer> {
+    PinGroup {
+        children: std::iter::once(header.into()).chain(rows).collect(),
+        collapsed: false,
+        spacing: 0.0,
+        width: Length::Shrink,
+    }
+}
+
+impl<'a, Message, Theme, Renderer> PinGroup<'a, Message, Theme, Renderer> {
+    /// Collapses the group to its header. Hidden pins anchor their edges on
+    /// the header row and cannot start or take a connection.
+    pub fn collapsed(mut self, collapsed: bool) -> Self {
+        self.collapsed = collapsed;
+        self
+    }
+
+    /// Sets the vertical gap between the header and each row.
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the width of the group.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// The children drawn and hit-tested: everything when open, only the
+    /// header when collapsed.
+    fn shown(&self) -> usize {
+        if self.collapsed {
+            1
+        } else {
+            self.children.len()
+        }
+    }
+}
+
+/// Asks every pin it visits to refuse interactions; run over the rows of a
+/// collapsed group after each update, and over collapsed nodes by the graph.
+/// See [`CollapsedPin`].
+pub(crate) struct CollapsePins;
+
+impl Operation for CollapsePins {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+        operate(self);
+    }
+
+    fn custom(
+        &mut self,
+        _id: Option<&iced::widget::Id>,
+        _bounds: Rectangle,
+        state: &mut dyn std::any::Any,
+    ) {
+        if let Some(pin) = state.downcast_mut::<CollapsedPin>() {
+            pin.0 = true;
+        }
+    }
+}
+
+/// Handed out by a pin during an operation; a collapsed [`PinGroup`] sets it
+/// to disable the pin. A separate type keeps the request independent of the
+/// pin's id and payload types.
+pub(super) struct CollapsedPin(pub bool);
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for PinGroup<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let shown = self.shown();
+        let node = layout::flex::resolve(
+            layout::flex::Axis::Vertical,
+            renderer,
+            limits,
+            self.width,
+            Length::Shrink,
+            Padding::ZERO,
+            self.spacing,
+            Alignment::Start,
+            &mut self.children[..shown],
+            &mut tree.children[..shown],
+        );
+        if !self.collapsed {
+            return node;
+        }
+        // Hidden rows are laid out at zero size on the header's centre, so
+        // their pins (and the edges they anchor) sit on the header row.
+        let header_center = node
+            .children()
+            .first()
+            .map_or(Point::ORIGIN, |header| header.bounds().center());
+        let size = node.size();
+        let zero = layout::Limits::new(Size::ZERO, Size::ZERO);
+        let mut nodes = node.children().to_vec();
+        nodes.extend(
+            self.children
+                .iter_mut()
+                .zip(&mut tree.children)
+                .skip(1)
+                .map(|(row, row_tree)| {
+                    row.as_widget_mut()
+                        .layout(row_tree, renderer, &zero)
+                        .move_to(header_center)
+                }),
+        );
+        layout::Node::with_children(size, nodes)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        // Hidden rows still see every event: their pins record where they
+        // sit, which is how edges find the header.
+        for ((child, child_tree), child_layout) in self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+        {
+            child.as_widget_mut().update(
+                child_tree,
+                event,
+                child_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+        if self.collapsed {
+            for ((row, row_tree), row_layout) in self
+                .children
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .skip(1)
+            {
+                row.as_widget_mut()
+                    .operate(row_tree, row_layout, renderer, &mut CollapsePins);
+            }
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((child, child_tree), child_layout) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .take(self.shown())
+        {
+            child.as_widget().draw(
+                child_tree,
+                renderer,
+                theme,
+                style,
+                child_layout,
+                cursor,
+                viewport,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .take(self.shown())
+            .map(|((child, child_tree), child_layout)| {
+                child.as_widget().mouse_interaction(
+                    child_tree,
+                    child_layout,
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            for ((child, child_tree), child_layout) in self
+                .children
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+            {
+                child
+                    .as_widget_mut()
+                    .operate(child_tree, child_layout, renderer, operation);
+            }
+        });
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<PinGroup<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(group: PinGroup<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(group)
+    }
+}
@@ -24,6 +24,8 @@
 //! - [`PinDirection`] - Whether the pin is an input or output
 //! - User info - Optional user-defined payload via [`NodePin::info`]
 //!
+//! Long pin lists can be split into collapsible sections with [`pin_group`].
+//!
 //! ## Connection Behavior
 //!
 //! When users drag from a pin, the widget tracks valid drop targets based on:
@@ -31,11 +33,16 @@
 //! - The graph's [`NodeGraph::can_connect`](crate::NodeGraph::can_connect) closure
 //! - Visual feedback via pulsing animation on valid targets
 
+mod group;
+
+pub(crate) use group::CollapsePins;
+pub use group::{PinGroup, pin_group};
+
 use crate::ids::PinId;
 use iced::{Element, Event, Length, Point, Rectangle, Size};
 use iced_wgpu::core::{
     Clipboard, Layout, Shell, Widget, layout, mouse, renderer,
-    widget::{Tree, tree},
+    widget::{Operation, Tree, tree},
 };
 /// Default pin size when no content widget is provided.
 const DEFAULT_PIN_SIZE: Size = Size::new(50.0, 20.0);
@@ -67,8 +74,15 @@ impl From<PinSide> for u32 {
 /// Direction of data flow for a pin.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum PinDirection {
+    /// Receives edges; holds at most one under the default connection rule.
     Input,
+    /// Sends edges; fans out to any number of inputs.
     Output,
+    /// Bidirectional: connects to an `Input`, an `Output` or another `Both`
+    /// pin and fans out like an output. Against an input it acts as the
+    /// output, against an output as the input; between two `Both` pins the
+    /// edge runs in drag order, which suits undirected graphs.
+    #[doc(alias = "Bidirectional")]
     #[default]
     Both,
 }
@@ -420,6 +434,36 @@ where
         self.content.as_widget().size_hint()
     }
 
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        // A collapsed `PinGroup` answers this to disable the pin until the
+        // next update.
+        let mut collapsed = group::CollapsedPin(false);
+        operation.custom(None, layout.bounds(), &mut collapsed);
+        if collapsed.0 {
+            tree.state
+                .downcast_mut::<NodePinState<P, UI>>()
+                .interactions_disabled = true;
+        }
+        if let Some((content_tree, content_layout)) =
+            tree.children.first_mut().zip(layout.children().next())
+        {
+            operation.traverse(&mut |operation| {
+                self.content.as_widget_mut().operate(
+                    content_tree,
+                    content_layout,
+                    renderer,
+                    operation,
+                );
+            });
+        }
+    }
+
     fn diff(&self, tree: &mut Tree) {
         if let Some(content_tree) = tree.children.first_mut() {
             self.content.as_widget().diff(content_tree);
@@ -12,7 +12,7 @@
 pub use crate::{Edge, Node, edge, node, node_graph, node_pin, pin};
 
 // Core types named when wiring callbacks and edges.
-pub use crate::{NodeGraph, PinRef};
+pub use crate::{NodeGraph, NodeGraphEvent, PinRef};
 
 // Pin and status vocabulary passed to `style` / `pin_style` / `can_connect` closures.
 pub use crate::{EdgeStatus, NodeStatus, PinDirection, PinEnd, PinInfo, PinSide, PinStatus};
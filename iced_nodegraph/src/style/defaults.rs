@@ -21,7 +21,7 @@ use iced_nodegraph_sdf::Pattern;
 
 use super::{
     EdgeCurve, EdgeStatus, EdgeStyle, NodeStatus, NodeStyle, PinShape, PinStatus, PinStyle,
-    SelectionStyle,
+    SelectionStyle, WidthSpace,
 };
 
 /// Complete theme-derived node style with status feedback layered on top:
@@ -56,6 +56,7 @@ pub fn default_node_style(theme: &Theme, status: NodeStatus) -> NodeStyle {
         border_pattern: Pattern::solid(1.0),
         border_outline_width: 0.0,
         border_outline_color: Color::TRANSPARENT.into(),
+        border_width_space: WidthSpace::World,
         shadow_color,
         shadow_distance,
         shadow_offset: (2.0, 2.0),
@@ -89,6 +90,7 @@ pub fn default_pin_style(theme: &Theme, _status: PinStatus) -> PinStyle {
         shape: PinShape::Circle,
         border_color: Color::TRANSPARENT.into(),
         border_width: 0.0,
+        radius_space: WidthSpace::World,
     }
 }
 
@@ -107,6 +109,8 @@ pub fn default_edge_style(theme: &Theme, status: EdgeStatus) -> EdgeStyle {
     let base = EdgeStyle {
         stroke_color: palette.secondary.base.color.into(),
         pattern: Pattern::solid(2.0),
+        pulse: None,
+        ribbon: None,
         stroke_outline_width: 0.0,
         stroke_outline_color: none.into(),
         border_color: none.into(),
@@ -120,6 +124,10 @@ pub fn default_edge_style(theme: &Theme, status: EdgeStatus) -> EdgeStyle {
         shadow_blur: 0.0,
         shadow_offset: (0.0, 0.0),
         curve: EdgeCurve::BezierCubic,
+        stub_length: 0.0,
+        width_space: WidthSpace::World,
+        min_screen_width: 0.0,
+        auto_straighten: 0.0,
     };
 
     match status {
@@ -13,11 +13,13 @@
 //! to transparent. On/off is a sentinel: border `width` 0, stroke/border outline
 //! `width` 0, shadow `blur` 0 or color alpha 0.
 //!
-use iced::Color;
-use iced_nodegraph_sdf::Pattern;
+use iced::{Color, Theme};
+use iced_nodegraph_sdf::{Pattern, Pulse};
 
 use super::ColorQuad;
 use super::EdgeCurve;
+use super::WidthSpace;
+use super::{HIGH_CONTRAST_MIN_WIDTH, contrast_ink, opaque, thickened};
 
 /// Visual style for an edge.
 #[derive(Debug, Clone, PartialEq)]
@@ -25,8 +27,17 @@ pub struct EdgeStyle {
     // Stroke (stroke_color: arc gradient start -> end)
     /// Stroke color as an arc-length gradient (start pin -> end pin).
     pub stroke_color: ColorQuad,
-    /// Stroke pattern (thickness, dash/gap, flow).
+    /// Stroke pattern (thickness or taper, dash/gap, flow).
     pub pattern: Pattern,
+    /// Bright band travelling along the stroke from start to end, for edges
+    /// that carry live data. `None` = no band. See [`EdgeStyle::flowing`].
+    pub pulse: Option<Pulse>,
+    /// Ribbon center color. When set, the stroke is a band
+    /// `pattern.thickness` wide shaded across its width, from the stroke
+    /// color at both rims to this color along the centerline. Dashes, flow
+    /// and taper don't apply. `None` = plain stroke. See
+    /// [`EdgeStyle::ribbon`].
+    pub ribbon: Option<Color>,
     /// Outline ring width on the stroke. 0 = no outline.
     pub stroke_outline_width: f32,
     /// Outline ring color on the stroke.
@@ -59,6 +70,21 @@ pub struct EdgeStyle {
     // Path
     /// Curve shape of the connection.
     pub curve: EdgeCurve,
+    /// Length in world-space pixels of the straight stub the edge leaves and
+    /// enters each pin by, perpendicular to the node side, before it curves.
+    /// 0 = the curve starts right at the pin.
+    pub stub_length: f32,
+    /// Space the stroke, outline, border, gap and shadow widths are measured
+    /// in. [`WidthSpace::World`] (the default) scales them with zoom.
+    pub width_space: WidthSpace,
+    /// Thinnest the stroke renders, in screen pixels, at any zoom and in
+    /// either [`width_space`](Self::width_space). 0 = no minimum. See
+    /// [`EdgeStyle::min_screen_width`].
+    pub min_screen_width: f32,
+    /// Drawn as a straight line when the pins are within this many
+    /// world-space pixels of lining up along the start pin's side. 0 = off.
+    /// See [`EdgeStyle::auto_straighten`].
+    pub auto_straighten: f32,
 }
 
 impl EdgeStyle {
@@ -68,6 +94,8 @@ impl EdgeStyle {
         Self {
             stroke_color: color,
             pattern,
+            pulse: None,
+            ribbon: None,
             stroke_outline_width: 0.0,
             stroke_outline_color: none,
             border_color: none,
@@ -81,43 +109,309 @@ impl EdgeStyle {
             shadow_blur: 0.0,
             shadow_offset: (0.0, 0.0),
             curve: EdgeCurve::BezierCubic,
+            stub_length: 0.0,
+            width_space: WidthSpace::World,
+            min_screen_width: 0.0,
+            auto_straighten: 0.0,
+        }
+    }
+
+    /// Cheapest edge the graph draws past its
+    /// [`edge_simplify_threshold`](crate::NodeGraph::edge_simplify_threshold):
+    /// a one-pixel straight line in `color`, no outline, border, pattern or
+    /// shadow.
+    pub(crate) fn simplified(color: ColorQuad) -> Self {
+        Self {
+            curve: EdgeCurve::Line,
+            width_space: WidthSpace::Screen,
+            ..Self::stroke(color, Pattern::solid(1.0))
+        }
+    }
+
+    /// Sets [`stub_length`](Self::stub_length): the edge runs straight out of
+    /// each pin for `px` world-space pixels, schematic style, and curves
+    /// between the stub ends. Stubs shrink between pins closer than three
+    /// stubs apart.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::EdgeStyle;
+    ///
+    /// let style = EdgeStyle::data_flow().stub_length(16.0);
+    /// assert_eq!(style.stub_length, 16.0);
+    /// ```
+    pub fn stub_length(mut self, px: f32) -> Self {
+        self.stub_length = px;
+        self
+    }
+
+    /// Tapers the stroke from `start` width at the source end to `end` width
+    /// at the target end, keeping the pattern's dashes and flow. Thick at the
+    /// output and thin at the input reads as flow direction, especially with
+    /// an arrowed pattern. The stroke outline follows the taper.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::EdgeStyle;
+    ///
+    /// let style = EdgeStyle::data_flow().taper(4.0, 1.0);
+    /// assert_eq!(style.pattern.thickness, 4.0);
+    /// assert_eq!(style.pattern.end_thickness, Some(1.0));
+    /// ```
+    pub fn taper(mut self, start: f32, end: f32) -> Self {
+        self.pattern = self.pattern.taper(start, end);
+        self
+    }
+
+    /// Sets [`width_space`](Self::width_space). With [`WidthSpace::Screen`]
+    /// the stroke pattern (thickness and dashes), outlines, border ring and
+    /// shadow band keep their pixel size on screen at every zoom, so edges
+    /// stay legible zoomed out instead of thinning into a hairball.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::{EdgeStyle, WidthSpace};
+    ///
+    /// let style = EdgeStyle::data_flow().width_space(WidthSpace::Screen);
+    /// assert_eq!(style.width_space, WidthSpace::Screen);
+    /// ```
+    pub fn width_space(mut self, space: WidthSpace) -> Self {
+        self.width_space = space;
+        self
+    }
+
+    /// Sets [`min_screen_width`](Self::min_screen_width): the stroke never
+    /// renders thinner than `px` screen pixels, so world-space edges zoomed
+    /// far out stay visible instead of vanishing or flickering, while keeping
+    /// their world width wherever that is wider. Both ends of a taper are
+    /// held to the minimum.
+    ///
+    /// The floor is applied each frame at the current zoom, before the
+    /// stroke's outline, border and shadow are laid out around it, so they
+    /// stay wrapped around the held stroke.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::EdgeStyle;
+    ///
+    /// let style = EdgeStyle::data_flow().min_screen_width(1.0);
+    /// assert_eq!(style.min_screen_width, 1.0);
+    /// ```
+    pub fn min_screen_width(mut self, px: f32) -> Self {
+        self.min_screen_width = px;
+        self
+    }
+
+    /// Sets [`auto_straighten`](Self::auto_straighten): an edge whose pins
+    /// face each other and sit within `tolerance` world-space pixels of one
+    /// row (left/right pins) or column (top/bottom pins) draws as a straight
+    /// line, with no stubs, instead of a barely bent curve. Edges with
+    /// waypoints keep their path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::EdgeStyle;
+    ///
+    /// let style = EdgeStyle::data_flow().auto_straighten(4.0);
+    /// assert_eq!(style.auto_straighten, 4.0);
+    /// ```
+    pub fn auto_straighten(mut self, tolerance: f32) -> Self {
+        self.auto_straighten = tolerance;
+        self
+    }
+
+    /// This style with its widths converted to world-space pixels at `zoom`,
+    /// as the renderer consumes them, and the stroke held to
+    /// [`min_screen_width`](Self::min_screen_width).
+    pub(crate) fn in_world_space(self, zoom: f32) -> Self {
+        let k = self.width_space.world_factor(zoom);
+        let min = self.min_screen_width / zoom;
+        let style = if k == 1.0 {
+            self
+        } else {
+            Self {
+                pattern: self.pattern.scale(k),
+                stroke_outline_width: self.stroke_outline_width * k,
+                border_width: self.border_width * k,
+                border_gap: self.border_gap * k,
+                border_outline_width: self.border_outline_width * k,
+                shadow_expand: self.shadow_expand * k,
+                shadow_blur: self.shadow_blur * k,
+                ..self
+            }
+        };
+        if min > 0.0 {
+            Self {
+                pattern: thickened(style.pattern, min),
+                ..style
+            }
+        } else {
+            style
+        }
+    }
+
+    /// This style adjusted for high-contrast mode over `background`: opaque
+    /// stroke colors (transparent pin-inheriting ends are kept), a stroke of
+    /// at least [`HIGH_CONTRAST_MIN_WIDTH`], and a black or white outline that
+    /// separates it from the canvas.
+    pub(crate) fn high_contrast(self, background: Color) -> Self {
+        Self {
+            stroke_color: opaque(self.stroke_color),
+            pattern: thickened(self.pattern, HIGH_CONTRAST_MIN_WIDTH),
+            stroke_outline_width: self.stroke_outline_width.max(HIGH_CONTRAST_MIN_WIDTH / 2.0),
+            stroke_outline_color: ColorQuad::solid(contrast_ink(background)),
+            ..self
         }
     }
 
     /// Data flow preset (blue, bezier).
     pub fn data_flow() -> Self {
-        Self::stroke(
-            ColorQuad::solid(Color::from_rgb(0.3, 0.6, 1.0)),
-            Pattern::solid(2.5),
-        )
+        Self::data_flow_in(Color::from_rgb(0.3, 0.6, 1.0))
+    }
+
+    /// [`data_flow`](Self::data_flow) in the theme's primary color.
+    pub fn data_flow_themed(theme: &Theme) -> Self {
+        Self::data_flow_in(theme.extended_palette().primary.base.color)
+    }
+
+    fn data_flow_in(color: Color) -> Self {
+        Self::stroke(ColorQuad::solid(color), Pattern::solid(2.5))
+    }
+
+    /// Flowing preset: a solid `color` stroke with a bright band running
+    /// from the start pin to the end pin, `speed` times per second. Unlike a
+    /// flowing dash [`Pattern`], the stroke stays continuous and the band
+    /// crosses every edge in the same time, whatever its length. Pauses when
+    /// animations are off.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced::Color;
+    /// use iced_nodegraph::EdgeStyle;
+    ///
+    /// let running = EdgeStyle::flowing(Color::from_rgb(0.3, 0.6, 1.0), 0.5);
+    /// assert_eq!(running.pulse.map(|band| band.speed), Some(0.5));
+    /// ```
+    pub fn flowing(color: Color, speed: f32) -> Self {
+        let bright = Color {
+            r: color.r + (1.0 - color.r) * 0.7,
+            g: color.g + (1.0 - color.g) * 0.7,
+            b: color.b + (1.0 - color.b) * 0.7,
+            a: 1.0,
+        };
+        let mut s = Self::data_flow_in(color);
+        s.pulse = Some(Pulse {
+            color: bright,
+            speed,
+            width: 0.25,
+        });
+        s
+    }
+
+    /// Ribbon preset: a band `width` world-space pixels wide, `center_color`
+    /// along the curve and shading to `edge_color` at both rims, for wide
+    /// bundle or flow-diagram edges. Plain strokes keep the cheaper pattern
+    /// path; only ribbon edges pay for the gradient.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced::Color;
+    /// use iced_nodegraph::EdgeStyle;
+    ///
+    /// let band = EdgeStyle::ribbon(12.0, Color::from_rgb(0.1, 0.3, 0.6), Color::WHITE);
+    /// assert_eq!(band.pattern.thickness, 12.0);
+    /// assert_eq!(band.ribbon, Some(Color::WHITE));
+    /// ```
+    pub fn ribbon(width: f32, edge_color: Color, center_color: Color) -> Self {
+        let mut s = Self::stroke(ColorQuad::solid(edge_color), Pattern::solid(width));
+        s.ribbon = Some(center_color);
+        s
     }
 
     /// Error preset (red, marching ants, with border ring).
     pub fn error() -> Self {
-        let red = Color::from_rgb(0.9, 0.2, 0.2);
+        Self::error_in(Color::from_rgb(0.9, 0.2, 0.2))
+    }
+
+    /// [`error`](Self::error) in the theme's danger color.
+    pub fn error_themed(theme: &Theme) -> Self {
+        Self::error_in(theme.extended_palette().danger.base.color)
+    }
+
+    fn error_in(color: Color) -> Self {
         let mut s = Self::stroke(
-            ColorQuad::solid(red),
+            ColorQuad::solid(color),
             Pattern::dashed(2.0, 6.0, 4.0).flow(30.0),
         );
-        s.border_color = ColorQuad::solid(red);
+        s.border_color = ColorQuad::solid(color);
         s.border_width = 1.0;
         s.border_gap = 0.5;
         s
     }
 
-    /// Disabled preset (gray, dashed).
-    pub fn disabled() -> Self {
+    /// Success preset in the theme's success color: the
+    /// [`data_flow`](Self::data_flow) stroke, for edges that validated or
+    /// carry a passing result.
+    pub fn success_themed(theme: &Theme) -> Self {
+        Self::data_flow_in(theme.extended_palette().success.base.color)
+    }
+
+    /// Warning preset in the theme's warning color: a dashed stroke, for edges
+    /// that connect but deserve attention (e.g. a lossy conversion).
+    pub fn warning_themed(theme: &Theme) -> Self {
         Self::stroke(
-            ColorQuad::solid(Color::from_rgb(0.5, 0.5, 0.5)),
-            Pattern::dashed(1.5, 12.0, 6.0),
+            ColorQuad::solid(theme.extended_palette().warning.base.color),
+            Pattern::dashed(2.5, 10.0, 4.0),
         )
     }
 
+    /// Disabled preset (gray, dashed).
+    pub fn disabled() -> Self {
+        Self::disabled_in(Color::from_rgb(0.5, 0.5, 0.5))
+    }
+
+    /// [`disabled`](Self::disabled) in the theme's strong background color,
+    /// which stays muted against both light and dark canvases.
+    pub fn disabled_themed(theme: &Theme) -> Self {
+        Self::disabled_in(theme.extended_palette().background.strong.color)
+    }
+
+    fn disabled_in(color: Color) -> Self {
+        Self::stroke(ColorQuad::solid(color), Pattern::dashed(1.5, 12.0, 6.0))
+    }
+
     /// Highlighted preset (bright yellow, with soft border ring).
     pub fn highlighted() -> Self {
-        let yellow = Color::from_rgb(1.0, 0.8, 0.2);
-        let mut s = Self::stroke(ColorQuad::solid(yellow), Pattern::solid(3.0));
-        s.border_color = ColorQuad::solid(Color::from_rgba(1.0, 1.0, 1.0, 0.3));
+        Self::highlighted_in(
+            Color::from_rgb(1.0, 0.8, 0.2),
+            Color::from_rgba(1.0, 1.0, 1.0, 0.3),
+        )
+    }
+
+    /// [`highlighted`](Self::highlighted) in the theme's warning color. The
+    /// soft ring follows the theme's text color, so it stays visible on light
+    /// themes where the fixed white ring disappears.
+    pub fn highlighted_themed(theme: &Theme) -> Self {
+        let palette = theme.extended_palette();
+        Self::highlighted_in(
+            palette.warning.base.color,
+            Color {
+                a: 0.3,
+                ..palette.background.base.text
+            },
+        )
+    }
+
+    fn highlighted_in(color: Color, ring: Color) -> Self {
+        let mut s = Self::stroke(ColorQuad::solid(color), Pattern::solid(3.0));
+        s.border_color = ColorQuad::solid(ring);
         s.border_width = 2.0;
         s.border_gap = 1.0;
         s
@@ -153,6 +447,86 @@ mod tests {
         assert_eq!(style.pattern, Pattern::solid(2.0)); // inherited from default
     }
 
+    #[test]
+    fn screen_widths_divide_by_zoom() {
+        let style = EdgeStyle::error();
+        assert_eq!(style.clone().in_world_space(4.0), style); // world: as given
+
+        let screen = style.clone().width_space(WidthSpace::Screen);
+        let world = screen.clone().in_world_space(4.0);
+        assert_eq!(world.pattern, style.pattern.scale(0.25));
+        assert_eq!(world.border_width, style.border_width / 4.0);
+        assert_eq!(world.border_gap, style.border_gap / 4.0);
+        assert_eq!(world.stub_length, style.stub_length); // geometry, not a width
+        assert_eq!(
+            screen.in_world_space(1.0),
+            style.width_space(WidthSpace::Screen)
+        );
+    }
+
+    #[test]
+    fn min_screen_width_holds_the_stroke_to_a_pixel_floor() {
+        let style = EdgeStyle::data_flow().taper(4.0, 1.0).min_screen_width(1.0);
+        // Zoomed out 8x the 4px world stroke is half a pixel: held to one
+        // screen pixel (1/8 world). Zoomed in it keeps its own width.
+        let far = style.clone().in_world_space(0.125);
+        assert_eq!(far.pattern.thickness, 8.0);
+        assert_eq!(far.pattern.end_thickness, Some(8.0));
+        let near = style.clone().in_world_space(2.0);
+        assert_eq!(near.pattern, style.pattern);
+
+        let screen = style.width_space(WidthSpace::Screen).in_world_space(0.125);
+        assert_eq!(screen.pattern.thickness, 32.0);
+        assert_eq!(screen.pattern.end_thickness, Some(8.0));
+    }
+
+    #[test]
+    fn min_screen_width_floor_carries_onto_the_stroke_outline() {
+        let layers = EdgeStyle {
+            stroke_outline_width: 1.0,
+            stroke_outline_color: ColorQuad::solid(Color::BLACK),
+            ..EdgeStyle::data_flow().taper(4.0, 1.0).min_screen_width(1.0)
+        }
+        .in_world_space(0.125)
+        .sdf_layers();
+        let stroke = layers[0].style.pattern.expect("stroke lost its pattern");
+        let outline = layers[1].style.pattern.expect("outline lost its pattern");
+        assert_eq!((stroke.thickness, stroke.end_thickness), (8.0, Some(8.0)));
+        assert_eq!(
+            (outline.thickness, outline.end_thickness),
+            (10.0, Some(10.0))
+        );
+    }
+
+    #[test]
+    fn themed_presets_follow_palette_and_keep_shape() {
+        for theme in [iced::Theme::Light, iced::Theme::Dark, iced::Theme::Dracula] {
+            let palette = theme.extended_palette();
+            let error = EdgeStyle::error_themed(&theme);
+            assert_eq!(
+                error.stroke_color,
+                ColorQuad::solid(palette.danger.base.color)
+            );
+            assert_eq!(
+                error.border_color,
+                ColorQuad::solid(palette.danger.base.color)
+            );
+            assert_eq!(error.pattern, EdgeStyle::error().pattern);
+
+            let success = EdgeStyle::success_themed(&theme);
+            assert_eq!(
+                success.stroke_color,
+                ColorQuad::solid(palette.success.base.color)
+            );
+            assert_eq!(success.pattern, EdgeStyle::data_flow().pattern);
+        }
+        // The fixed-color presets are unchanged.
+        assert_eq!(
+            EdgeStyle::error().stroke_color,
+            ColorQuad::solid(Color::from_rgb(0.9, 0.2, 0.2))
+        );
+    }
+
     #[test]
     fn sdf_layers_preserves_stroke_pattern() {
         let mut s = EdgeStyle::data_flow();
@@ -169,4 +543,80 @@ mod tests {
             pat.pattern_type
         );
     }
+
+    #[test]
+    fn simplified_edges_are_a_single_screen_pixel_line() {
+        let style = EdgeStyle::simplified(ColorQuad::solid(Color::WHITE)).in_world_space(4.0);
+        assert_eq!(style.curve, EdgeCurve::Line);
+        assert_eq!(style.pattern.thickness, 0.25);
+        assert_eq!(style.sdf_layers().len(), 1);
+    }
+
+    #[test]
+    fn flowing_band_rides_on_the_stroke_layer_only() {
+        let layers = EdgeStyle {
+            border_width: 1.0,
+            ..EdgeStyle::flowing(Color::from_rgb(0.2, 0.4, 0.8), 0.5)
+        }
+        .sdf_layers();
+        let band = layers[0].style.pulse.expect("stroke lost its band");
+        assert_eq!(band.speed, 0.5);
+        assert!(band.color.b > 0.8, "band is not brighter: {:?}", band.color);
+        assert!(layers[0].style.is_animated());
+        assert!(layers[1..].iter().all(|layer| layer.style.pulse.is_none()));
+    }
+
+    #[test]
+    fn taper_carries_onto_the_stroke_outline() {
+        let layers = EdgeStyle {
+            stroke_outline_width: 1.0,
+            stroke_outline_color: ColorQuad::solid(Color::BLACK),
+            ..EdgeStyle::data_flow().taper(4.0, 1.0)
+        }
+        .sdf_layers();
+        let stroke = layers[0].style.pattern.expect("stroke lost its pattern");
+        let outline = layers[1].style.pattern.expect("outline lost its pattern");
+        assert_eq!((stroke.thickness, stroke.end_thickness), (4.0, Some(1.0)));
+        assert_eq!((outline.thickness, outline.end_thickness), (6.0, Some(3.0)));
+    }
+
+    #[test]
+    fn ribbon_shades_rims_to_center_across_its_width() {
+        let edge = Color::from_rgb(0.1, 0.3, 0.6);
+        let layers = EdgeStyle::ribbon(10.0, edge, Color::WHITE).sdf_layers();
+        let ribbon = &layers[0].style;
+        assert!(
+            ribbon.pattern.is_none(),
+            "ribbon is a stop chain, not a stroke"
+        );
+        let stops: Vec<_> = ribbon.stops.iter().map(|s| (s.dist, s.start)).collect();
+        assert_eq!(
+            stops,
+            [
+                (-5.0, Color { a: 0.0, ..edge }),
+                (-5.0, edge),
+                (0.0, Color::WHITE),
+                (5.0, edge),
+                (5.0, Color { a: 0.0, ..edge }),
+            ]
+        );
+        assert_eq!(ribbon.extent(false), 5.0);
+
+        // Plain strokes stay on the pattern path.
+        let plain = EdgeStyle::data_flow().sdf_layers();
+        assert!(plain[0].style.pattern.is_some());
+    }
+
+    #[test]
+    fn high_contrast_keeps_inheriting_stroke_ends() {
+        let style = EdgeStyle {
+            stroke_color: ColorQuad::arc(Color::TRANSPARENT, Color::from_rgba(1.0, 0.0, 0.0, 0.4)),
+            ..EdgeStyle::data_flow()
+        }
+        .high_contrast(Color::BLACK);
+        assert_eq!(style.stroke_color.near_start, Color::TRANSPARENT);
+        assert_eq!(style.stroke_color.near_end, Color::from_rgb(1.0, 0.0, 0.0));
+        assert!(style.pattern.thickness >= HIGH_CONTRAST_MIN_WIDTH);
+        assert_eq!(style.stroke_outline_color, ColorQuad::solid(Color::WHITE));
+    }
 }
@@ -6,10 +6,17 @@
 //! and [`default_pin_style`]; override individual fields with struct-update
 //! syntax over them. See [`ColorQuad`] for the unified color type.
 //!
+//! There is no separate config layer to merge: a graph sets its look once
+//! with [`NodeGraph::node_style`](crate::NodeGraph::node_style),
+//! [`pin_style`](crate::NodeGraph::pin_style) and
+//! [`edge_style`](crate::NodeGraph::edge_style), and a node or edge with its
+//! own `.style()` closure replaces that graph-wide closure for itself.
+//!
 //! [`GraphStyle`] and [`SelectionStyle`] (canvas background, selection overlay,
 //! drag-edge colors) are also plain structs; they are not per-element styles.
 
 use iced::{Color, Theme};
+use iced_nodegraph_sdf::Pattern;
 
 mod defaults;
 mod edge;
@@ -90,8 +97,86 @@ pub enum EdgeCurve {
     /// Smooth cubic bezier curve (default)
     #[default]
     BezierCubic,
-    /// Direct straight line between pins
+    /// Direct straight line between pins; with waypoints (see
+    /// [`NodeGraph::push_edge_via`](crate::NodeGraph::push_edge_via)) a
+    /// polyline through them
     Line,
+    /// Smooth centripetal Catmull-Rom spline from the start pin through the
+    /// edge's waypoints to the end pin, leaving and entering each pin along
+    /// its side like the bezier
+    Catmull,
+}
+
+// ============================================================================
+// Width Space
+// ============================================================================
+
+/// Space a style's stroke widths are measured in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WidthSpace {
+    /// World-space pixels: strokes scale with zoom like the geometry they
+    /// outline (default).
+    #[default]
+    World,
+    /// Screen pixels: strokes keep their on-screen width at every zoom, so
+    /// they stay legible zoomed out and thin zoomed in.
+    Screen,
+}
+
+impl WidthSpace {
+    /// Factor converting a width in this space to world-space pixels at
+    /// `zoom`.
+    pub(crate) fn world_factor(self, zoom: f32) -> f32 {
+        match self {
+            Self::World => 1.0,
+            Self::Screen => 1.0 / zoom,
+        }
+    }
+}
+
+// ============================================================================
+// High Contrast
+// ============================================================================
+
+/// Minimum border and stroke width high-contrast mode enforces, in the
+/// style's own width space.
+pub(crate) const HIGH_CONTRAST_MIN_WIDTH: f32 = 2.0;
+
+/// Black or white, whichever stands out more against `background`.
+pub(crate) fn contrast_ink(background: Color) -> Color {
+    let luminance = 0.2126 * background.r + 0.7152 * background.g + 0.0722 * background.b;
+    if luminance > 0.5 {
+        Color::BLACK
+    } else {
+        Color::WHITE
+    }
+}
+
+/// `quad` with every visible corner made fully opaque. Fully transparent
+/// corners stay transparent: they are "none"/"inherit" sentinels.
+pub(crate) fn opaque(quad: ColorQuad) -> ColorQuad {
+    let opaque = |color: Color| {
+        if color.a > 0.0 {
+            Color { a: 1.0, ..color }
+        } else {
+            color
+        }
+    };
+    ColorQuad {
+        near_start: opaque(quad.near_start),
+        near_end: opaque(quad.near_end),
+        far_start: opaque(quad.far_start),
+        far_end: opaque(quad.far_end),
+    }
+}
+
+/// `pattern` thickened to at least `min`.
+pub(crate) fn thickened(pattern: Pattern, min: f32) -> Pattern {
+    Pattern {
+        thickness: pattern.thickness.max(min),
+        end_thickness: pattern.end_thickness.map(|end| end.max(min)),
+        ..pattern
+    }
 }
 
 // ============================================================================
@@ -100,6 +185,7 @@ pub enum EdgeCurve {
 
 /// The repeating pattern of a [`TilingBackground`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TilingKind {
     /// Rectangular grid lines.
     #[default]
@@ -112,10 +198,25 @@ pub enum TilingKind {
     Hex,
 }
 
+/// Space a [`TilingBackground`] is laid out in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BackgroundSpace {
+    /// World units: the pattern pans and zooms with the camera, like the
+    /// nodes on it (default).
+    #[default]
+    World,
+    /// Screen pixels from the widget's top-left: the pattern stays fixed
+    /// while the camera moves, for decorative backdrops.
+    Screen,
+}
+
 /// A tiling background (grid, dots, ...) drawn over the canvas
-/// [`background_color`](GraphStyle::background_color), panning and zooming with
-/// the camera and repeating infinitely across the viewport.
+/// [`background_color`](GraphStyle::background_color), repeating infinitely
+/// across the viewport. It pans and zooms with the camera unless its
+/// [`space`](Self::space) is [`BackgroundSpace::Screen`].
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TilingBackground {
     /// Which repeating pattern to draw.
     pub kind: TilingKind,
@@ -125,7 +226,13 @@ pub struct TilingBackground {
     /// in world units.
     pub thickness: f32,
     /// Pattern color.
+    #[cfg_attr(feature = "serde", serde(with = "crate::document::color"))]
     pub color: Color,
+    /// Space `spacing` and `thickness` are measured in. With
+    /// [`BackgroundSpace::Screen`] they are screen pixels and the pattern
+    /// ignores the camera.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub space: BackgroundSpace,
 }
 
 impl TilingBackground {
@@ -136,6 +243,7 @@ impl TilingBackground {
             spacing,
             thickness,
             color,
+            space: BackgroundSpace::World,
         }
     }
 
@@ -146,6 +254,7 @@ impl TilingBackground {
             spacing,
             thickness: radius,
             color,
+            space: BackgroundSpace::World,
         }
     }
 
@@ -156,6 +265,7 @@ impl TilingBackground {
             spacing,
             thickness,
             color,
+            space: BackgroundSpace::World,
         }
     }
 
@@ -166,14 +276,34 @@ impl TilingBackground {
             spacing,
             thickness,
             color,
+            space: BackgroundSpace::World,
         }
     }
+
+    /// Sets [`space`](Self::space), pinning the pattern to the world
+    /// (default) or to the screen.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced::Color;
+    /// use iced_nodegraph::{BackgroundSpace, TilingBackground};
+    ///
+    /// let dots = TilingBackground::dots(24.0, 1.0, Color::WHITE).space(BackgroundSpace::Screen);
+    /// assert_eq!(dots.space, BackgroundSpace::Screen);
+    /// ```
+    pub fn space(mut self, space: BackgroundSpace) -> Self {
+        self.space = space;
+        self
+    }
 }
 
 /// Complete graph style configuration.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GraphStyle {
     /// Background color for the canvas.
+    #[cfg_attr(feature = "serde", serde(with = "crate::document::color"))]
     pub background_color: Color,
     /// Optional tiling drawn over `background_color` (grid, dots, ...).
     pub tiling: Option<TilingBackground>,
@@ -256,17 +386,33 @@ impl GraphStyle {
 
 /// Style configuration for node selection and hover highlighting.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SelectionStyle {
     /// Border color for selected nodes
+    #[cfg_attr(feature = "serde", serde(with = "crate::document::color"))]
     pub selected_border_color: Color,
     /// Border width for selected nodes
     pub selected_border_width: f32,
     /// Fill color for the box selection rectangle (semi-transparent)
+    #[cfg_attr(feature = "serde", serde(with = "crate::document::color"))]
     pub box_select_fill: Color,
     /// Border color for the box selection rectangle
+    #[cfg_attr(feature = "serde", serde(with = "crate::document::color"))]
     pub box_select_border: Color,
+    /// Border stroke of the box selection rectangle, sized in screen pixels
+    /// (solid, dashed, dotted, ...)
+    pub box_select_pattern: Pattern,
     /// Color for the edge cutting line
+    #[cfg_attr(feature = "serde", serde(with = "crate::document::color"))]
     pub edge_cutting_color: Color,
+    /// Shadow or glow that replaces a selected node's own shadow, for a lifted
+    /// look. `None` leaves selected nodes with their normal shadow.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub shadow: Option<ShadowStyle>,
+    /// Ring around the keyboard-focused node, kept apart from the selection
+    /// border since focus and selection can differ.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub focus_ring: FocusRingStyle,
 }
 
 impl Default for SelectionStyle {
@@ -276,7 +422,10 @@ impl Default for SelectionStyle {
             selected_border_width: 2.5,
             box_select_fill: Color::from_rgba(0.3, 0.6, 1.0, 0.15),
             box_select_border: Color::from_rgba(0.3, 0.6, 1.0, 0.6),
+            box_select_pattern: Pattern::solid(1.5),
             edge_cutting_color: Color::from_rgb(1.0, 0.3, 0.3),
+            shadow: None,
+            focus_ring: FocusRingStyle::default(),
         }
     }
 }
@@ -306,6 +455,56 @@ impl SelectionStyle {
         self
     }
 
+    /// Sets the box selection border stroke, in screen pixels.
+    pub fn box_select_pattern(mut self, pattern: Pattern) -> Self {
+        self.box_select_pattern = pattern;
+        self
+    }
+
+    /// Gives selected nodes `shadow` in place of their own, drawn in the node
+    /// shadow layer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced::Color;
+    /// use iced_nodegraph::{SelectionStyle, ShadowStyle};
+    ///
+    /// let lifted = SelectionStyle::new().shadow(ShadowStyle::lifted());
+    /// let glowing = SelectionStyle::new().shadow(ShadowStyle::glow(Color::from_rgb(0.3, 0.6, 1.0)));
+    /// assert!(lifted.shadow.is_some() && glowing.shadow.is_some());
+    /// ```
+    pub fn shadow(mut self, shadow: ShadowStyle) -> Self {
+        self.shadow = Some(shadow);
+        self
+    }
+
+    /// Sets the ring drawn around the keyboard-focused node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced::Color;
+    /// use iced_nodegraph::{FocusRingStyle, SelectionStyle};
+    ///
+    /// let style = SelectionStyle::new().focus_ring(FocusRingStyle::new(Color::WHITE).width(3.0));
+    /// assert_eq!(style.focus_ring.width, 3.0);
+    /// ```
+    pub fn focus_ring(mut self, focus_ring: FocusRingStyle) -> Self {
+        self.focus_ring = focus_ring;
+        self
+    }
+
+    /// The box selection appearance this style implies; the default for
+    /// [`NodeGraph::box_select_style`](crate::NodeGraph::box_select_style).
+    pub fn box_select(&self) -> BoxSelectStyle {
+        BoxSelectStyle {
+            fill: self.box_select_fill,
+            border: self.box_select_border,
+            pattern: self.box_select_pattern,
+        }
+    }
+
     /// Creates a selection style derived from an iced Theme.
     pub fn from_theme(theme: &Theme) -> Self {
         let palette = theme.extended_palette();
@@ -317,8 +516,244 @@ impl SelectionStyle {
             // Translucent overlays must stay alpha-based; the hue is the accent.
             box_select_fill: Color { a: 0.15, ..primary },
             box_select_border: Color { a: 0.6, ..primary },
+            box_select_pattern: Pattern::solid(1.5),
             // A cut is destructive: danger, theme-driven instead of hardcoded red.
             edge_cutting_color: palette.danger.base.color,
+            shadow: None,
+            // Focus is not selection: the neutral text ink, not the accent.
+            focus_ring: FocusRingStyle::new(palette.background.base.text),
+        }
+    }
+}
+
+/// A node shadow: the [`NodeStyle`] shadow fields as one value, used by
+/// [`SelectionStyle::shadow`] to restyle selected nodes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ShadowStyle {
+    /// Base shadow color; the widget modulates its alpha across the bands.
+    #[cfg_attr(feature = "serde", serde(with = "crate::document::color"))]
+    pub color: Color,
+    /// Blur half-width across the shape edge, in world-space pixels.
+    pub distance: f32,
+    /// Shadow offset in world-space pixels (x, y).
+    pub offset: (f32, f32),
+}
+
+impl ShadowStyle {
+    /// Shadow of `color` blurred over `distance`, not offset.
+    pub fn new(color: Color, distance: f32) -> Self {
+        Self {
+            color,
+            distance,
+            offset: (0.0, 0.0),
+        }
+    }
+
+    /// Deep, dropped shadow that makes a node look raised off the canvas.
+    pub fn lifted() -> Self {
+        Self::new(Color::from_rgba(0.0, 0.0, 0.0, 0.45), 12.0).offset(4.0, 6.0)
+    }
+
+    /// Centered halo in `color`, e.g. the selection accent.
+    pub fn glow(color: Color) -> Self {
+        Self::new(Color { a: 0.6, ..color }, 10.0)
+    }
+
+    /// Sets the shadow offset in world-space pixels.
+    pub fn offset(mut self, x: f32, y: f32) -> Self {
+        self.offset = (x, y);
+        self
+    }
+}
+
+/// The ring drawn around the node that has keyboard focus, set through
+/// [`SelectionStyle::focus_ring`].
+///
+/// Keyboard navigation (Tab, arrows) moves the focus; it stays on its node
+/// when the selection is cleared, and a mouse press hides it. Sizes are in
+/// screen pixels so the ring stays visible at every zoom. A zero `width`
+/// draws no ring.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FocusRingStyle {
+    /// Ring color.
+    #[cfg_attr(feature = "serde", serde(with = "crate::document::color"))]
+    pub color: Color,
+    /// Ring width in screen pixels.
+    pub width: f32,
+    /// Gap between the node's outline and the ring, in screen pixels.
+    pub offset: f32,
+}
+
+impl FocusRingStyle {
+    /// A 2 px ring of `color`, 3 px outside the node.
+    pub fn new(color: Color) -> Self {
+        Self {
+            color,
+            width: 2.0,
+            offset: 3.0,
+        }
+    }
+
+    /// Sets the ring width in screen pixels.
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the gap between the node's outline and the ring, in screen
+    /// pixels.
+    pub fn offset(mut self, offset: f32) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+impl Default for FocusRingStyle {
+    fn default() -> Self {
+        Self::new(Color::from_rgba(1.0, 1.0, 1.0, 0.9))
+    }
+}
+
+/// Appearance of the box selection rectangle, drawn in screen space over the
+/// graph while a box select is dragged.
+///
+/// Derived from [`SelectionStyle::box_select`] unless overridden through
+/// [`NodeGraph::box_select_style`](crate::NodeGraph::box_select_style).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoxSelectStyle {
+    /// Fill color; its alpha sets the fill opacity.
+    pub fill: Color,
+    /// Border stroke color.
+    pub border: Color,
+    /// Border stroke (thickness, dash/gap), in screen pixels.
+    pub pattern: Pattern,
+}
+
+impl Default for BoxSelectStyle {
+    fn default() -> Self {
+        SelectionStyle::default().box_select()
+    }
+}
+
+/// Node corner a [`Badge`] is anchored to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BadgeCorner {
+    /// Top-left corner
+    TopLeft,
+    /// Top-right corner (default)
+    #[default]
+    TopRight,
+    /// Bottom-left corner
+    BottomLeft,
+    /// Bottom-right corner
+    BottomRight,
+}
+
+/// A small status indicator drawn over one corner of a node, e.g. a
+/// validation error or warning count.
+///
+/// Badges are host state: pass them per frame through
+/// [`NodeGraph::node_badges`](crate::NodeGraph::node_badges). They keep a
+/// constant screen size regardless of zoom, sit inside the node's corner and
+/// are clipped to the node.
+///
+/// # Examples
+///
+/// ```
+/// use iced::Color;
+/// use iced_nodegraph::{Badge, BadgeCorner};
+///
+/// let badge = Badge::new(Color::from_rgb(0.9, 0.2, 0.2))
+///     .corner(BadgeCorner::TopLeft)
+///     .count(3);
+/// assert_eq!(badge.count, Some(3));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Badge {
+    /// Fill color of the badge circle.
+    pub color: Color,
+    /// Node corner the badge sits in.
+    pub corner: BadgeCorner,
+    /// Number drawn inside the badge; a plain dot when `None`.
+    pub count: Option<u32>,
+}
+
+impl Badge {
+    /// Creates a plain dot badge in the top-right corner.
+    pub fn new(color: impl Into<Color>) -> Self {
+        Self {
+            color: color.into(),
+            corner: BadgeCorner::default(),
+            count: None,
         }
     }
+
+    /// Sets the node corner the badge sits in.
+    pub fn corner(mut self, corner: BadgeCorner) -> Self {
+        self.corner = corner;
+        self
+    }
+
+    /// Sets the number drawn inside the badge.
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+}
+
+/// A glowing halo drawn behind a node, e.g. a "currently executing" or
+/// breakpoint marker.
+///
+/// Decorations are host state, separate from the node's own style: pass them
+/// per frame through
+/// [`NodeGraph::node_decorations`](crate::NodeGraph::node_decorations). The
+/// halo follows the node's silhouette (rounded corners, rotation) and sits
+/// behind every node body, above the grid. Sizes are in world-space pixels,
+/// like the node shadow.
+///
+/// # Examples
+///
+/// ```
+/// use iced::Color;
+/// use iced_nodegraph::Decoration;
+///
+/// let running = Decoration::halo(Color::from_rgb(0.2, 0.8, 0.3))
+///     .expand(6.0)
+///     .blur(10.0);
+/// assert_eq!(running.expand, 6.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Decoration {
+    /// Halo color; its alpha sets the strength.
+    pub color: Color,
+    /// Half-width of the soft fade, in world-space pixels.
+    pub blur: f32,
+    /// How far past the node's outline the fade is centred, in world-space
+    /// pixels.
+    pub expand: f32,
+}
+
+impl Decoration {
+    /// A halo of `color`, centred 4px out from the node with an 8px fade.
+    pub fn halo(color: impl Into<Color>) -> Self {
+        Self {
+            color: color.into(),
+            blur: 8.0,
+            expand: 4.0,
+        }
+    }
+
+    /// Sets the half-width of the fade.
+    pub fn blur(mut self, blur: f32) -> Self {
+        self.blur = blur;
+        self
+    }
+
+    /// Sets how far past the node's outline the fade is centred.
+    pub fn expand(mut self, expand: f32) -> Self {
+        self.expand = expand;
+        self
+    }
 }
@@ -14,7 +14,10 @@
 use iced::Color;
 use iced_nodegraph_sdf::Pattern;
 
-use super::ColorQuad;
+use super::{
+    ColorQuad, HIGH_CONTRAST_MIN_WIDTH, NodeStatus, ShadowStyle, WidthSpace, contrast_ink, opaque,
+    thickened,
+};
 
 /// Visual style for a node.
 ///
@@ -39,6 +42,9 @@ pub struct NodeStyle {
     pub border_outline_width: f32,
     /// Outline ring color.
     pub border_outline_color: ColorQuad,
+    /// Space the border pattern and outline widths are measured in.
+    /// [`WidthSpace::World`] (the default) scales them with zoom.
+    pub border_width_space: WidthSpace,
 
     // Shadow. The widget renders the node's real SDF silhouette (with pin
     // cutouts), offset by `shadow_offset`, as a single stop chain: full shadow
@@ -54,6 +60,73 @@ pub struct NodeStyle {
 }
 
 impl NodeStyle {
+    /// Replaces the shadow fields with `shadow`.
+    pub fn with_shadow(self, shadow: ShadowStyle) -> Self {
+        Self {
+            shadow_color: shadow.color,
+            shadow_distance: shadow.distance,
+            shadow_offset: shadow.offset,
+            ..self
+        }
+    }
+
+    /// Sets [`border_width_space`](Self::border_width_space). With
+    /// [`WidthSpace::Screen`] the border keeps its pixel width on screen at
+    /// every zoom.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::{NodeStyle, WidthSpace};
+    ///
+    /// let style = NodeStyle::process().border_width_space(WidthSpace::Screen);
+    /// assert_eq!(style.border_width_space, WidthSpace::Screen);
+    /// ```
+    pub fn border_width_space(mut self, space: WidthSpace) -> Self {
+        self.border_width_space = space;
+        self
+    }
+
+    /// This style with its border widths converted to world-space pixels at
+    /// `zoom`, as the renderer consumes them.
+    pub(crate) fn in_world_space(self, zoom: f32) -> Self {
+        let k = self.border_width_space.world_factor(zoom);
+        if k == 1.0 {
+            return self;
+        }
+        Self {
+            border_pattern: self.border_pattern.scale(k),
+            border_outline_width: self.border_outline_width * k,
+            ..self
+        }
+    }
+
+    /// This style adjusted for high-contrast mode over `background`: opaque
+    /// body, a border of at least [`HIGH_CONTRAST_MIN_WIDTH`] in black or
+    /// white, and for selected nodes a thicker opaque border ringed by that
+    /// ink so selection never hinges on a subtle hue change.
+    pub(crate) fn high_contrast(self, background: Color, status: NodeStatus) -> Self {
+        let ink = ColorQuad::solid(contrast_ink(background));
+        match status {
+            NodeStatus::Idle => Self {
+                fill_color: opaque(self.fill_color),
+                opacity: 1.0,
+                border_color: ink,
+                border_pattern: thickened(self.border_pattern, HIGH_CONTRAST_MIN_WIDTH),
+                ..self
+            },
+            NodeStatus::Selected => Self {
+                fill_color: opaque(self.fill_color),
+                opacity: 1.0,
+                border_color: opaque(self.border_color),
+                border_pattern: thickened(self.border_pattern, HIGH_CONTRAST_MIN_WIDTH * 1.5),
+                border_outline_width: self.border_outline_width.max(HIGH_CONTRAST_MIN_WIDTH),
+                border_outline_color: ink,
+                ..self
+            },
+        }
+    }
+
     /// Input node preset (blue tint).
     pub fn input() -> Self {
         Self::preset(
@@ -110,6 +183,24 @@ impl NodeStyle {
         )
     }
 
+    /// Placeholder node preset (faint fill, dashed gray border, no shadow),
+    /// for template or not-yet-configured nodes.
+    pub fn placeholder() -> Self {
+        Self {
+            border_pattern: Pattern::dashed(1.5, 6.0, 4.0),
+            ..Self::preset(
+                Color::from_rgba(0.20, 0.20, 0.22, 0.25),
+                Color::from_rgb(0.55, 0.55, 0.60),
+                1.5,
+                6.0,
+                0.70,
+                Color::TRANSPARENT,
+                0.0,
+                (0.0, 0.0),
+            )
+        }
+    }
+
     /// Builds a resolved node style from solid fill/border colors plus shadow.
     #[allow(clippy::too_many_arguments)]
     fn preset(
@@ -130,6 +221,7 @@ impl NodeStyle {
             border_pattern: Pattern::solid(border_width),
             border_outline_width: 0.0,
             border_outline_color: ColorQuad::solid(Color::TRANSPARENT),
+            border_width_space: WidthSpace::World,
             shadow_color: shadow,
             shadow_distance,
             shadow_offset,
@@ -157,4 +249,60 @@ mod tests {
         assert_eq!(style.corner_radius, 5.0); // inherited from theme default
         assert_eq!(style.border_pattern, Pattern::solid(1.0)); // inherited
     }
+
+    #[test]
+    fn screen_border_divides_by_zoom() {
+        let style = NodeStyle::output().border_width_space(WidthSpace::Screen);
+        let world = style.clone().in_world_space(0.5);
+        assert_eq!(world.border_pattern, Pattern::solid(4.0)); // 2px on screen
+        assert_eq!(world.corner_radius, style.corner_radius);
+        assert_eq!(NodeStyle::output().in_world_space(0.5), NodeStyle::output());
+    }
+
+    #[test]
+    fn placeholder_has_a_dashed_border_and_no_shadow() {
+        let style = NodeStyle::placeholder();
+        assert!(!style.border_pattern.is_solid());
+        assert_eq!(style.border_pattern.thickness, 1.5);
+        assert!(!style.has_shadow());
+        // Dashes scale with the rest of the border in screen space.
+        let world = style
+            .border_width_space(WidthSpace::Screen)
+            .in_world_space(2.0);
+        assert_eq!(world.border_pattern.thickness, 0.75);
+        assert!(!world.border_pattern.is_solid());
+    }
+
+    #[test]
+    fn with_shadow_replaces_only_the_shadow() {
+        use crate::style::{NodeStatus, default_node_style};
+        let base = default_node_style(&iced::Theme::Dark, NodeStatus::Selected);
+        let lifted = ShadowStyle::lifted();
+        let style = base.clone().with_shadow(lifted);
+
+        assert_eq!(style.shadow_color, lifted.color);
+        assert_eq!(style.shadow_distance, lifted.distance);
+        assert_eq!(style.shadow_offset, (4.0, 6.0));
+        assert_eq!(style.border_color, base.border_color); // selection border kept
+        assert!(style.has_shadow());
+    }
+
+    #[test]
+    fn high_contrast_forces_opaque_body_and_inked_border() {
+        let dark = Color::from_rgb(0.1, 0.1, 0.1);
+        let idle = NodeStyle::comment().high_contrast(dark, NodeStatus::Idle);
+        assert_eq!(idle.fill_color.near_start.a, 1.0);
+        assert_eq!(idle.opacity, 1.0);
+        assert_eq!(idle.border_color, ColorQuad::solid(Color::WHITE));
+        assert_eq!(idle.border_pattern.thickness, HIGH_CONTRAST_MIN_WIDTH);
+
+        let light = Color::from_rgb(0.95, 0.95, 0.95);
+        let selected = NodeStyle::comment().high_contrast(light, NodeStatus::Selected);
+        assert_eq!(
+            selected.border_outline_color,
+            ColorQuad::solid(Color::BLACK)
+        );
+        assert!(selected.border_outline_width >= HIGH_CONTRAST_MIN_WIDTH);
+        assert!(selected.border_pattern.thickness > idle.border_pattern.thickness);
+    }
 }
@@ -5,10 +5,13 @@
 //! Color fields are [`ColorQuad`]s; a plain `Color` coerces to a solid quad.
 //! Border on/off is the `border_width` sentinel (0 = no border).
 //!
-use iced::Color;
+use iced::{Color, Theme};
 
 use super::ColorQuad;
 use super::PinShape;
+use super::WidthSpace;
+use super::{HIGH_CONTRAST_MIN_WIDTH, PinStatus, contrast_ink, opaque};
+use crate::node_pin::PinDirection;
 
 /// Visual style for a pin indicator.
 #[derive(Debug, Clone, PartialEq)]
@@ -16,7 +19,7 @@ pub struct PinStyle {
     // Indicator
     /// Pin indicator color.
     pub color: ColorQuad,
-    /// Indicator radius in world-space pixels.
+    /// Indicator radius in [`radius_space`](Self::radius_space) pixels.
     pub radius: f32,
     /// Indicator shape.
     pub shape: PinShape,
@@ -24,11 +27,84 @@ pub struct PinStyle {
     // Border (width 0 = no border)
     /// Border color.
     pub border_color: ColorQuad,
-    /// Border width in world-space pixels. 0 = no border.
+    /// Border width in [`radius_space`](Self::radius_space) pixels. 0 = no
+    /// border.
     pub border_width: f32,
+    /// Space the radius and border width are measured in.
+    /// [`WidthSpace::World`] (the default) scales them with zoom.
+    pub radius_space: WidthSpace,
 }
 
 impl PinStyle {
+    /// Theme-derived default style for a pin of `direction`: inputs paint in
+    /// the `primary` accent, outputs in `secondary`, so flow direction reads
+    /// without manual coloring. [`PinDirection::Both`] keeps `primary`.
+    ///
+    /// This is what the widget draws when no
+    /// [`Node::pin_style`](crate::Node::pin_style) closure is set; override
+    /// fields with struct update as usual.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced::Theme;
+    /// use iced_nodegraph::{PinDirection, PinStyle};
+    ///
+    /// let input = PinStyle::from_theme(&Theme::Dark, PinDirection::Input);
+    /// let output = PinStyle::from_theme(&Theme::Dark, PinDirection::Output);
+    /// assert_ne!(input.color, output.color);
+    /// ```
+    pub fn from_theme(theme: &Theme, direction: PinDirection) -> Self {
+        let base = super::default_pin_style(theme, PinStatus::Idle);
+        match direction {
+            PinDirection::Output => Self {
+                color: theme.extended_palette().secondary.base.color.into(),
+                ..base
+            },
+            PinDirection::Input | PinDirection::Both => base,
+        }
+    }
+
+    /// Sets [`radius_space`](Self::radius_space). With [`WidthSpace::Screen`]
+    /// the indicator and its border keep their pixel size on screen at every
+    /// zoom, so pins stay easy to grab on a zoomed-out graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph::{PinStyle, WidthSpace};
+    ///
+    /// let style = PinStyle::data().radius_space(WidthSpace::Screen);
+    /// assert_eq!(style.radius_space, WidthSpace::Screen);
+    /// ```
+    pub fn radius_space(mut self, space: WidthSpace) -> Self {
+        self.radius_space = space;
+        self
+    }
+
+    /// This style with its radius and border width converted to world-space
+    /// pixels at `zoom`, as the renderer consumes them.
+    pub(crate) fn in_world_space(self, zoom: f32) -> Self {
+        let k = self.radius_space.world_factor(zoom);
+        Self {
+            radius: self.radius * k,
+            border_width: self.border_width * k,
+            ..self
+        }
+    }
+
+    /// This style adjusted for high-contrast mode over `background`: an
+    /// opaque indicator ringed by a black or white border of at least half
+    /// [`HIGH_CONTRAST_MIN_WIDTH`].
+    pub(crate) fn high_contrast(self, background: Color) -> Self {
+        Self {
+            color: opaque(self.color),
+            border_color: ColorQuad::solid(contrast_ink(background)),
+            border_width: self.border_width.max(HIGH_CONTRAST_MIN_WIDTH / 2.0),
+            ..self
+        }
+    }
+
     /// Data pin preset (circle, blue).
     pub fn data() -> Self {
         Self {
@@ -37,6 +113,7 @@ impl PinStyle {
             shape: PinShape::Circle,
             border_color: ColorQuad::solid(Color::from_rgb(0.5, 0.7, 1.0)),
             border_width: 1.0,
+            radius_space: WidthSpace::World,
         }
     }
 
@@ -48,6 +125,7 @@ impl PinStyle {
             shape: PinShape::Triangle,
             border_color: ColorQuad::solid(Color::TRANSPARENT),
             border_width: 0.0,
+            radius_space: WidthSpace::World,
         }
     }
 
@@ -59,6 +137,7 @@ impl PinStyle {
             shape: PinShape::Diamond,
             border_color: ColorQuad::solid(Color::from_rgb(1.0, 0.95, 0.6)),
             border_width: 1.0,
+            radius_space: WidthSpace::World,
         }
     }
 
@@ -70,6 +149,7 @@ impl PinStyle {
             shape: PinShape::Square,
             border_color: ColorQuad::solid(Color::from_rgb(0.5, 0.9, 0.6)),
             border_width: 1.0,
+            radius_space: WidthSpace::World,
         }
     }
 }
@@ -92,4 +172,29 @@ mod tests {
         assert_eq!(style.shape, PinShape::Square); // override wins
         assert_eq!(style.color, base.color); // inherited from default
     }
+
+    #[test]
+    fn from_theme_colors_pins_by_direction() {
+        let theme = iced::Theme::Light;
+        let palette = theme.extended_palette();
+        let input = PinStyle::from_theme(&theme, PinDirection::Input);
+        let output = PinStyle::from_theme(&theme, PinDirection::Output);
+        let both = PinStyle::from_theme(&theme, PinDirection::Both);
+
+        assert_eq!(input.color, palette.primary.base.color.into());
+        assert_eq!(output.color, palette.secondary.base.color.into());
+        assert_eq!(both, input);
+        assert_eq!(output.shape, input.shape); // only the color differs
+        assert_eq!(output.radius, input.radius);
+    }
+
+    #[test]
+    fn screen_radius_divides_by_zoom() {
+        let style = PinStyle::data().radius_space(WidthSpace::Screen);
+        let world = style.clone().in_world_space(0.5);
+        assert_eq!(world.radius, 12.0); // 6px on screen at half zoom
+        assert_eq!(world.border_width, 2.0);
+        assert_eq!(world.shape, style.shape);
+        assert_eq!(PinStyle::data().in_world_space(0.5), PinStyle::data());
+    }
 }
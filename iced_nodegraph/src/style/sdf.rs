@@ -22,7 +22,7 @@ use iced_nodegraph_sdf::{Pattern, Stop, Style};
 
 use crate::node_pin::PinDirection;
 
-use super::{EdgeStyle, NodeStyle, PinStyle};
+use super::{Decoration, EdgeStyle, NodeStyle, PinStyle};
 
 /// Same color with zero alpha.
 fn transparent(c: Color) -> Color {
@@ -109,6 +109,7 @@ impl NodeStyle {
             stops,
             pattern: None,
             transfer: Default::default(),
+            pulse: None,
         }]
     }
 
@@ -143,10 +144,33 @@ impl NodeStyle {
             stops: vec![Stop::new(-d, full), Stop::new(d, none)],
             pattern: None,
             transfer: Default::default(),
+            pulse: None,
         }]
     }
 }
 
+impl Decoration {
+    /// The halo as one stop chain over the node silhouette: solid inside
+    /// `expand - blur`, fading to transparent at `expand + blur`. `opacity`
+    /// is the node's own (appear animation), so a halo fades in with it.
+    pub(crate) fn sdf_style(&self, opacity: f32) -> Style {
+        let d = self.blur.max(0.001);
+        let full = Color {
+            a: self.color.a * opacity,
+            ..self.color
+        };
+        Style {
+            stops: vec![
+                Stop::new(self.expand - d, full),
+                Stop::new(self.expand + d, transparent(full)),
+            ],
+            pattern: None,
+            transfer: Default::default(),
+            pulse: None,
+        }
+    }
+}
+
 impl EdgeStyle {
     /// Decompose into SDF layers front-to-back: stroke, optional stroke outline,
     /// optional border (ring, outline, background), then shadow deepest.
@@ -158,10 +182,32 @@ impl EdgeStyle {
     pub(crate) fn sdf_layers(&self) -> Vec<EdgeLayer> {
         let mut layers = Vec::with_capacity(6);
 
-        // Stroke (front).
+        // Stroke (front), carrying the travelling band if any. A ribbon is a
+        // pattern-less chain over the signed distance: transparent outside,
+        // rim color at +-half width, the center color on the curve.
+        let mut stroke = match self.ribbon {
+            Some(center) => {
+                let (start, end) = self.stroke_color.arc_pair();
+                let half = self.pattern.thickness * 0.5;
+                Style {
+                    stops: vec![
+                        Stop::grad(-half, transparent(start), transparent(end)),
+                        Stop::grad(-half, start, end),
+                        Stop::new(0.0, center),
+                        Stop::grad(half, start, end),
+                        Stop::grad(half, transparent(start), transparent(end)),
+                    ],
+                    pattern: None,
+                    transfer: Default::default(),
+                    pulse: None,
+                }
+            }
+            None => Style::quad_stroke(&self.stroke_color, self.pattern),
+        };
+        stroke.pulse = self.pulse;
         layers.push(EdgeLayer {
             geometry: EdgeGeometry::Stroke,
-            style: Style::quad_stroke(&self.stroke_color, self.pattern),
+            style: stroke,
         });
 
         // Stroke outline (halo behind the stroke).
@@ -169,8 +215,11 @@ impl EdgeStyle {
             && (self.stroke_outline_color.near_start.a > 0.0
                 || self.stroke_outline_color.near_end.a > 0.0)
         {
-            let outline_pat =
-                Pattern::solid(self.pattern.thickness + self.stroke_outline_width * 2.0);
+            let outline = self.stroke_outline_width * 2.0;
+            let mut outline_pat = Pattern::solid(self.pattern.thickness + outline);
+            if let Some(end) = self.pattern.end_thickness {
+                outline_pat = outline_pat.taper(self.pattern.thickness + outline, end + outline);
+            }
             layers.push(EdgeLayer {
                 geometry: EdgeGeometry::Stroke,
                 style: Style::quad_stroke(&self.stroke_outline_color, outline_pat),
@@ -181,7 +230,7 @@ impl EdgeStyle {
         // contour, thickness `border_width`.
         if self.border_width > 0.0 {
             let border_center =
-                self.pattern.thickness * 0.5 + self.border_gap + self.border_width * 0.5;
+                self.pattern.max_thickness() * 0.5 + self.border_gap + self.border_width * 0.5;
             let border_outer = border_center + self.border_width * 0.5;
 
             layers.push(EdgeLayer {
@@ -250,7 +299,7 @@ impl PinStyle {
 
 #[cfg(test)]
 mod shadow_tests {
-    use super::NodeStyle;
+    use super::{Decoration, NodeStyle};
 
     /// The shadow is one chain (no separate composited bands to seam): a solid
     /// core held below `-shadow_distance`, gradient to transparent at
@@ -277,4 +326,19 @@ mod shadow_tests {
         );
         assert_eq!(stops[1].start.a, 0.0, "transparent at the outer edge");
     }
+
+    /// A halo is the shadow chain pushed out by `expand`, scaled by the node
+    /// opacity.
+    #[test]
+    fn decoration_fade_is_centred_on_the_expanded_outline() {
+        let halo = Decoration::halo(iced::Color::from_rgba(1.0, 0.5, 0.0, 0.8))
+            .expand(6.0)
+            .blur(2.0);
+        let stops = halo.sdf_style(0.5).stops;
+
+        assert_eq!(stops.len(), 2);
+        assert_eq!((stops[0].dist, stops[1].dist), (4.0, 8.0));
+        assert_eq!(stops[0].start.a, 0.4);
+        assert_eq!(stops[1].start.a, 0.0);
+    }
 }
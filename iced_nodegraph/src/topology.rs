@@ -0,0 +1,567 @@
+//! Adjacency queries over a graph's structure.
+//!
+//! A [`Topology`] indexes node ids and the `(from, to)` pins of each edge, so
+//! compilers and evaluators can walk the graph without rebuilding adjacency
+//! lists themselves. [`NodeGraph::topology`](crate::NodeGraph::topology) and
+//! [`on_graph_changed`](crate::NodeGraph::on_graph_changed) hand one out for
+//! the graph as pushed; [`Topology::new`] builds one from the host's own
+//! model. [`graph_to_dot`] writes a graph as Graphviz DOT for external layout
+//! or analysis tools.
+//!
+//! For prototyping from data, [`Topology::from_adjacency`] takes a plain
+//! `(from, to)` node list and [`Topology::layered_positions`] places the nodes
+//! in left-to-right layers, ready to push. [`GraphMetrics`] sums a graph up
+//! (counts, degrees, components, acyclicity) for dashboards and for tests
+//! that check a generated graph's structure.
+//!
+//! ```rust
+//! use iced_nodegraph::PinRef;
+//! use iced_nodegraph::topology::Topology;
+//!
+//! // 0 -> 1 -> 2, and 0 -> 2
+//! let topology: Topology = Topology::from_count(
+//!     3,
+//!     [
+//!         (PinRef::new(0, 0), PinRef::new(1, 0)),
+//!         (PinRef::new(1, 1), PinRef::new(2, 0)),
+//!         (PinRef::new(0, 0), PinRef::new(2, 1)),
+//!     ],
+//! );
+//! assert_eq!(topology.roots().collect::<Vec<_>>(), [&0]);
+//! assert_eq!(topology.leaves().collect::<Vec<_>>(), [&2]);
+//! assert_eq!(topology.out_edges(&0).count(), 2);
+//! assert_eq!(topology.neighbors(&2).collect::<Vec<_>>(), [&1, &0]);
+//! ```
+
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+use std::hash::Hash;
+
+use iced::{Point, Vector};
+
+use crate::PinRef;
+
+/// Node ids and edges with per-node incoming and outgoing edge lists.
+///
+/// Edges are directed `from -> to` as pushed. An edge naming a node that is
+/// not in the topology is kept in [`edges`](Self::edges) but only indexed at
+/// the end that is. Equality compares nodes and edges.
+#[derive(Debug, Clone)]
+pub struct Topology<N = usize, P = usize> {
+    nodes: Vec<N>,
+    edges: Vec<(PinRef<N, P>, PinRef<N, P>)>,
+    index: HashMap<N, usize>,
+    /// Edge indices leaving each node, by node position.
+    outgoing: Vec<Vec<usize>>,
+    /// Edge indices arriving at each node, by node position.
+    incoming: Vec<Vec<usize>>,
+}
+
+impl<N: Clone + Eq + Hash, P> Topology<N, P> {
+    /// Indexes `nodes` and the `(from, to)` pins of `edges`. A repeated node
+    /// id is kept once, at its first position.
+    pub fn new(
+        nodes: impl IntoIterator<Item = N>,
+        edges: impl IntoIterator<Item = (PinRef<N, P>, PinRef<N, P>)>,
+    ) -> Self {
+        let mut index = HashMap::new();
+        let mut ids = Vec::new();
+        for id in nodes {
+            if let Entry::Vacant(slot) = index.entry(id.clone()) {
+                slot.insert(ids.len());
+                ids.push(id);
+            }
+        }
+        let nodes = ids;
+        let edges: Vec<_> = edges.into_iter().collect();
+        let mut outgoing = vec![Vec::new(); nodes.len()];
+        let mut incoming = vec![Vec::new(); nodes.len()];
+        for (edge, (from, to)) in edges.iter().enumerate() {
+            if let Some(&node) = index.get(&from.node_id) {
+                outgoing[node].push(edge);
+            }
+            if let Some(&node) = index.get(&to.node_id) {
+                incoming[node].push(edge);
+            }
+        }
+        Self {
+            nodes,
+            edges,
+            index,
+            outgoing,
+            incoming,
+        }
+    }
+
+    /// Node ids, in the order given.
+    pub fn nodes(&self) -> &[N] {
+        &self.nodes
+    }
+
+    /// Every edge's `(from, to)` pins, in the order given.
+    pub fn edges(&self) -> &[(PinRef<N, P>, PinRef<N, P>)] {
+        &self.edges
+    }
+
+    /// Whether `id` is one of the nodes.
+    pub fn contains(&self, id: &N) -> bool {
+        self.index.contains_key(id)
+    }
+
+    /// Edges whose `from` pin is on node `id`, in edge order.
+    pub fn out_edges<'s>(
+        &'s self,
+        id: &N,
+    ) -> impl Iterator<Item = &'s (PinRef<N, P>, PinRef<N, P>)> + 's {
+        self.edges_at(&self.outgoing, id)
+    }
+
+    /// Edges whose `to` pin is on node `id`, in edge order.
+    pub fn in_edges<'s>(
+        &'s self,
+        id: &N,
+    ) -> impl Iterator<Item = &'s (PinRef<N, P>, PinRef<N, P>)> + 's {
+        self.edges_at(&self.incoming, id)
+    }
+
+    /// Nodes joined to `id` by an edge in either direction, each once:
+    /// targets of its outgoing edges first, then sources of its incoming ones.
+    pub fn neighbors<'s>(&'s self, id: &N) -> impl Iterator<Item = &'s N> + 's {
+        let mut seen = HashSet::new();
+        self.out_edges(id)
+            .map(|(_, to)| &to.node_id)
+            .chain(self.in_edges(id).map(|(from, _)| &from.node_id))
+            .filter(move |neighbor| seen.insert(*neighbor))
+    }
+
+    /// Nodes with no incoming edge (sources), in node order.
+    pub fn roots(&self) -> impl Iterator<Item = &N> + '_ {
+        self.nodes_where(&self.incoming)
+    }
+
+    /// Nodes with no outgoing edge (sinks), in node order.
+    pub fn leaves(&self) -> impl Iterator<Item = &N> + '_ {
+        self.nodes_where(&self.outgoing)
+    }
+
+    fn edges_at<'s>(
+        &'s self,
+        lists: &'s [Vec<usize>],
+        id: &N,
+    ) -> impl Iterator<Item = &'s (PinRef<N, P>, PinRef<N, P>)> + 's {
+        let list = self.index.get(id).map_or(&[][..], |&node| &lists[node]);
+        list.iter().map(|&edge| &self.edges[edge])
+    }
+
+    /// Top-left positions for a left-to-right layered layout, one per node
+    /// in [`nodes`](Self::nodes) order.
+    ///
+    /// Each node sits one layer right of its furthest-upstream predecessor,
+    /// so roots are in layer 0 and every edge points rightwards; a cycle is
+    /// broken at its first node in node order. Layer `l` is at
+    /// `x = l * spacing.x`, and its nodes stack down from `y = 0`
+    /// `spacing.y` apart, in node order. `spacing` should exceed the node
+    /// size to keep nodes apart.
+    pub fn layered_positions(&self, spacing: Vector) -> Vec<Point> {
+        let layers = self.layers();
+        let mut rows = vec![0usize; layers.iter().max().map_or(0, |&l| l + 1)];
+        layers
+            .iter()
+            .map(|&layer| {
+                let row = rows[layer];
+                rows[layer] += 1;
+                Point::new(layer as f32 * spacing.x, row as f32 * spacing.y)
+            })
+            .collect()
+    }
+
+    /// Longest-path layer of each node by position: a topological walk
+    /// (Kahn) that, when only cycles remain, releases the first unplaced node.
+    fn layers(&self) -> Vec<usize> {
+        let count = self.nodes.len();
+        let target = |edge: usize| self.index.get(&self.edges[edge].1.node_id).copied();
+        // In-degree from other known nodes; self-loops and dangling edges
+        // never hold a node back.
+        let mut waiting = vec![0usize; count];
+        for (node, outgoing) in self.outgoing.iter().enumerate() {
+            for to in outgoing.iter().filter_map(|&edge| target(edge)) {
+                if to != node {
+                    waiting[to] += 1;
+                }
+            }
+        }
+        let mut layer = vec![0usize; count];
+        let mut placed = vec![false; count];
+        let mut queue: std::collections::VecDeque<usize> =
+            (0..count).filter(|&node| waiting[node] == 0).collect();
+        let mut next_unplaced = 0;
+        loop {
+            let node = match queue.pop_front() {
+                Some(node) => node,
+                None => {
+                    while next_unplaced < count && placed[next_unplaced] {
+                        next_unplaced += 1;
+                    }
+                    if next_unplaced == count {
+                        break;
+                    }
+                    next_unplaced
+                }
+            };
+            placed[node] = true;
+            for to in self.outgoing[node].iter().filter_map(|&edge| target(edge)) {
+                if placed[to] {
+                    continue;
+                }
+                layer[to] = layer[to].max(layer[node] + 1);
+                waiting[to] -= 1;
+                if waiting[to] == 0 {
+                    queue.push_back(to);
+                }
+            }
+        }
+        layer
+    }
+
+    /// Counts, degrees, components and acyclicity of this graph. See
+    /// [`GraphMetrics`].
+    pub fn metrics(&self) -> GraphMetrics {
+        let count = self.nodes.len();
+        let ends = |edge: &(PinRef<N, P>, PinRef<N, P>)| {
+            Some((
+                *self.index.get(&edge.0.node_id)?,
+                *self.index.get(&edge.1.node_id)?,
+            ))
+        };
+
+        // Weak components by union-find over the edges between known nodes.
+        let mut parent: Vec<usize> = (0..count).collect();
+        // Acyclic when a topological walk (Kahn) places every node.
+        let mut waiting = vec![0usize; count];
+        for (from, to) in self.edges.iter().filter_map(ends) {
+            let (a, b) = (root(&mut parent, from), root(&mut parent, to));
+            parent[a] = b;
+            waiting[to] += 1;
+        }
+        let components = (0..count)
+            .filter(|&node| root(&mut parent, node) == node)
+            .count();
+        let mut queue: Vec<usize> = (0..count).filter(|&node| waiting[node] == 0).collect();
+        let mut placed = 0;
+        while let Some(node) = queue.pop() {
+            placed += 1;
+            for &edge in &self.outgoing[node] {
+                if let Some((_, to)) = ends(&self.edges[edge]) {
+                    waiting[to] -= 1;
+                    if waiting[to] == 0 {
+                        queue.push(to);
+                    }
+                }
+            }
+        }
+
+        GraphMetrics {
+            node_count: count,
+            edge_count: self.edges.len(),
+            max_in_degree: self.incoming.iter().map(Vec::len).max().unwrap_or(0),
+            max_out_degree: self.outgoing.iter().map(Vec::len).max().unwrap_or(0),
+            components,
+            acyclic: placed == count,
+        }
+    }
+
+    /// Nodes whose list in `lists` is empty.
+    fn nodes_where<'s>(&'s self, lists: &'s [Vec<usize>]) -> impl Iterator<Item = &'s N> + 's {
+        self.nodes
+            .iter()
+            .zip(lists)
+            .filter(|(_, list)| list.is_empty())
+            .map(|(id, _)| id)
+    }
+}
+
+impl<P> Topology<usize, P> {
+    /// A topology of nodes `0..node_count`, for graphs keyed by position.
+    pub fn from_count(
+        node_count: usize,
+        edges: impl IntoIterator<Item = (PinRef<usize, P>, PinRef<usize, P>)>,
+    ) -> Self {
+        Self::new(0..node_count, edges)
+    }
+}
+
+impl Topology {
+    /// A topology of nodes `0..node_count` from `(from, to)` node pairs, each
+    /// edge joining pin 0 of `from` to pin 0 of `to`. Pair it with
+    /// [`layered_positions`](Self::layered_positions) to place the nodes.
+    ///
+    /// ```rust
+    /// use iced::Vector;
+    /// use iced_nodegraph::topology::Topology;
+    ///
+    /// let topology = Topology::from_adjacency(3, &[(0, 1), (1, 2), (0, 2)]);
+    /// let positions = topology.layered_positions(Vector::new(250.0, 100.0));
+    /// assert_eq!(positions[2].x, 500.0); // two layers right of node 0
+    /// ```
+    pub fn from_adjacency(node_count: usize, edges: &[(usize, usize)]) -> Self {
+        Self::from_count(
+            node_count,
+            edges
+                .iter()
+                .map(|&(from, to)| (PinRef::new(from, 0), PinRef::new(to, 0))),
+        )
+    }
+}
+
+/// Summary numbers of a graph's structure.
+///
+/// Edges are counted as pushed, parallel edges included; an edge whose end is
+/// not a node adds to the degree of its known end only and joins no
+/// components. A self-loop is a cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GraphMetrics {
+    /// Number of nodes.
+    pub node_count: usize,
+    /// Number of edges.
+    pub edge_count: usize,
+    /// Most edges arriving at one node.
+    pub max_in_degree: usize,
+    /// Most edges leaving one node.
+    pub max_out_degree: usize,
+    /// Weakly connected components (edge direction ignored); an isolated
+    /// node is a component of its own.
+    pub components: usize,
+    /// Whether no directed cycle exists.
+    pub acyclic: bool,
+}
+
+impl GraphMetrics {
+    /// Metrics of nodes `0..node_count` joined by `edges`, as
+    /// [`Topology::from_count`] would index them.
+    ///
+    /// ```rust
+    /// use iced_nodegraph::PinRef;
+    /// use iced_nodegraph::topology::GraphMetrics;
+    ///
+    /// // 0 -> 1 -> 2, and 3 on its own.
+    /// let metrics = GraphMetrics::compute(
+    ///     4,
+    ///     [
+    ///         (PinRef::new(0, 0), PinRef::new(1, 0)),
+    ///         (PinRef::new(1, 0), PinRef::new(2, 0)),
+    ///     ],
+    /// );
+    /// assert_eq!(metrics.components, 2);
+    /// assert!(metrics.acyclic);
+    /// ```
+    pub fn compute<P>(
+        node_count: usize,
+        edges: impl IntoIterator<Item = (PinRef<usize, P>, PinRef<usize, P>)>,
+    ) -> Self {
+        Topology::from_count(node_count, edges).metrics()
+    }
+}
+
+impl<N: PartialEq, P: PartialEq> PartialEq for Topology<N, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.nodes == other.nodes && self.edges == other.edges
+    }
+}
+
+/// Writes a position-keyed graph as a Graphviz DOT `digraph`.
+///
+/// Node `i` becomes `n{i}` labelled `node_labels[i]`; each `(from, to)` edge
+/// becomes `n{from} -> n{to}`. Pin ids are not written: DOT edges join nodes.
+/// Labels are quoted and escaped, so any text is safe. Pass
+/// [`Topology::edges`] to export a [`Topology`] built with
+/// [`Topology::from_count`].
+///
+/// ```rust
+/// use iced_nodegraph::PinRef;
+/// use iced_nodegraph::topology::graph_to_dot;
+///
+/// let labels = ["Input".to_string(), "Output".to_string()];
+/// let dot = graph_to_dot(&labels, &[(PinRef::new(0, 0), PinRef::new(1, 0))]);
+/// assert!(dot.contains("n0 -> n1;"));
+/// ```
+pub fn graph_to_dot<P>(
+    node_labels: &[String],
+    edges: &[(PinRef<usize, P>, PinRef<usize, P>)],
+) -> String {
+    let mut dot = String::from("digraph {\n");
+    for (i, label) in node_labels.iter().enumerate() {
+        let _ = writeln!(dot, "    n{i} [label=\"{}\"];", escape_dot(label));
+    }
+    for (from, to) in edges {
+        let _ = writeln!(dot, "    n{} -> n{};", from.node_id, to.node_id);
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+/// `text` escaped for a DOT double-quoted string.
+fn escape_dot(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Union-find root of `node`, halving the path on the way.
+fn root(parent: &mut [usize], mut node: usize) -> usize {
+    while parent[node] != node {
+        parent[node] = parent[parent[node]];
+        node = parent[node];
+    }
+    node
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wire(from: usize, to: usize) -> (PinRef<usize, usize>, PinRef<usize, usize>) {
+        (PinRef::new(from, 0), PinRef::new(to, 0))
+    }
+
+    #[test]
+    fn edges_are_indexed_at_both_ends() {
+        let t = Topology::from_count(3, [wire(0, 1), wire(1, 2), wire(0, 2)]);
+        assert_eq!(
+            t.out_edges(&0).collect::<Vec<_>>(),
+            [&wire(0, 1), &wire(0, 2)]
+        );
+        assert_eq!(
+            t.in_edges(&2).collect::<Vec<_>>(),
+            [&wire(1, 2), &wire(0, 2)]
+        );
+        assert_eq!(t.in_edges(&0).count(), 0);
+        assert_eq!(t.out_edges(&7).count(), 0); // unknown node
+    }
+
+    #[test]
+    fn neighbors_are_distinct_in_both_directions() {
+        // Parallel edges 0 -> 1 and a back edge 1 -> 0.
+        let t = Topology::from_count(3, [wire(0, 1), wire(0, 1), wire(1, 0), wire(2, 0)]);
+        assert_eq!(t.neighbors(&0).collect::<Vec<_>>(), [&1, &2]);
+        assert_eq!(t.neighbors(&1).collect::<Vec<_>>(), [&0]);
+    }
+
+    #[test]
+    fn roots_and_leaves_include_isolated_nodes() {
+        let t = Topology::from_count(4, [wire(0, 1), wire(1, 2)]);
+        assert_eq!(t.roots().collect::<Vec<_>>(), [&0, &3]);
+        assert_eq!(t.leaves().collect::<Vec<_>>(), [&2, &3]);
+    }
+
+    #[test]
+    fn dangling_edges_index_only_their_known_end() {
+        let t = Topology::new(["a", "b"], [(PinRef::new("a", 0), PinRef::new("gone", 0))]);
+        assert_eq!(t.edges().len(), 1);
+        assert_eq!(t.out_edges(&"a").count(), 1);
+        assert!(!t.contains(&"gone"));
+        assert_eq!(t.leaves().collect::<Vec<_>>(), [&"b"]);
+    }
+
+    #[test]
+    fn repeated_node_ids_are_kept_once() {
+        let t = Topology::new([1, 2, 1], [wire(1, 2)]);
+        assert_eq!(t.nodes(), [1, 2]);
+        assert_eq!(t, Topology::new([1, 2], [wire(1, 2)]));
+    }
+
+    #[test]
+    fn layers_follow_the_longest_path() {
+        // 0 -> 1 -> 3 and 0 -> 3 directly; 2 is isolated.
+        let t = Topology::from_adjacency(4, &[(0, 1), (1, 3), (0, 3)]);
+        assert_eq!(t.layers(), [0, 1, 0, 2]);
+        let at = t.layered_positions(Vector::new(100.0, 50.0));
+        assert_eq!(
+            at,
+            [
+                Point::new(0.0, 0.0),
+                Point::new(100.0, 0.0),
+                Point::new(0.0, 50.0),
+                Point::new(200.0, 0.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn cycles_and_self_loops_still_get_layers() {
+        // 0 -> 1 -> 2 -> 1 (cycle), 3 -> 3 (self-loop).
+        let t = Topology::from_adjacency(4, &[(0, 1), (1, 2), (2, 1), (3, 3)]);
+        assert_eq!(t.layers(), [0, 1, 2, 0]);
+        // A pure cycle is broken at its first node.
+        let ring = Topology::from_adjacency(3, &[(0, 1), (1, 2), (2, 0)]);
+        assert_eq!(ring.layers(), [0, 1, 2]);
+    }
+
+    #[test]
+    fn metrics_of_known_graphs() {
+        // Diamond 0 -> {1, 2} -> 3, plus isolated 4.
+        let diamond = GraphMetrics::compute(5, [wire(0, 1), wire(0, 2), wire(1, 3), wire(2, 3)]);
+        assert_eq!(
+            diamond,
+            GraphMetrics {
+                node_count: 5,
+                edge_count: 4,
+                max_in_degree: 2,
+                max_out_degree: 2,
+                components: 2,
+                acyclic: true,
+            }
+        );
+
+        let ring = GraphMetrics::compute(3, [wire(0, 1), wire(1, 2), wire(2, 0)]);
+        assert_eq!((ring.components, ring.acyclic), (1, false));
+
+        let self_loop = GraphMetrics::compute(2, [wire(0, 1), wire(1, 1)]);
+        assert!(!self_loop.acyclic);
+
+        let empty = GraphMetrics::compute::<usize>(0, []);
+        assert_eq!((empty.components, empty.max_in_degree), (0, 0));
+        assert!(empty.acyclic);
+    }
+
+    #[test]
+    fn metrics_count_dangling_edges_at_their_known_end() {
+        let t = Topology::new(["a", "b"], [(PinRef::new("a", 0), PinRef::new("gone", 0))]);
+        let m = t.metrics();
+        assert_eq!((m.edge_count, m.max_out_degree, m.max_in_degree), (1, 1, 0));
+        assert_eq!(m.components, 2);
+        assert!(m.acyclic);
+    }
+
+    #[test]
+    fn dot_lists_labelled_nodes_then_edges() {
+        let labels = ["Add".to_string(), "Mul".to_string(), "Out".to_string()];
+        let dot = graph_to_dot(&labels, &[wire(0, 2), wire(1, 2)]);
+        assert_eq!(
+            dot.lines().collect::<Vec<_>>(),
+            [
+                "digraph {",
+                "    n0 [label=\"Add\"];",
+                "    n1 [label=\"Mul\"];",
+                "    n2 [label=\"Out\"];",
+                "    n0 -> n2;",
+                "    n1 -> n2;",
+                "}",
+            ]
+        );
+    }
+
+    #[test]
+    fn dot_labels_are_escaped() {
+        let labels = ["say \"hi\"\\\nbye".to_string()];
+        let dot = graph_to_dot::<usize>(&labels, &[]);
+        assert!(dot.contains(r#"n0 [label="say \"hi\"\\\nbye"];"#), "{dot}");
+    }
+}
@@ -15,7 +15,10 @@
 use iced::widget::{container, text};
 use iced::{Element, Length, Point, Theme, Vector};
 use iced::{keyboard, mouse};
-use iced_nodegraph::{NodeGraph, PinRef, edge, node, pin};
+use iced_nodegraph::{
+    ConnectMode, Connection, ConnectionState, DragInfo, DragProgress, EdgeCurve, NodeGraph,
+    NodeGraphEvent, PinDirection, PinRef, ScreenAnchor, edge, node, pin,
+};
 use iced_test::Simulator;
 
 type Renderer = iced::Renderer;
@@ -30,10 +33,16 @@ enum Msg {
     Clone(Vec<usize>),
     Delete(Vec<usize>),
     Connect(Pin, Pin),
+    Connected(Connection),
+    Wiring(ConnectionState),
     Disconnect(Pin, Pin),
     Camera(Point, f32),
     Button,
     Input(String),
+    Event(NodeGraphEvent),
+    Drag(DragInfo, DragProgress),
+    Split((Pin, Pin), usize),
+    Reshape((Pin, Pin), Vec<Point>),
 }
 
 const NODE_W: f32 = 60.0;
@@ -42,6 +51,10 @@ const NODE_H: f32 = 30.0;
 /// Builds a graph with one fixed-size node body per `(id, world-position)`,
 /// every interaction callback wired into `Msg`.
 fn graph_with(nodes: &[(usize, Point)]) -> Element<'static, Msg, Theme, Renderer> {
+    node_graph_with(nodes).into()
+}
+
+fn node_graph_with(nodes: &[(usize, Point)]) -> Graph {
     let mut ng: Graph = NodeGraph::default()
         .width(Length::Fill)
         .height(Length::Fill)
@@ -55,7 +68,7 @@ fn graph_with(nodes: &[(usize, Point)]) -> Element<'static, Msg, Theme, Renderer
             .height(Length::Fixed(NODE_H));
         ng.push_node(node(id, pos, body));
     }
-    ng.into()
+    ng
 }
 
 /// Screen center of a node body whose top-left world position is `p`.
@@ -192,11 +205,39 @@ fn ctrl_a_selects_all() {
         (1, Point::new(400.0, 100.0)),
         (2, Point::new(700.0, 100.0)),
     ]));
-    ui.point_at(Point::new(500.0, 400.0));
+    // A press on empty canvas gives the graph keyboard focus.
+    click(&mut ui, Point::new(500.0, 400.0));
     ui.simulate([key_pressed(keyboard::Key::Character("a".into()), cmd())]);
     assert_eq!(last_selection(&messages(ui)), Some(vec![0, 1, 2]));
 }
 
+#[test]
+fn ctrl_i_inverts_selection() {
+    let mut ui = Simulator::new(graph_with(&[
+        (0, Point::new(100.0, 100.0)),
+        (1, Point::new(400.0, 100.0)),
+        (2, Point::new(700.0, 100.0)),
+    ]));
+    click(&mut ui, center(Point::new(100.0, 100.0)));
+    ui.simulate([key_pressed(keyboard::Key::Character("i".into()), cmd())]);
+    assert_eq!(last_selection(&messages(ui)), Some(vec![1, 2]));
+}
+
+#[test]
+fn standard_shortcuts_opt_out_leaves_keys_to_the_app() {
+    let graph: Element<'static, Msg, Theme, Renderer> =
+        node_graph_with(&[(0, Point::new(100.0, 100.0))])
+            .standard_shortcuts(false)
+            .into();
+    let mut ui = Simulator::new(graph);
+    ui.point_at(Point::new(500.0, 400.0));
+    ui.simulate([
+        key_pressed(keyboard::Key::Character("a".into()), cmd()),
+        key_pressed(keyboard::Key::Character("i".into()), cmd()),
+    ]);
+    assert_eq!(last_selection(&messages(ui)), None);
+}
+
 #[test]
 fn escape_clears_selection() {
     let mut ui = Simulator::new(graph_with(&[(0, Point::new(100.0, 100.0))]));
@@ -208,6 +249,60 @@ fn escape_clears_selection() {
     assert_eq!(last_selection(&messages(ui)), Some(vec![]));
 }
 
+/// Every `on_select` payload in order.
+fn selections(msgs: &[Msg]) -> Vec<Vec<usize>> {
+    msgs.iter()
+        .filter_map(|m| match m {
+            Msg::Select(ids) => Some(sorted(ids.clone())),
+            _ => None,
+        })
+        .collect()
+}
+
+fn named(key: keyboard::key::Named, modifiers: keyboard::Modifiers) -> iced::Event {
+    key_pressed(keyboard::Key::Named(key), modifiers)
+}
+
+// Reading order of NAV_NODES is 1, 0, 2.
+const NAV_NODES: [(usize, Point); 3] = [
+    (0, Point::new(300.0, 100.0)),
+    (1, Point::new(100.0, 100.0)),
+    (2, Point::new(100.0, 300.0)),
+];
+
+#[test]
+fn tab_cycles_selection_in_reading_order_once_focused() {
+    use keyboard::key::Named::Tab;
+    let none = keyboard::Modifiers::default();
+    let mut ui = Simulator::new(graph_with(&NAV_NODES));
+    // Unfocused: Tab belongs to the rest of the app.
+    ui.simulate([named(Tab, none)]);
+    click(&mut ui, center(NAV_NODES[1].1));
+    ui.simulate([named(Tab, none), named(Tab, none), named(Tab, none)]);
+    ui.simulate([named(Tab, keyboard::Modifiers::SHIFT)]);
+
+    assert_eq!(
+        selections(&messages(ui)),
+        vec![vec![1], vec![0], vec![2], vec![1], vec![2]],
+    );
+}
+
+#[test]
+fn arrow_keys_select_the_nearest_node_in_that_direction() {
+    use keyboard::key::Named::{ArrowDown, ArrowLeft, ArrowRight, ArrowUp};
+    let none = keyboard::Modifiers::default();
+    let mut ui = Simulator::new(graph_with(&NAV_NODES));
+    click(&mut ui, center(NAV_NODES[1].1));
+    ui.simulate([named(ArrowRight, none), named(ArrowDown, none)]);
+    // Nothing lies left of node 2: the selection stays.
+    ui.simulate([named(ArrowLeft, none), named(ArrowUp, none)]);
+
+    assert_eq!(
+        selections(&messages(ui)),
+        vec![vec![1], vec![0], vec![2], vec![1]],
+    );
+}
+
 #[test]
 fn box_select_grabs_enclosed_nodes() {
     let mut ui = Simulator::new(graph_with(&[
@@ -255,7 +350,7 @@ fn group_move_emits_move_with_delta_and_all_ids() {
         (1, Point::new(400.0, 100.0)),
     ]));
     // Select both, then drag one of them: the move reports the whole group.
-    ui.point_at(Point::new(500.0, 400.0));
+    click(&mut ui, Point::new(500.0, 400.0));
     ui.simulate([key_pressed(keyboard::Key::Character("a".into()), cmd())]);
     let from = center(Point::new(100.0, 100.0));
     drag(&mut ui, from, from + Vector::new(30.0, -10.0));
@@ -273,6 +368,46 @@ fn group_move_emits_move_with_delta_and_all_ids() {
     );
 }
 
+#[test]
+fn on_drag_reports_start_and_delta() {
+    let start = Point::new(100.0, 100.0);
+    let mut ng: Graph = NodeGraph::default()
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .on_move(Msg::Move)
+        .on_drag(Msg::Drag);
+    ng.push_node(node(
+        0usize,
+        start,
+        container(text("n"))
+            .width(Length::Fixed(NODE_W))
+            .height(Length::Fixed(NODE_H)),
+    ));
+    let mut ui = Simulator::new(ng);
+    drag(
+        &mut ui,
+        center(start),
+        center(start) + Vector::new(25.0, -5.0),
+    );
+
+    let msgs = messages(ui);
+    let (info, progress) = msgs
+        .iter()
+        .rev()
+        .find_map(|m| match m {
+            Msg::Drag(info, progress) => Some((info.clone(), *progress)),
+            _ => None,
+        })
+        .expect("moving during a drag must emit on_drag");
+    assert_eq!(info, DragInfo::Node { node_id: 0 });
+    assert_eq!(progress.start, center(start));
+    assert!(
+        (progress.delta.x - 25.0).abs() < 0.5 && (progress.delta.y + 5.0).abs() < 0.5,
+        "delta should be (25, -5), got {:?}",
+        progress.delta,
+    );
+}
+
 // ---------------------------------------------------------------------------
 // Keyboard commands
 // ---------------------------------------------------------------------------
@@ -333,6 +468,66 @@ fn click_without_motion_does_not_emit_move() {
     );
 }
 
+#[test]
+fn on_event_reports_interactions_without_granular_handlers() {
+    let mut ng: Graph = NodeGraph::default()
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .on_event(Msg::Event);
+    let start = Point::new(100.0, 100.0);
+    ng.push_node(node(
+        0usize,
+        start,
+        container(text("n"))
+            .width(Length::Fixed(NODE_W))
+            .height(Length::Fixed(NODE_H)),
+    ));
+    let mut ui = Simulator::new(ng);
+    drag(
+        &mut ui,
+        center(start),
+        center(start) + Vector::new(40.0, 0.0),
+    );
+
+    let msgs = messages(ui);
+    assert!(
+        msgs.iter().any(|m| matches!(
+            m,
+            Msg::Event(NodeGraphEvent::Move { node_ids, .. }) if node_ids == &vec![0]
+        )),
+        "the unified handler must receive the move: {msgs:?}",
+    );
+}
+
+#[test]
+fn granular_handler_takes_precedence_over_on_event() {
+    // on_event set first, on_select afterwards: selection reports only via
+    // on_select; the other direction is covered by graph_with (granular first).
+    let mut ng: Graph = NodeGraph::default()
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .on_event(Msg::Event)
+        .on_select(Msg::Select);
+    ng.push_node(node(
+        0usize,
+        Point::new(100.0, 100.0),
+        container(text("n"))
+            .width(Length::Fixed(NODE_W))
+            .height(Length::Fixed(NODE_H)),
+    ));
+    let mut ui = Simulator::new(ng);
+    click(&mut ui, center(Point::new(100.0, 100.0)));
+
+    let msgs = messages(ui);
+    assert_eq!(last_selection(&msgs), Some(vec![0]));
+    assert!(
+        !msgs
+            .iter()
+            .any(|m| matches!(m, Msg::Event(NodeGraphEvent::Select { .. }))),
+        "a granular handler must suppress the unified one: {msgs:?}",
+    );
+}
+
 // ---------------------------------------------------------------------------
 // Edge connect / disconnect (pin drag)
 //
@@ -363,6 +558,10 @@ fn pin_body() -> iced::widget::Container<'static, Msg, Theme, Renderer> {
 /// Two single-pin nodes: node 0 has a Right/Output pin, node 1 a Left/Input pin.
 /// `connect_ok` drives `can_connect`; `seed_edge` pre-pushes edge 0:0 -> 1:0.
 fn pin_graph(connect_ok: bool, seed_edge: bool) -> Element<'static, Msg, Theme, Renderer> {
+    pin_node_graph(connect_ok, seed_edge).into()
+}
+
+fn pin_node_graph(connect_ok: bool, seed_edge: bool) -> Graph {
     let mut ng: Graph = NodeGraph::default()
         .width(Length::Fill)
         .height(Length::Fill)
@@ -378,7 +577,7 @@ fn pin_graph(connect_ok: bool, seed_edge: bool) -> Element<'static, Msg, Theme,
     if seed_edge {
         ng.push_edge(edge!(PinRef::new(0, 0), PinRef::new(1, 0)));
     }
-    ng.into()
+    ng
 }
 
 #[test]
@@ -407,6 +606,95 @@ fn drag_input_to_output_reports_output_first() {
     );
 }
 
+#[test]
+fn normalize_connections_off_reports_drag_order() {
+    let ng = pin_node_graph(true, false).normalize_connections(false);
+    let mut ui = Simulator::new(Element::from(ng));
+    drag(&mut ui, in_anchor(), out_anchor());
+
+    let msgs = messages(ui);
+    assert!(
+        msgs.contains(&Msg::Connect(PinRef::new(1, 0), PinRef::new(0, 0))),
+        "without normalization the pressed input pin is reported first: {msgs:?}",
+    );
+}
+
+/// The `on_connection_state_change` reports among `msgs`, in order.
+fn wiring(msgs: &[Msg]) -> Vec<ConnectionState> {
+    msgs.iter()
+        .filter_map(|m| match m {
+            Msg::Wiring(state) => Some(state.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn connection_state_reports_connecting_then_idle() {
+    let ng = pin_node_graph(true, false).on_connection_state_change(Msg::Wiring);
+    let mut ui = Simulator::new(Element::from(ng));
+    drag(&mut ui, out_anchor(), in_anchor());
+
+    assert_eq!(
+        wiring(&messages(ui)),
+        vec![
+            ConnectionState::Connecting {
+                from: PinRef::new(0, 0)
+            },
+            ConnectionState::Idle,
+        ],
+    );
+}
+
+#[test]
+fn connection_state_reports_rerouting_when_unplugging() {
+    // Grabbing the connected input pulls the seeded edge off it, keeping the
+    // output end.
+    let ng = pin_node_graph(true, true).on_connection_state_change(Msg::Wiring);
+    let mut ui = Simulator::new(Element::from(ng));
+    drag(
+        &mut ui,
+        in_anchor(),
+        Point::new(in_anchor().x, in_anchor().y + 80.0),
+    );
+
+    assert_eq!(
+        wiring(&messages(ui)),
+        vec![
+            ConnectionState::Rerouting {
+                from: PinRef::new(0, 0),
+                detached: PinRef::new(1, 0),
+            },
+            ConnectionState::Idle,
+        ],
+    );
+}
+
+#[test]
+fn on_connect_with_reports_directions_output_first() {
+    let ng = pin_node_graph(true, false).on_connect_with(Msg::Connected);
+    let mut ui = Simulator::new(Element::from(ng));
+    drag(&mut ui, in_anchor(), out_anchor());
+
+    let msgs = messages(ui);
+    let expected = Connection {
+        from: PinRef::new(0, 0),
+        from_direction: PinDirection::Output,
+        from_info: (),
+        to: PinRef::new(1, 0),
+        to_direction: PinDirection::Input,
+        to_info: (),
+    };
+    assert!(
+        msgs.contains(&Msg::Connected(expected)),
+        "on_connect_with must report both ends with directions, output-first: {msgs:?}",
+    );
+    assert!(
+        !msgs.iter().any(|m| matches!(m, Msg::Connect(..))),
+        "on_connect_with replaces on_connect: {msgs:?}",
+    );
+}
+
 #[test]
 fn drag_to_empty_space_does_not_connect() {
     let mut ui = Simulator::new(pin_graph(true, false));
@@ -431,6 +719,163 @@ fn can_connect_false_blocks_connection() {
     );
 }
 
+#[test]
+fn click_click_connects_output_to_input() {
+    let graph: Element<'static, Msg, Theme, Renderer> = pin_node_graph(true, false)
+        .connect_mode(ConnectMode::ClickClick)
+        .into();
+    let mut ui = Simulator::new(graph);
+    click(&mut ui, out_anchor());
+    click(&mut ui, in_anchor());
+
+    let msgs = messages(ui);
+    assert!(
+        msgs.contains(&Msg::Connect(PinRef::new(0, 0), PinRef::new(1, 0))),
+        "clicking output then input must connect them: {msgs:?}",
+    );
+    assert!(
+        !msgs.iter().any(|m| matches!(m, Msg::Disconnect(_, _))),
+        "the second click must keep the connection: {msgs:?}",
+    );
+}
+
+#[test]
+fn click_click_on_empty_space_cancels() {
+    let graph: Element<'static, Msg, Theme, Renderer> = pin_node_graph(true, false)
+        .connect_mode(ConnectMode::ClickClick)
+        .into();
+    let mut ui = Simulator::new(graph);
+    click(&mut ui, out_anchor());
+    click(&mut ui, Point::new(600.0, 500.0));
+    // The edge is gone: moving over the input no longer snaps.
+    ui.point_at(in_anchor());
+    ui.simulate([moved(in_anchor())]);
+
+    let msgs = messages(ui);
+    assert!(
+        !msgs.iter().any(|m| matches!(m, Msg::Connect(_, _))),
+        "a click away from any target must cancel the edge: {msgs:?}",
+    );
+}
+
+#[test]
+fn escape_cancels_armed_edge_and_undoes_snap() {
+    // Both: a pin click without motion arms; hovering the target snaps
+    // (live connect), and Escape rolls that back.
+    let graph: Element<'static, Msg, Theme, Renderer> = pin_node_graph(true, false)
+        .connect_mode(ConnectMode::Both)
+        .into();
+    let mut ui = Simulator::new(graph);
+    click(&mut ui, out_anchor());
+    ui.point_at(in_anchor());
+    ui.simulate([moved(in_anchor())]);
+    ui.simulate([key_pressed(
+        keyboard::Key::Named(keyboard::key::Named::Escape),
+        keyboard::Modifiers::default(),
+    )]);
+
+    let msgs = messages(ui);
+    let pair = (PinRef::new(0, 0), PinRef::new(1, 0));
+    assert!(
+        msgs.contains(&Msg::Connect(pair.0, pair.1)),
+        "hovering the target while armed must snap: {msgs:?}",
+    );
+    assert_eq!(
+        msgs.last(),
+        Some(&Msg::Disconnect(pair.0, pair.1)),
+        "Escape must undo the live connection: {msgs:?}",
+    );
+}
+
+const SPLIT_POS: Point = Point::new(100.0, 300.0);
+
+/// `pin_graph` with edge 0:0 -> 1:0 plus a pinless node 2 below it.
+fn split_graph(splitting: bool) -> Element<'static, Msg, Theme, Renderer> {
+    let mut ng = pin_node_graph(true, true)
+        .on_move(Msg::Move)
+        .edge_splitting(splitting)
+        .on_edge_split(Msg::Split);
+    ng.push_node(node(2usize, SPLIT_POS, pin_body()));
+    ng.into()
+}
+
+#[test]
+fn dropping_node_on_edge_reports_split() {
+    let mut ui = Simulator::new(split_graph(true));
+    // Drop node 2 so its body straddles the edge (y = 115, x 160..300).
+    drag(&mut ui, center(SPLIT_POS), center(Point::new(200.0, 100.0)));
+
+    let msgs = messages(ui);
+    assert_eq!(
+        msgs.last(),
+        Some(&Msg::Split((PinRef::new(0, 0), PinRef::new(1, 0)), 2)),
+        "dropping a node across an edge must report the split: {msgs:?}",
+    );
+}
+
+#[test]
+fn edge_split_requires_opt_in() {
+    let mut ui = Simulator::new(split_graph(false));
+    drag(&mut ui, center(SPLIT_POS), center(Point::new(200.0, 100.0)));
+
+    let msgs = messages(ui);
+    assert!(
+        !msgs.iter().any(|m| matches!(m, Msg::Split(..))),
+        "edge splitting is off by default: {msgs:?}",
+    );
+}
+
+/// Components {0, 1, 3} (0 -> 1, 3 -> 1) and {2, 4} (2 -> 4).
+fn two_component_graph() -> Graph {
+    let mut ng: Graph = NodeGraph::default()
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .on_select(Msg::Select);
+    for (id, x, y) in [(0, 100.0, 100.0), (1, 300.0, 100.0), (2, 100.0, 300.0)] {
+        ng.push_node(node(id, Point::new(x, y), pin_body()));
+    }
+    for (id, x, y) in [(3, 100.0, 200.0), (4, 300.0, 300.0)] {
+        ng.push_node(node(id, Point::new(x, y), pin_body()));
+    }
+    ng.push_edge(edge!(PinRef::new(0, 0), PinRef::new(1, 0)));
+    ng.push_edge(edge!(PinRef::new(3, 0), PinRef::new(1, 1)));
+    ng.push_edge(edge!(PinRef::new(2, 0), PinRef::new(4, 0)));
+    ng
+}
+
+#[test]
+fn connected_component_stays_within_its_subgraph() {
+    let ng = two_component_graph();
+    assert_eq!(sorted(ng.connected_component(&3)), vec![0, 1, 3]);
+    assert_eq!(sorted(ng.connected_component(&4)), vec![2, 4]);
+    assert!(ng.connected_component(&9).is_empty());
+}
+
+#[test]
+fn clear_empties_the_graph_for_reuse() {
+    let mut ng = two_component_graph();
+    ng.clear();
+    assert!(ng.connected_component(&0).is_empty());
+    assert_eq!(ng.pin_count(&0), 0);
+    // The freed ids push again, without the old edges.
+    ng.push_node(node(0, Point::new(100.0, 100.0), pin_body()));
+    assert_eq!(ng.connected_component(&0), vec![0]);
+}
+
+#[test]
+fn command_double_click_node_selects_its_component() {
+    let graph: Element<'static, Msg, Theme, Renderer> = two_component_graph().into();
+    let mut ui = Simulator::new(graph);
+    let body = center(Point::new(100.0, 300.0));
+    ui.simulate([iced::Event::Keyboard(keyboard::Event::ModifiersChanged(
+        cmd(),
+    ))]);
+    click(&mut ui, body);
+    click(&mut ui, body);
+
+    assert_eq!(last_selection(&messages(ui)), Some(vec![2, 4]));
+}
+
 #[test]
 fn ctrl_click_on_edge_disconnects() {
     // Ctrl+click on the edge line (Fruit Ninja cut) disconnects it.
@@ -452,11 +897,105 @@ fn ctrl_click_on_edge_disconnects() {
     );
 }
 
+/// Waypoint of the routed edge, well below the straight path between pins.
+const DETOUR: Point = Point::new(230.0, 300.0);
+
+/// Ctrl+clicks `at` on a pin graph whose edge is a Catmull spline through
+/// [`DETOUR`].
+fn ctrl_click_routed_edge(at: Point) -> Vec<Msg> {
+    let mut ng = pin_node_graph(true, false);
+    ng.push_edge_via(
+        edge!(PinRef::new(0, 0), PinRef::new(1, 0)),
+        &[DETOUR],
+        EdgeCurve::Catmull,
+    );
+    let mut ui = Simulator::new(Element::from(ng));
+    ui.point_at(at);
+    ui.simulate([
+        iced::Event::Keyboard(keyboard::Event::ModifiersChanged(cmd())),
+        moved(at),
+    ]);
+    ui.simulate([press(), release()]);
+    messages(ui)
+}
+
+#[test]
+fn ctrl_click_hits_routed_edge_along_its_waypoints() {
+    let cut = Msg::Disconnect(PinRef::new(0, 0), PinRef::new(1, 0));
+
+    let msgs = ctrl_click_routed_edge(DETOUR);
+    assert!(msgs.contains(&cut), "waypoint lies on the edge: {msgs:?}");
+
+    // The straight path between the pins is empty space for a routed edge.
+    let mid = Point::new((out_anchor().x + in_anchor().x) / 2.0, out_anchor().y);
+    let msgs = ctrl_click_routed_edge(mid);
+    assert!(
+        !msgs.contains(&cut),
+        "routed edge left the straight path: {msgs:?}"
+    );
+}
+
+/// Drags from `from` to `to` on a pin graph with editable edges, whose edge
+/// goes through `waypoints` (a plain edge when empty).
+fn drag_edge_handle(waypoints: &[Point], editable: bool, from: Point, to: Point) -> Vec<Msg> {
+    let mut ng = pin_node_graph(true, waypoints.is_empty())
+        .editable_edges(editable)
+        .on_edge_reshape(Msg::Reshape);
+    if !waypoints.is_empty() {
+        ng.push_edge_via(
+            edge!(PinRef::new(0, 0), PinRef::new(1, 0)),
+            waypoints,
+            EdgeCurve::Catmull,
+        );
+    }
+    let mut ui = Simulator::new(Element::from(ng));
+    drag(&mut ui, from, to);
+    messages(ui)
+}
+
+fn last_reshape(msgs: &[Msg]) -> Option<&Vec<Point>> {
+    msgs.iter().rev().find_map(|m| match m {
+        Msg::Reshape((from, to), waypoints)
+            if *from == PinRef::new(0, 0) && *to == PinRef::new(1, 0) =>
+        {
+            Some(waypoints)
+        }
+        _ => None,
+    })
+}
+
+#[test]
+fn dragging_insert_handle_adds_a_waypoint() {
+    // The bezier between facing pins peaks at the chord midpoint.
+    let mid = Point::new((out_anchor().x + in_anchor().x) / 2.0, out_anchor().y);
+    let to = Point::new(mid.x, 250.0);
+    let msgs = drag_edge_handle(&[], true, mid, to);
+    assert_eq!(last_reshape(&msgs), Some(&vec![to]), "{msgs:?}");
+}
+
+#[test]
+fn dragging_waypoint_handle_moves_it() {
+    let to = Point::new(260.0, 40.0);
+    let msgs = drag_edge_handle(&[DETOUR], true, DETOUR, to);
+    assert_eq!(last_reshape(&msgs), Some(&vec![to]), "{msgs:?}");
+}
+
+#[test]
+fn edge_handles_require_opt_in() {
+    let to = Point::new(260.0, 40.0);
+    let msgs = drag_edge_handle(&[DETOUR], false, DETOUR, to);
+    assert_eq!(last_reshape(&msgs), None, "{msgs:?}");
+}
+
 // ---------------------------------------------------------------------------
 // Camera: right-drag pan and wheel zoom
 // ---------------------------------------------------------------------------
 
 fn camera_graph() -> Element<'static, Msg, Theme, Renderer> {
+    camera_node_graph().into()
+}
+
+fn camera_node_graph() -> Graph {
     let mut ng: Graph = NodeGraph::default()
         .width(Length::Fill)
         .height(Length::Fill)
@@ -468,7 +1007,7 @@ fn camera_graph() -> Element<'static, Msg, Theme, Renderer> {
             .width(Length::Fixed(NODE_W))
             .height(Length::Fixed(NODE_H)),
     ));
-    ng.into()
+    ng
 }
 
 fn last_camera(msgs: &[Msg]) -> Option<(Point, f32)> {
@@ -529,6 +1068,101 @@ fn wheel_scroll_zooms_camera() {
     );
 }
 
+fn redraw() -> iced::Event {
+    iced::Event::Window(iced::window::Event::RedrawRequested(
+        std::time::Instant::now(),
+    ))
+}
+
+#[test]
+fn elastic_zoom_overshoot_settles_on_limit() {
+    let graph: Element<'static, Msg, Theme, Renderer> =
+        camera_node_graph().zoom_elastic(true).into();
+    let mut ui = Simulator::new(graph);
+    let at = Point::new(400.0, 400.0);
+    ui.point_at(at);
+    ui.simulate([
+        moved(at),
+        iced::Event::Mouse(mouse::Event::WheelScrolled {
+            delta: mouse::ScrollDelta::Lines { x: 0.0, y: 300.0 },
+        }),
+    ]);
+    // The bounce advances on real frame time; give it a few spaced frames.
+    for _ in 0..12 {
+        std::thread::sleep(std::time::Duration::from_millis(40));
+        ui.simulate([redraw()]);
+    }
+
+    let msgs = messages(ui);
+    assert!(
+        msgs.iter()
+            .all(|m| !matches!(m, Msg::Camera(_, zoom) if *zoom > 10.0)),
+        "the overshoot must never be committed: {msgs:?}",
+    );
+    let (_pos, zoom) = last_camera(&msgs).expect("the settled bounce must commit the camera");
+    assert_eq!(zoom, 10.0, "the bounce must land on the zoom limit");
+}
+
+/// Drags node 0 of `graph` from its center to `to` and holds it there for a
+/// few spaced frames before dropping.
+fn hold_node_drag_at(graph: Graph, to: Point) -> Vec<Msg> {
+    let graph: Element<'static, Msg, Theme, Renderer> = graph.on_move(Msg::Move).into();
+    let mut ui = Simulator::new(graph);
+    let from = center(Point::new(100.0, 100.0));
+    ui.point_at(from);
+    ui.simulate([moved(from), press()]);
+    ui.point_at(to);
+    ui.simulate([moved(to)]);
+    for _ in 0..6 {
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        ui.simulate([redraw()]);
+    }
+    ui.simulate([release()]);
+    messages(ui)
+}
+
+fn last_move(msgs: &[Msg]) -> Option<Vector> {
+    msgs.iter().rev().find_map(|m| match m {
+        Msg::Move(delta, _) => Some(*delta),
+        _ => None,
+    })
+}
+
+#[test]
+fn edge_autopan_scrolls_while_a_node_is_held_at_the_edge() {
+    let to = Point::new(1020.0, 115.0);
+    let msgs = hold_node_drag_at(camera_node_graph().edge_autopan(true), to);
+
+    let (pos, zoom) = last_camera(&msgs).expect("holding at the edge must pan the camera");
+    assert_eq!(zoom, 1.0);
+    assert!(pos.x < 0.0, "the view must scroll right, got {pos:?}");
+    assert_eq!(pos.y, 0.0, "no pan away from the top/bottom edges");
+    // The node stayed under the cursor, so it travelled the pan on top.
+    let delta = last_move(&msgs).expect("the drop must report the move");
+    let travel = to.x - center(Point::new(100.0, 100.0)).x;
+    assert!(
+        (delta.x - (travel - pos.x)).abs() < 0.01,
+        "move {delta:?} must be the cursor travel plus the pan {pos:?}",
+    );
+}
+
+#[test]
+fn edge_autopan_is_off_by_default_and_idle_away_from_edges() {
+    let to = Point::new(1020.0, 115.0);
+    let msgs = hold_node_drag_at(camera_node_graph(), to);
+    assert_eq!(last_camera(&msgs), None, "autopan is opt-in: {msgs:?}");
+
+    let msgs = hold_node_drag_at(
+        camera_node_graph().edge_autopan(true),
+        Point::new(500.0, 400.0),
+    );
+    assert_eq!(
+        last_camera(&msgs),
+        None,
+        "no pan away from the edges: {msgs:?}"
+    );
+}
+
 // ---------------------------------------------------------------------------
 // Magnetic-plug grab: hysteresis + re-wiring
 //
@@ -1071,6 +1705,72 @@ fn ctrl_click_on_edge_disconnects_under_zoom_and_pan() {
     );
 }
 
+/// Margin of the screen-fixed node in `fixed_pin_graph` from the widget's
+/// top-left corner.
+const FIXED_MARGIN: f32 = 10.0;
+
+/// `zoomed_pin_graph` with the output node pinned to the widget's top-left
+/// instead of the canvas: its pushed world position is deliberately bogus.
+fn fixed_pin_graph() -> Element<'static, Msg, Theme, Renderer> {
+    let mut ng: Graph = NodeGraph::default()
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .view(CAM_POS, CAM_ZOOM)
+        .on_connect(Msg::Connect)
+        .on_move(Msg::Move)
+        .on_select(Msg::Select);
+    ng.push_node_screen_fixed(
+        ScreenAnchor::TopLeft(Vector::new(FIXED_MARGIN, FIXED_MARGIN)),
+        node(
+            0usize,
+            Point::new(-5000.0, -5000.0),
+            pin!(Right, 0usize, pin_body(), Output),
+        ),
+    );
+    ng.push_node(node(1usize, IN_POS, pin!(Left, 0usize, pin_body(), Input)));
+    ng.into()
+}
+
+/// Screen pixel of the fixed node's output pin: right edge, vertical middle,
+/// with the body scaled by the camera zoom.
+fn fixed_out_anchor() -> Point {
+    Point::new(
+        FIXED_MARGIN + NODE_W * CAM_ZOOM,
+        FIXED_MARGIN + NODE_H * CAM_ZOOM / 2.0,
+    )
+}
+
+#[test]
+fn screen_fixed_node_connects_from_its_screen_position() {
+    // The fixed node ignores both its world position and the camera pan: its
+    // pin is found at the anchored screen pixel, and connects to a canvas node.
+    let mut ui = Simulator::new(fixed_pin_graph());
+    drag(&mut ui, fixed_out_anchor(), world_to_screen(in_anchor()));
+
+    let msgs = messages(ui);
+    assert!(
+        msgs.contains(&Msg::Connect(PinRef::new(0, 0), PinRef::new(1, 0))),
+        "dragging from the fixed node's screen pin must connect: {msgs:?}",
+    );
+}
+
+#[test]
+fn screen_fixed_node_selects_but_does_not_drag() {
+    let mut ui = Simulator::new(fixed_pin_graph());
+    let body = Point::new(
+        FIXED_MARGIN + NODE_W * CAM_ZOOM / 4.0,
+        FIXED_MARGIN + NODE_H * CAM_ZOOM / 2.0,
+    );
+    drag(&mut ui, body, Point::new(body.x + 200.0, body.y + 150.0));
+
+    let msgs = messages(ui);
+    assert_eq!(last_selection(&msgs), Some(vec![0]), "{msgs:?}");
+    assert!(
+        !msgs.iter().any(|m| matches!(m, Msg::Move(..))),
+        "a screen-fixed node must not report moves: {msgs:?}",
+    );
+}
+
 // ---------------------------------------------------------------------------
 // Shift-click toggles selection off.
 // ---------------------------------------------------------------------------
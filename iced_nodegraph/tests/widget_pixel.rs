@@ -25,7 +25,7 @@ use iced::{Color, Element, Length, Point, Rectangle, Size, Theme};
 use iced_wgpu::core::clipboard;
 
 use common::shared;
-use iced_nodegraph::{ColorQuad, NodeGraph, NodeStyle, default_node_style, node};
+use iced_nodegraph::{ColorQuad, NodeGraph, NodeShape, NodeStyle, default_node_style, node};
 use iced_wgpu::Renderer;
 use iced_wgpu::graphics::Viewport;
 
@@ -484,3 +484,292 @@ fn full_widget_render_is_deterministic() {
     let differ = a.iter().zip(b.iter()).filter(|(x, y)| x != y).count();
     assert_eq!(differ, 0, "full-widget render flickered on {differ} pixels");
 }
+
+/// Render one red-filled 80x40 node mid-viewport, faded by `opacity` through
+/// `node_opacities` when given, and return the pixel at the body centre.
+/// Returns `None` if no GPU is available.
+fn red_node_center(opacity: Option<f32>) -> Option<[u8; 4]> {
+    let bytes = red_node(opacity, NodeShape::default())?;
+    Some(pixel(&bytes, W / 2, H / 2))
+}
+
+/// RGBA of the pixel at (`x`, `y`) in a `W`x`H` screenshot.
+fn pixel(bytes: &[u8], x: u32, y: u32) -> [u8; 4] {
+    let at = (y * W + x) as usize * 4;
+    [bytes[at], bytes[at + 1], bytes[at + 2], bytes[at + 3]]
+}
+
+/// Render the red 80x40 node of [`red_node_center`] drawn as `shape` to RGBA
+/// bytes. Returns `None` if no GPU is available.
+fn red_node(opacity: Option<f32>, shape: NodeShape) -> Option<Vec<u8>> {
+    use iced::widget::container;
+
+    let mut guard = shared()?;
+    let renderer = &mut *guard;
+
+    let mut graph: NodeGraph<'static, usize, usize, (), (), Theme, Renderer> = NodeGraph::default()
+        .width(Length::Fixed(W as f32))
+        .height(Length::Fixed(H as f32))
+        .view(
+            Point::new(W as f32 * 0.5 - 40.0, H as f32 * 0.5 - 20.0),
+            1.0,
+        );
+    graph.push_node(
+        node(
+            0_usize,
+            Point::new(0.0, 0.0),
+            Element::from(
+                container(text(""))
+                    .width(Length::Fixed(80.0))
+                    .height(Length::Fixed(40.0)),
+            ),
+        )
+        .style(|theme, status| NodeStyle {
+            fill_color: ColorQuad::solid(Color::from_rgb(1.0, 0.0, 0.0)),
+            ..default_node_style(theme, status)
+        })
+        .shape(shape),
+    );
+    if let Some(opacity) = opacity {
+        graph = graph.node_opacities(&std::collections::HashMap::from([(0_usize, opacity)]));
+    }
+
+    let mut tree = Tree::new(&graph as &dyn Widget<(), Theme, Renderer>);
+    let layout_node = graph.layout(
+        &mut tree,
+        &*renderer,
+        &layout::Limits::new(Size::ZERO, Size::new(W as f32, H as f32)),
+    );
+    let layout = Layout::new(&layout_node);
+    let viewport_rect = Rectangle::new(Point::ORIGIN, Size::new(W as f32, H as f32));
+    let mut msgs: Vec<()> = Vec::new();
+    let mut shell = iced_wgpu::core::Shell::new(&mut msgs);
+    graph.update(
+        &mut tree,
+        &iced::Event::Mouse(mouse::Event::CursorMoved {
+            position: Point::new(-1.0, -1.0),
+        }),
+        layout,
+        mouse::Cursor::Unavailable,
+        &*renderer,
+        &mut clipboard::Null,
+        &mut shell,
+        &viewport_rect,
+    );
+    graph.draw(
+        &tree,
+        renderer,
+        &Theme::Dark,
+        &renderer::Style {
+            text_color: Color::WHITE,
+        },
+        layout,
+        mouse::Cursor::Unavailable,
+        &viewport_rect,
+    );
+
+    Some(renderer.screenshot(
+        &Viewport::with_physical_size(Size::new(W, H), 1.0),
+        Color::TRANSPARENT,
+    ))
+}
+
+/// `node_opacities` multiplies the node's style opacity: as styled without
+/// an entry, gone at 0, and in between at 0.5.
+#[test]
+fn node_opacity_fades_the_body() {
+    let Some(full) = red_node_center(None) else {
+        eprintln!("no GPU adapter - skipping node_opacity_fades_the_body");
+        return;
+    };
+    let half = red_node_center(Some(0.5)).expect("GPU was available a moment ago");
+    let none = red_node_center(Some(0.0)).expect("GPU was available a moment ago");
+
+    assert!(full[0] > full[1] + 150, "red body, got {full:?}");
+    assert!(
+        none[0] < 100,
+        "fully faded body shows the canvas, got {none:?}"
+    );
+    assert!(
+        half[0] > none[0] + 40 && half[0] < full[0] - 40,
+        "half-faded body sits between canvas {none:?} and fill {full:?}, got {half:?}",
+    );
+}
+
+/// A round or hexagonal node fills its middle but leaves the corners of its
+/// box to the canvas, which a rounded rectangle covers.
+#[test]
+fn node_shape_outlines_the_body() {
+    let Some(rounded) = red_node(None, NodeShape::RoundedRect) else {
+        eprintln!("no GPU adapter - skipping node_shape_outlines_the_body");
+        return;
+    };
+    // 6px in from the top-left corner of the 80x40 body.
+    let (corner_x, corner_y) = (W / 2 - 34, H / 2 - 14);
+    let covered = pixel(&rounded, corner_x, corner_y);
+    assert!(
+        covered[0] > covered[1] + 150,
+        "the rounded body covers it: {covered:?}"
+    );
+    for shape in [NodeShape::Circle, NodeShape::Hexagon] {
+        let bytes = red_node(None, shape).expect("GPU was available a moment ago");
+        let center = pixel(&bytes, W / 2, H / 2);
+        let corner = pixel(&bytes, corner_x, corner_y);
+        assert!(
+            center[0] > center[1] + 150,
+            "{shape:?} fills its middle: {center:?}"
+        );
+        assert!(corner[0] < 100, "{shape:?} leaves the corner: {corner:?}");
+    }
+}
+
+/// Render an empty graph with a white grid tiling in `space`, the camera at
+/// `camera` and `zoom`. Returns `None` if no GPU is available.
+fn render_tiling(
+    space: iced_nodegraph::BackgroundSpace,
+    camera: Point,
+    zoom: f32,
+) -> Option<Vec<[u8; 4]>> {
+    use iced_nodegraph::{GraphStyle, TilingBackground};
+
+    let mut guard = shared()?;
+    let renderer = &mut *guard;
+
+    let mut graph: NodeGraph<'static, usize, usize, (), (), Theme, Renderer> = NodeGraph::default()
+        .width(Length::Fixed(W as f32))
+        .height(Length::Fixed(H as f32))
+        .view(camera, zoom)
+        .graph_style(move |_| {
+            GraphStyle::dark().tiling(TilingBackground::grid(40.0, 2.0, Color::WHITE).space(space))
+        });
+
+    let mut tree = Tree::new(&graph as &dyn Widget<(), Theme, Renderer>);
+    let layout_node = graph.layout(
+        &mut tree,
+        &*renderer,
+        &layout::Limits::new(Size::ZERO, Size::new(W as f32, H as f32)),
+    );
+    let layout = Layout::new(&layout_node);
+    let viewport_rect = Rectangle::new(Point::ORIGIN, Size::new(W as f32, H as f32));
+    let mut msgs: Vec<()> = Vec::new();
+    let mut shell = iced_wgpu::core::Shell::new(&mut msgs);
+    graph.update(
+        &mut tree,
+        &iced::Event::Mouse(mouse::Event::CursorMoved {
+            position: Point::new(-1.0, -1.0),
+        }),
+        layout,
+        mouse::Cursor::Unavailable,
+        &*renderer,
+        &mut clipboard::Null,
+        &mut shell,
+        &viewport_rect,
+    );
+    graph.draw(
+        &tree,
+        renderer,
+        &Theme::Dark,
+        &renderer::Style {
+            text_color: Color::WHITE,
+        },
+        layout,
+        mouse::Cursor::Unavailable,
+        &viewport_rect,
+    );
+
+    let bytes = renderer.screenshot(
+        &Viewport::with_physical_size(Size::new(W, H), 1.0),
+        Color::TRANSPARENT,
+    );
+    Some(
+        bytes
+            .chunks_exact(4)
+            .map(|c| [c[0], c[1], c[2], c[3]])
+            .collect(),
+    )
+}
+
+/// A world-space tiling moves with the camera; a screen-space one stays put
+/// whatever the camera's position and zoom.
+#[test]
+fn screen_space_tiling_ignores_the_camera() {
+    use iced_nodegraph::BackgroundSpace;
+
+    let Some(world_a) = render_tiling(BackgroundSpace::World, Point::ORIGIN, 1.0) else {
+        eprintln!("no GPU adapter - skipping screen_space_tiling_ignores_the_camera");
+        return;
+    };
+    let moved = Point::new(13.0, 7.0);
+    let world_b = render_tiling(BackgroundSpace::World, moved, 1.5).expect("GPU was available");
+    let screen_a = render_tiling(BackgroundSpace::Screen, Point::ORIGIN, 1.0).expect("GPU");
+    let screen_b = render_tiling(BackgroundSpace::Screen, moved, 1.5).expect("GPU");
+
+    let differ = |a: &[[u8; 4]], b: &[[u8; 4]]| a.iter().zip(b).filter(|(x, y)| x != y).count();
+    assert!(
+        differ(&world_a, &world_b) > 100,
+        "a world-space grid follows the camera"
+    );
+    assert_eq!(
+        differ(&screen_a, &screen_b),
+        0,
+        "a screen-space grid stays fixed"
+    );
+    // At the identity camera both spaces agree.
+    assert_eq!(differ(&world_a, &screen_a), 0);
+}
+
+/// Render an empty graph under `theme` and return a canvas pixel between
+/// the grid lines. Returns `None` if no GPU is available.
+fn canvas_pixel(theme: &Theme, follows_theme: bool) -> Option<[u8; 4]> {
+    let mut guard = shared()?;
+    let renderer = &mut *guard;
+
+    let mut graph: NodeGraph<'static, usize, usize, (), (), Theme, Renderer> = NodeGraph::default()
+        .width(Length::Fixed(W as f32))
+        .height(Length::Fixed(H as f32))
+        .view(Point::ORIGIN, 1.0)
+        .background_follows_theme(follows_theme);
+
+    let mut tree = Tree::new(&graph as &dyn Widget<(), Theme, Renderer>);
+    let layout_node = graph.layout(
+        &mut tree,
+        &*renderer,
+        &layout::Limits::new(Size::ZERO, Size::new(W as f32, H as f32)),
+    );
+    let layout = Layout::new(&layout_node);
+    let viewport_rect = Rectangle::new(Point::ORIGIN, Size::new(W as f32, H as f32));
+    graph.draw(
+        &tree,
+        renderer,
+        theme,
+        &renderer::Style {
+            text_color: Color::WHITE,
+        },
+        layout,
+        mouse::Cursor::Unavailable,
+        &viewport_rect,
+    );
+
+    let bytes = renderer.screenshot(
+        &Viewport::with_physical_size(Size::new(W, H), 1.0),
+        Color::TRANSPARENT,
+    );
+    let at = (20 * W + 20) as usize * 4;
+    Some([bytes[at], bytes[at + 1], bytes[at + 2], bytes[at + 3]])
+}
+
+/// The canvas follows a light theme by default; opted out, it keeps the
+/// fixed dark color.
+#[test]
+fn background_follows_the_theme_unless_turned_off() {
+    let Some(light) = canvas_pixel(&Theme::Light, true) else {
+        eprintln!("no GPU adapter - skipping background_follows_the_theme_unless_turned_off");
+        return;
+    };
+    let dark = canvas_pixel(&Theme::Dark, true).expect("GPU was available a moment ago");
+    let fixed = canvas_pixel(&Theme::Light, false).expect("GPU was available a moment ago");
+
+    assert!(light[0] > 200, "light theme, light canvas: {light:?}");
+    assert!(dark[0] < 80, "dark theme, dark canvas: {dark:?}");
+    assert!(fixed[0] < 80, "opted out, the canvas stays dark: {fixed:?}");
+}
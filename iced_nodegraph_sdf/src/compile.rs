@@ -128,6 +128,10 @@ fn compile_style(style: &Style) -> GpuStyle {
         }
         None => (0, 0.0, 0.0, 0.0, 0.0, 0.0),
     };
+    let pattern_thickness_end = style
+        .pattern
+        .and_then(|p| p.end_thickness)
+        .unwrap_or(pattern_thickness);
 
     debug_assert!(
         style.stops.len() <= MAX_STOPS,
@@ -149,6 +153,11 @@ fn compile_style(style: &Style) -> GpuStyle {
         Transfer::Gamma(g) => (2, g),
     };
 
+    let (pulse_speed, pulse_width, pulse_color) = match style.pulse {
+        Some(pulse) => (pulse.speed, pulse.width.max(0.0), c2v(pulse.color)),
+        None => (0.0, 0.0, GpuVec4::ZERO),
+    };
+
     GpuStyle {
         stop_start,
         stop_end,
@@ -163,8 +172,13 @@ fn compile_style(style: &Style) -> GpuStyle {
         flow_speed,
         transfer_type,
         transfer_param,
-        _transfer_pad0: 0,
-        _transfer_pad1: 0,
+        pulse_speed,
+        pulse_width,
+        pulse_color,
+        pattern_thickness_end,
+        _taper_pad0: 0,
+        _taper_pad1: 0,
+        _taper_pad2: 0,
     }
 }
 
@@ -2,6 +2,7 @@
 //!
 //! `Curve` provides static methods for all geometry:
 //! - Single segments: `Curve::line()`, `Curve::bezier()`
+//! - Open paths through points: `Curve::polyline()`, `Curve::catmull_rom()`
 //! - Connected contours: `Curve::shape()` returns a [`ShapeBuilder`]
 //! - Factory shapes: `Curve::rect()`, `Curve::rounded_rect()`, `Curve::circle()`
 //!
@@ -38,6 +39,125 @@ impl Curve {
         Drawable::single_line(Vec2::from(a.into()), Vec2::from(b.into()))
     }
 
+    /// Open polyline through `points` (straight segments, sharp corners).
+    /// Fewer than two points yield an empty drawable.
+    pub fn polyline(points: &[[f32; 2]]) -> Drawable {
+        let Some((first, rest)) = points.split_first() else {
+            return Curve::shape([0.0, 0.0], 0.0).end();
+        };
+        rest.iter()
+            .fold(Curve::shape(*first, 0.0), |b, p| b.line_to(*p))
+            .end()
+    }
+
+    /// Open centripetal Catmull-Rom spline passing through every point.
+    ///
+    /// Each span between neighbouring points becomes one cubic bezier (see
+    /// [`catmull_rom_beziers`](Self::catmull_rom_beziers)), arc-splined like
+    /// [`bezier`](Self::bezier). Fewer than two distinct points yield an empty
+    /// drawable.
+    pub fn catmull_rom(points: &[[f32; 2]]) -> Drawable {
+        let spans = Self::catmull_rom_beziers(points);
+        let Some(first) = spans.first() else {
+            return Curve::shape([0.0, 0.0], 0.0).end();
+        };
+        spans
+            .iter()
+            .fold(Curve::shape(first[0], 0.0), |b, [_, c1, c2, end]| {
+                b.bezier_to(*c1, *c2, *end)
+            })
+            .end()
+    }
+
+    /// Open path through a chain of cubic bezier spans `[p0, c1, c2, p3]`, each
+    /// continuing from the end of the previous one (only the first span's
+    /// `p0` is used). A span whose controls sit on its endpoints is drawn as a
+    /// straight segment, so lines and curves mix in one stroke. No spans yield
+    /// an empty drawable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph_sdf::Curve;
+    ///
+    /// // A straight lead-out into a curve.
+    /// let path = Curve::spans(&[
+    ///     [[0.0, 0.0], [0.0, 0.0], [20.0, 0.0], [20.0, 0.0]],
+    ///     [[20.0, 0.0], [60.0, 0.0], [60.0, 40.0], [100.0, 40.0]],
+    /// ]);
+    /// # let _ = path;
+    /// ```
+    pub fn spans(spans: &[[[f32; 2]; 4]]) -> Drawable {
+        let Some(first) = spans.first() else {
+            return Curve::shape([0.0, 0.0], 0.0).end();
+        };
+        spans
+            .iter()
+            .fold(Curve::shape(first[0], 0.0), |b, &[p0, c1, c2, end]| {
+                if c1 == p0 && c2 == end {
+                    b.line_to(end)
+                } else {
+                    b.bezier_to(c1, c2, end)
+                }
+            })
+            .end()
+    }
+
+    /// Cubic bezier control points `[p0, c1, c2, p3]` of the centripetal
+    /// Catmull-Rom spline through `points`, one per span.
+    ///
+    /// The centripetal parametrization (knot spacing by the square root of the
+    /// chord length) never forms cusps or self-loops within a span, which
+    /// matters for unevenly spaced routing points. The end spans use mirrored
+    /// phantom points, so the curve leaves the first point heading toward the
+    /// second. Consecutive duplicate points are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use iced_nodegraph_sdf::Curve;
+    ///
+    /// let spans = Curve::catmull_rom_beziers(&[[0.0, 0.0], [50.0, 20.0], [100.0, 0.0]]);
+    /// assert_eq!(spans.len(), 2);
+    /// assert_eq!(spans[0][3], [50.0, 20.0]); // passes through the middle point
+    /// ```
+    pub fn catmull_rom_beziers(points: &[[f32; 2]]) -> Vec<[[f32; 2]; 4]> {
+        let mut pts: Vec<Vec2> = Vec::with_capacity(points.len());
+        for p in points.iter().map(|p| Vec2::from(*p)) {
+            if pts.last().is_none_or(|last| last.distance(p) > 1e-4) {
+                pts.push(p);
+            }
+        }
+        let n = pts.len();
+        if n < 2 {
+            return Vec::new();
+        }
+
+        let at = |i: isize| -> Vec2 {
+            if i < 0 {
+                2.0 * pts[0] - pts[1]
+            } else if i as usize >= n {
+                2.0 * pts[n - 1] - pts[n - 2]
+            } else {
+                pts[i as usize]
+            }
+        };
+        // Knot interval: sqrt of the chord length (centripetal, alpha = 0.5).
+        let dt = |a: Vec2, b: Vec2| a.distance(b).sqrt().max(1e-4);
+
+        (0..n as isize - 1)
+            .map(|i| {
+                let (p0, p1, p2, p3) = (at(i - 1), at(i), at(i + 1), at(i + 2));
+                let (d0, d1, d2) = (dt(p0, p1), dt(p1, p2), dt(p2, p3));
+                let m1 = ((p1 - p0) / d0 - (p2 - p0) / (d0 + d1) + (p2 - p1) / d1) * d1;
+                let m2 = ((p2 - p1) / d1 - (p3 - p1) / (d1 + d2) + (p3 - p2) / d2) * d1;
+                let c1 = p1 + m1 / 3.0;
+                let c2 = p2 - m2 / 3.0;
+                [p1.into(), c1.into(), c2.into(), p2.into()]
+            })
+            .collect()
+    }
+
     /// Single junction point with heading (radians). Useful for debugging.
     pub fn point(pos: impl Into<[f32; 2]>, heading: f32) -> Drawable {
         Drawable::single_point(Vec2::from(pos.into()), heading)
@@ -475,6 +595,93 @@ mod tests {
         assert!((a - b).length() < eps, "{msg}: {a:?} != {b:?}");
     }
 
+    // --- Catmull-Rom ---
+
+    fn cubic_at(span: &[[f32; 2]; 4], t: f32) -> Vec2 {
+        let [p0, p1, p2, p3] = span.map(Vec2::from);
+        let u = 1.0 - t;
+        p0 * (u * u * u) + p1 * (3.0 * u * u * t) + p2 * (3.0 * u * t * t) + p3 * (t * t * t)
+    }
+
+    #[test]
+    fn catmull_rom_interpolates_every_point() {
+        let pts = [[0.0, 0.0], [40.0, 30.0], [90.0, -10.0], [130.0, 60.0]];
+        let spans = Curve::catmull_rom_beziers(&pts);
+        assert_eq!(spans.len(), 3);
+        for (i, span) in spans.iter().enumerate() {
+            assert_vec_near(cubic_at(span, 0.0), Vec2::from(pts[i]), 1e-4, "span start");
+            assert_vec_near(
+                cubic_at(span, 1.0),
+                Vec2::from(pts[i + 1]),
+                1e-4,
+                "span end",
+            );
+        }
+    }
+
+    #[test]
+    fn catmull_rom_is_tangent_continuous_at_waypoints() {
+        let pts = [[0.0, 0.0], [10.0, 40.0], [100.0, 45.0], [110.0, 0.0]];
+        let spans = Curve::catmull_rom_beziers(&pts);
+        for pair in spans.windows(2) {
+            let out = (Vec2::from(pair[0][3]) - Vec2::from(pair[0][2])).normalize();
+            let inn = (Vec2::from(pair[1][1]) - Vec2::from(pair[1][0])).normalize();
+            assert_vec_near(out, inn, 1e-4, "tangent direction at a joint");
+        }
+    }
+
+    #[test]
+    fn catmull_rom_collinear_points_stay_on_the_line() {
+        let spans = Curve::catmull_rom_beziers(&[[0.0, 5.0], [20.0, 5.0], [70.0, 5.0]]);
+        for span in &spans {
+            for k in 0..=10 {
+                assert_near(cubic_at(span, k as f32 / 10.0).y, 5.0, 1e-4, "off the line");
+            }
+        }
+    }
+
+    #[test]
+    fn catmull_rom_skips_duplicates_and_degenerate_input() {
+        assert!(Curve::catmull_rom_beziers(&[]).is_empty());
+        assert!(Curve::catmull_rom_beziers(&[[3.0, 3.0], [3.0, 3.0]]).is_empty());
+        let spans = Curve::catmull_rom_beziers(&[[0.0, 0.0], [0.0, 0.0], [10.0, 0.0]]);
+        assert_eq!(spans.len(), 1);
+        assert!(
+            spans[0]
+                .iter()
+                .all(|p| p[0].is_finite() && p[1].is_finite())
+        );
+    }
+
+    #[test]
+    fn catmull_rom_drawable_spans_the_points() {
+        let d = Curve::catmull_rom(&[[0.0, 0.0], [50.0, 40.0], [100.0, 0.0]]);
+        assert!(!d.is_closed());
+        let [x0, y0, x1, y1] = d.bounds();
+        assert_near(x0, 0.0, 0.1, "min x");
+        assert_near(x1, 100.0, 0.1, "max x");
+        assert!(y0 <= 0.01 && y1 >= 40.0 - 0.01, "bounds {:?}", d.bounds());
+        // Arc length exceeds the chord polyline only slightly for a gentle curve.
+        let chords = 2.0 * (50.0f32 * 50.0 + 40.0 * 40.0).sqrt();
+        assert!(d.total_arc_length() >= chords - 0.1);
+    }
+
+    #[test]
+    fn polyline_length_is_sum_of_segments() {
+        let d = Curve::polyline(&[[0.0, 0.0], [30.0, 0.0], [30.0, 40.0]]);
+        assert_near(d.total_arc_length(), 70.0, 1e-3, "polyline length");
+    }
+
+    #[test]
+    fn spans_draw_degenerate_spans_as_lines() {
+        let d = Curve::spans(&[
+            [[0.0, 0.0], [0.0, 0.0], [20.0, 0.0], [20.0, 0.0]],
+            [[20.0, 0.0], [20.0, 0.0], [20.0, 30.0], [20.0, 30.0]],
+        ]);
+        assert_near(d.total_arc_length(), 50.0, 1e-3, "straight spans length");
+        assert!(Curve::spans(&[]).segments.is_empty());
+    }
+
     // --- Heading convention ---
 
     #[test]
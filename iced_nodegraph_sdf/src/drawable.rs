@@ -201,6 +201,28 @@ impl Drawable {
         out
     }
 
+    /// Rotates every segment about the local origin by `angle` radians
+    /// (clockwise on screen, as y points down) and recomputes the bounds.
+    /// Curvature is rotation-invariant; point headings turn with the
+    /// geometry. Curve and shape drawables only, like
+    /// [`translated`](Self::translated).
+    pub fn rotated(&self, angle: f32) -> Self {
+        let mut out = self.clone();
+        let rot = Vec2::from_angle(angle);
+        let mut min = Vec2::splat(f32::INFINITY);
+        let mut max = Vec2::splat(f32::NEG_INFINITY);
+        for seg in &mut out.segments {
+            seg.start = rot.rotate(seg.start);
+            seg.end = rot.rotate(seg.end);
+            seg.heading += angle;
+            seg.grow_aabb(&mut min, &mut max);
+        }
+        if !out.segments.is_empty() {
+            out.bounds = [min.x, min.y, max.x, max.y];
+        }
+        out
+    }
+
     /// Create a line segment drawable (convenience for Curve::single_line).
     pub(crate) fn single_line(a: Vec2, b: Vec2) -> Self {
         let length = a.distance(b);
@@ -541,6 +563,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn rotated_turns_geometry_about_the_origin() {
+        let d = Drawable::single_line(Vec2::new(1.0, 0.0), Vec2::new(3.0, 0.0));
+        let r = d.rotated(std::f32::consts::FRAC_PI_2);
+
+        // A quarter turn maps +x onto +y (y down: clockwise on screen).
+        assert!(r.segments[0].start.distance(Vec2::new(0.0, 1.0)) < 1e-5);
+        assert!(r.segments[0].end.distance(Vec2::new(0.0, 3.0)) < 1e-5);
+        let [x0, y0, x1, y1] = r.bounds();
+        assert!(x0.abs() < 1e-5 && x1.abs() < 1e-5);
+        assert!((y0 - 1.0).abs() < 1e-5 && (y1 - 3.0).abs() < 1e-5);
+        assert!((r.total_arc_length() - d.total_arc_length()).abs() < 1e-6);
+    }
+
     #[test]
     fn translated_shifts_geometry_and_bounds() {
         let d = Drawable::single_line(Vec2::new(1.0, 2.0), Vec2::new(4.0, 6.0));
@@ -65,7 +65,7 @@ pub use curve::{Curve, ShapeBuilder};
 pub use drawable::Drawable;
 pub use pattern::Pattern;
 pub use pipeline::types::SdfStats;
-pub use primitive::{SdfPrimitive, sdf_stats};
+pub use primitive::{DEFAULT_AA_WIDTH, SdfPrimitive, sdf_stats};
 pub use shape::{Shape, ShapeCache};
-pub use style::{Stop, Style, Transfer};
+pub use style::{Pulse, Stop, Style, Transfer};
 pub use tiling::Tiling;
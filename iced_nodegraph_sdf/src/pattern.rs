@@ -5,6 +5,7 @@
 
 /// Pattern type for stroke rendering.
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PatternType {
     /// Solid stroke.
     #[default]
@@ -31,6 +32,7 @@ pub enum PatternType {
 
 /// Pattern configuration for SDF stroke rendering.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Pattern {
     /// Stroke thickness.
     pub thickness: f32,
@@ -38,6 +40,11 @@ pub struct Pattern {
     pub pattern_type: PatternType,
     /// Flow animation speed (world units per second, 0 = no animation).
     pub flow_speed: f32,
+    /// Stroke thickness at the end of the contour (arc 1); the stroke narrows
+    /// or widens linearly from [`thickness`](Self::thickness) at arc 0. `None`
+    /// keeps `thickness` throughout. Set with [`Pattern::taper`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub end_thickness: Option<f32>,
 }
 
 impl Default for Pattern {
@@ -46,6 +53,7 @@ impl Default for Pattern {
             thickness: 1.0,
             pattern_type: PatternType::Solid,
             flow_speed: 0.0,
+            end_thickness: None,
         }
     }
 }
@@ -62,6 +70,7 @@ impl Pattern {
             thickness,
             pattern_type: PatternType::Solid,
             flow_speed: 0.0,
+            end_thickness: None,
         }
     }
 
@@ -75,6 +84,7 @@ impl Pattern {
                 angle: 0.0,
             },
             flow_speed: 0.0,
+            end_thickness: None,
         }
     }
 
@@ -92,6 +102,7 @@ impl Pattern {
                 angle: clamp_cap_angle(angle),
             },
             flow_speed: 0.0,
+            end_thickness: None,
         }
     }
 
@@ -106,6 +117,7 @@ impl Pattern {
                 angle,
             },
             flow_speed: 0.0,
+            end_thickness: None,
         }
     }
 
@@ -121,6 +133,7 @@ impl Pattern {
                 angle: clamp_cap_angle(angle),
             },
             flow_speed: 0.0,
+            end_thickness: None,
         }
     }
 
@@ -130,6 +143,7 @@ impl Pattern {
             thickness: radius * 2.0,
             pattern_type: PatternType::Dotted { spacing, radius },
             flow_speed: 0.0,
+            end_thickness: None,
         }
     }
 
@@ -143,6 +157,7 @@ impl Pattern {
                 dot_radius,
             },
             flow_speed: 0.0,
+            end_thickness: None,
         }
     }
 
@@ -156,6 +171,7 @@ impl Pattern {
                 dot_radius,
             },
             flow_speed: 0.0,
+            end_thickness: None,
         }
     }
 
@@ -165,6 +181,72 @@ impl Pattern {
         self
     }
 
+    /// Taper the stroke from `start` thickness at the contour's start (arc 0)
+    /// to `end` thickness at its end (arc 1). Dashes, arrows and dots follow
+    /// the taper; a dot's radius is unchanged.
+    pub fn taper(mut self, start: f32, end: f32) -> Self {
+        self.thickness = start;
+        self.end_thickness = Some(end);
+        self
+    }
+
+    /// The widest the stroke gets anywhere along the contour.
+    pub fn max_thickness(&self) -> f32 {
+        self.end_thickness
+            .map_or(self.thickness, |end| self.thickness.max(end))
+    }
+
+    /// Scales every length (thickness, dash/gap/spacing, dot radius) and the
+    /// flow speed by `factor`; cap angles are kept. Turns a pattern sized in
+    /// screen pixels into world units via `1.0 / zoom`.
+    pub fn scale(self, factor: f32) -> Self {
+        let pattern_type = match self.pattern_type {
+            PatternType::Solid => PatternType::Solid,
+            PatternType::Dashed { dash, gap, angle } => PatternType::Dashed {
+                dash: dash * factor,
+                gap: gap * factor,
+                angle,
+            },
+            PatternType::Arrowed {
+                segment,
+                gap,
+                angle,
+            } => PatternType::Arrowed {
+                segment: segment * factor,
+                gap: gap * factor,
+                angle,
+            },
+            PatternType::Dotted { spacing, radius } => PatternType::Dotted {
+                spacing: spacing * factor,
+                radius: radius * factor,
+            },
+            PatternType::DashDotted {
+                dash,
+                gap,
+                dot_radius,
+            } => PatternType::DashDotted {
+                dash: dash * factor,
+                gap: gap * factor,
+                dot_radius: dot_radius * factor,
+            },
+            PatternType::ArrowDotted {
+                segment,
+                gap,
+                dot_radius,
+            } => PatternType::ArrowDotted {
+                segment: segment * factor,
+                gap: gap * factor,
+                dot_radius: dot_radius * factor,
+            },
+        };
+        Self {
+            thickness: self.thickness * factor,
+            pattern_type,
+            flow_speed: self.flow_speed * factor,
+            end_thickness: self.end_thickness.map(|end| end * factor),
+        }
+    }
+
     /// Whether this pattern has active animation.
     pub fn is_animated(&self) -> bool {
         self.flow_speed != 0.0
@@ -256,6 +338,18 @@ mod tests {
         assert_eq!(dot_r, 1.5);
     }
 
+    #[test]
+    fn test_scale_pattern() {
+        let p = Pattern::dashed_angle(2.0, 10.0, 5.0, 0.5)
+            .flow(20.0)
+            .scale(0.5);
+        let (ty, thickness, dash, gap, angle, flow) = p.as_gpu();
+        assert_eq!(ty, 1);
+        assert_eq!((thickness, dash, gap), (1.0, 5.0, 2.5));
+        assert_eq!(angle, 0.5);
+        assert_eq!(flow, 10.0);
+    }
+
     #[test]
     fn test_flow_animation() {
         let p = Pattern::solid(2.0).flow(50.0);
@@ -263,4 +357,13 @@ mod tests {
         let (_, _, _, _, _, flow) = p.as_gpu();
         assert_eq!(flow, 50.0);
     }
+
+    #[test]
+    fn test_taper_pattern() {
+        let p = Pattern::dashed(2.0, 10.0, 5.0).taper(4.0, 1.0);
+        assert_eq!((p.thickness, p.end_thickness), (4.0, Some(1.0)));
+        assert_eq!(p.max_thickness(), 4.0);
+        assert_eq!(p.scale(0.5).end_thickness, Some(0.5));
+        assert_eq!(Pattern::solid(2.0).max_thickness(), 2.0);
+    }
 }
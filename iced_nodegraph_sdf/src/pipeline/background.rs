@@ -0,0 +1,361 @@
+//! Texture cache for tiling-only background primitives.
+//!
+//! A tiling (grid, dots, ...) is periodic, so panning it changes nothing but
+//! its phase. A primitive made only of tilings is therefore rendered once into
+//! an offscreen texture, one period larger than its bounds on each axis, with
+//! the camera at the origin. Later frames sample that texture shifted by the
+//! camera's position modulo the period (`background.wgsl`) instead of running
+//! the SDF fragment pass again. The shift is rounded to whole texels, so the
+//! blit copies texels rather than resampling the pattern, and every frame of
+//! a cacheable background - direct ones included - is drawn at that rounded
+//! phase, so the modes match pixel for pixel while the pattern sits within
+//! half a pixel of the camera. The texture is keyed on the primitive's
+//! geometry (pattern and style), zoom, DPI scale and size; any change renders
+//! it again, but no pan does.
+//!
+//! A key is drawn directly the first frame it appears and cached only when the
+//! next frame repeats it (Direct -> Populate -> Blit), so a continuous zoom,
+//! which changes the key every frame, never pays for a populate it cannot
+//! reuse.
+
+use iced::wgpu::{
+    BindGroup, BindGroupDescriptor, BindGroupEntry, BindingResource, Buffer, BufferDescriptor,
+    BufferUsages, CommandEncoderDescriptor, Device, Extent3d, LoadOp, Operations, Queue,
+    RenderPass, RenderPassColorAttachment, RenderPassDescriptor, RenderPipeline, StoreOp,
+    TextureDescriptor, TextureDimension, TextureFormat, TextureUsages, TextureView,
+    TextureViewDescriptor,
+};
+
+use crate::shared::SharedSdfResources;
+
+/// How a cacheable background primitive is drawn this frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CacheMode {
+    /// Shaded in place like any other primitive.
+    Direct,
+    /// Rendered into the cache texture first, then blitted.
+    Populate,
+    /// Blitted from the cache texture as it stands.
+    Blit,
+}
+
+/// The cache's bookkeeping, apart from its GPU resources.
+#[derive(Debug, Default)]
+struct CacheState {
+    /// Key of the most recent cacheable background.
+    key: Option<u64>,
+    /// Whether the texture holds `key`.
+    populated: bool,
+    /// Set once a primitive took the cache this frame; any other cacheable
+    /// primitive in the same frame (a second graph) draws directly and leaves
+    /// the key alone.
+    claimed: bool,
+}
+
+impl CacheState {
+    fn advance(&mut self, key: u64) -> CacheMode {
+        if std::mem::replace(&mut self.claimed, true) {
+            return CacheMode::Direct;
+        }
+        if self.key.replace(key) != Some(key) {
+            self.populated = false;
+            return CacheMode::Direct;
+        }
+        if std::mem::replace(&mut self.populated, true) {
+            CacheMode::Blit
+        } else {
+            CacheMode::Populate
+        }
+    }
+}
+
+/// The texture a populated background lives in, with the blit's bind group
+/// over it.
+struct CacheTarget {
+    size: [u32; 2],
+    view: TextureView,
+    bind_group: BindGroup,
+}
+
+pub(crate) struct BackgroundCache {
+    state: CacheState,
+    /// Draw slot whose `DrawData` renders the texture, when a populate is due
+    /// this frame.
+    populate_slot: Option<u32>,
+    target: Option<CacheTarget>,
+    /// The blit's placement uniform (see `background.wgsl`).
+    uniform: Buffer,
+}
+
+impl BackgroundCache {
+    pub fn new(device: &Device) -> Self {
+        Self {
+            state: CacheState::default(),
+            populate_slot: None,
+            target: None,
+            uniform: device.create_buffer(&BufferDescriptor {
+                label: Some("sdf_background_blit"),
+                size: 32,
+                usage: BufferUsages::UNIFORM | BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            }),
+        }
+    }
+
+    /// Decides how the background keyed `key` is drawn this frame and, when
+    /// it comes from the cache, sizes the texture (`size` texels) and writes
+    /// the blit placement: the bounds' physical `origin` and the camera
+    /// `offset` from [`phase_offset`]. A populate renders draw slot `slot`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn prepare(
+        &mut self,
+        device: &Device,
+        queue: &Queue,
+        shared: &SharedSdfResources,
+        format: TextureFormat,
+        key: u64,
+        size: [u32; 2],
+        origin: [f32; 2],
+        offset: [f32; 2],
+        slot: u32,
+    ) -> CacheMode {
+        let mode = self.state.advance(key);
+        if mode == CacheMode::Direct {
+            return mode;
+        }
+        if mode == CacheMode::Populate {
+            if self.target.as_ref().is_none_or(|t| t.size != size) {
+                self.target = Some(self.create_target(device, shared, format, size));
+            }
+            self.populate_slot = Some(slot);
+        }
+        let mut bytes = [0u8; 32];
+        let values = [
+            origin[0],
+            origin[1],
+            offset[0],
+            offset[1],
+            size[0] as f32,
+            size[1] as f32,
+        ];
+        for (chunk, v) in bytes.chunks_exact_mut(4).zip(values) {
+            chunk.copy_from_slice(&v.to_le_bytes());
+        }
+        queue.write_buffer(&self.uniform, 0, &bytes);
+        mode
+    }
+
+    fn create_target(
+        &self,
+        device: &Device,
+        shared: &SharedSdfResources,
+        format: TextureFormat,
+        size: [u32; 2],
+    ) -> CacheTarget {
+        let texture = device.create_texture(&TextureDescriptor {
+            label: Some("sdf_background_cache"),
+            size: Extent3d {
+                width: size[0],
+                height: size[1],
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: TextureDimension::D2,
+            format,
+            usage: TextureUsages::RENDER_ATTACHMENT | TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&TextureViewDescriptor::default());
+        let bind_group = device.create_bind_group(&BindGroupDescriptor {
+            label: Some("sdf_background_blit_g0"),
+            layout: &shared.blit_group_layout,
+            entries: &[
+                BindGroupEntry {
+                    binding: 0,
+                    resource: self.uniform.as_entire_binding(),
+                },
+                BindGroupEntry {
+                    binding: 1,
+                    resource: BindingResource::TextureView(&view),
+                },
+                BindGroupEntry {
+                    binding: 2,
+                    resource: BindingResource::Sampler(&shared.blit_sampler),
+                },
+            ],
+        });
+        CacheTarget {
+            size,
+            view,
+            bind_group,
+        }
+    }
+
+    /// Renders this frame's pending populate, if any, into the texture with
+    /// the SDF render pipeline: one instance of the prepared draw slot over a
+    /// transparent clear. Runs from the first `draw`, after every prepare, so
+    /// `render_group0` binds the frame's final buffers.
+    pub fn populate(
+        &self,
+        device: &Device,
+        queue: &Queue,
+        render_pipeline: &RenderPipeline,
+        render_group0: &BindGroup,
+    ) {
+        let (Some(slot), Some(target)) = (self.populate_slot, self.target.as_ref()) else {
+            return;
+        };
+        let mut encoder = device.create_command_encoder(&CommandEncoderDescriptor {
+            label: Some("sdf_background_populate"),
+        });
+        {
+            let mut pass = encoder.begin_render_pass(&RenderPassDescriptor {
+                label: Some("sdf_background_populate"),
+                color_attachments: &[Some(RenderPassColorAttachment {
+                    view: &target.view,
+                    depth_slice: None,
+                    resolve_target: None,
+                    ops: Operations {
+                        load: LoadOp::Clear(iced::wgpu::Color::TRANSPARENT),
+                        store: StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            pass.set_pipeline(render_pipeline);
+            pass.set_bind_group(0, render_group0, &[]);
+            pass.draw(0..3, slot..slot + 1);
+        }
+        queue.submit(std::iter::once(encoder.finish()));
+    }
+
+    /// Records the blit of the cached background into `pass`.
+    pub fn record_blit(&self, pass: &mut RenderPass<'_>, shared: &SharedSdfResources) {
+        let Some(target) = self.target.as_ref() else {
+            return;
+        };
+        pass.push_debug_group("sdf_background_blit");
+        pass.set_pipeline(&shared.blit_pipeline);
+        pass.set_bind_group(0, &target.bind_group, &[]);
+        pass.draw(0..3, 0..1);
+        pass.pop_debug_group();
+    }
+
+    /// Releases the frame's claim and pending populate (from `trim`).
+    pub fn end_frame(&mut self) {
+        self.state.claimed = false;
+        self.populate_slot = None;
+    }
+}
+
+/// Texture size for bounds of `bounds` physical pixels and a pattern period
+/// of `period` physical pixels: the bounds plus one period and one texel of
+/// margin for the whole-texel shift ([`whole_texel_shift`]) per axis. `None`
+/// when the margin outgrows the bounds (a sparse pattern zoomed far in) or
+/// the texture would exceed `max_dim`; such backgrounds are drawn directly.
+pub(crate) fn texture_size(bounds: [u32; 2], period: [f32; 2], max_dim: u32) -> Option<[u32; 2]> {
+    let axis = |bounds: u32, period: f32| {
+        if period.is_nan() || period <= 0.0 || period > bounds as f32 {
+            return None;
+        }
+        let size = bounds + period.ceil() as u32 + 1;
+        (size <= max_dim).then_some(size)
+    };
+    Some([axis(bounds[0], period[0])?, axis(bounds[1], period[1])?])
+}
+
+/// Rounds the blit's texel shift `shift` (texel = framebuffer pixel +
+/// `shift`) to whole texels, so the blit reads texel centres and never
+/// resamples the pattern. Also returns the camera nudge, in world units for
+/// `cs` (zoom times DPI scale), that moves a direct render onto the same
+/// rounded phase: with it, direct, populate and blit frames match exactly,
+/// and the pattern sits within half a pixel of the true camera.
+pub(crate) fn whole_texel_shift(shift: [f32; 2], cs: f32) -> ([f32; 2], [f32; 2]) {
+    let whole = shift.map(f32::round);
+    (
+        whole,
+        [(shift[0] - whole[0]) / cs, (shift[1] - whole[1]) / cs],
+    )
+}
+
+/// Texel offset of the bounds' top-left pixel into a texture rendered with
+/// the camera at the origin: the camera's phase within one `period` (world
+/// units), scaled by `cs` (zoom times DPI scale) to pixels. Always within
+/// `0..=period * cs`.
+pub(crate) fn phase_offset(camera: [f32; 2], period: [f32; 2], cs: f32) -> [f32; 2] {
+    [
+        (-camera[0]).rem_euclid(period[0]) * cs,
+        (-camera[1]).rem_euclid(period[1]) * cs,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_key_is_cached_only_once_it_repeats() {
+        let mut state = CacheState::default();
+        let mut frame = |key| {
+            let mode = state.advance(key);
+            state.claimed = false;
+            mode
+        };
+        assert_eq!(frame(1), CacheMode::Direct);
+        assert_eq!(frame(1), CacheMode::Populate);
+        assert_eq!(frame(1), CacheMode::Blit);
+        // A zoom or style change: direct again until it settles.
+        assert_eq!(frame(2), CacheMode::Direct);
+        assert_eq!(frame(3), CacheMode::Direct);
+        assert_eq!(frame(3), CacheMode::Populate);
+        // Returning to an older key must not trust the texture.
+        assert_eq!(frame(2), CacheMode::Direct);
+        assert_eq!(frame(2), CacheMode::Populate);
+    }
+
+    #[test]
+    fn only_the_first_background_of_a_frame_takes_the_cache() {
+        let mut state = CacheState::default();
+        assert_eq!(state.advance(1), CacheMode::Direct);
+        assert_eq!(state.advance(2), CacheMode::Direct);
+        state.claimed = false;
+        // The second background did not disturb the first one's key.
+        assert_eq!(state.advance(1), CacheMode::Populate);
+        assert_eq!(state.advance(2), CacheMode::Direct);
+    }
+
+    #[test]
+    fn texture_covers_bounds_plus_one_period() {
+        assert_eq!(
+            texture_size([800, 600], [24.5, 30.0], 8192),
+            Some([826, 631])
+        );
+        // A period wider than the bounds, or a texture past the device limit.
+        assert_eq!(texture_size([800, 600], [900.0, 30.0], 8192), None);
+        assert_eq!(texture_size([8170, 600], [30.0, 30.0], 8192), None);
+        assert_eq!(texture_size([800, 600], [0.0, 30.0], 8192), None);
+    }
+
+    #[test]
+    fn whole_texel_shift_rounds_and_nudges_the_camera_by_the_rest() {
+        assert_eq!(
+            whole_texel_shift([3.0, -2.0], 1.0),
+            ([3.0, -2.0], [0.0, 0.0])
+        );
+        assert_eq!(
+            whole_texel_shift([3.25, -2.75], 0.5),
+            ([3.0, -3.0], [0.5, 0.5])
+        );
+    }
+
+    #[test]
+    fn phase_offset_wraps_the_camera_into_one_period() {
+        assert_eq!(phase_offset([0.0, 0.0], [20.0, 10.0], 1.0), [0.0, 0.0]);
+        // world = px / cs - camera, so the texture must be read `-camera` ahead.
+        assert_eq!(phase_offset([-5.0, 3.0], [20.0, 10.0], 1.0), [5.0, 7.0]);
+        assert_eq!(phase_offset([-45.0, -10.0], [20.0, 10.0], 2.0), [10.0, 0.0]);
+    }
+}
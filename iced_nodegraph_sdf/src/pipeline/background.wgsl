@@ -0,0 +1,39 @@
+// Background cache blit.
+//
+// Draws a cached tiling background (see background.rs): a fullscreen triangle
+// whose fragments sample the cache texture shifted by the camera's phase
+// within one pattern period. The texture holds premultiplied colour exactly as
+// the SDF fragment pass wrote it, so the blit composites with the same blend
+// state and the result matches shading the tiling in place.
+
+struct Blit {
+    // Physical-pixel origin of the primitive's bounds in the framebuffer.
+    origin: vec2<f32>,
+    // Texel offset of the bounds' top-left pixel: the camera phase, in pixels,
+    // rounded so `offset - origin` is whole texels.
+    offset: vec2<f32>,
+    // Cache texture size in texels.
+    size: vec2<f32>,
+    _pad: vec2<f32>,
+}
+
+@group(0) @binding(0) var<uniform> blit: Blit;
+@group(0) @binding(1) var cache: texture_2d<f32>;
+@group(0) @binding(2) var cache_sampler: sampler;
+
+@vertex
+fn vs_blit(@builtin(vertex_index) vid: u32) -> @builtin(position) vec4<f32> {
+    let x = f32(i32(vid & 1u)) * 4.0 - 1.0;
+    let y = f32(i32(vid >> 1u)) * 4.0 - 1.0;
+    return vec4<f32>(x, y, 0.0, 1.0);
+}
+
+@fragment
+fn fs_blit(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+    // `position` is a pixel centre and the shift is whole texels, so this
+    // reads a texel centre: a copy, never a resample of the pattern.
+    let texel = position.xy - blit.origin + blit.offset;
+    let color = textureSampleLevel(cache, cache_sampler, texel / blit.size, 0.0);
+    if color.a < 0.001 { discard; }
+    return color;
+}
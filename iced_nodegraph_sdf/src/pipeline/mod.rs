@@ -1,6 +1,7 @@
 //! GPU pipeline infrastructure for SDF rendering.
 
 pub(crate) mod arena;
+pub(crate) mod background;
 pub(crate) mod buffer;
 pub(crate) mod overflow;
 pub(crate) mod types;
@@ -314,6 +314,11 @@ impl TestRenderer {
             coarse_rows: grid_rows.div_ceil(COARSE_FACTOR),
             coarse_base: 0,
             tilings: [u32::MAX; 4],
+            aa_width: crate::primitive::DEFAULT_AA_WIDTH,
+            pixel_snap: 0,
+            _pad0: 0,
+            _pad1: 0,
+            _pad2: 0,
         };
 
         self.execute_render(
@@ -602,6 +607,11 @@ impl TestRenderer {
             coarse_rows,
             coarse_base: 0,
             tilings: [u32::MAX; 4],
+            aa_width: crate::primitive::DEFAULT_AA_WIDTH,
+            pixel_snap: 0,
+            _pad0: 0,
+            _pad1: 0,
+            _pad2: 0,
         };
 
         let cull_lists = self.build_cull_lists(
@@ -875,6 +885,11 @@ impl TestRenderer {
                 coarse_rows,
                 coarse_base,
                 tilings: [u32::MAX; 4],
+                aa_width: crate::primitive::DEFAULT_AA_WIDTH,
+                pixel_snap: 0,
+                _pad0: 0,
+                _pad1: 0,
+                _pad2: 0,
             });
             tile_base += grid_cols * grid_rows;
             coarse_base += coarse_cols * coarse_rows;
@@ -1195,6 +1210,11 @@ impl TestRenderer {
             coarse_rows: grid_rows.div_ceil(COARSE_FACTOR),
             coarse_base: 0,
             tilings: [u32::MAX; 4],
+            aa_width: crate::primitive::DEFAULT_AA_WIDTH,
+            pixel_snap: 0,
+            _pad0: 0,
+            _pad1: 0,
+            _pad2: 0,
         };
 
         // Encode to GPU format via encase
@@ -1465,6 +1485,11 @@ impl TestRenderer {
             coarse_rows: grid_rows.div_ceil(COARSE_FACTOR),
             coarse_base: 0,
             tilings: [u32::MAX; 4],
+            aa_width: crate::primitive::DEFAULT_AA_WIDTH,
+            pixel_snap: 0,
+            _pad0: 0,
+            _pad1: 0,
+            _pad2: 0,
         };
         self.execute_render(
             &gpu_entries,
@@ -3114,6 +3139,7 @@ fn abutting_chain_bands_stay_opaque_across_boundary() {
         ],
         pattern: None,
         transfer: Default::default(),
+        pulse: None,
     };
     let shape = Curve::circle([0.0, 0.0], radius);
     let pixels = renderer.render(&[(&shape, &style)], width, height, zoom);
@@ -3853,6 +3879,7 @@ fn premultiplied_band_blend_no_rgb_fringe() {
         ],
         pattern: None,
         transfer: Default::default(),
+        pulse: None,
     };
     let radius = 50.0_f32;
     let circle = Curve::circle([0.0, 0.0], radius);
@@ -4028,6 +4055,7 @@ fn transfer_gamma_warps_blend_toward_near_stop() {
         ],
         pattern: None,
         transfer: t,
+        pulse: None,
     };
     let circle = Curve::circle([0.0, 0.0], 30.0);
     let lin = mk(crate::style::Transfer::Linear);
@@ -4048,6 +4076,112 @@ fn transfer_gamma_warps_blend_toward_near_stop() {
     );
 }
 
+/// A pulse band brightens the stroke around its arc position and leaves the
+/// rest of the contour in the stroke colour. At time 0 the band sits at arc 0,
+/// so the line's start is near the band colour and its middle is untouched.
+#[test]
+fn pulse_band_brightens_only_around_its_arc_position() {
+    let r = shared_renderer();
+    let (w, h, zoom) = (256u32, 64u32, 1.0f32);
+    let line = Curve::line([-100.0, 0.0], [100.0, 0.0]);
+    let style = Style::stroke(rgba(0.0, 0.0, 1.0, 1.0), Pattern::solid(8.0)).pulse(
+        rgba(1.0, 1.0, 1.0, 1.0),
+        1.0,
+        0.4,
+    );
+    let px = r.render_opts(&[(&line, &style)], w, h, zoom, true);
+    // World x -> screen x + 128; y = 0 is the centre row.
+    let start = TestRenderer::pixel_at(&px, w, 128 - 95, h / 2);
+    let middle = TestRenderer::pixel_at(&px, w, 128, h / 2);
+    assert!(start[0] > 200, "band must brighten the start: {start:?}");
+    assert!(middle[0] < 20, "band must not reach the middle: {middle:?}");
+    assert!(
+        middle[2] > 200 && middle[3] > 200,
+        "stroke lost: {middle:?}"
+    );
+}
+
+/// A tapered stroke is as thick as its start width at arc 0 and narrows
+/// toward its end width at arc 1.
+#[test]
+fn tapered_stroke_narrows_along_the_arc() {
+    let r = shared_renderer();
+    let (w, h, zoom) = (256u32, 64u32, 1.0f32);
+    let line = Curve::line([-100.0, 0.0], [100.0, 0.0]);
+    let style = Style::stroke(
+        rgba(1.0, 1.0, 1.0, 1.0),
+        Pattern::solid(1.0).taper(16.0, 2.0),
+    );
+    let px = r.render_opts(&[(&line, &style)], w, h, zoom, true);
+    // 6px off the centre line: inside the 16px start, outside the ~3px end.
+    let near_start = TestRenderer::pixel_at(&px, w, 128 - 90, h / 2 + 6);
+    let near_end = TestRenderer::pixel_at(&px, w, 128 + 90, h / 2 + 6);
+    assert!(near_start[3] > 200, "start is not thick: {near_start:?}");
+    assert!(near_end[3] < 20, "end is not thin: {near_end:?}");
+}
+
+/// `SdfPrimitive::aa_width` widens the edge fade in screen pixels: 2px either
+/// side of a filled circle's edge is crisp at the default width and partly
+/// faded at 8px.
+#[test]
+fn aa_width_softens_edges() {
+    use crate::primitive::SdfPrimitive;
+    use crate::shape::Shape;
+
+    let r = shared_renderer();
+    let (w, h) = (128u32, 96u32);
+    let style = Style::solid(rgba(1.0, 1.0, 1.0, 1.0));
+    let render = |aa: f32| {
+        let mut p = SdfPrimitive::new();
+        p.push(&Shape::circle(20.0), &style, [0.0, 0.0]);
+        r.render_primitive(&p.camera(64.0, 48.0, 1.0).aa_width(aa), w, h)
+    };
+    let crisp = render(crate::primitive::DEFAULT_AA_WIDTH);
+    let soft = render(8.0);
+    let (inside, outside) = (64 + 18, 64 + 22);
+    assert_eq!(TestRenderer::pixel_at(&crisp, w, inside, 48)[3], 255);
+    assert_eq!(TestRenderer::pixel_at(&crisp, w, outside, 48)[3], 0);
+    let soft_in = TestRenderer::pixel_at(&soft, w, inside, 48)[3];
+    let soft_out = TestRenderer::pixel_at(&soft, w, outside, 48)[3];
+    assert!(soft_in < 250, "wide AA left the inside crisp: {soft_in}");
+    assert!(soft_out > 10, "wide AA did not reach outside: {soft_out}");
+}
+
+/// `SdfPrimitive::pixel_snap` lands a 1px grid on whole pixels at any zoom
+/// and pan: every pixel is either on a line or clear of it, where the
+/// unsnapped grid smears lines across pixel pairs.
+#[test]
+fn pixel_snapped_grid_has_no_partial_pixels() {
+    use crate::primitive::SdfPrimitive;
+    use crate::shape::Shape;
+    use crate::tiling::Tiling;
+
+    let r = shared_renderer();
+    let (w, h) = (128u32, 96u32);
+    let style = Style::solid(rgba(1.0, 1.0, 1.0, 1.0));
+    let render = |snap: bool| {
+        let mut p = SdfPrimitive::new();
+        p.push(
+            &Shape::tiling(Tiling::grid(13.3, 9.7, 1.0 / 1.37)),
+            &style,
+            [0.0, 0.0],
+        );
+        r.render_primitive(&p.camera(3.21, -7.66, 1.37).pixel_snap(snap), w, h)
+    };
+    let partial = |px: &[[u8; 4]]| px.iter().filter(|p| p[3] > 5 && p[3] < 250).count();
+    let smeared = render(false);
+    assert!(
+        partial(&smeared) > 0,
+        "unsnapped grid is already crisp - vacuous"
+    );
+    let snapped = render(true);
+    assert!(
+        snapped.iter().any(|p| p[3] >= 250),
+        "snapped grid drew no lines"
+    );
+    assert_eq!(partial(&snapped), 0, "snapped grid has partial pixels");
+}
+
 /// A3 sign-aware patterns new-capability golden: a DOTTED pattern on a CLOSED
 /// contour keeps its dots on the OUTER half plus a thin inner line, so the
 /// interior stays clean (no inward dot bulge). At dist -4 inside the contour the
@@ -4610,6 +4744,92 @@ fn render_pipeline_frame(
     px
 }
 
+/// A tiling-only primitive goes Direct -> Populate -> Blit as its pattern,
+/// zoom and size repeat (`SdfStats::background_cached`), and a pan served
+/// from the cache texture renders what shading it in place renders.
+/// Whole-pixel camera steps sample texel centres, so the match is exact.
+/// At a fractional zoom any pan, sub-pixel or across a period, keeps the
+/// cache: every mode draws the phase rounded to whole pixels, so the blit
+/// copies texels instead of resampling the lines soft.
+#[test]
+fn background_cache_pan_matches_direct_render() {
+    use crate::primitive::{SdfPipeline, SdfPrimitive, sdf_stats};
+    use crate::shape::Shape;
+    use crate::tiling::Tiling;
+    use iced_wgpu::primitive::Pipeline;
+
+    let r = shared_renderer();
+    let (w, h) = (160u32, 120u32);
+    let style = Style::solid(rgba(0.8, 0.8, 0.9, 1.0));
+    let grid = |cam: [f32; 2], zoom: f32| {
+        let mut p = SdfPrimitive::new();
+        p.push(
+            &Shape::tiling(Tiling::grid(24.0, 16.0, 2.0)),
+            &style,
+            [0.0, 0.0],
+        );
+        p.camera(cam[0], cam[1], zoom)
+    };
+    let frame = |pipeline: &mut SdfPipeline, cam: [f32; 2], zoom: f32| {
+        let px = render_pipeline_frame(&r, pipeline, &[&grid(cam, zoom)], w, h);
+        (px, sdf_stats().background_cached)
+    };
+
+    let mut pipeline = SdfPipeline::new(&r.device, &r.queue, TextureFormat::Rgba8Unorm);
+    let (_, cached) = frame(&mut pipeline, [3.0, -7.0], 1.0);
+    assert!(!cached, "a new background is shaded directly");
+    let (populated, cached) = frame(&mut pipeline, [3.0, -7.0], 1.0);
+    assert!(cached, "a repeated background populates the cache");
+    let (panned, cached) = frame(&mut pipeline, [-41.0, 12.0], 1.0);
+    assert!(cached, "a pan blits the cached texture");
+    let (_, cached) = frame(&mut pipeline, [-41.0, 12.0], 1.5);
+    assert!(!cached, "a zoom change invalidates the cache");
+
+    let mut reference = SdfPipeline::new(&r.device, &r.queue, TextureFormat::Rgba8Unorm);
+    let (direct, _) = frame(&mut reference, [3.0, -7.0], 1.0);
+    assert!(
+        direct.iter().any(|px| px[3] > 0),
+        "grid rendered nothing - test would be vacuous"
+    );
+    assert_eq!(
+        populated, direct,
+        "populate frame differs from direct shading"
+    );
+    let (direct, _) = frame(&mut reference, [-41.0, 12.0], 1.0);
+    assert_eq!(panned, direct, "blitted pan differs from direct shading");
+
+    // Fractional zoom and DPI-style fractional pans: every frame of the
+    // background sits on the same whole-pixel phase, so a pan by a fraction
+    // of a pixel across period boundaries still blits, and the blit matches
+    // a direct render of the same camera.
+    let zoom = 1.37;
+    let worst = |a: &[[u8; 4]], b: &[[u8; 4]]| {
+        a.iter()
+            .zip(b)
+            .flat_map(|(pa, pb)| (0..4).map(move |c| pa[c].abs_diff(pb[c])))
+            .max()
+            .unwrap()
+    };
+    let start = [3.3, -7.1];
+    frame(&mut pipeline, start, zoom);
+    frame(&mut pipeline, start, zoom);
+    // 41.3 and 23.6 physical pixels: more than one 24x16 period (32.9 and
+    // 21.9 pixels at this zoom) on each axis.
+    let far = [start[0] - 41.3 / zoom, start[1] + 23.6 / zoom];
+    for cam in [[start[0] - 0.37, start[1] + 0.21], far] {
+        let (panned, cached) = frame(&mut pipeline, cam, zoom);
+        assert!(
+            cached,
+            "a fractional pan to {cam:?} blits the cached texture"
+        );
+        let mut reference = SdfPipeline::new(&r.device, &r.queue, TextureFormat::Rgba8Unorm);
+        let (direct, cached) = frame(&mut reference, cam, zoom);
+        assert!(!cached, "a fresh pipeline renders directly");
+        let diff = worst(&panned, &direct);
+        assert!(diff <= 1, "blitted pan to {cam:?} differs by {diff}");
+    }
+}
+
 /// A primitive recoloring in place must not leak into primitives whose
 /// resident entries reference its shared segment/style slots (instancing and
 /// style dedup are cross-primitive, by absolute index). Under arena residency
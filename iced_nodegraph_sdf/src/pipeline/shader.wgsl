@@ -68,6 +68,14 @@ struct DrawData {
     tiling1: u32,
     tiling2: u32,
     tiling3: u32,
+    // Antialiasing width in physical pixels (`SdfPrimitive::aa_width`).
+    aa_width: f32,
+    // Nonzero snaps placements and grid lines to the pixel grid
+    // (`SdfPrimitive::pixel_snap`); the CPU has already snapped the camera.
+    pixel_snap: u32,
+    _pad0: u32,
+    _pad1: u32,
+    _pad2: u32,
 }
 
 struct GpuSegment {
@@ -116,8 +124,13 @@ struct GpuStyle {
     flow_speed: f32,
     transfer_type: u32,
     transfer_param: f32,
-    _transfer_pad0: u32,
-    _transfer_pad1: u32,
+    pulse_speed: f32,
+    pulse_width: f32,
+    pulse_color: vec4<f32>,
+    pattern_thickness_end: f32,
+    _taper_pad0: u32,
+    _taper_pad1: u32,
+    _taper_pad2: u32,
 }
 
 // A3 transfer (variant B): a color-domain warp on the post-smoothstep blend t.
@@ -131,6 +144,17 @@ fn apply_transfer(t: f32, kind: u32, param: f32) -> f32 {
     return t;
 }
 
+// Pulse band: a bright band of `pulse_color` centred at arc `time * speed`
+// (wrapping 1 -> 0), mixed over the straight-alpha colour `c`. Only RGB is
+// mixed, so transparent stops stay transparent. Parametric in arc_t, so it
+// crosses every contour in the same time.
+fn apply_pulse(c: vec4<f32>, arc_t: f32, style: GpuStyle, time: f32) -> vec4<f32> {
+    if style.pulse_width <= 0.0 { return c; }
+    let offset = abs(fract(arc_t - time * style.pulse_speed + 0.5) - 0.5);
+    let k = (1.0 - smoothstep(0.0, style.pulse_width * 0.5, offset)) * style.pulse_color.a;
+    return vec4(mix(c.rgb, style.pulse_color.rgb, k), c.a);
+}
+
 // Signed distance of stop `i` (distances are packed 4 per vec4).
 fn stop_dist_at(style: GpuStyle, i: u32) -> f32 {
     return style.stop_dist[i / 4u][i % 4u];
@@ -147,10 +171,10 @@ fn style_max_dist(style: GpuStyle) -> f32 {
 
 // Perpendicular half-reach of a pattern's stroke: the widest distance a feature
 // can occupy ACROSS the contour, independent of `time` and the along-u dash/dot
-// layout (C1). Half thickness for line-like patterns; the dot radius can exceed
-// it for the dotted families.
+// layout (C1). Half thickness (of the wider end, if tapered) for line-like
+// patterns; the dot radius can exceed it for the dotted families.
 fn pattern_perp_reach(style: GpuStyle) -> f32 {
-    let half_t = style.pattern_thickness * 0.5;
+    let half_t = max(style.pattern_thickness, style.pattern_thickness_end) * 0.5;
     switch style.pattern_type {
         case PATTERN_DOTTED: { return max(half_t, style.pattern_param1); }
         case PATTERN_DASH_DOTTED, PATTERN_ARROW_DOTTED: { return max(half_t, style.pattern_param2); }
@@ -411,6 +435,36 @@ fn sd_tiling(p: vec2<f32>, tiling_type: u32, params: vec4<f32>) -> SdfResult {
     }
 }
 
+// Grid lines snapped to the pixel grid: each line's centre moves to the
+// nearest pixel centre (odd widths) or pixel edge (even widths), and its width
+// rounds to whole pixels, at least one. `p * cs` counts pixels from the world
+// origin, which the snapped camera has put on a pixel edge.
+fn sd_grid_snapped(p: vec2<f32>, params: vec4<f32>, cs: f32) -> SdfResult {
+    let q = p * cs;
+    let spacing = params.xy * cs;
+    let width = max(round(params.z * cs), 1.0);
+    let o = 0.5 * (width % 2.0);
+    let line = round(round(q / spacing) * spacing - o) + o;
+    let m = abs(q - line);
+    return SdfResult((min(m.x, m.y) - width * 0.5) / cs, 0.0);
+}
+
+// A tiling entry's field at world `p`, snapped when the draw asks for it.
+fn sd_draw_tiling(p: vec2<f32>, entry: GpuDrawEntry, draw: DrawData) -> SdfResult {
+    if draw.pixel_snap != 0u && entry.tiling_type == TILING_GRID {
+        return sd_grid_snapped(p, entry.tiling_params, draw.camera_zoom * draw.scale_factor);
+    }
+    return sd_tiling(p, entry.tiling_type, entry.tiling_params);
+}
+
+// An entry's placement, rounded to whole pixels when the draw snaps so every
+// instance keeps the world origin's alignment with the pixel grid.
+fn draw_translate(entry: GpuDrawEntry, draw: DrawData) -> vec2<f32> {
+    if draw.pixel_snap == 0u { return entry.translate; }
+    let cs = draw.camera_zoom * draw.scale_factor;
+    return round(entry.translate * cs) / cs;
+}
+
 fn eval_segment(p: vec2<f32>, seg: GpuSegment) -> SdfResult {
     // Segments are stored in the entry's local frame; the caller passes
     // `world_p - entry.translate`. Distance is translation-invariant.
@@ -445,9 +499,14 @@ fn segment_total_arc(seg_idx: u32) -> f32 {
 // Style Rendering
 // ============================================================================
 
+// Antialiasing width in world units: `draw.aa_width` physical pixels.
+fn aa_world_width(draw: DrawData) -> f32 {
+    return draw.aa_width / max(draw.camera_zoom * draw.scale_factor, 1e-6);
+}
+
 // total_arc: total arc-length of the contour (for normalizing u to 0..1)
 fn render_style(sdf: SdfResult, style: GpuStyle, draw: DrawData, total_arc: f32, is_closed: bool) -> vec4<f32> {
-    // Antialiasing half-width, in world units. The contour SDF has |grad|=1 in
+    // Antialiasing width, in world units. The contour SDF has |grad|=1 in
     // world space, so one screen pixel spans `1/(zoom*scale)` world units. We
     // derive the AA band analytically instead of with `fwidth(dist)`: the tile
     // loop above is data-dependent (different tiles per pixel), so a derivative
@@ -455,7 +514,7 @@ fn render_style(sdf: SdfResult, style: GpuStyle, draw: DrawData, total_arc: f32,
     // screen-space derivatives are undefined per the WGSL spec. On some GPUs
     // that produced a 1px seam at every tile boundary; the analytic form is
     // deterministic everywhere.
-    let aa = 1.1 / max(draw.camera_zoom * draw.scale_factor, 1e-6);
+    let aa = aa_world_width(draw);
 
     // Normalize u from world-space to 0..1 for color gradient
     var arc_t = 0.0;
@@ -465,9 +524,9 @@ fn render_style(sdf: SdfResult, style: GpuStyle, draw: DrawData, total_arc: f32,
 
     if (style.flags & STYLE_FLAG_HAS_PATTERN) != 0u {
         // Pattern uses world-space u (sdf.u) for dash layout
-        dist = apply_pattern(dist, sdf, style, draw.time, is_closed);
+        dist = apply_pattern(dist, sdf, style, draw.time, is_closed, arc_t);
 
-        let color = mix(style.stop_start[0], style.stop_end[0], arc_t);
+        let color = apply_pulse(mix(style.stop_start[0], style.stop_end[0], arc_t), arc_t, style, draw.time);
         let alpha = color.a * (1.0 - smoothstep(-aa, aa, dist));
         if alpha < 0.001 { return vec4(0.0); }
         return vec4(color.rgb * alpha, alpha);
@@ -484,10 +543,10 @@ fn render_style(sdf: SdfResult, style: GpuStyle, draw: DrawData, total_arc: f32,
     // shadows/glows where a transparent outer stop meets an opaque one.
     // Premultiplied mixing avoids it. For stops at equal alpha the result is
     // identical to a straight-space mix, so opaque/abutting bands are unchanged.
-    let c0 = mix(style.stop_start[0], style.stop_end[0], arc_t);
+    let c0 = apply_pulse(mix(style.stop_start[0], style.stop_end[0], arc_t), arc_t, style, draw.time);
     var acc = vec4(c0.rgb * c0.a, c0.a);
     for (var i = 0u; i + 1u < style.stop_count; i++) {
-        let cj = mix(style.stop_start[i + 1u], style.stop_end[i + 1u], arc_t);
+        let cj = apply_pulse(mix(style.stop_start[i + 1u], style.stop_end[i + 1u], arc_t), arc_t, style, draw.time);
         let pcj = vec4(cj.rgb * cj.a, cj.a);
         var lo = stop_dist_at(style, i);
         var hi = stop_dist_at(style, i + 1u);
@@ -519,8 +578,10 @@ fn render_style(sdf: SdfResult, style: GpuStyle, draw: DrawData, total_arc: f32,
     return vec4(acc.rgb, alpha);
 }
 
-fn apply_pattern(dist: f32, sdf: SdfResult, style: GpuStyle, time: f32, is_closed: bool) -> f32 {
-    let thickness = style.pattern_thickness;
+// arc_t: normalized arc position (0..1), which a tapered stroke's thickness
+// follows from `pattern_thickness` to `pattern_thickness_end`.
+fn apply_pattern(dist: f32, sdf: SdfResult, style: GpuStyle, time: f32, is_closed: bool, arc_t: f32) -> f32 {
+    let thickness = mix(style.pattern_thickness, style.pattern_thickness_end, arc_t);
     let half_t = thickness * 0.5;
     var u = sdf.u;
     if style.flow_speed != 0.0 { u = u - time * style.flow_speed; }
@@ -687,7 +748,7 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
 
             // Check for tiling marker
             if (raw_seg & TILING_BIT) != 0u {
-                let sdf = sd_tiling(world_p, entry.tiling_type, entry.tiling_params);
+                let sdf = sd_draw_tiling(world_p, entry, draw);
                 let frag = render_style(sdf, style, draw, 0.0, false);
                 acc = acc + frag * (1.0 - acc.a);
                 i++;
@@ -696,7 +757,7 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
 
             // Segments are stored local; shift the eval point by the instance
             // translate. Find the nearest among this entry's consecutive slots.
-            let lp = world_p - entry.translate;
+            let lp = world_p - draw_translate(entry, draw);
             var best_sdf = eval_single_segment(lp, raw_seg);
             var best_abs = abs(best_sdf.dist);
             var best_seg = raw_seg;
@@ -730,11 +791,11 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
             let entry = draw_entries[i];
             let style = styles[entry.style_idx];
             if entry.entry_type == ENTRY_TILING {
-                let sdf = sd_tiling(world_p, entry.tiling_type, entry.tiling_params);
+                let sdf = sd_draw_tiling(world_p, entry, draw);
                 let frag = render_style(sdf, style, draw, 0.0, false);
                 acc = acc + frag * (1.0 - acc.a);
             } else {
-                let lp = world_p - entry.translate;
+                let lp = world_p - draw_translate(entry, draw);
                 // Fold to the NEAREST segment (as the tiled path does) so a
                 // multi-segment entry renders as ONE contour, not N overlapping
                 // per-segment strokes. Without this the untiled fallback double-AAs
@@ -783,16 +844,29 @@ const CULL_SENTINEL: u32 = 0xFFFFFFFFu;
 const TILING_RESERVE: u32 = 4u;
 
 // Style reach band half-width for the cull: the outermost stop distance plus
-// the pattern's perpendicular reach plus the AA safety margin; dashed/arrowed
-// caps tilt with the pattern angle, widening with the tile half-diagonal.
-fn style_reach(style: GpuStyle, tile_thd: f32) -> f32 {
-    var reach = style_max_dist(style) + pattern_perp_reach(style) + 0.5;
+// the pattern's perpendicular reach plus the AA margin (`aa_margin`);
+// dashed/arrowed caps tilt with the pattern angle, widening with the tile
+// half-diagonal.
+fn style_reach(style: GpuStyle, tile_thd: f32, draw: DrawData) -> f32 {
+    var reach = style_max_dist(style) + pattern_perp_reach(style) + aa_margin(draw);
     if style.pattern_type == PATTERN_DASHED || style.pattern_type == PATTERN_ARROWED {
         reach = reach + tile_thd * abs(tan(style.pattern_param2));
     }
     return reach;
 }
 
+// How far past a band's edge its antialiasing fade reaches, in world units:
+// the AA width, and never less than the historic half-unit margin. Pixel
+// snapping moves contours up to half a pixel and widens grid lines to a
+// whole one, so it adds a pixel.
+fn aa_margin(draw: DrawData) -> f32 {
+    var margin = max(aa_world_width(draw), 0.5);
+    if draw.pixel_snap != 0u {
+        margin = margin + 1.0 / max(draw.camera_zoom * draw.scale_factor, 1e-6);
+    }
+    return margin;
+}
+
 // Conservative bbox (min.xy, max.xy) of one segment in its LOCAL frame.
 // Line/point: endpoint box. Arc: bbox of the 30-degree sub-chord endpoints
 // inflated by the per-piece sagitta (the split of `arc_box_interval`), so the
@@ -1106,7 +1180,7 @@ fn cs_scatter_open(
 
     let inv_cs = 1.0 / (draw.camera_zoom * draw.scale_factor);
     let coarse_thd = TILE_SIZE * f32(COARSE_FACTOR) * 0.70710678 * inv_cs;
-    let reach = style_reach(style, coarse_thd);
+    let reach = style_reach(style, coarse_thd, draw);
 
     let seg = cs_segments[seg_idx];
     let bb = seg_bbox(seg);
@@ -1163,7 +1237,7 @@ fn cs_scatter_closed(
 
     let inv_cs = 1.0 / (draw.camera_zoom * draw.scale_factor);
     let coarse_thd = TILE_SIZE * f32(COARSE_FACTOR) * 0.70710678 * inv_cs;
-    let reach = style_reach(style, coarse_thd);
+    let reach = style_reach(style, coarse_thd, draw);
     let lo = wg_bbox.xy + entry.translate - vec2(reach, reach);
     let hi = wg_bbox.zw + entry.translate + vec2(reach, reach);
     let r = coarse_range(draw, lo, hi);
@@ -1275,7 +1349,7 @@ fn cs_sort_fine(
             let style = cs_styles[entry.style_idx];
             let td = tiling_box_dist(entry.tiling_type, entry.tiling_params,
                 coarse_center, coarse_half);
-            if td - coarse_thd <= style_max_dist(style) + 0.5 {
+            if td - coarse_thd <= style_max_dist(style) + aa_margin(draw) {
                 wg_cseg[cnt] = te | TILING_BIT;
                 wg_centry[cnt] = te;
                 cnt++;
@@ -1355,7 +1429,7 @@ fn cs_sort_fine(
 
         if (raw & TILING_BIT) != 0u {
             let td = tiling_box_dist(entry.tiling_type, entry.tiling_params, fworld, fhalf);
-            if td - thd <= style_max_dist(style) + 0.5 {
+            if td - thd <= style_max_dist(style) + aa_margin(draw) {
                 freplaced = fine_push(fine_base, &fcount, &fdist, j, td) || freplaced;
             }
             j++;
@@ -1365,7 +1439,7 @@ fn cs_sort_fine(
         let is_closed = (entry.flags & FLAG_CLOSED) != 0u;
         let bmin = fworld - fhalf - entry.translate;
         let bmax = fworld + fhalf - entry.translate;
-        let reach = style_reach(style, thd);
+        let reach = style_reach(style, thd, draw);
 
         // The entry's contiguous run in the coarse list.
         var k = j;
@@ -145,8 +145,18 @@ pub(crate) struct GpuStyle {
     pub transfer_type: u32,
     /// Transfer parameter (gamma exponent when `transfer_type == 2`).
     pub transfer_param: f32,
-    pub _transfer_pad0: u32,
-    pub _transfer_pad1: u32,
+    /// Pulse band speed in laps (arc 0..1) per second.
+    pub pulse_speed: f32,
+    /// Pulse band width in arc-length; 0 = no band.
+    pub pulse_width: f32,
+    /// Pulse band colour.
+    pub pulse_color: GpuVec4,
+    /// Pattern stroke thickness at arc 1 (equal to `pattern_thickness`
+    /// unless the pattern tapers).
+    pub pattern_thickness_end: f32,
+    pub _taper_pad0: u32,
+    pub _taper_pad1: u32,
+    pub _taper_pad2: u32,
 }
 
 /// Per-draw-call parameters.
@@ -183,6 +193,13 @@ pub(crate) struct DrawData {
     /// compute stage must stay within the WebGPU spec-default 8 storage
     /// buffers per stage for wasm).
     pub tilings: [u32; 4],
+    /// Antialiasing width in physical pixels.
+    pub aa_width: f32,
+    /// Nonzero snaps placements and grid lines to the pixel grid.
+    pub pixel_snap: u32,
+    pub _pad0: u32,
+    pub _pad1: u32,
+    pub _pad2: u32,
 }
 
 // --- Defaults ---
@@ -235,8 +252,13 @@ impl Default for GpuStyle {
             flow_speed: 0.0,
             transfer_type: 0,
             transfer_param: 0.0,
-            _transfer_pad0: 0,
-            _transfer_pad1: 0,
+            pulse_speed: 0.0,
+            pulse_width: 0.0,
+            pulse_color: GpuVec4::ZERO,
+            pattern_thickness_end: 1.0,
+            _taper_pad0: 0,
+            _taper_pad1: 0,
+            _taper_pad2: 0,
         }
     }
 }
@@ -258,6 +280,11 @@ impl Default for DrawData {
             coarse_rows: 0,
             coarse_base: 0,
             tilings: [u32::MAX; 4],
+            aa_width: crate::primitive::DEFAULT_AA_WIDTH,
+            pixel_snap: 0,
+            _pad0: 0,
+            _pad1: 0,
+            _pad2: 0,
         }
     }
 }
@@ -325,6 +352,11 @@ pub struct SdfStats {
     /// of the scatter cull). Zero in healthy scenes; nonzero is the evidence
     /// that exact slot allocation is needed.
     pub coarse_overflow_tiles: u32,
+    /// True when a tiling background came from the background cache this
+    /// frame (populated or blitted) instead of being shaded in place: the
+    /// pattern, zoom and size matched the previous frame, so a pan cost a
+    /// texture offset.
+    pub background_cached: bool,
 }
 
 #[cfg(test)]
@@ -5,7 +5,7 @@
 //! a fullscreen triangle that reads the index for per-tile evaluation.
 
 use std::sync::Arc;
-use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 
 use parking_lot::Mutex;
 use web_time::Instant;
@@ -25,9 +25,10 @@ use crate::compile::{
 };
 use crate::pattern::PatternType;
 use crate::pipeline::arena::ArenaAlloc;
+use crate::pipeline::background::{self, BackgroundCache, CacheMode};
 use crate::pipeline::overflow::OverflowProbe;
 use crate::pipeline::{buffer, types};
-use crate::shape::{Shape, ShapeCache};
+use crate::shape::{Shape, ShapeCache, ShapeExpr};
 use crate::shared::SharedSdfResources;
 use crate::style::{Style, Transfer};
 
@@ -47,6 +48,7 @@ static LAST_STATS: Mutex<types::SdfStats> = Mutex::new(types::SdfStats {
     arena_compactions: 0,
     coarse_demand_max: 0,
     coarse_overflow_tiles: 0,
+    background_cached: false,
 });
 
 /// Read performance statistics from the last completed frame.
@@ -83,6 +85,10 @@ const RESIDENT_MAX_AGE: u64 = 8;
 const COMPACT_MIN_HIGH_WATER: u32 = 4096;
 const COMPACT_SLACK_FACTOR: u32 = 4;
 
+/// Default antialiasing width in physical pixels: a touch over one pixel, so
+/// an edge's fade always covers the pixel it crosses.
+pub const DEFAULT_AA_WIDTH: f32 = 1.1;
+
 /// One queued draw: a position-free [`Shape`] (evaluated once by the pipeline's
 /// frame-surviving `ShapeCache` when cacheable) placed at world `placement` (the
 /// per-instance translate, excluded from the cache key), with its band `style`.
@@ -100,6 +106,10 @@ pub struct SdfPrimitive {
     pub camera_position: (f32, f32),
     pub camera_zoom: f32,
     pub time: f32,
+    /// Antialiasing width in physical pixels (see [`Self::aa_width`]).
+    pub aa_width: f32,
+    /// Whether contours snap to the pixel grid (see [`Self::pixel_snap`]).
+    pub pixel_snap: bool,
     /// The `DrawData` slot this primitive was assigned in `prepare`, stored on the
     /// primitive itself rather than derived from draw order. iced PREPARES every
     /// queued instance but SKIPS drawing those whose bounds snap empty or fall off
@@ -107,6 +117,10 @@ pub struct SdfPrimitive {
     /// wrong slot (wrong camera/tiles -> misrendered fill, missing border/pins).
     /// Interior-mutable because `Primitive::prepare` takes `&self`.
     draw_slot: AtomicU32,
+    /// Set in `prepare` when this primitive comes from the pipeline's
+    /// background cache this frame; `draw` then blits the cached texture
+    /// instead of shading the slot.
+    cached: AtomicBool,
 }
 
 impl Clone for SdfPrimitive {
@@ -116,7 +130,10 @@ impl Clone for SdfPrimitive {
             camera_position: self.camera_position,
             camera_zoom: self.camera_zoom,
             time: self.time,
+            aa_width: self.aa_width,
+            pixel_snap: self.pixel_snap,
             draw_slot: AtomicU32::new(self.draw_slot.load(Ordering::Relaxed)),
+            cached: AtomicBool::new(self.cached.load(Ordering::Relaxed)),
         }
     }
 }
@@ -128,7 +145,10 @@ impl SdfPrimitive {
             camera_position: (0.0, 0.0),
             camera_zoom: 1.0,
             time: 0.0,
+            aa_width: DEFAULT_AA_WIDTH,
+            pixel_snap: false,
             draw_slot: AtomicU32::new(0),
+            cached: AtomicBool::new(false),
         }
     }
 
@@ -166,6 +186,27 @@ impl SdfPrimitive {
         self.time = time;
         self
     }
+
+    /// Sets the antialiasing width in physical pixels: roughly how many
+    /// pixels each edge fades over, the same on screen at any zoom. Larger
+    /// values soften edges, `0` gives hard aliased edges. Defaults to
+    /// [`DEFAULT_AA_WIDTH`].
+    pub fn aa_width(mut self, px: f32) -> Self {
+        self.aa_width = px.max(0.0);
+        self
+    }
+
+    /// Snaps the world origin and every placement to whole physical pixels,
+    /// and grid tiling lines to pixel centres with a whole-pixel width, so
+    /// 1px lines render crisp instead of smeared across two pixels. Other
+    /// contours land on pixel edges when their world coordinates are whole
+    /// pixels apart (whole-number zoom times DPI scale). Off by default:
+    /// snapped content moves in whole-pixel steps, which shimmers under a
+    /// slow animated pan.
+    pub fn pixel_snap(mut self, snap: bool) -> Self {
+        self.pixel_snap = snap;
+        self
+    }
     pub fn is_empty(&self) -> bool {
         self.entries.is_empty()
     }
@@ -184,6 +225,25 @@ impl SdfPrimitive {
         self.draw_slot.load(Ordering::Relaxed)
     }
 
+    /// The common period of a primitive made only of tilings, which the
+    /// background cache can pan by texture offset (see
+    /// `pipeline/background.rs`). `None` for anything else: other shapes,
+    /// tilings that repeat at different periods, or animated styles, whose
+    /// pixels change with time rather than only with the camera.
+    fn background_period(&self) -> Option<[f32; 2]> {
+        let mut period = None;
+        for e in &self.entries {
+            let ShapeExpr::Tiling(tiling) = e.shape.expr() else {
+                return None;
+            };
+            if e.style.is_animated() || period.is_some_and(|p| p != tiling.period()) {
+                return None;
+            }
+            period = Some(tiling.period());
+        }
+        period
+    }
+
     /// Hashes everything that determines this primitive's COMPILED geometry buffers
     /// (each entry's shape, world placement and style) - but NOT the camera, time or
     /// debug flags, which live in `DrawData` and never touch the segment/entry/style
@@ -236,6 +296,13 @@ impl KeyHasher {
     }
 }
 
+/// `camera` nudged so the world origin falls on a whole physical pixel of a
+/// draw whose bounds start at physical `origin`, for
+/// [`SdfPrimitive::pixel_snap`]. `cs` is zoom times DPI scale.
+fn snap_camera(camera: [f32; 2], origin: [f32; 2], cs: f32) -> [f32; 2] {
+    [0, 1].map(|i| ((origin[i] + camera[i] * cs).round() - origin[i]) / cs)
+}
+
 /// Folds every [`types::DrawData`] field EXCEPT `time` into a key. The spatial
 /// index depends on camera, viewport, grid geometry and entry ranges - but
 /// never on the animation clock (reach bands and tile boxes are
@@ -250,6 +317,8 @@ fn cull_key(d: &types::DrawData) -> u64 {
     h.f32(d.camera_position.0[1]);
     h.f32(d.camera_zoom);
     h.f32(d.scale_factor);
+    h.f32(d.aa_width);
+    h.u32(d.pixel_snap);
     h.u32(d.entry_count);
     h.u32(d.entry_start);
     h.u32(d.grid_cols);
@@ -264,7 +333,7 @@ fn cull_key(d: &types::DrawData) -> u64 {
     h.0
 }
 
-/// Folds a [`Style`]'s geometry-relevant recipe (stops, pattern, transfer, df) into
+/// Folds a [`Style`]'s geometry-relevant recipe (stops, pattern, transfer, pulse, df) into
 /// `h`. Used by [`SdfPrimitive::geometry_hash`] to detect whether a primitive's
 /// compiled output would differ from last frame - so the camera/time are NOT here.
 fn hash_style_into(h: &mut KeyHasher, s: &Style) {
@@ -282,11 +351,21 @@ fn hash_style_into(h: &mut KeyHasher, s: &Style) {
             h.f32(g);
         }
     }
+    match s.pulse {
+        None => h.u32(0),
+        Some(p) => {
+            h.u32(1);
+            h.color(p.color);
+            h.f32(p.speed);
+            h.f32(p.width);
+        }
+    }
     match &s.pattern {
         None => h.u32(0),
         Some(p) => {
             h.u32(1);
             h.f32(p.thickness);
+            h.f32(p.end_thickness.unwrap_or(p.thickness));
             h.f32(p.flow_speed);
             match p.pattern_type {
                 PatternType::Solid => h.u32(0),
@@ -337,9 +416,9 @@ fn hash_style_into(h: &mut KeyHasher, s: &Style) {
 }
 
 /// Hash a compiled [`types::GpuStyle`] for per-frame style deduplication. Folds
-/// every field that reaches the GPU (stop colours/distances + pattern + transfer)
-/// so byte-identical styles collide and share one slot. Padding is excluded; it
-/// is always zero and carries no rendered state.
+/// every field that reaches the GPU (stop colours/distances, pattern, transfer
+/// and pulse) so byte-identical styles collide and share one slot. Padding is
+/// excluded; it is always zero and carries no rendered state.
 fn hash_gpu_style(s: &types::GpuStyle) -> u64 {
     let mut h = KeyHasher::new();
     for v in s
@@ -362,6 +441,12 @@ fn hash_gpu_style(s: &types::GpuStyle) -> u64 {
     h.f32(s.flow_speed);
     h.u32(s.transfer_type);
     h.f32(s.transfer_param);
+    h.f32(s.pulse_speed);
+    h.f32(s.pulse_width);
+    h.f32(s.pattern_thickness_end);
+    for &c in s.pulse_color.as_ref() {
+        h.f32(c);
+    }
     h.0
 }
 
@@ -578,6 +663,13 @@ pub struct SdfPipeline {
     /// Most recent completed demand readback: (max per-tile demand, tiles over
     /// the usable cap). Sticky between culls; reported in [`types::SdfStats`].
     coarse_demand: (u32, u32),
+    /// Render target format, for the background cache texture (which the
+    /// shared render pipeline draws into when populating it).
+    format: TextureFormat,
+    max_texture_dim: u32,
+    /// Frame-surviving texture of the last tiling-only background, panned by
+    /// UV offset (see [`background`]). Survives `trim`.
+    background: BackgroundCache,
 }
 
 /// The two-level index buffers, in bind order for compute group 1:
@@ -887,6 +979,9 @@ impl Pipeline for SdfPipeline {
             cull_dirty: true,
             overflow_probe: Mutex::new(OverflowProbe::new()),
             coarse_demand: (0, 0),
+            format,
+            max_texture_dim: device.limits().max_texture_dimension_2d,
+            background: BackgroundCache::new(device),
         }
     }
 
@@ -920,6 +1015,7 @@ impl Pipeline for SdfPipeline {
         // larger frame's slots.
         self.prev_cull_keys.truncate(self.draw_data_buffer.len());
         self.cull_dirty = false;
+        self.background.end_frame();
         // Per-frame buffers only: entries/segments/styles are arena-resident
         // and survive across frames.
         self.draw_data_buffer.clear();
@@ -1362,6 +1458,7 @@ impl Primitive for SdfPrimitive {
         if self.entries.is_empty() {
             let draw_index = pipeline.draw_data_buffer.len() as u32;
             self.draw_slot.store(draw_index, Ordering::Relaxed);
+            self.cached.store(false, Ordering::Relaxed);
             // Invalidate the slot's scatter record: while this primitive is
             // empty, later slots' scatter ranges pack shifted-down over its
             // resident range, so a later frame with the old content must NOT
@@ -1481,9 +1578,75 @@ impl Primitive for SdfPrimitive {
             pipeline.closed_scratch = closed_batch;
         }
 
+        // Background cache (see `pipeline/background.rs`): a tiling-only
+        // primitive whose pattern, zoom and size repeat last frame's is drawn
+        // from a texture panned by the camera's phase. Its slot then renders
+        // that texture - camera at the origin, no spatial index - rather than
+        // the screen. Every frame of a cacheable background, direct ones
+        // included, shows the phase rounded to whole pixels, so the three
+        // modes agree pixel for pixel.
+        let cs = self.camera_zoom * scale;
+        let origin = [bounds.x * scale, bounds.y * scale];
+        let mut camera = [self.camera_position.0, self.camera_position.1];
+        if self.pixel_snap {
+            camera = snap_camera(camera, origin, cs);
+        }
+        let cache = self.background_period().and_then(|period| {
+            let bounds_px = [
+                (bounds.width * scale).ceil() as u32,
+                (bounds.height * scale).ceil() as u32,
+            ];
+            let size = background::texture_size(
+                bounds_px,
+                [period[0] * cs, period[1] * cs],
+                pipeline.max_texture_dim,
+            )?;
+            let offset = background::phase_offset(camera, period, cs);
+            let (shift, nudge) =
+                background::whole_texel_shift([offset[0] - origin[0], offset[1] - origin[1]], cs);
+            let mut h = KeyHasher::new();
+            h.u64(geom_hash);
+            h.f32(self.camera_zoom);
+            h.f32(scale);
+            h.f32(self.aa_width);
+            h.u32(self.pixel_snap as u32);
+            h.u32(size[0]);
+            h.u32(size[1]);
+            let mode = pipeline.background.prepare(
+                device,
+                queue,
+                &pipeline.shared,
+                pipeline.format,
+                h.0,
+                size,
+                origin,
+                [shift[0] + origin[0], shift[1] + origin[1]],
+                draw_slot as u32,
+            );
+            // A snapped camera already has the world origin on a pixel edge,
+            // which the snapped grid needs, so it is kept as is.
+            let direct_camera = if self.pixel_snap {
+                camera
+            } else {
+                [camera[0] + nudge[0], camera[1] + nudge[1]]
+            };
+            Some((mode, direct_camera))
+        });
+        let cache_mode = cache.map_or(CacheMode::Direct, |(mode, _)| mode);
+        let cached = cache_mode != CacheMode::Direct;
+        self.cached.store(cached, Ordering::Relaxed);
+        pipeline.frame_stats.background_cached |= cached;
+
         let entry_count = self.entries.len() as u32;
-        let camera_pos = types::GpuVec2::new(self.camera_position.0, self.camera_position.1);
-        let grid_origin = types::GpuVec2::new(bounds.x * scale, bounds.y * scale);
+        let (camera_pos, grid_origin) = if cached {
+            (types::GpuVec2::new(0.0, 0.0), types::GpuVec2::new(0.0, 0.0))
+        } else {
+            let camera = cache.map_or(camera, |(_, direct_camera)| direct_camera);
+            (
+                types::GpuVec2::new(camera[0], camera[1]),
+                types::GpuVec2::new(origin[0], origin[1]),
+            )
+        };
         let mut grid_cols = ((bounds.width * scale / TILE_SIZE).ceil() as u32).max(1);
         let mut grid_rows = ((bounds.height * scale / TILE_SIZE).ceil() as u32).max(1);
 
@@ -1496,11 +1659,14 @@ impl Primitive for SdfPrimitive {
         // overlapping primitives, like a pile of nodes stacked into one spot),
         // this draw falls back to grid 0 = "no spatial index, iterate all entries"
         // instead. Slower for that draw, but it renders correctly and never panics.
+        // A cached background takes the same path: its populate evaluates only
+        // tilings, so an index would buy nothing.
         let want_fine = grid_cols as u64 * grid_rows as u64;
         let want_coarse = coarse_cols as u64 * coarse_rows as u64;
         let tile_base;
         let coarse_base;
-        if pipeline.total_fine_tiles as u64 + want_fine > pipeline.max_fine_tiles as u64
+        if cached
+            || pipeline.total_fine_tiles as u64 + want_fine > pipeline.max_fine_tiles as u64
             || pipeline.total_coarse_tiles as u64 + want_coarse > pipeline.max_coarse_tiles as u64
         {
             grid_cols = 0;
@@ -1567,6 +1733,11 @@ impl Primitive for SdfPrimitive {
             coarse_rows,
             coarse_base,
             tilings: dd_tilings,
+            aa_width: self.aa_width,
+            pixel_snap: self.pixel_snap as u32,
+            _pad0: 0,
+            _pad1: 0,
+            _pad2: 0,
         };
         pipeline.note_cull_key(draw_index as usize, cull_key(&dd));
         let _ = pipeline.draw_data_buffer.push(device, queue, dd);
@@ -1654,6 +1825,20 @@ impl Primitive for SdfPrimitive {
         // any deferred background-cache populate, before any primitive is drawn.
         if !pipeline.compute_submitted.swap(true, Ordering::Relaxed) {
             pipeline.run_deferred_compute();
+            if let (Some(device), Some(queue)) = (&pipeline.frame_device, &pipeline.frame_queue) {
+                pipeline.background.populate(
+                    device,
+                    queue,
+                    &pipeline.shared.render_pipeline,
+                    &pipeline.render_group0,
+                );
+            }
+        }
+        if self.cached.load(Ordering::Relaxed) {
+            pipeline
+                .background
+                .record_blit(render_pass, &pipeline.shared);
+            return true;
         }
         // The `DrawData` slot assigned to THIS primitive in `prepare` (not a
         // draw-order counter): iced skips drawing off-viewport instances it still
@@ -1684,4 +1869,17 @@ mod tests {
         p.push(&shape, &s, [0.0, 0.0]);
         assert_eq!(p.entry_count(), 1);
     }
+
+    #[test]
+    fn snapped_camera_puts_the_world_origin_on_a_pixel() {
+        let (origin, cs) = ([10.5, 3.25], 1.5);
+        let camera = snap_camera([7.3, -2.1], origin, cs);
+        for i in 0..2 {
+            let px = origin[i] + camera[i] * cs;
+            assert!((px - px.round()).abs() < 1e-4, "axis {i} at {px}");
+        }
+        // Never moves the origin by more than half a pixel.
+        assert!((camera[0] - 7.3).abs() * cs <= 0.5 + 1e-4);
+        assert!((camera[1] + 2.1).abs() * cs <= 0.5 + 1e-4);
+    }
 }
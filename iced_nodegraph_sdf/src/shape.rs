@@ -25,7 +25,7 @@
 //! a tree walk.
 
 use std::collections::HashMap;
-use std::f32::consts::FRAC_PI_2;
+use std::f32::consts::{FRAC_PI_2, PI, TAU};
 
 use crate::boolean;
 use crate::curve::Curve;
@@ -33,9 +33,10 @@ use crate::drawable::Drawable;
 use crate::tiling::Tiling;
 
 /// A position-free geometry recipe: an expression tree of primitives
-/// (`RoundedBox`, `Circle`, the open strokes `Line`/`Bezier`/`Arc`, the
-/// degenerate `Point`, and `Tiling`) and operations (`Translate`, and the
-/// booleans `Difference`, `Union`, `Intersection`), built in a LOCAL frame.
+/// (`RoundedBox`, `Circle`, `Hexagon`, the open strokes `Line`/`Bezier`/`Polyline`/
+/// `CatmullRom`/`Arc`, the degenerate `Point`, and `Tiling`) and operations
+/// (`Translate`, and the booleans `Difference`, `Union`, `Intersection`), built
+/// in a LOCAL frame.
 /// Every operand of an operation variant is a [`Shape`] (never a bare
 /// `ShapeExpr`), so its already-computed hash is available to fold in
 /// without re-walking the subtree - see [`Shape`] for the incremental
@@ -48,6 +49,9 @@ pub(crate) enum ShapeExpr {
     RoundedBox { size: [f32; 2], radii: [f32; 4] },
     /// Circle centred on the local origin.
     Circle { radius: f32 },
+    /// Flat-topped hexagon filling a `size` box centred on the local origin,
+    /// with its side points at the middle of the left and right edges.
+    Hexagon { size: [f32; 2] },
     /// Open straight segment from `a` to `b` (a stroke, never an interior).
     Line { a: [f32; 2], b: [f32; 2] },
     /// Open cubic bezier (materialised as an arc-spline; a stroke).
@@ -57,6 +61,13 @@ pub(crate) enum ShapeExpr {
         p2: [f32; 2],
         p3: [f32; 2],
     },
+    /// Open polyline through `points` (a stroke).
+    Polyline { points: Vec<[f32; 2]> },
+    /// Open centripetal Catmull-Rom spline through `points` (a stroke).
+    CatmullRom { points: Vec<[f32; 2]> },
+    /// Open chain of cubic bezier spans `[p0, c1, c2, p3]`, each starting where
+    /// the previous one ends (a stroke). See [`Curve::spans`].
+    Spans { spans: Vec<[[f32; 2]; 4]> },
     /// Open circular arc stroke: `sweep` radians of a circle of `radius` about
     /// `center`, starting at angle `start`. Unlike the centred primitives this
     /// carries its `center` directly (like [`ShapeExpr::Line`]).
@@ -75,6 +86,9 @@ pub(crate) enum ShapeExpr {
     /// `inner` shifted by `offset` - an operation like any other, so a pin is
     /// `Shape::circle(r).translate([x, y])`.
     Translate(Box<Shape>, [f32; 2]),
+    /// `inner` rotated about the local origin by the angle in radians
+    /// (clockwise on screen, as y points down).
+    Rotate(Box<Shape>, f32),
     /// `0 - 1`: the second shape subtracted from the first (`a - b`).
     Difference(Box<Shape>, Box<Shape>),
     /// `0 | 1`: the union of two shapes (`a | b`).
@@ -114,7 +128,7 @@ impl Shape {
     /// The recipe tree. `pub(crate)` so `evaluate`/`is_cacheable` (and any
     /// future in-crate consumer) can match on it; never exposed outside the
     /// crate, so external code stays limited to constructors/operators/the
-    /// `hash`/`evaluate`/`is_cacheable`/`translate` methods.
+    /// `hash`/`evaluate`/`is_cacheable`/`translate`/`rotate` methods.
     pub(crate) fn expr(&self) -> &ShapeExpr {
         &self.expr
     }
@@ -146,6 +160,29 @@ impl Shape {
             expr: ShapeExpr::Circle { radius },
         }
     }
+    /// Flat-topped hexagon filling a `size` box centred on the local origin:
+    /// horizontal top and bottom edges, points at the middle of the left and
+    /// right sides. The slanted edges run at 60 degrees, as in a regular
+    /// hexagon, unless the box is too narrow for them, in which case the top
+    /// and bottom shrink to a point.
+    pub fn hexagon(size: impl Into<[f32; 2]>) -> Self {
+        let size = size.into();
+        let mut h = Fnv::new();
+        h.write_u32(OP_HEXAGON);
+        h.write_f32(size[0]);
+        h.write_f32(size[1]);
+        Shape {
+            hash: h.finish(),
+            expr: ShapeExpr::Hexagon { size },
+        }
+    }
+    /// How far the top and bottom edges of a [`hexagon`](Self::hexagon) of
+    /// `size` sit in from its left and right sides: the run of a 60 degree
+    /// edge over half the height, capped at half the width.
+    pub fn hexagon_inset(size: impl Into<[f32; 2]>) -> f32 {
+        let [w, h] = size.into();
+        (h * 0.5 / 3f32.sqrt()).min(w * 0.5).max(0.0)
+    }
     /// Open straight segment from `a` to `b`.
     pub fn line(a: impl Into<[f32; 2]>, b: impl Into<[f32; 2]>) -> Self {
         let a = a.into();
@@ -183,6 +220,32 @@ impl Shape {
             expr: ShapeExpr::Bezier { p0, p1, p2, p3 },
         }
     }
+    /// Open polyline stroke through `points`.
+    pub fn polyline(points: impl IntoIterator<Item = [f32; 2]>) -> Self {
+        let points: Vec<[f32; 2]> = points.into_iter().collect();
+        Shape {
+            hash: hash_points(OP_POLYLINE, &points),
+            expr: ShapeExpr::Polyline { points },
+        }
+    }
+    /// Open stroke along the centripetal Catmull-Rom spline through `points`;
+    /// see [`Curve::catmull_rom_beziers`].
+    pub fn catmull_rom(points: impl IntoIterator<Item = [f32; 2]>) -> Self {
+        let points: Vec<[f32; 2]> = points.into_iter().collect();
+        Shape {
+            hash: hash_points(OP_CATMULL_ROM, &points),
+            expr: ShapeExpr::CatmullRom { points },
+        }
+    }
+    /// Open stroke along a chain of cubic bezier spans; see [`Curve::spans`].
+    pub fn spans(spans: impl IntoIterator<Item = [[f32; 2]; 4]>) -> Self {
+        let spans: Vec<[[f32; 2]; 4]> = spans.into_iter().collect();
+        let points: Vec<[f32; 2]> = spans.iter().flatten().copied().collect();
+        Shape {
+            hash: hash_points(OP_SPANS, &points),
+            expr: ShapeExpr::Spans { spans },
+        }
+    }
     /// Open circular arc stroke: `sweep` radians of a circle of `radius` about
     /// `center`, starting at angle `start`.
     pub fn arc(center: impl Into<[f32; 2]>, radius: f32, start: f32, sweep: f32) -> Self {
@@ -243,6 +306,19 @@ impl Shape {
             expr: ShapeExpr::Translate(Box::new(self), offset),
         }
     }
+    /// This shape rotated about its local origin by `angle` radians
+    /// (clockwise on screen, as y points down). Rotate before translating to
+    /// turn a centred primitive in place.
+    pub fn rotate(self, angle: f32) -> Self {
+        let mut h = Fnv::new();
+        h.write_u32(OP_ROTATE);
+        h.write_f32(angle);
+        h.write_u64(self.hash);
+        Shape {
+            hash: h.finish(),
+            expr: ShapeExpr::Rotate(Box::new(self), angle),
+        }
+    }
 }
 
 impl std::ops::Sub for Shape {
@@ -301,6 +377,24 @@ const OP_INTERSECTION: u32 = 8;
 const OP_TILING: u32 = 9;
 const OP_ARC: u32 = 10;
 const OP_POINT: u32 = 11;
+const OP_POLYLINE: u32 = 12;
+const OP_CATMULL_ROM: u32 = 13;
+const OP_SPANS: u32 = 14;
+const OP_ROTATE: u32 = 15;
+const OP_HEXAGON: u32 = 16;
+
+/// Hash of a point-list primitive: its opcode, the point count, then every
+/// point.
+fn hash_points(op: u32, points: &[[f32; 2]]) -> u64 {
+    let mut h = Fnv::new();
+    h.write_u32(op);
+    h.write_u32(points.len() as u32);
+    for p in points {
+        h.write_f32(p[0]);
+        h.write_f32(p[1]);
+    }
+    h.finish()
+}
 
 /// Canonical bit pattern of an `f32`: `-0.0` collapses to `+0.0` and every NaN
 /// to one quiet NaN, so semantically-equal operands hash equal across platforms.
@@ -348,11 +442,11 @@ impl Shape {
     /// boolean re-stitch (`Difference`/`Union`/`Intersection`) is cached; bare
     /// primitives and open strokes evaluate cheaply and - for edges - change
     /// every frame, so they bypass the frame-surviving cache and never churn its
-    /// LRU. `Translate` inherits its inner shape's cacheability.
+    /// LRU. `Translate` and `Rotate` inherit their inner shape's cacheability.
     pub fn is_cacheable(&self) -> bool {
         match self.expr() {
             ShapeExpr::Difference(..) | ShapeExpr::Union(..) | ShapeExpr::Intersection(..) => true,
-            ShapeExpr::Translate(inner, _) => inner.is_cacheable(),
+            ShapeExpr::Translate(inner, _) | ShapeExpr::Rotate(inner, _) => inner.is_cacheable(),
             _ => false,
         }
     }
@@ -372,8 +466,12 @@ impl Shape {
         match self.expr() {
             ShapeExpr::RoundedBox { size, radii } => eval_rounded_box(*size, *radii),
             ShapeExpr::Circle { radius } => Curve::circle([0.0, 0.0], *radius),
+            ShapeExpr::Hexagon { size } => eval_hexagon(*size),
             ShapeExpr::Line { a, b } => Curve::line(*a, *b),
             ShapeExpr::Bezier { p0, p1, p2, p3 } => Curve::bezier(*p0, *p1, *p2, *p3),
+            ShapeExpr::Polyline { points } => Curve::polyline(points),
+            ShapeExpr::CatmullRom { points } => Curve::catmull_rom(points),
+            ShapeExpr::Spans { spans } => Curve::spans(spans),
             ShapeExpr::Arc {
                 center,
                 radius,
@@ -386,6 +484,7 @@ impl Shape {
                 Drawable::new_tiling(tt, params)
             }
             ShapeExpr::Translate(inner, off) => inner.evaluate().translated(off[0], off[1]),
+            ShapeExpr::Rotate(inner, angle) => inner.evaluate().rotated(*angle),
             ShapeExpr::Difference(_, _) => {
                 // Flatten the left-nested difference chain into base + cuts.
                 let mut cuts = Vec::new();
@@ -426,6 +525,45 @@ fn eval_rounded_box(size: [f32; 2], radii: [f32; 4]) -> Drawable {
         .close()
 }
 
+/// Build a flat-topped hexagon CENTRED on the local origin. The contour walks
+/// clockwise from the top-left corner with a sharp turn at each vertex, like
+/// `Curve::rect`; an edge shrunk to nothing (a box too narrow for 60 degree
+/// sides) is skipped so no zero-length segment is emitted.
+fn eval_hexagon(size: [f32; 2]) -> Drawable {
+    let (x, y) = (size[0] * 0.5, size[1] * 0.5);
+    let inset = Shape::hexagon_inset(size);
+    let vertices = [
+        [-x + inset, -y],
+        [x - inset, -y],
+        [x, 0.0],
+        [x - inset, y],
+        [-x + inset, y],
+        [-x, 0.0],
+    ];
+    // Turtle heading from `a` to `b`: 0 = up, clockwise.
+    let heading = |a: [f32; 2], b: [f32; 2]| (b[0] - a[0]).atan2(a[1] - b[1]);
+    let mut builder = Curve::shape(vertices[0], heading(vertices[0], vertices[1]));
+    let mut current: Option<f32> = None;
+    for (i, &a) in vertices.iter().enumerate() {
+        let b = vertices[(i + 1) % vertices.len()];
+        let length = (b[0] - a[0]).hypot(b[1] - a[1]);
+        if length <= 1e-4 {
+            continue;
+        }
+        let direction = heading(a, b);
+        if let Some(current) = current {
+            // Turn the short way round so the junction bisects the corner.
+            let turn = (direction - current + PI).rem_euclid(TAU) - PI;
+            builder = builder.angle(turn);
+        } else {
+            builder = Curve::shape(a, direction);
+        }
+        builder = builder.line(length);
+        current = Some(direction);
+    }
+    builder.close()
+}
+
 /// One cached, evaluated shape: the expensive local-frame arcs, plus the frame
 /// tick it was last used on (for LRU eviction).
 struct CachedShape {
@@ -570,6 +708,48 @@ mod tests {
         assert_ne!(rect.hash(), circ.hash());
     }
 
+    #[test]
+    fn rotated_box_spins_in_place() {
+        let body = Shape::rounded_box([40.0, 40.0], [0.0; 4]);
+        let diamond = body.clone().rotate(std::f32::consts::FRAC_PI_4);
+        assert_ne!(diamond.hash(), body.hash());
+        assert_eq!(diamond.is_cacheable(), body.is_cacheable());
+        assert!(
+            (node_body() - Shape::circle(2.0))
+                .rotate(0.3)
+                .is_cacheable()
+        );
+
+        // A square turned 45 degrees reaches half its diagonal on each axis.
+        let half_diagonal = 20.0 * std::f32::consts::SQRT_2;
+        for (got, want) in diamond.evaluate().bounds().into_iter().zip([
+            -half_diagonal,
+            -half_diagonal,
+            half_diagonal,
+            half_diagonal,
+        ]) {
+            assert!((got - want).abs() < 1e-3, "{got} vs {want}");
+        }
+    }
+
+    #[test]
+    fn point_list_shapes_hash_by_kind_and_points() {
+        let pts = [[0.0, 0.0], [10.0, 5.0], [20.0, 0.0]];
+        assert_eq!(
+            Shape::catmull_rom(pts).hash(),
+            Shape::catmull_rom(pts).hash()
+        );
+        // Same points, different primitive.
+        assert_ne!(Shape::catmull_rom(pts).hash(), Shape::polyline(pts).hash());
+        // A moved waypoint changes the recipe.
+        let moved = [[0.0, 0.0], [10.0, 6.0], [20.0, 0.0]];
+        assert_ne!(
+            Shape::catmull_rom(pts).hash(),
+            Shape::catmull_rom(moved).hash()
+        );
+        assert!(!Shape::catmull_rom(pts).is_cacheable());
+    }
+
     #[test]
     fn hash_excludes_placement() {
         // The shape is position-free, so two identical bodies hash equal; their
@@ -625,6 +805,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn hexagon_fills_its_box_with_six_sharp_sides() {
+        // A regular hexagon's box: twice the circumradius wide, sqrt(3) tall.
+        let d = Shape::hexagon([100.0, 50.0 * 3f32.sqrt()]).evaluate();
+        assert_eq!(d.segment_count(), 6 + 5, "six sides, five junctions");
+        let b = d.bounds();
+        let expected = [-50.0, -25.0 * 3f32.sqrt(), 50.0, 25.0 * 3f32.sqrt()];
+        for i in 0..4 {
+            assert!(
+                (b[i] - expected[i]).abs() < 1e-3,
+                "bounds differ at {i}: {b:?} vs {expected:?}"
+            );
+        }
+        assert!((Shape::hexagon_inset([100.0, 50.0 * 3f32.sqrt()]) - 25.0).abs() < 1e-4);
+        // Too narrow for 60 degree sides: a diamond, still closed and in the box.
+        let narrow = Shape::hexagon([10.0, 100.0]).evaluate();
+        assert_eq!(narrow.segment_count(), 4 + 3);
+        assert_eq!(Shape::hexagon_inset([10.0, 100.0]), 5.0);
+        assert_ne!(
+            Shape::hexagon([10.0, 10.0]).hash(),
+            Shape::rounded_box([10.0, 10.0], [0.0; 4]).hash()
+        );
+    }
+
     #[test]
     fn arc_and_point_evaluate_to_their_curve_primitives() {
         let arc = Shape::arc([10.0, -5.0], 40.0, -FRAC_PI_2, FRAC_PI_2).evaluate();
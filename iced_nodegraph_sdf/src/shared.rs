@@ -6,12 +6,13 @@ use std::sync::Arc;
 
 use encase::ShaderSize;
 use iced::wgpu::{
-    BindGroupLayout, BindGroupLayoutDescriptor, BindGroupLayoutEntry, BindingType, BlendState,
-    BufferBindingType, ColorTargetState, ColorWrites, ComputePipeline, ComputePipelineDescriptor,
-    Device, FragmentState, FrontFace, MultisampleState, PipelineCompilationOptions, PipelineLayout,
-    PipelineLayoutDescriptor, PolygonMode, PrimitiveState, PrimitiveTopology, RenderPipeline,
-    RenderPipelineDescriptor, ShaderModule, ShaderModuleDescriptor, ShaderSource, ShaderStages,
-    TextureFormat, VertexState,
+    AddressMode, BindGroupLayout, BindGroupLayoutDescriptor, BindGroupLayoutEntry, BindingType,
+    BlendState, BufferBindingType, ColorTargetState, ColorWrites, ComputePipeline,
+    ComputePipelineDescriptor, Device, FilterMode, FragmentState, FrontFace, MultisampleState,
+    PipelineCompilationOptions, PipelineLayout, PipelineLayoutDescriptor, PolygonMode,
+    PrimitiveState, PrimitiveTopology, RenderPipeline, RenderPipelineDescriptor, Sampler,
+    SamplerBindingType, SamplerDescriptor, ShaderModule, ShaderModuleDescriptor, ShaderSource,
+    ShaderStages, TextureFormat, TextureSampleType, TextureViewDimension, VertexState,
 };
 
 use crate::pipeline::types;
@@ -53,6 +54,15 @@ pub(crate) struct SharedSdfResources {
     pub scatter_open_pipeline: ComputePipeline,
     pub scatter_closed_pipeline: ComputePipeline,
     pub sort_fine_pipeline: ComputePipeline,
+    _blit_module: ShaderModule,
+    /// Background-cache blit group 0: placement uniform, cache texture,
+    /// sampler (see `pipeline/background.rs`).
+    pub blit_group_layout: BindGroupLayout,
+    _blit_pipeline_layout: PipelineLayout,
+    pub blit_pipeline: RenderPipeline,
+    /// Bilinear, clamp-to-edge: a fractional camera phase blends neighbouring
+    /// texels instead of snapping the pattern to whole pixels.
+    pub blit_sampler: Sampler,
 }
 
 impl SharedSdfResources {
@@ -129,6 +139,29 @@ impl SharedSdfResources {
         );
         let sort_fine_pipeline = compute("SDF Sort Fine", "cs_sort_fine", &sort_pipeline_layout);
 
+        let blit_module = device.create_shader_module(ShaderModuleDescriptor {
+            label: Some("iced_nodegraph_sdf_background_blit"),
+            source: ShaderSource::Wgsl(std::borrow::Cow::Borrowed(include_str!(
+                "pipeline/background.wgsl"
+            ))),
+        });
+        let blit_group_layout = create_blit_group_layout(device);
+        let blit_pipeline_layout = device.create_pipeline_layout(&PipelineLayoutDescriptor {
+            label: Some("SDF Background Blit Pipeline Layout"),
+            bind_group_layouts: &[&blit_group_layout],
+            ..Default::default()
+        });
+        let blit_pipeline =
+            create_blit_pipeline(device, format, &blit_pipeline_layout, &blit_module);
+        let blit_sampler = device.create_sampler(&SamplerDescriptor {
+            label: Some("sdf_background_sampler"),
+            address_mode_u: AddressMode::ClampToEdge,
+            address_mode_v: AddressMode::ClampToEdge,
+            mag_filter: FilterMode::Nearest,
+            min_filter: FilterMode::Nearest,
+            ..Default::default()
+        });
+
         Self {
             _shader_module: shader_module,
             render_group0_layout,
@@ -142,6 +175,11 @@ impl SharedSdfResources {
             scatter_open_pipeline,
             scatter_closed_pipeline,
             sort_fine_pipeline,
+            _blit_module: blit_module,
+            blit_group_layout,
+            _blit_pipeline_layout: blit_pipeline_layout,
+            blit_pipeline,
+            blit_sampler,
         }
     }
 }
@@ -401,3 +439,85 @@ fn create_render_pipeline(
         cache: None,
     })
 }
+
+/// Background-cache blit group 0: the placement uniform (origin, camera
+/// phase, texture size), the cache texture and its sampler.
+fn create_blit_group_layout(device: &Device) -> BindGroupLayout {
+    use std::num::NonZeroU64;
+    device.create_bind_group_layout(&BindGroupLayoutDescriptor {
+        label: Some("SDF Background Blit Group 0"),
+        entries: &[
+            BindGroupLayoutEntry {
+                binding: 0,
+                visibility: ShaderStages::FRAGMENT,
+                ty: BindingType::Buffer {
+                    ty: BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: Some(NonZeroU64::new(32).unwrap()),
+                },
+                count: None,
+            },
+            BindGroupLayoutEntry {
+                binding: 1,
+                visibility: ShaderStages::FRAGMENT,
+                ty: BindingType::Texture {
+                    sample_type: TextureSampleType::Float { filterable: true },
+                    view_dimension: TextureViewDimension::D2,
+                    multisampled: false,
+                },
+                count: None,
+            },
+            BindGroupLayoutEntry {
+                binding: 2,
+                visibility: ShaderStages::FRAGMENT,
+                ty: BindingType::Sampler(SamplerBindingType::Filtering),
+                count: None,
+            },
+        ],
+    })
+}
+
+/// The cache texture already holds premultiplied colour, so the blit blends
+/// exactly like the SDF render pipeline it stands in for.
+fn create_blit_pipeline(
+    device: &Device,
+    format: TextureFormat,
+    layout: &PipelineLayout,
+    module: &ShaderModule,
+) -> RenderPipeline {
+    device.create_render_pipeline(&RenderPipelineDescriptor {
+        label: Some("SDF Background Blit Pipeline"),
+        layout: Some(layout),
+        vertex: VertexState {
+            module,
+            entry_point: Some("vs_blit"),
+            buffers: &[],
+            compilation_options: PipelineCompilationOptions::default(),
+        },
+        primitive: PrimitiveState {
+            topology: PrimitiveTopology::TriangleList,
+            front_face: FrontFace::Ccw,
+            cull_mode: None,
+            polygon_mode: PolygonMode::Fill,
+            ..Default::default()
+        },
+        depth_stencil: None,
+        multisample: MultisampleState {
+            count: 1,
+            mask: !0,
+            alpha_to_coverage_enabled: false,
+        },
+        fragment: Some(FragmentState {
+            module,
+            entry_point: Some("fs_blit"),
+            targets: &[Some(ColorTargetState {
+                format,
+                blend: Some(BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+                write_mask: ColorWrites::ALL,
+            })],
+            compilation_options: PipelineCompilationOptions::default(),
+        }),
+        multiview: None,
+        cache: None,
+    })
+}
@@ -75,6 +75,21 @@ pub enum Transfer {
     Gamma(f32),
 }
 
+/// A bright band travelling along the arc-length axis, mixed over the style's
+/// colours. Positions are parametric (arc 0..1), so the band takes the same
+/// time to cross a short contour as a long one, and wraps from arc 1 back to 0.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pulse {
+    /// Band colour at its centre; its alpha scales how strongly it covers
+    /// the style's own colours.
+    pub color: Color,
+    /// Laps per second: arc-length travelled per second of animation time.
+    /// Negative runs from arc 1 toward 0; 0 holds the band still.
+    pub speed: f32,
+    /// Band width in arc-length (0..1). 0 disables the band.
+    pub width: f32,
+}
+
 /// Rendering style: a distance-stop chain + optional pattern.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Style {
@@ -85,6 +100,8 @@ pub struct Style {
     /// Color-domain warp on the stop-blend parameter. Defaults to
     /// [`Transfer::Linear`] (identity).
     pub transfer: Transfer,
+    /// Optional band travelling along the arc. See [`Style::pulse`].
+    pub pulse: Option<Pulse>,
 }
 
 impl Style {
@@ -103,6 +120,7 @@ impl Style {
             stops: vec![Stop::new(0.0, color)],
             pattern: Some(pattern),
             transfer: Transfer::Linear,
+            pulse: None,
         }
     }
 
@@ -120,6 +138,7 @@ impl Style {
             stops: vec![Stop::grad(0.0, start, end)],
             pattern: Some(pattern),
             transfer: Transfer::Linear,
+            pulse: None,
         }
     }
 
@@ -149,6 +168,7 @@ impl Style {
             stops: vec![Stop::grad(0.0, start, end)],
             pattern: Some(pattern),
             transfer: Transfer::Linear,
+            pulse: None,
         }
     }
 
@@ -184,6 +204,18 @@ impl Style {
         self
     }
 
+    /// Run a [`Pulse`] band of `color` along the arc at `speed` laps per
+    /// second, `width` wide in arc-length. The band animates with the
+    /// primitive's time, like a flowing [`Pattern`].
+    pub fn pulse(mut self, color: Color, speed: f32, width: f32) -> Self {
+        self.pulse = Some(Pulse {
+            color,
+            speed,
+            width,
+        });
+        self
+    }
+
     /// Replace the chain with a clipped band `[from, to]` of the current first
     /// color: transparent outside the band, antialiased at both edges.
     pub fn dist_range(mut self, from: f32, to: f32) -> Self {
@@ -211,12 +243,14 @@ impl Style {
             stops,
             pattern: None,
             transfer: Transfer::Linear,
+            pulse: None,
         }
     }
 
     /// Whether this style has active animations.
     pub fn is_animated(&self) -> bool {
         self.pattern.as_ref().is_some_and(|p| p.is_animated())
+            || self.pulse.is_some_and(|p| p.speed != 0.0 && p.width > 0.0)
     }
 
     /// Whether this style fills the interior (no pattern, opaque innermost stop).
@@ -236,7 +270,10 @@ impl Style {
     /// shape, so the larger magnitude bound applies. A pattern adds its half
     /// thickness.
     pub fn extent(&self, closed: bool) -> f32 {
-        let pat = self.pattern.as_ref().map_or(0.0, |p| p.thickness * 0.5);
+        let pat = self
+            .pattern
+            .as_ref()
+            .map_or(0.0, |p| p.max_thickness() * 0.5);
         let max_d = self.stops.iter().map(|s| s.dist).fold(0.0_f32, f32::max);
         let min_d = self.stops.iter().map(|s| s.dist).fold(0.0_f32, f32::min);
         if closed {
@@ -251,6 +288,15 @@ impl Style {
 mod tests {
     use super::*;
 
+    #[test]
+    fn pulse_animates_only_when_moving_and_visible() {
+        let base = Style::stroke(Color::WHITE, Pattern::solid(2.0));
+        assert!(!base.is_animated());
+        assert!(base.clone().pulse(Color::WHITE, 0.5, 0.2).is_animated());
+        assert!(!base.clone().pulse(Color::WHITE, 0.0, 0.2).is_animated());
+        assert!(!base.pulse(Color::WHITE, 0.5, 0.0).is_animated());
+    }
+
     #[test]
     fn solid_is_fill() {
         let s = Style::solid(Color::WHITE);
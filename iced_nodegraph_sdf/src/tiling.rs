@@ -55,6 +55,29 @@ impl Tiling {
         Tiling::Hex { spacing, thickness }
     }
 
+    /// The smallest axis-aligned translation, in world units, that maps the
+    /// pattern onto itself: shifting the camera by a multiple of it leaves
+    /// every pixel unchanged, which is what lets the background cache pan by
+    /// texture offset alone. Triangles and hexes repeat along oblique axes
+    /// too; only the rectangular period is reported.
+    pub(crate) fn period(self) -> [f32; 2] {
+        const SQRT_3: f32 = 1.732_050_8;
+        match self {
+            Tiling::Grid {
+                spacing_x,
+                spacing_y,
+                ..
+            }
+            | Tiling::Dots {
+                spacing_x,
+                spacing_y,
+                ..
+            } => [spacing_x, spacing_y],
+            Tiling::Triangles { spacing, .. } => [spacing, spacing * SQRT_3],
+            Tiling::Hex { spacing, .. } => [spacing * SQRT_3, spacing],
+        }
+    }
+
     /// The GPU tiling type and its four packed params (the form the shader reads).
     pub(crate) fn to_gpu(self) -> (TilingType, [f32; 4]) {
         match self {